@@ -0,0 +1,337 @@
+//! Compares runs of the arena based [`Organism`] with runs of a replica of the previous
+//! organism, which linked its substrates and receptors by reference counted pointers and
+//! scheduled receptors in an [`ActionChain`]. Every run of the previous organism required a
+//! fresh translation, while the arena based [`Organism`] is translated once and cloned for
+//! every run. The replica neither handles feedback nor checks the organism size, so its
+//! timings are a lower bound of the previous implementation.
+//! Run with `cargo bench --bench organism`.
+//!
+//! [`Organism`]: oben::evolution::population::Organism
+
+use std::cell::RefCell;
+use std::hint::black_box;
+use std::num::NonZeroU32;
+use std::rc::{Rc, Weak};
+use std::time::{Duration, Instant};
+
+use oben::evolution::chemistry::{Information, Reaction, State};
+use oben::evolution::environment::{Environment, EnvironmentBuilder};
+use oben::evolution::helper::{ActionChain, Iteration, Nlbf64};
+use oben::evolution::neuron::{
+    random_genome, SimpleDendriteActivationPotential, SimpleDendriteThreshold, SimpleNeuron,
+    SimpleNeuronParameterInputSensor, SimpleNeuronParameterOutputSensor,
+};
+use oben::evolution::population::Organism;
+
+/// The number of runs per measurement.
+const RUNS: u32 = 20;
+
+/// The number of input substrates of the random networks.
+const INPUTS: u32 = 16;
+
+/// The [`Organism`] of a random neuronal network.
+///
+/// [`Organism`]: oben::evolution::population::Organism
+type NeuronalOrganism = Organism<
+    SimpleDendriteActivationPotential,
+    SimpleDendriteThreshold,
+    SimpleNeuron,
+    Vec<Nlbf64>,
+    SimpleNeuronParameterInputSensor,
+    Vec<Nlbf64>,
+    SimpleNeuronParameterOutputSensor,
+>;
+
+/// A substrate of the previous organism, which references the receptors detecting it.
+struct RcSubstrate<R, S, T> {
+    value: T,
+    last_updated: Iteration,
+    receptors: Vec<Rc<RcReceptor<R, S, T>>>,
+}
+
+impl<R, S, T: Information> RcSubstrate<R, S, T> {
+    /// Returns the value of the substrate at the specified timepoint.
+    fn value(&mut self, time: Iteration) -> &T {
+        if time != self.last_updated {
+            let time_passed = time - self.last_updated;
+            self.value.update_value(time_passed);
+            self.last_updated = time;
+        }
+        &self.value
+    }
+}
+
+/// A weak reference to a substrate of the previous organism.
+type SubstrateReference<R, S, T> = Weak<RefCell<RcSubstrate<R, S, T>>>;
+
+/// A receptor of the previous organism including its catalytic centre.
+struct RcReceptor<R, S, T> {
+    substrates: Vec<SubstrateReference<R, S, T>>,
+    state: S,
+    educts: Vec<SubstrateReference<R, S, T>>,
+    products: Vec<SubstrateReference<R, S, T>>,
+    reaction: R,
+}
+
+impl<R: Reaction<T>, S: State<T>, T: Information> RcReceptor<R, S, T> {
+    /// Returns `true` if the state of the receptor is detected at the specified timepoint.
+    fn detect(&self, time: Iteration) -> bool {
+        let strong: Vec<_> = self
+            .substrates
+            .iter()
+            .map(|weak| weak.upgrade().unwrap())
+            .collect();
+        let mut substrates: Vec<_> = strong.iter().map(|sub| sub.borrow_mut()).collect();
+        let values: Vec<&T> = substrates.iter_mut().map(|sub| sub.value(time)).collect();
+        self.state.detect(&values, time)
+    }
+
+    /// Performs the reaction at the specified timepoint and returns the receptors detecting
+    /// the products.
+    fn catalyse(&self, time: Iteration) -> Vec<Rc<RcReceptor<R, S, T>>> {
+        let strong: Vec<_> = self
+            .educts
+            .iter()
+            .map(|weak| weak.upgrade().unwrap())
+            .collect();
+        let mut educts: Vec<_> = strong.iter().map(|sub| sub.borrow_mut()).collect();
+        let values: Vec<&T> = educts.iter_mut().map(|sub| sub.value(time)).collect();
+        let mut product_values = self.reaction.react(&values, time);
+        drop(educts);
+        for product in &self.products {
+            product.upgrade().unwrap().borrow_mut().value = product_values.remove(0);
+        }
+        self.products
+            .iter()
+            .flat_map(|product| product.upgrade().unwrap().borrow().receptors.clone())
+            .collect()
+    }
+}
+
+impl<R: Reaction<T>, S: State<T>, T: Information> PartialEq for RcReceptor<R, S, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+            && self.reaction == other.reaction
+            && [
+                (&self.substrates, &other.substrates),
+                (&self.educts, &other.educts),
+                (&self.products, &other.products),
+            ]
+            .iter()
+            .all(|(a, b)| a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| a.ptr_eq(b)))
+    }
+}
+
+/// The previous organism, which owns its substrates by reference counted pointers.
+struct RcOrganism<R, S, T> {
+    substrates: Vec<Rc<RefCell<RcSubstrate<R, S, T>>>>,
+    input: Vec<usize>,
+}
+
+impl<R: Reaction<T>, S: State<T>, T: Information> RcOrganism<R, S, T> {
+    /// Runs all receptors detecting the input until no receptor is scheduled anymore or
+    /// the lifespan expired and returns the number of catalyses.
+    fn live(&self, environment: &Environment) -> usize {
+        let birth = Instant::now();
+        let mut actions: ActionChain<Rc<RcReceptor<R, S, T>>> = ActionChain::new();
+        let mut receptors_fired = 0;
+        for id in &self.input {
+            for receptor in &self.substrates[*id].borrow().receptors {
+                actions.push_action(receptor.clone());
+            }
+        }
+        while !actions.is_empty() && birth.elapsed() < environment.lifespan() {
+            let time = actions.current_iteration();
+            for receptor in actions.pop_actions() {
+                if receptor.detect(time) {
+                    receptors_fired += 1;
+                    for cascading_receptor in receptor.catalyse(time) {
+                        actions.push_action(cascading_receptor);
+                    }
+                }
+            }
+        }
+        receptors_fired
+    }
+}
+
+/// Links the substrates and receptors of the specified [`Organism`] by reference counted
+/// pointers as the previous translation did.
+///
+/// # Parameters
+///
+/// * `organism` - the [`Organism`] to replicate
+///
+/// [`Organism`]: oben::evolution::population::Organism
+fn translate(
+    organism: &NeuronalOrganism,
+) -> RcOrganism<SimpleDendriteActivationPotential, SimpleDendriteThreshold, SimpleNeuron> {
+    let substrates: Vec<_> = organism
+        .substrates()
+        .iter()
+        .map(|substrate| {
+            Rc::new(RefCell::new(RcSubstrate {
+                value: *substrate.last_value(),
+                last_updated: organism.time_alive(),
+                receptors: Vec::new(),
+            }))
+        })
+        .collect();
+    let references = |ids: &[usize]| -> Vec<_> {
+        ids.iter()
+            .map(|id| Rc::downgrade(&substrates[*id]))
+            .collect()
+    };
+    for receptor in organism.receptors() {
+        let rc_receptor = Rc::new(RcReceptor {
+            substrates: references(receptor.substrates()),
+            state: *receptor.state(),
+            educts: references(receptor.enzyme().educts()),
+            products: references(receptor.enzyme().products()),
+            reaction: *receptor.enzyme().reaction(),
+        });
+        for id in receptor.substrates() {
+            substrates[*id]
+                .borrow_mut()
+                .receptors
+                .push(rc_receptor.clone());
+        }
+    }
+    let input = organism
+        .input_sensor()
+        .input_substrates()
+        .iter()
+        .flatten()
+        .copied()
+        .collect();
+    RcOrganism { substrates, input }
+}
+
+/// The mean timings of a single run.
+struct Timing {
+    /// The time to prepare the organism for the run.
+    preparation: Duration,
+    /// The time of the run itself.
+    run: Duration,
+    /// The number of catalyses during the run.
+    receptors_fired: usize,
+}
+
+impl Timing {
+    /// Returns the mean time per catalysis.
+    fn per_catalysis(&self) -> Duration {
+        self.run / (self.receptors_fired.max(1) as u32)
+    }
+}
+
+/// Returns the mean timing of cloning the translated [`Organism`] and running the clone.
+///
+/// # Parameters
+///
+/// * `organism` - the translated [`Organism`]
+/// * `input` - the input of every run
+/// * `environment` - the [`Environment`] of the runs
+///
+/// [`Environment`]: oben::evolution::environment::Environment
+/// [`Organism`]: oben::evolution::population::Organism
+fn measure_arena(
+    organism: &NeuronalOrganism,
+    input: &[Nlbf64],
+    environment: &Environment,
+) -> Timing {
+    let mut timing = Timing {
+        preparation: Duration::ZERO,
+        run: Duration::ZERO,
+        receptors_fired: 0,
+    };
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        let mut copy = organism.clone();
+        copy.set_input(input.to_vec());
+        timing.preparation += start.elapsed();
+        let start = Instant::now();
+        let outcome = black_box(copy.live(environment));
+        timing.run += start.elapsed();
+        timing.receptors_fired += outcome.receptors_fired();
+    }
+    timing.preparation /= RUNS;
+    timing.run /= RUNS;
+    timing.receptors_fired /= RUNS as usize;
+    timing
+}
+
+/// Returns the mean timing of translating the previous organism and running it.
+///
+/// # Parameters
+///
+/// * `organism` - the translated [`Organism`] with the input of every run already set
+/// * `environment` - the [`Environment`] of the runs
+///
+/// [`Environment`]: oben::evolution::environment::Environment
+/// [`Organism`]: oben::evolution::population::Organism
+fn measure_rc(organism: &NeuronalOrganism, environment: &Environment) -> Timing {
+    let mut timing = Timing {
+        preparation: Duration::ZERO,
+        run: Duration::ZERO,
+        receptors_fired: 0,
+    };
+    for _ in 0..RUNS {
+        let start = Instant::now();
+        let replica = translate(organism);
+        timing.preparation += start.elapsed();
+        let start = Instant::now();
+        timing.receptors_fired += black_box(replica.live(environment));
+        timing.run += start.elapsed();
+    }
+    timing.preparation /= RUNS;
+    timing.run /= RUNS;
+    timing.receptors_fired /= RUNS as usize;
+    timing
+}
+
+fn main() {
+    let environment = EnvironmentBuilder::new()
+        .lifespan(Duration::from_millis(50))
+        .constant_organism_size(true)
+        .build()
+        .unwrap();
+    let input = vec![Nlbf64::from(1.0); INPUTS as usize];
+    println!(
+        "{:>8} {:>9} {:>12} {:>10} {:>10} {:>12} {:>10} {:>10}",
+        "neurons",
+        "dendrites",
+        "arena prep",
+        "arena cat",
+        "arena/cat",
+        "Rc prep",
+        "Rc cat",
+        "Rc/cat"
+    );
+    for neurons in [1_000, 4_000, 16_000] {
+        let dendrites = neurons * 2;
+        let mut genome = random_genome(
+            NonZeroU32::new(neurons).unwrap(),
+            NonZeroU32::new(dendrites).unwrap(),
+            INPUTS,
+            1,
+        );
+        // The replica cannot finish early, so neither may the arena based organism.
+        genome.output_mut().set_finish_substrate(None);
+        let organism = genome.translate();
+        let arena = measure_arena(&organism, &input, &environment);
+        let mut with_input = organism.clone();
+        with_input.set_input(input.clone());
+        let rc = measure_rc(&with_input, &environment);
+        println!(
+            "{:>8} {:>9} {:>12?} {:>10} {:>10?} {:>12?} {:>10} {:>10?}",
+            neurons,
+            dendrites,
+            arena.preparation,
+            arena.receptors_fired,
+            arena.per_catalysis(),
+            rc.preparation,
+            rc.receptors_fired,
+            rc.per_catalysis()
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! Compares the linear deduplication of an [`ActionChain`] with the constant time
+//! deduplication of an [`IndexedActionChain`] when scheduling the receptors of large random
+//! neuronal networks. Run with `cargo bench --bench scheduler`.
+
+use std::hint::black_box;
+use std::num::NonZeroU32;
+use std::time::{Duration, Instant};
+
+use oben::evolution::helper::{ActionChain, IndexedActionChain};
+use oben::evolution::neuron::random_genome;
+
+/// The number of scheduling cycles per measurement.
+const CYCLES: u32 = 3;
+
+/// Returns for every receptor the ids of the receptors detecting changes of its products,
+/// which are scheduled if the receptor catalyses.
+///
+/// # Parameters
+///
+/// * `neurons` - the number of neurons of the random network
+/// * `dendrites` - the number of dendrites of the random network
+fn cascades(neurons: u32, dendrites: u32) -> Vec<Vec<usize>> {
+    let organism =
+        random_genome(NonZeroU32::new(neurons).unwrap(), NonZeroU32::new(dendrites).unwrap(), 1, 1)
+            .translate();
+    organism
+        .receptors()
+        .iter()
+        .map(|receptor| {
+            receptor
+                .enzyme()
+                .products()
+                .iter()
+                .flat_map(|product| organism.substrates()[*product].receptors().to_vec())
+                .collect()
+        })
+        .collect()
+}
+
+/// A `Scheduler` deduplicates the receptors scheduled for the next iteration.
+trait Scheduler {
+    /// Schedules the receptor with the specified id.
+    fn push(&mut self, receptor: usize);
+
+    /// Starts the next cycle and returns the number of scheduled receptors.
+    fn pop(&mut self) -> usize;
+}
+
+impl Scheduler for ActionChain<usize> {
+    fn push(&mut self, receptor: usize) {
+        self.push_action(receptor);
+    }
+
+    fn pop(&mut self) -> usize {
+        self.pop_actions().len()
+    }
+}
+
+impl Scheduler for IndexedActionChain {
+    fn push(&mut self, receptor: usize) {
+        self.push_action(receptor);
+    }
+
+    fn pop(&mut self) -> usize {
+        self.pop_actions().len()
+    }
+}
+
+/// Returns the mean time of scheduling the cascades of all receptors once.
+///
+/// # Parameters
+///
+/// * `scheduler` - the [`Scheduler`] to measure
+/// * `cascades` - the cascading receptors of every receptor
+fn measure<S: Scheduler>(mut scheduler: S, cascades: &[Vec<usize>]) -> Duration {
+    let start = Instant::now();
+    for _ in 0..CYCLES {
+        for cascade in cascades {
+            for receptor in cascade {
+                scheduler.push(*receptor);
+            }
+        }
+        black_box(scheduler.pop());
+    }
+    start.elapsed() / CYCLES
+}
+
+fn main() {
+    println!("{:>8} {:>9} {:>14} {:>14}", "neurons", "dendrites", "ActionChain", "Indexed");
+    for neurons in [1_000, 4_000, 16_000] {
+        let dendrites = neurons * 2;
+        let cascades = cascades(neurons, dendrites);
+        let linear_time = measure(ActionChain::new(), &cascades);
+        let indexed_time = measure(IndexedActionChain::new(), &cascades);
+        println!("{:>8} {:>9} {:>14?} {:>14?}", neurons, dendrites, linear_time, indexed_time);
+    }
+}
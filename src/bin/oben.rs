@@ -0,0 +1,446 @@
+//! The `oben` command line tool inspects population snapshots written to
+//! [`Environment::population_path`](oben::evolution::environment::Environment::population_path).
+
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use oben::evolution::{
+    chemistry::{Information, Input, Output, Reaction, State},
+    gene::{Genome, GenomeDiff, GenomeFormat},
+    helper::Nlbf64,
+    inspection::{IndividualSummary, SnapshotDiff, SnapshotSummary},
+    neuron::{
+        SimpleDendriteActivationPotential, SimpleDendriteThreshold, SimpleNeuron,
+        SimpleNeuronAudioSixteenOutputSensor, SimpleNeuronParameterInputSensor,
+        SimpleNeuronParameterOutputSensor, SimpleNeuronTextInputSensor,
+    },
+    population::{Population, PopulationStatistics},
+    visualisation::{genome_to_dot, organism_to_dot},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use uuid::Uuid;
+
+/// The usage information of the tool.
+const USAGE: &str = "Usage: oben [--genome <parameter|tts>] <command> [arguments]
+
+Commands:
+    list <snapshot>                     lists all individuals ordered by fitness
+    statistics <snapshot>               prints the population statistics
+    extract <snapshot> <output> [uuid]  writes the genome of the fittest or the specified
+                                        individual to the output file, which is human
+                                        readable for json and ron files
+    convert <snapshot> <output>         converts the snapshot to a JSON file
+    dot <snapshot> <output> [uuid]      writes the genome of the fittest or the specified
+                                        individual as Graphviz DOT graph
+    diff <before> <after>               compares two snapshots of the same run
+    genome-diff <before> <after> [output]
+                                        compares two genome files, e.g. written by extract,
+                                        and optionally writes the changes as JSON file
+    help                                prints this message
+
+Options:
+    --genome <parameter|tts>            the genome type of the snapshot, either parameter
+                                        input and output (default) or text input and
+                                        16 bit audio output
+    --organism                          renders the translated organism instead of the
+                                        genome with the dot command";
+
+/// The built-in neuronal [`Genome`](oben::evolution::gene::Genome) type combinations a
+/// snapshot can contain.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum GenomeType {
+    /// Parameter input and parameter output sensors.
+    Parameter,
+    /// Text input and 16 bit audio output sensors.
+    TextToSpeech,
+}
+
+/// The subcommands of the tool.
+#[derive(Debug, PartialEq, Eq, Clone)]
+enum Command {
+    List(PathBuf),
+    Statistics(PathBuf),
+    Extract(PathBuf, PathBuf, Option<Uuid>),
+    Convert(PathBuf, PathBuf),
+    Dot(PathBuf, PathBuf, Option<Uuid>, bool),
+    Diff(PathBuf, PathBuf),
+    GenomeDiff(PathBuf, PathBuf, Option<PathBuf>),
+    Help,
+}
+
+fn main() -> ExitCode {
+    match parse_arguments(std::env::args().skip(1)).and_then(|(genome_type, command)| {
+        match genome_type {
+            GenomeType::Parameter => execute::<
+                SimpleDendriteActivationPotential,
+                SimpleDendriteThreshold,
+                SimpleNeuron,
+                Vec<Nlbf64>,
+                SimpleNeuronParameterInputSensor,
+                Vec<Nlbf64>,
+                SimpleNeuronParameterOutputSensor,
+            >(&command),
+            GenomeType::TextToSpeech => execute::<
+                SimpleDendriteActivationPotential,
+                SimpleDendriteThreshold,
+                SimpleNeuron,
+                String,
+                SimpleNeuronTextInputSensor,
+                Vec<i16>,
+                SimpleNeuronAudioSixteenOutputSensor,
+            >(&command),
+        }
+    }) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        },
+    }
+}
+
+/// Parses the command line arguments into the [`GenomeType`] and [`Command`].
+/// An error containing the usage information will be returned if the arguments are invalid.
+///
+/// # Parameters
+///
+/// * `arguments` - the command line arguments without the name of the binary
+fn parse_arguments<I: Iterator<Item = String>>(
+    arguments: I,
+) -> Result<(GenomeType, Command), Box<dyn Error>> {
+    let mut genome_type = GenomeType::Parameter;
+    let mut organism = false;
+    let mut positional = Vec::new();
+    let mut arguments = arguments;
+    while let Some(argument) = arguments.next() {
+        match argument.as_str() {
+            "--genome" => {
+                genome_type = match arguments.next().as_deref() {
+                    Some("parameter") => GenomeType::Parameter,
+                    Some("tts") => GenomeType::TextToSpeech,
+                    other => {
+                        return Err(format!(
+                            "Unknown genome type {:?}.\n\n{}",
+                            other.unwrap_or_default(),
+                            USAGE
+                        )
+                        .into())
+                    },
+                }
+            },
+            "--organism" => organism = true,
+            "-h" | "--help" => return Ok((genome_type, Command::Help)),
+            _ => positional.push(argument),
+        }
+    }
+    let positional: Vec<&str> = positional.iter().map(String::as_str).collect();
+    let command = match positional.as_slice() {
+        ["list", snapshot] => Command::List(snapshot.into()),
+        ["statistics", snapshot] => Command::Statistics(snapshot.into()),
+        ["extract", snapshot, output] => Command::Extract(snapshot.into(), output.into(), None),
+        ["extract", snapshot, output, uuid] => {
+            Command::Extract(snapshot.into(), output.into(), Some(Uuid::parse_str(uuid)?))
+        },
+        ["convert", snapshot, output] => Command::Convert(snapshot.into(), output.into()),
+        ["dot", snapshot, output] => Command::Dot(snapshot.into(), output.into(), None, organism),
+        ["dot", snapshot, output, uuid] => {
+            Command::Dot(snapshot.into(), output.into(), Some(Uuid::parse_str(uuid)?), organism)
+        },
+        ["diff", before, after] => Command::Diff(before.into(), after.into()),
+        ["genome-diff", before, after] => Command::GenomeDiff(before.into(), after.into(), None),
+        ["genome-diff", before, after, output] => {
+            Command::GenomeDiff(before.into(), after.into(), Some(output.into()))
+        },
+        ["help"] | [] => Command::Help,
+        _ => return Err(format!("Invalid arguments.\n\n{}", USAGE).into()),
+    };
+    if organism && !matches!(command, Command::Dot(..)) {
+        return Err(format!("The option --organism is only valid for dot.\n\n{}", USAGE).into());
+    }
+    Ok((genome_type, command))
+}
+
+/// Executes the [`Command`] on snapshots of the specified genome type.
+/// An error will be returned if loading or writing a file failed.
+///
+/// # Parameters
+///
+/// * `command` - the [`Command`] to execute
+fn execute<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    command: &Command,
+) -> Result<(), Box<dyn Error>> {
+    let load = |path: &Path| {
+        Population::<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >::load_from_file(path)
+        .map_err(|err| format!("Loading the snapshot {} failed: {}", path.display(), err))
+    };
+    // Selects the individual with the specified UUID or the fittest one.
+    let select = |snapshot: &Path, uuid: &Option<Uuid>| -> Result<_, Box<dyn Error>> {
+        let population = load(snapshot)?;
+        Ok(match uuid {
+            Some(uuid) => population.individual(uuid).ok_or_else(|| {
+                format!("No individual with UUID {} is present in the snapshot.", uuid)
+            })?,
+            None => population
+                .fittest_individual(0)
+                .ok_or("The snapshot does not contain any individuals.")?,
+        })
+    };
+    match command {
+        Command::List(snapshot) => {
+            let summary = SnapshotSummary::from_population(&load(snapshot)?);
+            println!(
+                "{:<36} {:>12} {:>6} {:>10} {:>8}",
+                "uuid", "fitness", "age", "bytes", "tested"
+            );
+            for individual in summary.individuals() {
+                print_individual(individual);
+            }
+        },
+        Command::Statistics(snapshot) => {
+            print_statistics(&load(snapshot)?.statistics());
+        },
+        Command::Extract(snapshot, output, uuid) => {
+            let individual = select(snapshot, uuid)?;
+            let individual = individual
+                .lock()
+                .expect("A thread paniced while holding the individual's lock.");
+            if GenomeFormat::from_path(output).is_some() {
+                individual.genome().export_to_file(output)?;
+            } else {
+                individual.genome().write_to_file(output)?;
+            }
+            println!(
+                "Wrote the genome of individual {} to {}.",
+                individual.uuid(),
+                output.display()
+            );
+        },
+        Command::Dot(snapshot, output, uuid, organism) => {
+            let individual = select(snapshot, uuid)?;
+            let individual = individual
+                .lock()
+                .expect("A thread paniced while holding the individual's lock.");
+            let dot = if *organism {
+                organism_to_dot(&individual.genome().translate())
+            } else {
+                genome_to_dot(&individual.genome())
+            };
+            std::fs::write(output, dot)?;
+            println!(
+                "Wrote the graph of individual {} to {}.",
+                individual.uuid(),
+                output.display()
+            );
+        },
+        Command::Convert(snapshot, output) => {
+            load(snapshot)?.write_json_to_file(output)?;
+            println!("Wrote the snapshot to {}.", output.display());
+        },
+        Command::Diff(before, after) => {
+            let diff = SnapshotDiff::between(
+                &SnapshotSummary::from_population(&load(before)?),
+                &SnapshotSummary::from_population(&load(after)?),
+            );
+            print_diff(&diff);
+        },
+        Command::GenomeDiff(before, after, output) => {
+            let load_genome = |path: &Path| {
+                let genome = if GenomeFormat::from_path(path).is_some() {
+                    Genome::<
+                        ReactionType,
+                        StateType,
+                        InformationType,
+                        InputElementType,
+                        InputSensorType,
+                        OutputElementType,
+                        OutputSensorType,
+                    >::import_from_file(path)
+                    .map_err(|err| err.to_string())
+                } else {
+                    Genome::load_from_file(path).map_err(|err| err.to_string())
+                };
+                genome
+                    .map_err(|err| format!("Loading the genome {} failed: {}", path.display(), err))
+            };
+            let diff = GenomeDiff::between(&load_genome(before)?, &load_genome(after)?);
+            println!("{}", diff);
+            if let Some(output) = output {
+                std::fs::write(output, serde_json::to_string_pretty(&diff)?)?;
+                println!("Wrote the changes to {}.", output.display());
+            }
+        },
+        Command::Help => println!("{}", USAGE),
+    }
+    Ok(())
+}
+
+/// Prints a single table row describing the individual.
+///
+/// # Parameters
+///
+/// * `individual` - the [`IndividualSummary`] to print
+fn print_individual(individual: &IndividualSummary) {
+    println!(
+        "{:<36} {:>12} {:>6} {:>10} {:>8}",
+        individual.uuid(),
+        format_fitness(individual.fitness()),
+        individual.age(),
+        individual.bytes(),
+        individual.times_tested()
+    );
+}
+
+/// Prints the [`PopulationStatistics`].
+///
+/// # Parameters
+///
+/// * `statistics` - the [`PopulationStatistics`] to print
+fn print_statistics(statistics: &PopulationStatistics) {
+    println!("size:             {}", statistics.size());
+    println!("mean fitness:     {}", statistics.mean_fitness());
+    println!("maximum fitness:  {}", format_fitness(statistics.maximum_fitness()));
+    println!("mean genome size: {}", statistics.mean_genome_size());
+    println!("mean age:         {}", statistics.mean_age());
+    println!("resources:        {}", statistics.resources().total());
+}
+
+/// Prints the [`SnapshotDiff`].
+///
+/// # Parameters
+///
+/// * `diff` - the [`SnapshotDiff`] to print
+fn print_diff(diff: &SnapshotDiff) {
+    let (before, after) = diff.statistics();
+    println!("Statistics before:");
+    print_statistics(before);
+    println!("\nStatistics after:");
+    print_statistics(after);
+    println!("\nAdded individuals: {}", diff.added().len());
+    for individual in diff.added() {
+        print_individual(individual);
+    }
+    println!("\nRemoved individuals: {}", diff.removed().len());
+    for individual in diff.removed() {
+        print_individual(individual);
+    }
+    println!("\nChanged individuals: {}", diff.changed().len());
+    for (earlier, later) in diff.changed() {
+        print_individual(earlier);
+        print_individual(later);
+    }
+}
+
+/// Formats an optional fitness value.
+///
+/// # Parameters
+///
+/// * `fitness` - the fitness if any
+fn format_fitness(fitness: Option<f64>) -> String {
+    fitness.map_or_else(|| "untested".to_string(), |fitness| format!("{:.6}", fitness))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Converts the specified arguments into owned strings.
+    fn arguments(arguments: &[&str]) -> std::vec::IntoIter<String> {
+        arguments
+            .iter()
+            .map(|argument| argument.to_string())
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+
+    #[test]
+    /// Tests if the function `parse_arguments` correctly parses valid arguments.
+    fn test_parse_arguments() {
+        let uuid = Uuid::from_u128(42);
+        assert_eq!(
+            parse_arguments(arguments(&["list", "a.population"])).unwrap(),
+            (GenomeType::Parameter, Command::List("a.population".into()))
+        );
+        assert_eq!(
+            parse_arguments(arguments(&[
+                "--genome",
+                "tts",
+                "extract",
+                "a.population",
+                "b.json",
+                &uuid.to_string()
+            ]))
+            .unwrap(),
+            (
+                GenomeType::TextToSpeech,
+                Command::Extract("a.population".into(), "b.json".into(), Some(uuid))
+            )
+        );
+        assert_eq!(
+            parse_arguments(arguments(&["dot", "a.population", "b.dot", "--organism"])).unwrap(),
+            (
+                GenomeType::Parameter,
+                Command::Dot("a.population".into(), "b.dot".into(), None, true)
+            )
+        );
+        assert_eq!(
+            parse_arguments(arguments(&["genome-diff", "a.json", "b.json"])).unwrap(),
+            (GenomeType::Parameter, Command::GenomeDiff("a.json".into(), "b.json".into(), None))
+        );
+        assert_eq!(
+            parse_arguments(arguments(&[])).unwrap(),
+            (GenomeType::Parameter, Command::Help)
+        );
+        assert_eq!(
+            parse_arguments(arguments(&["list", "--help"])).unwrap(),
+            (GenomeType::Parameter, Command::Help)
+        );
+    }
+
+    #[test]
+    /// Tests if the function `parse_arguments` correctly rejects missing arguments.
+    fn test_parse_arguments_missing() {
+        assert!(parse_arguments(arguments(&["list"])).is_err());
+        assert!(parse_arguments(arguments(&["extract", "a.population"])).is_err());
+        assert!(parse_arguments(arguments(&["diff", "a.population"])).is_err());
+        assert!(parse_arguments(arguments(&["--genome"])).is_err());
+    }
+
+    #[test]
+    /// Tests if the function `parse_arguments` correctly rejects the option `--organism`
+    /// outside of the command `dot`.
+    fn test_parse_arguments_misplaced_organism() {
+        assert!(parse_arguments(arguments(&["list", "a.population", "--organism"])).is_err());
+        assert!(parse_arguments(arguments(&["--organism", "extract", "a.population", "b.json"]))
+            .is_err());
+        assert!(parse_arguments(arguments(&["--organism"])).is_err());
+        assert!(parse_arguments(arguments(&["--organism", "dot", "a.population", "b.dot"])).is_ok());
+    }
+
+    #[test]
+    /// Tests if the function `parse_arguments` correctly rejects unknown arguments.
+    fn test_parse_arguments_unknown() {
+        assert!(parse_arguments(arguments(&["remove", "a.population"])).is_err());
+        assert!(
+            parse_arguments(arguments(&["--genome", "binary", "list", "a.population"])).is_err()
+        );
+        assert!(parse_arguments(arguments(&["list", "a.population", "b.population"])).is_err());
+        assert!(parse_arguments(arguments(&["extract", "a.population", "b.json", "x"])).is_err());
+    }
+}
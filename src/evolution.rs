@@ -1,10 +1,17 @@
 pub mod binary;
 pub mod chemistry;
+pub mod debugger;
 pub mod environment;
+pub mod fitness;
 pub mod function;
 pub mod gene;
+pub mod genealogy;
 pub mod helper;
+pub mod inspection;
+pub mod knockout;
 pub mod neuron;
 pub mod population;
 pub mod protein;
 pub mod resource;
+pub mod trace;
+pub mod visualisation;
@@ -17,6 +17,12 @@ pub trait Information:
     ///
     /// * `time_passed` - the time passed since the last update of the value in iteration steps
     fn update_value(&mut self, time_passed: i32);
+
+    /// Returns a short human readable description used when visualising networks.
+    /// Defaults to the debug representation.
+    fn label(&self) -> String {
+        format!("{:?}", self)
+    }
 }
 
 /// A `State` is an elementary operation for comparing substrates.
@@ -41,6 +47,12 @@ pub trait State<T: Information>:
 
     /// Creates a random `Reaction`.
     fn random() -> Self;
+
+    /// Returns a short human readable description used when visualising networks.
+    /// Defaults to the debug representation.
+    fn label(&self) -> String {
+        format!("{:?}", self)
+    }
 }
 
 /// A `Reaction` represents an elementary operation for modification of substrates.
@@ -69,6 +81,12 @@ pub trait Reaction<T: Information>:
 
     /// Creates a random `Reaction`.
     fn random() -> Self;
+
+    /// Returns a short human readable description used when visualising networks.
+    /// Defaults to the debug representation.
+    fn label(&self) -> String {
+        format!("{:?}", self)
+    }
 }
 
 /// An `Input` represents an element that can convert external stimuli to
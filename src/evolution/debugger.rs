@@ -0,0 +1,367 @@
+//! The `debugger` module contains the step-by-step execution of an [`Organism`].
+//! An [`OrganismDebugger`] drives the [`Organism`] manually one [`Iteration`] at a time,
+//! allows inspecting and overwriting [`Substrate`] values and pauses execution at
+//! [`Breakpoint`]s. [`Substrate`]s and [`Receptor`]s are identified by the same ids as
+//! in an [`ExecutionTrace`].
+//!
+//! [`Breakpoint`]: ./enum.Breakpoint.html
+//! [`ExecutionTrace`]: ../trace/struct.ExecutionTrace.html
+//! [`Iteration`]: ../helper/struct.Iteration.html
+//! [`Organism`]: ../population/struct.Organism.html
+//! [`OrganismDebugger`]: ./struct.OrganismDebugger.html
+//! [`Receptor`]: ../protein/struct.Receptor.html
+//! [`Substrate`]: ../protein/struct.Substrate.html
+
+use std::time::Duration;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use super::chemistry::{Information, Input, Output, Reaction, State};
+use super::environment::Environment;
+use super::helper::Iteration;
+use super::population::{Execution, Organism, TerminationReason};
+use super::protein::{Receptor, Substrate};
+use super::trace::{product_values, Recorder};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+/// A `Breakpoint` pauses the execution of an [`OrganismDebugger`] after the
+/// [`Iteration`] it was hit in.
+///
+/// [`Iteration`]: ../helper/struct.Iteration.html
+/// [`OrganismDebugger`]: ./struct.OrganismDebugger.html
+pub enum Breakpoint {
+    /// Hit if a catalysis changes the value of the [`Substrate`] with the specified id.
+    ///
+    /// [`Substrate`]: ../protein/struct.Substrate.html
+    SubstrateChange(usize),
+    /// Hit if the [`Receptor`] with the specified id catalyses.
+    ///
+    /// [`Receptor`]: ../protein/struct.Receptor.html
+    ReceptorFiring(usize),
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+/// A `DebugEvent` describes the result of driving an [`OrganismDebugger`].
+///
+/// [`OrganismDebugger`]: ./struct.OrganismDebugger.html
+pub enum DebugEvent {
+    /// A single [`Iteration`] was executed without hitting a [`Breakpoint`].
+    ///
+    /// [`Breakpoint`]: ./enum.Breakpoint.html
+    /// [`Iteration`]: ../helper/struct.Iteration.html
+    Stepped,
+    /// The specified [`Breakpoint`]s were hit during the last executed [`Iteration`].
+    ///
+    /// [`Breakpoint`]: ./enum.Breakpoint.html
+    /// [`Iteration`]: ../helper/struct.Iteration.html
+    BreakpointHit(Vec<Breakpoint>),
+    /// The run ended for the specified reason.
+    Terminated(TerminationReason),
+}
+
+/// An `OrganismDebugger` drives an [`Organism`] manually. In contrast to
+/// [`Organism::live`] the lifespan of the [`Environment`] is not enforced, since the
+/// execution time depends on the user.
+///
+/// [`Environment`]: ../environment/struct.Environment.html
+/// [`Organism`]: ../population/struct.Organism.html
+/// [`Organism::live`]: ../population/struct.Organism.html#method.live
+pub struct OrganismDebugger<
+    'a,
+    ReactionType,
+    StateType,
+    InformationType,
+    InputElementType,
+    InputSensorType,
+    OutputElementType,
+    OutputSensorType,
+> {
+    organism: &'a mut Organism<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+    environment: &'a Environment,
+    execution: Execution,
+    breakpoints: Vec<Breakpoint>,
+}
+
+impl<
+        'a,
+        ReactionType: Reaction<InformationType>,
+        StateType: State<InformationType>,
+        InformationType: Information,
+        InputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+        InputSensorType: Input<InputElementType, InformationType>,
+        OutputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+        OutputSensorType: Output<OutputElementType, InformationType>,
+    >
+    OrganismDebugger<
+        'a,
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >
+{
+    /// Creates a new `OrganismDebugger` starting a run of the specified [`Organism`] with
+    /// its current input.
+    ///
+    /// # Parameters
+    ///
+    /// * `organism` - the [`Organism`] to debug
+    /// * `environment` - the [`Environment`] the [`Organism`] lives in
+    ///
+    /// [`Environment`]: ../environment/struct.Environment.html
+    /// [`Organism`]: ../population/struct.Organism.html
+    pub fn new(
+        organism: &'a mut Organism<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+        environment: &'a Environment,
+    ) -> Self {
+        let execution = organism.begin_execution(environment);
+        OrganismDebugger {
+            organism,
+            environment,
+            execution,
+            breakpoints: Vec::new(),
+        }
+    }
+
+    /// Sets the input of the [`Organism`] and schedules all [`Receptor`]s detecting the
+    /// input, so a terminated run can be continued.
+    ///
+    /// # Parameters
+    ///
+    /// * `input` - the input values to set
+    ///
+    /// [`Organism`]: ../population/struct.Organism.html
+    /// [`Receptor`]: ../protein/struct.Receptor.html
+    pub fn set_input(&mut self, input: InputElementType) {
+        self.organism.set_input(input);
+        self.execution.reset_finished();
+        self.execution.schedule(
+            self.organism
+                .input_sensor()
+                .cascading_receptors(self.organism.substrates()),
+        );
+    }
+
+    /// Executes exactly one [`Iteration`] unless the run is terminated.
+    ///
+    /// [`Iteration`]: ../helper/struct.Iteration.html
+    pub fn step(&mut self) -> DebugEvent {
+        if let Some(termination) = self.termination() {
+            return DebugEvent::Terminated(termination);
+        }
+        let mut recorder = BreakpointRecorder {
+            breakpoints: &self.breakpoints,
+            hits: Vec::new(),
+            products_before: Vec::new(),
+        };
+        self.organism.iterate(&mut self.execution, &mut recorder);
+        if recorder.hits.is_empty() {
+            DebugEvent::Stepped
+        } else {
+            DebugEvent::BreakpointHit(recorder.hits)
+        }
+    }
+
+    /// Executes [`Iteration`]s until a [`Breakpoint`] is hit or the run terminates.
+    ///
+    /// [`Breakpoint`]: ./enum.Breakpoint.html
+    /// [`Iteration`]: ../helper/struct.Iteration.html
+    pub fn resume(&mut self) -> DebugEvent {
+        loop {
+            match self.step() {
+                DebugEvent::Stepped => {},
+                event => return event,
+            }
+        }
+    }
+
+    /// Returns the reason the run is terminated if it cannot continue.
+    pub fn termination(&self) -> Option<TerminationReason> {
+        self.organism
+            .termination(&self.execution, self.environment, Duration::ZERO)
+    }
+
+    /// Returns the [`Iteration`] the next step is executed at.
+    ///
+    /// [`Iteration`]: ../helper/struct.Iteration.html
+    pub fn current_iteration(&self) -> Iteration {
+        self.execution.current_iteration()
+    }
+
+    /// Returns the ids of all [`Receptor`]s scheduled for the next step.
+    ///
+    /// [`Receptor`]: ../protein/struct.Receptor.html
+    pub fn pending_receptors(&self) -> Vec<usize> {
+        self.execution.scheduled().to_vec()
+    }
+
+    /// Returns the number of [`Substrate`]s of the [`Organism`].
+    ///
+    /// [`Organism`]: ../population/struct.Organism.html
+    /// [`Substrate`]: ../protein/struct.Substrate.html
+    pub fn number_of_substrates(&self) -> usize {
+        self.organism.number_of_substrates()
+    }
+
+    /// Returns the value of the [`Substrate`] with the specified id if it exists.
+    ///
+    /// # Parameters
+    ///
+    /// * `substrate` - the id of the [`Substrate`]
+    ///
+    /// [`Substrate`]: ../protein/struct.Substrate.html
+    pub fn substrate_value(&self, substrate: usize) -> Option<InformationType> {
+        self.organism
+            .substrates()
+            .get(substrate)
+            .map(|substrate| substrate.last_value().clone())
+    }
+
+    /// Overwrites the value of the [`Substrate`] with the specified id and schedules all
+    /// [`Receptor`]s detecting it. Returns `false` if the [`Substrate`] does not exist.
+    ///
+    /// # Parameters
+    ///
+    /// * `substrate` - the id of the [`Substrate`]
+    /// * `value` - the new value
+    ///
+    /// [`Receptor`]: ../protein/struct.Receptor.html
+    /// [`Substrate`]: ../protein/struct.Substrate.html
+    pub fn set_substrate_value(&mut self, substrate: usize, value: InformationType) -> bool {
+        if let Some(receptors) = self.organism.set_substrate_value(substrate, value) {
+            self.execution.schedule(receptors);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Adds a [`Breakpoint`] unless it is already present.
+    ///
+    /// # Parameters
+    ///
+    /// * `breakpoint` - the [`Breakpoint`] to add
+    ///
+    /// [`Breakpoint`]: ./enum.Breakpoint.html
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) -> &mut Self {
+        if !self.breakpoints.contains(&breakpoint) {
+            self.breakpoints.push(breakpoint);
+        }
+        self
+    }
+
+    /// Removes the specified [`Breakpoint`] and returns `true` if it was present.
+    ///
+    /// # Parameters
+    ///
+    /// * `breakpoint` - the [`Breakpoint`] to remove
+    ///
+    /// [`Breakpoint`]: ./enum.Breakpoint.html
+    pub fn remove_breakpoint(&mut self, breakpoint: &Breakpoint) -> bool {
+        let number_of_breakpoints = self.breakpoints.len();
+        self.breakpoints.retain(|present| present != breakpoint);
+        self.breakpoints.len() != number_of_breakpoints
+    }
+
+    /// Returns all [`Breakpoint`]s.
+    ///
+    /// [`Breakpoint`]: ./enum.Breakpoint.html
+    pub fn breakpoints(&self) -> &[Breakpoint] {
+        &self.breakpoints
+    }
+
+    /// Returns the current values of the output [`Substrate`]s.
+    ///
+    /// [`Substrate`]: ../protein/struct.Substrate.html
+    pub fn result(&mut self) -> OutputElementType {
+        self.organism.get_result()
+    }
+}
+
+/// A `BreakpointRecorder` is a [`Recorder`] collecting the [`Breakpoint`]s hit during
+/// an [`Iteration`].
+///
+/// [`Breakpoint`]: ./enum.Breakpoint.html
+/// [`Iteration`]: ../helper/struct.Iteration.html
+/// [`Recorder`]: ../trace/trait.Recorder.html
+struct BreakpointRecorder<'a, InformationType> {
+    breakpoints: &'a [Breakpoint],
+    hits: Vec<Breakpoint>,
+    products_before: Vec<InformationType>,
+}
+
+impl<'a, InformationType> BreakpointRecorder<'a, InformationType> {
+    /// Records the [`Breakpoint`] if it is set and was not hit before.
+    ///
+    /// # Parameters
+    ///
+    /// * `breakpoint` - the potentially hit [`Breakpoint`]
+    ///
+    /// [`Breakpoint`]: ./enum.Breakpoint.html
+    fn hit(&mut self, breakpoint: Breakpoint) {
+        if self.breakpoints.contains(&breakpoint) && !self.hits.contains(&breakpoint) {
+            self.hits.push(breakpoint);
+        }
+    }
+}
+
+impl<
+        'a,
+        ReactionType: Reaction<InformationType>,
+        StateType: State<InformationType>,
+        InformationType: Information,
+    > Recorder<ReactionType, StateType, InformationType>
+    for BreakpointRecorder<'a, InformationType>
+{
+    fn detected(
+        &mut self,
+        _id: usize,
+        receptor: &Receptor<ReactionType, StateType, InformationType>,
+        substrates: &[Substrate<ReactionType, StateType, InformationType>],
+    ) {
+        self.products_before = product_values(receptor, substrates);
+    }
+
+    fn catalysed(
+        &mut self,
+        id: usize,
+        receptor: &Receptor<ReactionType, StateType, InformationType>,
+        substrates: &[Substrate<ReactionType, StateType, InformationType>],
+    ) {
+        self.hit(Breakpoint::ReceptorFiring(id));
+        let products_before = std::mem::take(&mut self.products_before);
+        for ((product, before), after) in receptor
+            .enzyme()
+            .products()
+            .iter()
+            .zip(products_before)
+            .zip(product_values(receptor, substrates))
+        {
+            if before != after {
+                self.hit(Breakpoint::SubstrateChange(*product));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
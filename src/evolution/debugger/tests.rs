@@ -0,0 +1,62 @@
+use crate::evolution::environment::EnvironmentBuilder;
+use crate::evolution::helper::testing::{neuron_chain_genome, neuron_organism};
+use crate::evolution::helper::Nlbf64;
+use crate::evolution::neuron::SimpleNeuron;
+
+use super::*;
+
+#[test]
+/// Tests if the function `step` of the [`OrganismDebugger`] struct correctly executes
+/// single iterations and allows inspecting and overwriting substrates.
+fn test_step() {
+    let environment = EnvironmentBuilder::new().build().unwrap();
+    let mut untraced = neuron_organism(&neuron_chain_genome());
+    untraced.live(&environment);
+    let mut organism = neuron_organism(&neuron_chain_genome());
+    let mut debugger = OrganismDebugger::new(&mut organism, &environment);
+    assert_eq!(debugger.number_of_substrates(), 5);
+    assert_eq!(debugger.pending_receptors(), vec![0]);
+    assert_eq!(debugger.step(), DebugEvent::Stepped);
+    assert_eq!(debugger.current_iteration(), Iteration::new().increment());
+    assert_eq!(debugger.pending_receptors(), vec![1, 2]);
+    assert_eq!(debugger.substrate_value(1).unwrap().current_potential(), Nlbf64::from(0.75));
+    assert_eq!(debugger.step(), DebugEvent::Stepped);
+    assert!(debugger.pending_receptors().is_empty());
+    assert_eq!(debugger.result(), untraced.get_result());
+    assert_eq!(debugger.step(), DebugEvent::Terminated(TerminationReason::ActionsExhausted));
+    assert_eq!(debugger.substrate_value(5), None);
+    assert!(!debugger.set_substrate_value(5, SimpleNeuron::new(0.0, 10.0)));
+    assert!(debugger.set_substrate_value(1, SimpleNeuron::new(0.0, 10.0)));
+    assert_eq!(debugger.termination(), None);
+    assert_eq!(debugger.pending_receptors(), vec![1, 2]);
+    assert_eq!(debugger.step(), DebugEvent::Stepped);
+    assert_eq!(debugger.step(), DebugEvent::Terminated(TerminationReason::ActionsExhausted));
+    debugger.set_input(vec![Nlbf64::from(0.5)]);
+    assert_eq!(debugger.pending_receptors(), vec![0]);
+}
+
+#[test]
+/// Tests if the function `resume` of the [`OrganismDebugger`] struct correctly pauses
+/// at hit breakpoints.
+fn test_resume() {
+    let environment = EnvironmentBuilder::new().build().unwrap();
+    let mut organism = neuron_organism(&neuron_chain_genome());
+    let mut debugger = OrganismDebugger::new(&mut organism, &environment);
+    debugger
+        .add_breakpoint(Breakpoint::ReceptorFiring(2))
+        .add_breakpoint(Breakpoint::SubstrateChange(2))
+        .add_breakpoint(Breakpoint::SubstrateChange(2));
+    assert_eq!(debugger.breakpoints().len(), 2);
+    assert_eq!(
+        debugger.resume(),
+        DebugEvent::BreakpointHit(vec![
+            Breakpoint::SubstrateChange(2),
+            Breakpoint::ReceptorFiring(2)
+        ])
+    );
+    assert_eq!(debugger.current_iteration(), Iteration::new().increment().increment());
+    assert_eq!(debugger.resume(), DebugEvent::Terminated(TerminationReason::ActionsExhausted));
+    assert!(debugger.remove_breakpoint(&Breakpoint::ReceptorFiring(2)));
+    assert!(!debugger.remove_breakpoint(&Breakpoint::ReceptorFiring(2)));
+    assert_eq!(debugger.breakpoints(), &[Breakpoint::SubstrateChange(2)]);
+}
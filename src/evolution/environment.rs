@@ -1,8 +1,33 @@
 //! The `environment` module contains the setup of the evolutionary network.
-pub use self::configuration::{Environment, EnvironmentBuilder};
+pub use self::adaptation::{AdaptationStrategy, OperatorAdaptation, SelfAdaptation};
+pub use self::coevolution::{CoevolutionaryNiche, HallOfFame};
+pub use self::configuration::{Environment, EnvironmentBuilder, EnvironmentError, InvalidField};
 pub use self::execution::EcologicalNiche;
-pub use self::mutation::{Mutation, MutationCompendium};
+pub use self::format::ConfigurationFormat;
+pub use self::layering::{AgeLayer, AgeLayering};
+pub use self::metrics::GenerationMetrics;
+pub use self::mortality::{
+    AgeSigmoidMortality, CombinedMortality, DensityDependentMortality, FitnessDependentMortality,
+    MaximumAgeMortality, MortalityModel,
+};
+pub use self::mutation::{
+    FailurePolicy, Mutation, MutationCompendium, MutationRecord, OperatorStatistics,
+};
+pub use self::registry::{
+    ConfiguredMutation, MutationConfiguration, MutationConfigurationError, MutationRegistry,
+};
+pub use self::retesting::{ConfidenceTesting, SigmoidTesting, TestingModel};
+pub use self::schedule::{Schedule, ScheduledParameter};
 
+mod adaptation;
+mod coevolution;
 mod configuration;
 mod execution;
+mod format;
+mod layering;
+mod metrics;
+mod mortality;
 mod mutation;
+mod registry;
+mod retesting;
+mod schedule;
@@ -0,0 +1,308 @@
+//! The `adaptation` module contains the adaptive selection of [`Mutation`] operators.
+//!
+//! [`Mutation`]: ./struct.Mutation.html
+
+use serde::{Deserialize, Serialize};
+
+use super::mutation::OperatorStatistics;
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+/// An `AdaptationStrategy` determines how the quality of a [`Mutation`] operator is estimated
+/// from its [`OperatorStatistics`].
+///
+/// [`Mutation`]: ./struct.Mutation.html
+/// [`OperatorStatistics`]: ./struct.OperatorStatistics.html
+pub enum AdaptationStrategy {
+    /// The chances are proportional to the mean improvement of offspring over their parents.
+    /// Operators without tested offspring are assumed to perform like the average operator.
+    ProbabilityMatching,
+    /// The chances are proportional to the upper confidence bound (UCB1) of the mean fitness
+    /// difference of offspring and their parents. Operators without tested offspring are
+    /// preferred, so every operator is explored.
+    UpperConfidenceBound {
+        /// The weight of the exploration term.
+        exploration: f64,
+    },
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// An `OperatorAdaptation` reallocates the chances of the [`Mutation`]s of a
+/// [`MutationCompendium`] during a run according to the observed improvement of offspring.
+/// The sum of all chances is kept constant, while every single chance is bound to the
+/// configured range.
+///
+/// [`Mutation`]: ./struct.Mutation.html
+/// [`MutationCompendium`]: ./struct.MutationCompendium.html
+pub struct OperatorAdaptation {
+    strategy: AdaptationStrategy,
+    minimum_chance: f64,
+    maximum_chance: f64,
+}
+
+impl OperatorAdaptation {
+    /// Creates a new `OperatorAdaptation`.
+    ///
+    /// # Parameters
+    ///
+    /// * `strategy` - the [`AdaptationStrategy`] used to estimate the quality of operators
+    /// * `minimum_chance` - the lower bound of every mutation chance
+    /// * `maximum_chance` - the upper bound of every mutation chance
+    ///
+    /// # Panics
+    ///
+    /// If the bounds are not within `[0.0, 1.0]`, the minimum exceeds the maximum or the
+    /// exploration weight is negative.
+    ///
+    /// [`AdaptationStrategy`]: ./enum.AdaptationStrategy.html
+    pub fn new(strategy: AdaptationStrategy, minimum_chance: f64, maximum_chance: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&minimum_chance) && (0.0..=1.0).contains(&maximum_chance),
+            "The mutation chance bounds must be within [0.0, 1.0], but were {} and {}.",
+            minimum_chance,
+            maximum_chance
+        );
+        assert!(
+            minimum_chance <= maximum_chance,
+            "The minimum mutation chance {} exceeds the maximum mutation chance {}.",
+            minimum_chance,
+            maximum_chance
+        );
+        if let AdaptationStrategy::UpperConfidenceBound { exploration } = strategy {
+            assert!(
+                exploration >= 0.0,
+                "The exploration weight must not be negative, but was {}.",
+                exploration
+            );
+        }
+        OperatorAdaptation {
+            strategy,
+            minimum_chance,
+            maximum_chance,
+        }
+    }
+
+    /// Returns the [`AdaptationStrategy`].
+    ///
+    /// [`AdaptationStrategy`]: ./enum.AdaptationStrategy.html
+    pub fn strategy(&self) -> AdaptationStrategy {
+        self.strategy
+    }
+
+    /// Returns the lower bound of every mutation chance.
+    pub fn minimum_chance(&self) -> f64 {
+        self.minimum_chance
+    }
+
+    /// Returns the upper bound of every mutation chance.
+    pub fn maximum_chance(&self) -> f64 {
+        self.maximum_chance
+    }
+
+    /// Distributes the specified total mutation chance among the operators according to
+    /// their [`OperatorStatistics`] and returns the resulting chances in the same order.
+    /// Operators exceeding a bound are fixed at that bound and the remaining chance is
+    /// redistributed among the other operators until all bounds are met. The chances only
+    /// sum up to less or more than the total chance if it cannot be reached within the
+    /// bounds.
+    ///
+    /// # Parameters
+    ///
+    /// * `total_chance` - the sum of all mutation chances to distribute
+    /// * `operators` - the [`OperatorStatistics`] of every operator if any were recorded
+    ///
+    /// [`OperatorStatistics`]: ./struct.OperatorStatistics.html
+    pub fn allocate(
+        &self,
+        total_chance: f64,
+        operators: &[Option<&OperatorStatistics>],
+    ) -> Vec<f64> {
+        let weights = self.weights(operators);
+        let mut chances: Vec<Option<f64>> = vec![None; operators.len()];
+        loop {
+            let unbound: Vec<usize> = (0..chances.len())
+                .filter(|&i| chances[i].is_none())
+                .collect();
+            if unbound.is_empty() {
+                break;
+            }
+            let remaining_chance = total_chance - chances.iter().flatten().sum::<f64>();
+            let weight_sum: f64 = unbound.iter().map(|&i| weights[i]).sum();
+            let proposals: Vec<(usize, f64)> = unbound
+                .iter()
+                .map(|&i| {
+                    let chance = if weight_sum > 0.0 {
+                        remaining_chance * weights[i] / weight_sum
+                    } else {
+                        // Without any information the chance is distributed equally.
+                        remaining_chance / unbound.len() as f64
+                    };
+                    (i, chance)
+                })
+                .collect();
+            let excess: f64 = proposals
+                .iter()
+                .map(|(_, chance)| (chance - self.maximum_chance).max(0.0))
+                .sum();
+            let deficit: f64 = proposals
+                .iter()
+                .map(|(_, chance)| (self.minimum_chance - chance).max(0.0))
+                .sum();
+            if excess == 0.0 && deficit == 0.0 {
+                for (i, chance) in proposals {
+                    chances[i] = Some(chance);
+                }
+                break;
+            }
+            // Only the operators violating the dominant bound are fixed, since fixing them
+            // shifts the remaining chance towards the other bound.
+            for (i, chance) in proposals {
+                if excess >= deficit && chance > self.maximum_chance {
+                    chances[i] = Some(self.maximum_chance);
+                } else if excess < deficit && chance < self.minimum_chance {
+                    chances[i] = Some(self.minimum_chance);
+                }
+            }
+        }
+        chances.into_iter().flatten().collect()
+    }
+
+    /// Returns the non-negative weight of every operator according to the
+    /// [`AdaptationStrategy`].
+    ///
+    /// # Parameters
+    ///
+    /// * `operators` - the [`OperatorStatistics`] of every operator if any were recorded
+    ///
+    /// [`AdaptationStrategy`]: ./enum.AdaptationStrategy.html
+    /// [`OperatorStatistics`]: ./struct.OperatorStatistics.html
+    fn weights(&self, operators: &[Option<&OperatorStatistics>]) -> Vec<f64> {
+        let means: Vec<Option<(f64, u32)>> = operators
+            .iter()
+            .map(|operator| {
+                operator.and_then(|statistics| {
+                    statistics
+                        .mean_fitness_delta()
+                        .map(|mean| (mean, statistics.fitness_delta().count()))
+                })
+            })
+            .collect();
+        match self.strategy {
+            AdaptationStrategy::ProbabilityMatching => {
+                let qualities: Vec<f64> = means
+                    .iter()
+                    .flatten()
+                    .map(|(mean, _)| mean.max(0.0))
+                    .collect();
+                let average_quality = if qualities.is_empty() {
+                    0.0
+                } else {
+                    qualities.iter().sum::<f64>() / qualities.len() as f64
+                };
+                means
+                    .iter()
+                    .map(|mean| mean.map_or(average_quality, |(mean, _)| mean.max(0.0)))
+                    .collect()
+            },
+            AdaptationStrategy::UpperConfidenceBound { exploration } => {
+                let total_count: u32 = means.iter().flatten().map(|(_, count)| count).sum();
+                let bounds: Vec<Option<f64>> = means
+                    .iter()
+                    .map(|mean| {
+                        mean.map(|(mean, count)| {
+                            mean + exploration
+                                * (2.0 * (total_count as f64).ln() / count as f64).sqrt()
+                        })
+                    })
+                    .collect();
+                let lowest = bounds
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .fold(f64::INFINITY, f64::min);
+                let highest = bounds
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .fold(f64::NEG_INFINITY, f64::max);
+                if lowest.is_infinite() {
+                    // No operator produced tested offspring yet.
+                    return vec![0.0; operators.len()];
+                }
+                // Unexplored operators are treated like the best operator plus an exploration
+                // bonus, the worst explored operator receives only the minimum chance.
+                let unexplored = highest - lowest + exploration.max(f64::EPSILON);
+                bounds
+                    .iter()
+                    .map(|bound| bound.map_or(unexplored, |bound| bound - lowest))
+                    .collect()
+            },
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+/// A `SelfAdaptation` lets every [`Genome`] carry its own [`RateModifiers`] scaling the
+/// chances of the [`Mutation`]s of a [`MutationCompendium`]. The modifiers are inherited,
+/// recombined and mutated log-normally before they are used to mutate offspring, as in
+/// evolution strategies.
+///
+/// [`Genome`]: ../gene/struct.Genome.html
+/// [`Mutation`]: ./struct.Mutation.html
+/// [`MutationCompendium`]: ./struct.MutationCompendium.html
+/// [`RateModifiers`]: ../gene/struct.RateModifiers.html
+pub struct SelfAdaptation {
+    learning_rate: f64,
+    minimum_modifier: f64,
+    maximum_modifier: f64,
+}
+
+impl SelfAdaptation {
+    /// Creates a new `SelfAdaptation`.
+    ///
+    /// # Parameters
+    ///
+    /// * `learning_rate` - the standard deviation of the logarithmic change of a modifier per
+    ///   generation, commonly `1 / sqrt(number of mutations)`
+    /// * `minimum_modifier` - the lower bound of every modifier
+    /// * `maximum_modifier` - the upper bound of every modifier
+    ///
+    /// # Panics
+    ///
+    /// If the learning rate is negative or the bounds are not positive and ordered.
+    pub fn new(learning_rate: f64, minimum_modifier: f64, maximum_modifier: f64) -> Self {
+        assert!(
+            learning_rate >= 0.0,
+            "The learning rate must not be negative, but was {}.",
+            learning_rate
+        );
+        assert!(
+            minimum_modifier > 0.0 && minimum_modifier <= maximum_modifier,
+            "The modifier bounds must be positive and ordered, but were {} and {}.",
+            minimum_modifier,
+            maximum_modifier
+        );
+        SelfAdaptation {
+            learning_rate,
+            minimum_modifier,
+            maximum_modifier,
+        }
+    }
+
+    /// Returns the standard deviation of the logarithmic change of a modifier.
+    pub fn learning_rate(&self) -> f64 {
+        self.learning_rate
+    }
+
+    /// Returns the lower bound of every modifier.
+    pub fn minimum_modifier(&self) -> f64 {
+        self.minimum_modifier
+    }
+
+    /// Returns the upper bound of every modifier.
+    pub fn maximum_modifier(&self) -> f64 {
+        self.maximum_modifier
+    }
+}
+
+#[cfg(test)]
+mod tests;
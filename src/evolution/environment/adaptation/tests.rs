@@ -0,0 +1,78 @@
+use crate::evolution::fitness::FitnessStatistics;
+
+use super::*;
+
+/// Creates [`OperatorStatistics`] with the specified fitness differences.
+fn test_operator(fitness_deltas: &[f64]) -> OperatorStatistics {
+    let mut fitness_delta = FitnessStatistics::new();
+    for delta in fitness_deltas {
+        fitness_delta.add(*delta);
+    }
+    OperatorStatistics::new(fitness_deltas.len() as u64, 0, fitness_delta)
+}
+
+#[test]
+#[should_panic]
+/// Tests if the function `new` of the [`OperatorAdaptation`] struct correctly panics on
+/// inverted bounds.
+fn test_operator_adaptation_new_invalid() {
+    OperatorAdaptation::new(AdaptationStrategy::ProbabilityMatching, 0.5, 0.1);
+}
+
+#[test]
+/// Tests if the function `allocate` of the [`OperatorAdaptation`] struct correctly matches
+/// the chances to the mean improvement.
+fn test_operator_adaptation_allocate_probability_matching() {
+    let adaptation = OperatorAdaptation::new(AdaptationStrategy::ProbabilityMatching, 0.01, 1.0);
+    let good = test_operator(&[0.3, 0.1]);
+    let bad = test_operator(&[-0.5]);
+    let chances = adaptation.allocate(0.4, &[Some(&good), Some(&bad), None]);
+    // The unobserved operator performs like the average observed operator.
+    // The chance taken by the bound operator is redistributed among the others.
+    assert_ulps_eq!(chances[0], 0.39 * 0.2 / 0.3);
+    assert_ulps_eq!(chances[1], 0.01);
+    assert_ulps_eq!(chances[2], 0.39 * 0.1 / 0.3);
+    // Without any observations the chances are distributed equally.
+    let chances = adaptation.allocate(0.4, &[None, None]);
+    assert_ulps_eq!(chances[0], 0.2);
+    assert_ulps_eq!(chances[1], 0.2);
+}
+
+#[test]
+/// Tests if the function `allocate` of the [`OperatorAdaptation`] struct correctly prefers
+/// unexplored and promising operators using upper confidence bounds.
+fn test_operator_adaptation_allocate_upper_confidence_bound() {
+    let adaptation = OperatorAdaptation::new(
+        AdaptationStrategy::UpperConfidenceBound { exploration: 0.1 },
+        0.05,
+        0.5,
+    );
+    let good = test_operator(&[0.2, 0.2, 0.2, 0.2]);
+    let bad = test_operator(&[-0.2, -0.2, -0.2, -0.2]);
+    let chances = adaptation.allocate(0.6, &[Some(&good), Some(&bad), None]);
+    // The worst operator is bound to the minimum chance.
+    assert_ulps_eq!(chances[1], 0.05);
+    assert!(chances[2] > chances[0]);
+    assert!(chances[0] > chances[1]);
+    assert!(chances.iter().all(|chance| *chance <= 0.5));
+    assert_ulps_eq!(chances.iter().sum::<f64>(), 0.6);
+}
+
+#[test]
+/// Tests if the function `allocate` of the [`OperatorAdaptation`] struct correctly keeps the
+/// sum of all chances constant while respecting the bounds.
+fn test_operator_adaptation_allocate_sum() {
+    let adaptation = OperatorAdaptation::new(AdaptationStrategy::ProbabilityMatching, 0.05, 0.3);
+    let best = test_operator(&[0.9]);
+    let good = test_operator(&[0.2]);
+    let bad = test_operator(&[-0.5]);
+    let worse = test_operator(&[-0.7]);
+    let chances = adaptation.allocate(0.8, &[Some(&best), Some(&good), Some(&bad), Some(&worse)]);
+    assert_ulps_eq!(chances.iter().sum::<f64>(), 0.8);
+    assert!(chances.iter().all(|chance| (0.05..=0.3).contains(chance)));
+    assert_ulps_eq!(chances[0], 0.3);
+    assert_ulps_eq!(chances[1], 0.3);
+    // The remaining chance is shared equally by operators without any improvement.
+    assert_ulps_eq!(chances[2], 0.1);
+    assert_ulps_eq!(chances[3], 0.1);
+}
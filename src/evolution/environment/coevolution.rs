@@ -0,0 +1,1016 @@
+//! The `coevolution` module contains the competitive co-evolution of a [`Population`] solving
+//! a task and a [`Population`] generating the test cases for said task.
+//!
+//! [`Population`]: ../population/struct.Population.html
+
+use crate::evolution::chemistry::{Information, Input, Output, Reaction, State};
+use crate::evolution::genealogy::{GenealogyEvent, GenealogyLog, Lineage};
+use crate::evolution::helper::ScalingFactor;
+use crate::evolution::population::{Individual, OrganismInformation, Population, SharedIndividual};
+use rand::{thread_rng, Rng};
+use rayon::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::{BTreeMap, VecDeque};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+use uuid::Uuid;
+
+use super::configuration::Environment;
+use super::metrics::GenerationMetrics;
+use super::mortality::{AgeSigmoidMortality, MortalityModel};
+use super::MutationCompendium;
+
+/// A thread-safe [`HallOfFame`] of [`Individual`]s.
+///
+/// [`Individual`]: ../population/struct.Individual.html
+type SharedHallOfFame<
+    ReactionType,
+    StateType,
+    InformationType,
+    InputElementType,
+    InputSensorType,
+    OutputElementType,
+    OutputSensorType,
+> = Mutex<
+    HallOfFame<
+        Individual<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    >,
+>;
+
+/// The function scoring a single challenge posed to an evaluating [`Organism`].
+///
+/// [`Organism`]: ../population/struct.Organism.html
+type CoevolutionFitnessFunction<InputElementType, OutputElementType> = Box<
+    dyn Fn(Vec<OrganismInformation<InputElementType, OutputElementType>>, ScalingFactor) -> f64
+        + Send
+        + Sync
+        + 'static,
+>;
+
+/// A `HallOfFame` keeps a limited number of the best members of past generations, so they can
+/// be used as opponents in later generations. This prevents co-evolving populations from
+/// cycling through the same strategies over and over again.
+#[derive(Debug, PartialEq, Clone)]
+pub struct HallOfFame<T> {
+    capacity: usize,
+    members: VecDeque<(Uuid, T)>,
+}
+
+impl<T: Clone> HallOfFame<T> {
+    /// Creates a new empty `HallOfFame` with the specified capacity.
+    /// A capacity of zero disables the `HallOfFame`.
+    ///
+    /// # Parameters
+    ///
+    /// * `capacity` - the maximum number of members
+    pub fn new(capacity: usize) -> Self {
+        HallOfFame {
+            capacity,
+            members: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the maximum number of members of the `HallOfFame`.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the current number of members of the `HallOfFame`.
+    pub fn size(&self) -> usize {
+        self.members.len()
+    }
+
+    /// Checks if the `HallOfFame` has no members.
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Checks if a member with the specified UUID is part of the `HallOfFame`.
+    ///
+    /// # Parameters
+    ///
+    /// * `uuid` - the UUID of the member
+    pub fn contains(&self, uuid: &Uuid) -> bool {
+        self.members
+            .iter()
+            .any(|(member_uuid, _)| member_uuid == uuid)
+    }
+
+    /// Adds a new member to the `HallOfFame`. If the `HallOfFame` is full, the oldest member
+    /// is removed. Members that are already part of the `HallOfFame` are not added a second time.
+    ///
+    /// # Parameters
+    ///
+    /// * `uuid` - the UUID of the new member
+    /// * `member` - the new member
+    pub fn induct(&mut self, uuid: Uuid, member: T) {
+        if self.capacity > 0 && !self.contains(&uuid) {
+            if self.members.len() >= self.capacity {
+                self.members.pop_front();
+            }
+            self.members.push_back((uuid, member));
+        }
+    }
+
+    /// Returns a copy of a random member if there is any.
+    pub fn random_member(&self) -> Option<T> {
+        if self.members.is_empty() {
+            None
+        } else {
+            let index = thread_rng().gen_range(0..self.members.len());
+            self.members.get(index).map(|(_, member)| member.clone())
+        }
+    }
+
+    /// Returns copies of all members ordered from the oldest to the newest one.
+    pub fn members(&self) -> Vec<T> {
+        self.members
+            .iter()
+            .map(|(_, member)| member.clone())
+            .collect()
+    }
+}
+
+/// A `CoevolutionaryNiche` contains two competing [`Population`]s.
+/// The evaluators are trying to solve a task, while the challengers are generating the test
+/// cases for said task. Each generation the evaluators are paired with challengers and the
+/// challengers are rewarded for every challenge the evaluators fail to solve.
+///
+/// [`Population`]: ../population/struct.Population.html
+pub struct CoevolutionaryNiche<
+    ReactionType,
+    StateType,
+    InformationType,
+    InputElementType,
+    InputSensorType,
+    OutputElementType,
+    OutputSensorType,
+    ChallengerReactionType,
+    ChallengerStateType,
+    ChallengerInformationType,
+    ChallengerInputElementType,
+    ChallengerInputSensorType,
+    ChallengerOutputSensorType,
+> {
+    environment: Arc<Environment>,
+    evaluators: Mutex<
+        Population<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    >,
+    challengers: Mutex<
+        Population<
+            ChallengerReactionType,
+            ChallengerStateType,
+            ChallengerInformationType,
+            ChallengerInputElementType,
+            ChallengerInputSensorType,
+            InputElementType,
+            ChallengerOutputSensorType,
+        >,
+    >,
+    seed_function: Box<dyn Fn() -> ChallengerInputElementType + Send + Sync + 'static>,
+    fitness_function: CoevolutionFitnessFunction<InputElementType, OutputElementType>,
+    evaluator_mutations: MutationCompendium<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+    challenger_mutations: MutationCompendium<
+        ChallengerReactionType,
+        ChallengerStateType,
+        ChallengerInformationType,
+        ChallengerInputElementType,
+        ChallengerInputSensorType,
+        InputElementType,
+        ChallengerOutputSensorType,
+    >,
+    evaluator_hall_of_fame: SharedHallOfFame<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+    challenger_hall_of_fame: SharedHallOfFame<
+        ChallengerReactionType,
+        ChallengerStateType,
+        ChallengerInformationType,
+        ChallengerInputElementType,
+        ChallengerInputSensorType,
+        InputElementType,
+        ChallengerOutputSensorType,
+    >,
+    hall_of_fame_opponents: usize,
+    mortality_model: Box<dyn MortalityModel>,
+}
+
+impl<
+        ReactionType: Reaction<InformationType>,
+        StateType: State<InformationType>,
+        InformationType: Information,
+        InputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+        InputSensorType: Input<InputElementType, InformationType>,
+        OutputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+        OutputSensorType: Output<OutputElementType, InformationType>,
+        ChallengerReactionType: Reaction<ChallengerInformationType>,
+        ChallengerStateType: State<ChallengerInformationType>,
+        ChallengerInformationType: Information,
+        ChallengerInputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+        ChallengerInputSensorType: Input<ChallengerInputElementType, ChallengerInformationType>,
+        ChallengerOutputSensorType: Output<InputElementType, ChallengerInformationType>,
+    >
+    CoevolutionaryNiche<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+        ChallengerReactionType,
+        ChallengerStateType,
+        ChallengerInformationType,
+        ChallengerInputElementType,
+        ChallengerInputSensorType,
+        ChallengerOutputSensorType,
+    >
+{
+    /// Creates a new `CoevolutionaryNiche` executing the competitive co-evolution of
+    /// evaluators and challengers. The output of a challenger is used as input of an evaluator,
+    /// so the challengers effectively generate the test cases for the evaluators.
+    ///
+    /// # Parameters
+    ///
+    /// * `environment` - the [`Environment`] that defines the basic properties of the network
+    /// * `evaluators` - the starting [`Population`] trying to solve the task
+    /// * `challengers` - the starting [`Population`] generating test cases for the task
+    /// * `seed_function` - the function supplying the challengers with input to generate
+    ///   test cases from
+    /// * `fitness_function` - the function evaluating an evaluator's fitness based on the
+    ///   result obtained for a test case, which is supplied as result information;
+    ///   the fitness of the challenger is the complementary value
+    /// * `evaluator_mutations` - the mutations that might occur during evaluator genome duplication
+    /// * `challenger_mutations` - the mutations that might occur during challenger genome duplication
+    ///
+    /// [`Environment`]: ./struct.Environment.html
+    /// [`Population`]: ../population/struct.Population.html
+    pub fn new(
+        environment: Environment,
+        evaluators: Population<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+        challengers: Population<
+            ChallengerReactionType,
+            ChallengerStateType,
+            ChallengerInformationType,
+            ChallengerInputElementType,
+            ChallengerInputSensorType,
+            InputElementType,
+            ChallengerOutputSensorType,
+        >,
+        seed_function: Box<dyn Fn() -> ChallengerInputElementType + Send + Sync + 'static>,
+        fitness_function: CoevolutionFitnessFunction<InputElementType, OutputElementType>,
+        evaluator_mutations: MutationCompendium<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+        challenger_mutations: MutationCompendium<
+            ChallengerReactionType,
+            ChallengerStateType,
+            ChallengerInformationType,
+            ChallengerInputElementType,
+            ChallengerInputSensorType,
+            InputElementType,
+            ChallengerOutputSensorType,
+        >,
+    ) -> Self {
+        CoevolutionaryNiche {
+            environment: Arc::new(environment),
+            evaluators: Mutex::new(evaluators),
+            challengers: Mutex::new(challengers),
+            seed_function,
+            fitness_function,
+            evaluator_mutations,
+            challenger_mutations,
+            evaluator_hall_of_fame: Mutex::new(HallOfFame::new(0)),
+            challenger_hall_of_fame: Mutex::new(HallOfFame::new(0)),
+            hall_of_fame_opponents: 0,
+            mortality_model: Box::new(AgeSigmoidMortality),
+        }
+    }
+
+    /// Enables the [`HallOfFame`]s of both populations. After each generation the fittest
+    /// [`Individual`] of each population is inducted into the respective [`HallOfFame`] and
+    /// each [`Individual`] additionally competes against the specified number of
+    /// past opponents.
+    ///
+    /// # Parameters
+    ///
+    /// * `capacity` - the number of past opponents to keep per population
+    /// * `opponents` - the number of past opponents each [`Individual`] competes against
+    ///   per generation
+    ///
+    /// [`HallOfFame`]: ./struct.HallOfFame.html
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn hall_of_fame(&mut self, capacity: usize, opponents: usize) -> &mut Self {
+        self.evaluator_hall_of_fame = Mutex::new(HallOfFame::new(capacity));
+        self.challenger_hall_of_fame = Mutex::new(HallOfFame::new(capacity));
+        self.hall_of_fame_opponents = opponents;
+        self
+    }
+
+    /// Sets the [`MortalityModel`] determining the death of [`Individual`]s of both populations
+    /// at the end of each generation. By default [`Individual`]s die based on a sigmoid of
+    /// their age.
+    ///
+    /// # Parameters
+    ///
+    /// * `mortality_model` - the [`MortalityModel`]
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    /// [`MortalityModel`]: ./trait.MortalityModel.html
+    pub fn mortality_model<M: MortalityModel + 'static>(
+        &mut self,
+        mortality_model: M,
+    ) -> &mut Self {
+        self.mortality_model = Box::new(mortality_model);
+        self
+    }
+
+    /// Starts the co-evolution of both populations.
+    /// The genealogy and the metrics of each population are recorded in separate files.
+    ///
+    /// # Panics
+    ///
+    /// If the genealogy logs could not be opened or the genealogy or metrics could not
+    /// be recorded.
+    pub fn breath_life(&self) {
+        println!("Initialiasing the network...");
+        self.environment.initialise();
+        println!("Starting execution...");
+        let evaluator_run = self.environment.generate_uuid();
+        let challenger_run = self.environment.generate_uuid();
+        let evaluator_genealogy = open_genealogy_log(&self.environment, &evaluator_run);
+        let challenger_genealogy = open_genealogy_log(&self.environment, &challenger_run);
+        record_births(
+            &self
+                .evaluators
+                .lock()
+                .expect("A thread paniced while holding the population lock.")
+                .individuals(),
+            &evaluator_genealogy,
+        );
+        record_births(
+            &self
+                .challengers
+                .lock()
+                .expect("A thread paniced while holding the population lock.")
+                .individuals(),
+            &challenger_genealogy,
+        );
+        let mut generation: u64 = 0;
+        let mut fitness_scaling: ScalingFactor = self.environment.initial_fitness_scaling_factor();
+        let mut start = Instant::now();
+        loop {
+            generation += 1;
+            println!("Generation {}", generation);
+            self.evaluators
+                .lock()
+                .expect("A thread paniced while holding the population lock.")
+                .increment_age();
+            self.challengers
+                .lock()
+                .expect("A thread paniced while holding the population lock.")
+                .increment_age();
+            self.compete(fitness_scaling);
+            self.update_halls_of_fame();
+            advance_population(
+                &self.evaluators,
+                &self.evaluator_mutations,
+                &self.environment,
+                self.mortality_model.as_ref(),
+                &evaluator_genealogy,
+                generation,
+            );
+            advance_population(
+                &self.challengers,
+                &self.challenger_mutations,
+                &self.environment,
+                self.mortality_model.as_ref(),
+                &challenger_genealogy,
+                generation,
+            );
+            record_metrics(
+                &self.evaluators,
+                &self.evaluator_mutations,
+                generation,
+                fitness_scaling,
+                self.environment.metrics_path(&evaluator_run),
+            );
+            record_metrics(
+                &self.challengers,
+                &self.challenger_mutations,
+                generation,
+                fitness_scaling,
+                self.environment.metrics_path(&challenger_run),
+            );
+            let (evaluator_size, evaluator_fitness) = {
+                let evaluators = self
+                    .evaluators
+                    .lock()
+                    .expect("A thread paniced while holding the population lock.");
+                (evaluators.size(), evaluators.mean_fitness())
+            };
+            let (challenger_size, challenger_fitness) = {
+                let challengers = self
+                    .challengers
+                    .lock()
+                    .expect("A thread paniced while holding the population lock.");
+                (challengers.size(), challengers.mean_fitness())
+            };
+            println!("Evaluators: {} ; Mean Fitness: {} ; Challengers: {} ; Mean Fitness: {} ; Fitness Scaling: {}",
+                evaluator_size,
+                evaluator_fitness,
+                challenger_size,
+                challenger_fitness,
+                fitness_scaling.exponent());
+            if start.elapsed() >= self.environment.population_save_intervall() {
+                self.save_populations();
+                start = Instant::now();
+            }
+            // Modify the fitness function scaling factor.
+            if evaluator_fitness > 0.5 {
+                fitness_scaling.decrement();
+            } else if evaluator_fitness < 0.4 {
+                fitness_scaling.increment();
+            }
+        }
+    }
+
+    /// Pairs evaluators and challengers and updates the fitness of both based on the outcome.
+    /// Each evaluator faces a number of random challengers as defined by the testing
+    /// repetitions of the [`Environment`] and the configured number of past challengers
+    /// from the [`HallOfFame`]. Each challenger additionally faces past evaluators from the
+    /// [`HallOfFame`].
+    ///
+    /// # Parameters
+    ///
+    /// * `fitness_scaling` - the current fitness [`ScalingFactor`]
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding a lock.
+    ///
+    /// [`Environment`]: ./struct.Environment.html
+    /// [`HallOfFame`]: ./struct.HallOfFame.html
+    /// [`ScalingFactor`]: ../helper/struct.ScalingFactor.html
+    fn compete(&self, fitness_scaling: ScalingFactor) {
+        let evaluators = self
+            .evaluators
+            .lock()
+            .expect("A thread paniced while holding the population lock.")
+            .individuals();
+        let challengers = self
+            .challengers
+            .lock()
+            .expect("A thread paniced while holding the population lock.")
+            .individuals();
+        let evaluator_hall_of_fame = self
+            .evaluator_hall_of_fame
+            .lock()
+            .expect("A thread paniced while holding the hall of fame lock.")
+            .clone();
+        let challenger_hall_of_fame = self
+            .challenger_hall_of_fame
+            .lock()
+            .expect("A thread paniced while holding the hall of fame lock.")
+            .clone();
+        evaluators.par_iter().for_each(|evaluator| {
+            let current_evaluator = evaluator
+                .lock()
+                .expect("A thread paniced while holding the individual's lock.")
+                .clone();
+            let mut scores = Vec::new();
+            if !challengers.is_empty() {
+                for _ in 0..self.environment.testing_repetitions() {
+                    let challenger = &challengers[thread_rng().gen_range(0..challengers.len())];
+                    let current_challenger = challenger
+                        .lock()
+                        .expect("A thread paniced while holding the individual's lock.")
+                        .clone();
+                    let score =
+                        self.score(&current_evaluator, &current_challenger, fitness_scaling);
+                    challenger
+                        .lock()
+                        .expect("A thread paniced while holding the individual's lock.")
+                        .evaluate_new_fitness(1.0 - score);
+                    scores.push(score);
+                }
+            }
+            for _ in 0..self.hall_of_fame_opponents {
+                if let Some(past_challenger) = challenger_hall_of_fame.random_member() {
+                    scores.push(self.score(&current_evaluator, &past_challenger, fitness_scaling));
+                }
+            }
+            if !scores.is_empty() {
+                let fitness = scores.iter().sum::<f64>() / scores.len() as f64;
+                evaluator
+                    .lock()
+                    .expect("A thread paniced while holding the individual's lock.")
+                    .evaluate_new_fitness(fitness);
+            }
+        });
+        challengers.par_iter().for_each(|challenger| {
+            for _ in 0..self.hall_of_fame_opponents {
+                if let Some(past_evaluator) = evaluator_hall_of_fame.random_member() {
+                    let current_challenger = challenger
+                        .lock()
+                        .expect("A thread paniced while holding the individual's lock.")
+                        .clone();
+                    let score = self.score(&past_evaluator, &current_challenger, fitness_scaling);
+                    challenger
+                        .lock()
+                        .expect("A thread paniced while holding the individual's lock.")
+                        .evaluate_new_fitness(1.0 - score);
+                }
+            }
+        });
+    }
+
+    /// Lets the challenger generate a test case, lets the evaluator solve it and returns the
+    /// evaluator's fitness for this test case.
+    ///
+    /// # Parameters
+    ///
+    /// * `evaluator` - the [`Individual`] solving the test case
+    /// * `challenger` - the [`Individual`] generating the test case
+    /// * `fitness_scaling` - the current fitness [`ScalingFactor`]
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    /// [`ScalingFactor`]: ../helper/struct.ScalingFactor.html
+    fn score(
+        &self,
+        evaluator: &Individual<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+        challenger: &Individual<
+            ChallengerReactionType,
+            ChallengerStateType,
+            ChallengerInformationType,
+            ChallengerInputElementType,
+            ChallengerInputSensorType,
+            InputElementType,
+            ChallengerOutputSensorType,
+        >,
+        fitness_scaling: ScalingFactor,
+    ) -> f64 {
+        // Generate the test case on a copy of the cached organism, so no state is carried
+        // over between runs.
+        let mut challenging_organism = challenger.organism();
+        challenging_organism.set_input((self.seed_function)());
+        challenging_organism.live(&self.environment);
+        let challenge = challenging_organism.get_result();
+        // Solve the test case.
+        let mut evaluating_organism = evaluator.organism();
+        evaluating_organism.set_input(challenge.clone());
+        let outcome = evaluating_organism.live(&self.environment);
+        let information = OrganismInformation::new(
+            evaluating_organism.get_result(),
+            challenge,
+            evaluator.bytes() * 8,
+            outcome,
+            self.environment.lifespan(),
+            evaluator.associated_inputs(),
+            evaluator.associated_outputs(),
+            evaluating_organism.binary_size(),
+            self.environment.max_organism_size(),
+        );
+        (self.fitness_function)(vec![information], fitness_scaling)
+    }
+
+    /// Inducts the fittest evaluator and challenger into the respective [`HallOfFame`].
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding a lock.
+    ///
+    /// [`HallOfFame`]: ./struct.HallOfFame.html
+    fn update_halls_of_fame(&self) {
+        let fittest_evaluator = self
+            .evaluators
+            .lock()
+            .expect("A thread paniced while holding the population lock.")
+            .fittest_individual(1);
+        if let Some(fittest_evaluator) = fittest_evaluator {
+            let fittest_evaluator = fittest_evaluator
+                .lock()
+                .expect("A thread paniced while holding the individual's lock.")
+                .clone();
+            self.evaluator_hall_of_fame
+                .lock()
+                .expect("A thread paniced while holding the hall of fame lock.")
+                .induct(*fittest_evaluator.uuid(), fittest_evaluator);
+        }
+        let fittest_challenger = self
+            .challengers
+            .lock()
+            .expect("A thread paniced while holding the population lock.")
+            .fittest_individual(1);
+        if let Some(fittest_challenger) = fittest_challenger {
+            let fittest_challenger = fittest_challenger
+                .lock()
+                .expect("A thread paniced while holding the individual's lock.")
+                .clone();
+            self.challenger_hall_of_fame
+                .lock()
+                .expect("A thread paniced while holding the hall of fame lock.")
+                .induct(*fittest_challenger.uuid(), fittest_challenger);
+        }
+    }
+
+    /// Saves the current populations of evaluators and challengers.
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding the population lock or the files could not
+    /// be created.
+    fn save_populations(&self) {
+        println!("Waiting to save...");
+        let evaluator_path = self
+            .environment
+            .population_path(&self.environment.generate_uuid());
+        self.evaluators
+            .lock()
+            .expect("A thread paniced while holding the population lock.")
+            .snapshot_to_file(&evaluator_path)
+            .unwrap_or_else(|_| panic!("The file {:?} could not be created.", evaluator_path));
+        let challenger_path = self
+            .environment
+            .population_path(&self.environment.generate_uuid());
+        self.challengers
+            .lock()
+            .expect("A thread paniced while holding the population lock.")
+            .snapshot_to_file(&challenger_path)
+            .unwrap_or_else(|_| panic!("The file {:?} could not be created.", challenger_path));
+        println!(
+            "Saved evaluators to {:?} and challengers to {:?}!",
+            evaluator_path, challenger_path
+        );
+    }
+}
+
+/// Opens the genealogy log of the run with the specified UUID.
+///
+/// # Parameters
+///
+/// * `environment` - the [`Environment`] of the run
+/// * `run_uuid` - the UUID of the run
+///
+/// # Panics
+///
+/// If the genealogy log could not be opened.
+///
+/// [`Environment`]: ./struct.Environment.html
+fn open_genealogy_log(environment: &Environment, run_uuid: &Uuid) -> GenealogyLog {
+    let genealogy_path = environment.genealogy_path(run_uuid);
+    GenealogyLog::open(&genealogy_path).unwrap_or_else(|err| {
+        panic!("The genealogy log {:?} could not be opened: {}", genealogy_path, err)
+    })
+}
+
+/// Records the birth of the specified [`Individual`]s in the genealogy log.
+///
+/// # Parameters
+///
+/// * `individuals` - the [`Individual`]s that were born
+/// * `genealogy_log` - the [`GenealogyLog`] of the [`Population`]
+///
+/// # Panics
+///
+/// If another thread paniced while holding an individual's lock or the genealogy could not
+/// be recorded.
+///
+/// [`GenealogyLog`]: ../genealogy/struct.GenealogyLog.html
+/// [`Individual`]: ../population/struct.Individual.html
+/// [`Population`]: ../population/struct.Population.html
+fn record_births<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    individuals: &[SharedIndividual<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >],
+    genealogy_log: &GenealogyLog,
+) {
+    for individual in individuals {
+        let birth = {
+            let ind = individual
+                .lock()
+                .expect("A thread paniced while holding the individual's lock.");
+            GenealogyEvent::Birth {
+                uuid: *ind.uuid(),
+                lineage: ind.lineage().clone(),
+            }
+        };
+        genealogy_log
+            .record(&birth)
+            .unwrap_or_else(|err| panic!("The genealogy could not be recorded: {}", err));
+    }
+}
+
+/// Appends the [`GenerationMetrics`] of the [`Population`] to the specified file.
+///
+/// # Parameters
+///
+/// * `population` - the [`Population`] to record
+/// * `mutations` - the mutations of the [`Population`]
+/// * `generation` - the current generation
+/// * `fitness_scaling` - the current fitness [`ScalingFactor`]
+/// * `metrics_path` - the JSON lines file to append to
+///
+/// # Panics
+///
+/// If another thread paniced while holding the population lock or the metrics could not be
+/// saved.
+///
+/// [`GenerationMetrics`]: ./struct.GenerationMetrics.html
+/// [`Population`]: ../population/struct.Population.html
+/// [`ScalingFactor`]: ../helper/struct.ScalingFactor.html
+fn record_metrics<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+    P: AsRef<Path>,
+>(
+    population: &Mutex<
+        Population<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    >,
+    mutations: &MutationCompendium<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+    generation: u64,
+    fitness_scaling: ScalingFactor,
+    metrics_path: P,
+) {
+    let (statistics, rate_distributions) = {
+        let population = population
+            .lock()
+            .expect("A thread paniced while holding the population lock.");
+        let rate_distributions = if mutations.self_adaptation().is_some() {
+            population.rate_distributions(mutations.size())
+        } else {
+            Vec::new()
+        };
+        (population.statistics(), rate_distributions)
+    };
+    GenerationMetrics::new(generation, statistics, fitness_scaling.exponent(), BTreeMap::new())
+        .with_operator_statistics(mutations.operator_statistics())
+        .with_rate_distributions(rate_distributions)
+        .append_to_file(&metrics_path)
+        .unwrap_or_else(|err| {
+            panic!("The metrics could not be saved to {:?}: {}", metrics_path.as_ref(), err)
+        });
+}
+
+/// Distributes [`Resource`]s, mates the [`Individual`]s of the [`Population`] and kills
+/// [`Individual`]s based on the [`MortalityModel`]. Births and deaths are recorded in the
+/// genealogy log.
+///
+/// # Parameters
+///
+/// * `population` - the [`Population`] to advance by one generation
+/// * `mutations` - the mutations that might occur during genome duplication
+/// * `environment` - the [`Environment`] the [`Population`] is living in
+/// * `mortality_model` - the [`MortalityModel`] determining the death of [`Individual`]s
+/// * `genealogy_log` - the [`GenealogyLog`] of the [`Population`]
+/// * `generation` - the current generation
+///
+/// # Panics
+///
+/// If another thread paniced while holding a lock, the population is completely extinct or
+/// the genealogy could not be recorded.
+///
+/// [`Environment`]: ./struct.Environment.html
+/// [`GenealogyLog`]: ../genealogy/struct.GenealogyLog.html
+/// [`Individual`]: ../population/struct.Individual.html
+/// [`MortalityModel`]: ./trait.MortalityModel.html
+/// [`Population`]: ../population/struct.Population.html
+/// [`Resource`]: ../resource/struct.Resource.html
+fn advance_population<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    population: &Mutex<
+        Population<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    >,
+    mutations: &MutationCompendium<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+    environment: &Environment,
+    mortality_model: &dyn MortalityModel,
+    genealogy_log: &GenealogyLog,
+    generation: u64,
+) {
+    let individuals = {
+        let mut population = population
+            .lock()
+            .expect("A thread paniced while holding the population lock.");
+        population.distribute_resources();
+        population.individuals()
+    };
+    // Mate the individuals based on their accumulated resources.
+    let offspring: Vec<
+        Individual<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    > = individuals
+        .par_iter()
+        .flat_map_iter(|individual| {
+            let (own_uuid, own_genome, number_of_offspring) = {
+                let mut ind = individual
+                    .lock()
+                    .expect("A thread paniced while holding the individual's lock.");
+                (*ind.uuid(), ind.genome(), ind.spend_resources_for_mating())
+            };
+            (0..number_of_offspring)
+                .map(|_| {
+                    let partner = population
+                        .lock()
+                        .expect("A thread paniced while holding the population lock.")
+                        .random_individual_fitness_based(|_| true)
+                        // Unwrapping is safe here since we cannot call this function on empty populations.
+                        .unwrap();
+                    let (partner_uuid, partner_genome) = {
+                        let partner = partner
+                            .lock()
+                            .expect("A thread paniced while holding the individual's lock.");
+                        (*partner.uuid(), partner.genome())
+                    };
+                    Individual::mate_and_mutate(
+                        Arc::clone(&own_genome),
+                        partner_genome,
+                        mutations,
+                        environment,
+                        Lineage::new(vec![own_uuid, partner_uuid], generation),
+                    )
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    for child in &offspring {
+        genealogy_log
+            .record(&GenealogyEvent::Birth {
+                uuid: *child.uuid(),
+                lineage: child.lineage().clone(),
+            })
+            .unwrap_or_else(|err| panic!("The genealogy could not be recorded: {}", err));
+    }
+    let mut population = population
+        .lock()
+        .expect("A thread paniced while holding the population lock.");
+    population.append(offspring);
+    // Kill individuals based on the mortality model.
+    let statistics = population.statistics();
+    let deceased: Vec<
+        SharedIndividual<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    > = population
+        .individuals()
+        .into_iter()
+        .filter(|individual| {
+            let status = individual
+                .lock()
+                .expect("A thread paniced while holding the individual's lock.")
+                .status();
+            mortality_model.death_chance(&status, &statistics, environment)
+                >= thread_rng().gen_range(0.0..=1.0)
+        })
+        .collect();
+    for individual in deceased {
+        let (uuid, resources, death) = {
+            let ind = individual
+                .lock()
+                .expect("A thread paniced while holding the individual's lock.");
+            let death = GenealogyEvent::Death {
+                uuid: *ind.uuid(),
+                fitness: ind.fitness(),
+                times_tested: ind.times_tested(),
+            };
+            // An individual consumes 1.0 resources when being born, so this has to be
+            // repatriated additionally to the accumulated resources.
+            (*ind.uuid(), ind.resources() + 1.0, death)
+        };
+        genealogy_log
+            .record(&death)
+            .unwrap_or_else(|err| panic!("The genealogy could not be recorded: {}", err));
+        population.repatriate_resources(resources);
+        population
+            .remove(uuid)
+            .expect("The individual could not be removed.");
+    }
+    population.recycle();
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,162 @@
+use crate::evolution::{
+    environment::EnvironmentBuilder,
+    gene::{Gene, Genome, GenomicInputSensor, GenomicOutputSensor},
+    genealogy::Genealogy,
+    helper::{
+        noop::{NoOpInputElement, NoOpOutputElement},
+        testing::{TestGenome, TestInformation, TestInput, TestOutput, TestReaction, TestState},
+    },
+    population::{IndividualStatus, PopulationStatistics},
+    resource::Resource,
+};
+
+use super::*;
+
+/// Creates a [`Population`] of test individuals of the specified size.
+fn test_population(
+    environment: &Environment,
+    size: usize,
+) -> Population<
+    TestReaction,
+    TestState,
+    TestInformation,
+    NoOpInputElement,
+    TestInput,
+    NoOpOutputElement,
+    TestOutput,
+> {
+    let individuals = (0..size)
+        .map(|_| {
+            let genome: TestGenome = Genome::new(
+                GenomicInputSensor::default(),
+                GenomicOutputSensor::default(),
+                vec![Gene::new(vec![TestInformation { value: 0 }])],
+            );
+            Individual::new(environment.generate_uuid(), genome)
+        })
+        .collect();
+    Population::new(individuals, Resource::default())
+}
+
+#[test]
+/// Tests if the function `new` of the [`HallOfFame`] struct correctly creates an empty [`HallOfFame`].
+fn test_hall_of_fame_new() {
+    let hall_of_fame: HallOfFame<u32> = HallOfFame::new(3);
+    assert_eq!(hall_of_fame.capacity(), 3);
+    assert_eq!(hall_of_fame.size(), 0);
+    assert!(hall_of_fame.is_empty());
+    assert!(hall_of_fame.random_member().is_none());
+}
+
+#[test]
+/// Tests if the function `induct` of the [`HallOfFame`] struct correctly adds members and
+/// evicts the oldest ones.
+fn test_hall_of_fame_induct() {
+    let uuids: Vec<Uuid> = (0..4u128).map(Uuid::from_u128).collect();
+    let mut hall_of_fame: HallOfFame<u32> = HallOfFame::new(3);
+    hall_of_fame.induct(uuids[0], 0);
+    hall_of_fame.induct(uuids[1], 1);
+    // Members are only added once.
+    hall_of_fame.induct(uuids[1], 1);
+    assert_eq!(hall_of_fame.members(), vec![0, 1]);
+    hall_of_fame.induct(uuids[2], 2);
+    hall_of_fame.induct(uuids[3], 3);
+    assert_eq!(hall_of_fame.members(), vec![1, 2, 3]);
+    assert!(!hall_of_fame.contains(&uuids[0]));
+    assert!(hall_of_fame.contains(&uuids[3]));
+    assert!(hall_of_fame
+        .members()
+        .contains(&hall_of_fame.random_member().unwrap()));
+    // A capacity of zero disables the hall of fame.
+    let mut disabled: HallOfFame<u32> = HallOfFame::new(0);
+    disabled.induct(uuids[0], 0);
+    assert!(disabled.is_empty());
+}
+
+#[test]
+/// Tests if the function `compete` of the [`CoevolutionaryNiche`] struct correctly evaluates
+/// evaluators and challengers with complementary fitness values.
+fn test_coevolutionary_niche_compete() {
+    let environment = EnvironmentBuilder::new()
+        .testing_repetitions(2)
+        .build()
+        .unwrap();
+    let evaluators = test_population(&environment, 5);
+    let challengers = test_population(&environment, 5);
+    let mut niche = CoevolutionaryNiche::new(
+        environment,
+        evaluators,
+        challengers,
+        Box::new(|| ()),
+        Box::new(|_, _| 0.25),
+        MutationCompendium::new(),
+        MutationCompendium::new(),
+    );
+    niche.hall_of_fame(2, 1);
+    niche.compete(ScalingFactor::new(1.0));
+    for evaluator in niche.evaluators.lock().unwrap().individuals() {
+        let evaluator = evaluator.lock().unwrap();
+        assert_ulps_eq!(evaluator.fitness().unwrap(), 0.25);
+    }
+    for challenger in niche.challengers.lock().unwrap().individuals() {
+        if let Some(fitness) = challenger.lock().unwrap().fitness() {
+            assert_ulps_eq!(fitness, 0.75);
+        }
+    }
+    niche.evaluators.lock().unwrap().increment_age();
+    niche.challengers.lock().unwrap().increment_age();
+    niche.update_halls_of_fame();
+    assert_eq!(niche.evaluator_hall_of_fame.lock().unwrap().size(), 1);
+    assert_eq!(niche.challenger_hall_of_fame.lock().unwrap().size(), 1);
+}
+
+/// A [`MortalityModel`] killing every [`Individual`] for testing purposes.
+struct CertainDeath;
+
+impl MortalityModel for CertainDeath {
+    fn death_chance(
+        &self,
+        _individual: &IndividualStatus,
+        _population: &PopulationStatistics,
+        _environment: &Environment,
+    ) -> f64 {
+        1.0
+    }
+}
+
+#[test]
+/// Tests if the function `advance_population` correctly kills [`Individual`]s based on the
+/// [`MortalityModel`] and records their deaths in the genealogy log.
+fn test_advance_population() {
+    let environment = EnvironmentBuilder::new().build().unwrap();
+    let population = test_population(&environment, 3);
+    let uuids: Vec<Uuid> = population
+        .individuals()
+        .iter()
+        .map(|individual| {
+            let mut individual = individual.lock().unwrap();
+            individual.evaluate_new_fitness(0.5);
+            *individual.uuid()
+        })
+        .collect();
+    let population = Mutex::new(population);
+    let path = std::env::temp_dir()
+        .join(format!("oben_coevolution_{}.jsonl", Uuid::from_u128(rand::random())));
+    {
+        let genealogy_log = GenealogyLog::open(&path).unwrap();
+        advance_population(
+            &population,
+            &MutationCompendium::new(),
+            &environment,
+            &CertainDeath,
+            &genealogy_log,
+            1,
+        );
+    }
+    let genealogy = Genealogy::from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(population.lock().unwrap().size(), 0);
+    for uuid in uuids {
+        assert_eq!(genealogy.fitness(&uuid), Some(0.5));
+    }
+}
@@ -2,10 +2,16 @@
 
 use crate::evolution::helper::ScalingFactor;
 use rand::{thread_rng, Rng};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt::Display;
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
 use std::time::{Duration, SystemTime};
 
 use super::super::resource::Resource;
+use super::format::ConfigurationFormat;
 use uuid::{v1::Context, v1::Timestamp, Uuid};
 
 /// The sub-folder in which genome files are stored.
@@ -14,16 +20,31 @@ const SUBFOLDER_GENOME: &str = "genomes/dummy";
 const SUBFOLDER_GENOME_EXTINCT: &str = "extinct/dummy";
 /// The sub-folder in which population snapshot files are stored.
 const SUBFOLDER_POPULATION: &str = "populations/dummy";
+/// The sub-folder in which metrics files are stored.
+const SUBFOLDER_METRICS: &str = "metrics/dummy";
+/// The sub-folder in which genealogy log files are stored.
+const SUBFOLDER_GENEALOGY: &str = "genealogy/dummy";
 /// The file extension of genome files.
 const FILE_EXTENSION_GENOME: &str = "genome";
 /// The file extension of population files.
 const FILE_EXTENSION_POPULATION: &str = "population";
+/// The file extension of metrics files.
+const FILE_EXTENSION_METRICS: &str = "jsonl";
+/// The file extension of genealogy log files.
+const FILE_EXTENSION_GENEALOGY: &str = "jsonl";
+/// The name of the configuration file in the working directory without extension.
+const FILE_NAME_CONFIGURATION: &str = "environment";
+/// The prefix of environment variables overriding configured settings.
+const ENVIRONMENT_VARIABLE_PREFIX: &str = "OBEN_";
 
 /// An `EnvironmentBuilder` specifing settings for an evolutionary network to develop in and
 /// returning the corresponding [`Environment`].
+/// It can be stored as JSON, RON or TOML file, where missing settings take their default
+/// value and durations are specified in seconds.
 ///
 /// [`Environment`]: ./struct.Environment.html
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
 pub struct EnvironmentBuilder {
     /// The directory to store all information realted to the network execution.
     working_directory: PathBuf,
@@ -38,10 +59,12 @@ pub struct EnvironmentBuilder {
     ///
     /// [`Individual`]: ../population/struct.Individual.html
     /// [`Organism`]: ../population/struct.Organism.html
+    #[serde(with = "duration_seconds")]
     lifespan: Duration,
     /// The time intervall in which to save the current [`Population`] to a file.
     ///
     /// [`Population`]: ../population/struct.Population.html
+    #[serde(with = "duration_seconds")]
     population_save_intervall: Duration,
     /// The node for UUID creation.
     uuid_node: [u8; 6],
@@ -70,6 +93,11 @@ pub struct EnvironmentBuilder {
     ///
     /// [`Organism`]: ../population/struct.Organism.html
     max_organism_size: usize,
+    /// If the size of an [`Organism`] is constant at runtime, so size tracking and checks
+    /// can be skipped.
+    ///
+    /// [`Organism`]: ../population/struct.Organism.html
+    constant_organism_size: bool,
     /// The initial fitness [`ScalingFactor`](crate::evolution::helper::ScalingFactor).
     initial_fitness_scaling_factor: ScalingFactor,
 }
@@ -89,15 +117,165 @@ impl EnvironmentBuilder {
             testing_chance_sigmoid_midpoint: 50.0,
             testing_repetitions: 1,
             max_organism_size: 8 * 1024 * 1024 * 50,
+            constant_organism_size: false,
             initial_fitness_scaling_factor: ScalingFactor::new(1.1),
         }
     }
 
+    /// Loads an `EnvironmentBuilder` from a JSON, RON or TOML file depending on its extension.
+    /// Settings missing from the file take their default value.
+    /// An error will be returned if reading or parsing the file failed.
+    ///
+    /// # Parameters
+    ///
+    /// * `path_to_file` - the file from which the `EnvironmentBuilder` should be loaded
+    pub fn load_from_file<P: AsRef<Path>>(path_to_file: P) -> Result<Self, Box<dyn Error>> {
+        ConfigurationFormat::load_from_file(path_to_file)
+    }
+
+    /// Writes the `EnvironmentBuilder` to a JSON, RON or TOML file depending on its extension.
+    /// An error will be returned if writing to the file failed.
+    ///
+    /// # Parameters
+    ///
+    /// * `path_to_file` - the file the `EnvironmentBuilder` should be written to
+    pub fn write_to_file<P: AsRef<Path>>(&self, path_to_file: P) -> Result<(), Box<dyn Error>> {
+        ConfigurationFormat::write_to_file(self, path_to_file)
+    }
+
+    /// Creates an `EnvironmentBuilder` for the specified working directory.
+    /// The settings are loaded from the first existing `environment.toml`, `environment.json`
+    /// or `environment.ron` file in the working directory and overridden by environment
+    /// variables as described in [`apply_environment_variables`].
+    /// If no configuration file exists, the default settings are used.
+    /// An error will be returned if loading the file or parsing a variable failed.
+    ///
+    /// # Parameters
+    ///
+    /// * `working_directory` - the working directory containing the configuration file
+    ///
+    /// [`apply_environment_variables`]: #method.apply_environment_variables
+    pub fn from_working_directory<P: AsRef<Path>>(
+        working_directory: P,
+    ) -> Result<Self, Box<dyn Error>> {
+        let configuration_file = [
+            ConfigurationFormat::Toml,
+            ConfigurationFormat::Json,
+            ConfigurationFormat::Ron,
+        ]
+        .iter()
+        .map(|format| configuration_path(working_directory.as_ref(), *format))
+        .find(|path| path.is_file());
+        let mut builder = match configuration_file {
+            Some(path) => Self::load_from_file(path)?,
+            None => Self::new(),
+        };
+        builder
+            .working_directory(working_directory)
+            .apply_environment_variables()?;
+        Ok(builder)
+    }
+
+    /// Overrides settings with the values of the corresponding environment variables if
+    /// present. The variables are named after the settings in upper case with the prefix
+    /// `OBEN_`, e.g. `OBEN_POPULATION_SIZE`. Durations are specified in seconds, the UUID node
+    /// as 12 hexadecimal digits optionally separated by colons and the initial fitness scaling
+    /// factor as `base` or `base^exponent`. An empty `OBEN_MAX_TESTING_AGE` removes the maximum
+    /// testing age and an empty `OBEN_LATERAL_GENE_TRANSFER_CHANCE` restores the population
+    /// size dependent default.
+    /// An error listing every variable that could not be parsed will be returned if parsing
+    /// failed.
+    pub fn apply_environment_variables(&mut self) -> Result<&mut Self, EnvironmentError> {
+        self.apply_overrides(std::env::vars())
+    }
+
+    /// Overrides settings with the values of the specified variables as described in
+    /// [`apply_environment_variables`]. Variables without the `OBEN_` prefix or not
+    /// corresponding to a setting are ignored.
+    /// An error listing every variable that could not be parsed will be returned if parsing
+    /// failed.
+    ///
+    /// # Parameters
+    ///
+    /// * `variables` - the variable names and values
+    ///
+    /// [`apply_environment_variables`]: #method.apply_environment_variables
+    pub fn apply_overrides<I: IntoIterator<Item = (String, String)>>(
+        &mut self,
+        variables: I,
+    ) -> Result<&mut Self, EnvironmentError> {
+        let mut invalid_fields = Vec::new();
+        for (name, value) in variables {
+            let Some(field) = name.strip_prefix(ENVIRONMENT_VARIABLE_PREFIX) else {
+                continue;
+            };
+            let field = field.to_ascii_lowercase();
+            let result = match field.as_str() {
+                "working_directory" => {
+                    self.working_directory = PathBuf::from(&value);
+                    Ok(())
+                },
+                "population_size" => parse_override(&value).map(|v| self.population_size = v),
+                "resource_half_life" => parse_override(&value).map(|v| self.resource_half_life = v),
+                "lifespan" => parse_duration_override(&value).map(|v| self.lifespan = v),
+                "population_save_intervall" => {
+                    parse_duration_override(&value).map(|v| self.population_save_intervall = v)
+                },
+                "uuid_node" => parse_uuid_node_override(&value).map(|v| self.uuid_node = v),
+                "max_testing_age" => {
+                    if value.trim().is_empty() {
+                        self.max_testing_age = None;
+                        Ok(())
+                    } else {
+                        parse_override(&value).map(|v| self.max_testing_age = Some(v))
+                    }
+                },
+                "death_age_sigmoid_midpoint" => {
+                    parse_override(&value).map(|v| self.death_age_sigmoid_midpoint = v)
+                },
+                "lateral_gene_transfer_chance" => {
+                    if value.trim().is_empty() {
+                        self.lateral_gene_transfer_chance = None;
+                        Ok(())
+                    } else {
+                        parse_override(&value).map(|v| self.lateral_gene_transfer_chance = Some(v))
+                    }
+                },
+                "testing_chance_sigmoid_midpoint" => {
+                    parse_override(&value).map(|v| self.testing_chance_sigmoid_midpoint = v)
+                },
+                "testing_repetitions" => {
+                    parse_override(&value).map(|v| self.testing_repetitions = v)
+                },
+                "max_organism_size" => parse_override(&value).map(|v| self.max_organism_size = v),
+                "constant_organism_size" => {
+                    parse_override(&value).map(|v| self.constant_organism_size = v)
+                },
+                "initial_fitness_scaling_factor" => parse_scaling_factor_override(&value)
+                    .map(|v| self.initial_fitness_scaling_factor = v),
+                _ => continue,
+            };
+            if let Err(reason) = result {
+                invalid_fields.push(InvalidField::new(field, value, reason));
+            }
+        }
+        if invalid_fields.is_empty() {
+            Ok(self)
+        } else {
+            Err(EnvironmentError::new(invalid_fields))
+        }
+    }
+
     /// Build an [`Environment`] to specify run time properties of an evolutionary network.
+    /// An error listing every invalid setting will be returned if any setting is invalid.
     ///
     /// [`Environment`]: ./struct.Environment.html
-    pub fn build(&self) -> Environment {
-        Environment {
+    pub fn build(&self) -> Result<Environment, EnvironmentError> {
+        let invalid_fields = self.validate();
+        if !invalid_fields.is_empty() {
+            return Err(EnvironmentError::new(invalid_fields));
+        }
+        Ok(Environment {
             working_directory: self.working_directory.clone(),
             population_size: self.population_size,
             resource_half_life: self.resource_half_life,
@@ -106,13 +284,14 @@ impl EnvironmentBuilder {
             uuid_node: self.uuid_node,
             max_testing_age: self.max_testing_age,
             death_age_sigmoid_midpoint: self.death_age_sigmoid_midpoint,
-            lateral_gene_transfer_chance: self.lateral_gene_transfer_chance_or_default(),
+            lateral_gene_transfer_chance: self.lateral_gene_transfer_chance,
             testing_chance_sigmoid_midpoint: self.testing_chance_sigmoid_midpoint,
             testing_repetitions: self.testing_repetitions,
             max_organism_size: self.max_organism_size,
-            uuid_context: Context::new(0),
+            constant_organism_size: self.constant_organism_size,
+            uuid_context: Arc::new(Context::new(0)),
             initial_fitness_scaling_factor: self.initial_fitness_scaling_factor,
-        }
+        })
     }
 
     /// Sets the working directory as specified.
@@ -203,6 +382,23 @@ impl EnvironmentBuilder {
         self
     }
 
+    /// Sets if the size of an [`Organism`] is constant at runtime. If so, the size is
+    /// only calculated when the [`Organism`] is created and never checked against the
+    /// maximum size.
+    /// This should only be enabled for chemistries with constant size [`Substrate`]s,
+    /// since it allows unbounded memory growth otherwise.
+    ///
+    /// # Parameters
+    ///
+    /// * `constant_organism_size` - if the size of an [`Organism`] is constant
+    ///
+    /// [`Organism`]: ../population/struct.Organism.html
+    /// [`Substrate`]: ../protein/struct.Substrate.html
+    pub fn constant_organism_size(&mut self, constant_organism_size: bool) -> &mut Self {
+        self.constant_organism_size = constant_organism_size;
+        self
+    }
+
     /// Sets the midpoint of the sigmoid determining the chance of death for an individual
     /// depending on its age.
     ///
@@ -263,15 +459,79 @@ impl EnvironmentBuilder {
         self
     }
 
-    /// Returns the chance of lateral gene transfer if set.
-    /// Otherwise defaults to a population size dependent value.
-    fn lateral_gene_transfer_chance_or_default(&self) -> f64 {
+    /// Returns all invalid settings.
+    fn validate(&self) -> Vec<InvalidField> {
+        let mut invalid_fields = Vec::new();
+        let mut check = |valid: bool, field: &str, value: String, reason: &str| {
+            if !valid {
+                invalid_fields.push(InvalidField::new(field, value, reason));
+            }
+        };
+        check(
+            !self.working_directory.as_os_str().is_empty(),
+            "working_directory",
+            self.working_directory.display().to_string(),
+            "must not be empty",
+        );
+        check(
+            self.population_size > 0,
+            "population_size",
+            self.population_size.to_string(),
+            "must be larger than 0",
+        );
+        check(
+            self.resource_half_life.is_finite() && self.resource_half_life > 0.0,
+            "resource_half_life",
+            self.resource_half_life.to_string(),
+            "must be a positive number",
+        );
+        check(
+            !self.lifespan.is_zero(),
+            "lifespan",
+            format!("{:?}", self.lifespan),
+            "must be longer than 0 seconds",
+        );
+        check(
+            self.death_age_sigmoid_midpoint.is_finite() && self.death_age_sigmoid_midpoint > 0.0,
+            "death_age_sigmoid_midpoint",
+            self.death_age_sigmoid_midpoint.to_string(),
+            "must be a positive number",
+        );
         if let Some(lateral_gene_transfer_chance) = self.lateral_gene_transfer_chance {
-            lateral_gene_transfer_chance
-        } else {
-            // Defaults to around 10 events per generation.
-            10.0 / self.population_size as f64
+            check(
+                (0.0..=1.0).contains(&lateral_gene_transfer_chance),
+                "lateral_gene_transfer_chance",
+                lateral_gene_transfer_chance.to_string(),
+                "must be within [0.0, 1.0]",
+            );
         }
+        check(
+            self.testing_chance_sigmoid_midpoint.is_finite()
+                && self.testing_chance_sigmoid_midpoint > 0.0,
+            "testing_chance_sigmoid_midpoint",
+            self.testing_chance_sigmoid_midpoint.to_string(),
+            "must be a positive number",
+        );
+        check(
+            self.testing_repetitions > 0,
+            "testing_repetitions",
+            self.testing_repetitions.to_string(),
+            "must be larger than 0",
+        );
+        check(
+            self.max_organism_size > 0,
+            "max_organism_size",
+            self.max_organism_size.to_string(),
+            "must be larger than 0",
+        );
+        check(
+            self.initial_fitness_scaling_factor.base().is_finite()
+                && self.initial_fitness_scaling_factor.base() > 0.0,
+            "initial_fitness_scaling_factor",
+            self.initial_fitness_scaling_factor.base().to_string(),
+            "must have a positive base",
+        );
+        invalid_fields
     }
 }
 
@@ -281,8 +541,29 @@ impl Default for EnvironmentBuilder {
     }
 }
 
+impl From<&Environment> for EnvironmentBuilder {
+    fn from(environment: &Environment) -> Self {
+        EnvironmentBuilder {
+            working_directory: environment.working_directory.clone(),
+            population_size: environment.population_size,
+            resource_half_life: environment.resource_half_life,
+            lifespan: environment.lifespan,
+            population_save_intervall: environment.population_save_intervall,
+            uuid_node: environment.uuid_node,
+            max_testing_age: environment.max_testing_age,
+            death_age_sigmoid_midpoint: environment.death_age_sigmoid_midpoint,
+            lateral_gene_transfer_chance: environment.lateral_gene_transfer_chance,
+            testing_chance_sigmoid_midpoint: environment.testing_chance_sigmoid_midpoint,
+            testing_repetitions: environment.testing_repetitions,
+            max_organism_size: environment.max_organism_size,
+            constant_organism_size: environment.constant_organism_size,
+            initial_fitness_scaling_factor: environment.initial_fitness_scaling_factor,
+        }
+    }
+}
+
 /// An `Environment` specifing settings for an evolutionary network to develop in.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Environment {
     /// The directory to store all information realted to the network execution.
     working_directory: PathBuf,
@@ -315,8 +596,8 @@ pub struct Environment {
     /// [`Individual`]: ../population/struct.Individual.html
     death_age_sigmoid_midpoint: f64,
     /// The chance per growth event that a lateral gene transfer between two
-    /// sub-populations happens.
-    lateral_gene_transfer_chance: f64,
+    /// sub-populations happens if set.
+    lateral_gene_transfer_chance: Option<f64>,
     /// The 50 percent midpoint of test cycles of the chance determining sigmoid for testing a [`Individual`].
     ///
     /// [`Individual`]: ../population/struct.Individual.html
@@ -329,8 +610,12 @@ pub struct Environment {
     ///
     /// [`Organism`]: ../population/struct.Organism.html
     max_organism_size: usize,
-    /// The context for UUID creation.
-    uuid_context: Context,
+    /// If the size of an [`Organism`] is constant at runtime.
+    ///
+    /// [`Organism`]: ../population/struct.Organism.html
+    constant_organism_size: bool,
+    /// The context for UUID creation, which is shared by all copies of the `Environment`.
+    uuid_context: Arc<Context>,
     /// The initial fitness [`ScalingFactor`](crate::evolution::helper::ScalingFactor).
     initial_fitness_scaling_factor: ScalingFactor,
 }
@@ -380,6 +665,12 @@ impl Environment {
         self.max_organism_size
     }
 
+    /// Returns `true` if the size of an individual is constant during testing, so size
+    /// tracking and checks are skipped.
+    pub fn constant_organism_size(&self) -> bool {
+        self.constant_organism_size
+    }
+
     /// The midpoint of the testing chance sigmoid in test cycles.
     pub fn testing_chance_sigmoid_midpoint(&self) -> f64 {
         self.testing_chance_sigmoid_midpoint
@@ -423,7 +714,18 @@ impl Environment {
 
     /// Checks if lateral gene transfer happend on a statistical basis.
     pub fn lateral_gene_transfer(&self) -> bool {
-        thread_rng().gen_range(0.0..=1.0) <= self.lateral_gene_transfer_chance
+        thread_rng().gen_range(0.0..=1.0) <= self.lateral_gene_transfer_chance()
+    }
+
+    /// Returns the chance of lateral gene transfer if set.
+    /// Otherwise defaults to a population size dependent value.
+    fn lateral_gene_transfer_chance(&self) -> f64 {
+        if let Some(lateral_gene_transfer_chance) = self.lateral_gene_transfer_chance {
+            lateral_gene_transfer_chance
+        } else {
+            // Defaults to around 10 events per generation.
+            10.0 / self.population_size as f64
+        }
     }
 
     /// Returns the number of repetitions per testing cycle.
@@ -440,7 +742,7 @@ impl Environment {
         let now = SystemTime::now()
             .duration_since(SystemTime::UNIX_EPOCH)
             .expect("The system clock is not set correctly, so no UUIDs can be created.");
-        Timestamp::from_unix(&self.uuid_context, now.as_secs(), now.subsec_nanos())
+        Timestamp::from_unix(self.uuid_context.as_ref(), now.as_secs(), now.subsec_nanos())
     }
 
     /// Generates a UUID based on the `Environment`.
@@ -502,6 +804,32 @@ impl Environment {
         path_to_genome
     }
 
+    /// Returns the file path to the metrics of the run with the specified UUID.
+    ///
+    /// # Parameters
+    ///
+    /// * `run_uuid` - the UUID of the run
+    pub fn metrics_path(&self, run_uuid: &Uuid) -> PathBuf {
+        let mut path_to_metrics: PathBuf = self.working_directory().into();
+        path_to_metrics.push(SUBFOLDER_METRICS);
+        path_to_metrics.set_file_name(run_uuid.to_string());
+        path_to_metrics.set_extension(FILE_EXTENSION_METRICS);
+        path_to_metrics
+    }
+
+    /// Returns the file path to the genealogy log of the run with the specified UUID.
+    ///
+    /// # Parameters
+    ///
+    /// * `run_uuid` - the UUID of the run
+    pub fn genealogy_path(&self, run_uuid: &Uuid) -> PathBuf {
+        let mut path_to_genealogy: PathBuf = self.working_directory().into();
+        path_to_genealogy.push(SUBFOLDER_GENEALOGY);
+        path_to_genealogy.set_file_name(run_uuid.to_string());
+        path_to_genealogy.set_extension(FILE_EXTENSION_GENEALOGY);
+        path_to_genealogy
+    }
+
     /// Returns the amount of time an [`Organism`] of a [`Individual`] has to complete a task.
     ///
     /// [`Organism`]: ../population/struct.Organism.html
@@ -522,6 +850,39 @@ impl Environment {
         self.initial_fitness_scaling_factor
     }
 
+    /// Returns the path of the configuration file in the specified format inside the
+    /// working directory.
+    ///
+    /// # Parameters
+    ///
+    /// * `format` - the [`ConfigurationFormat`] of the file
+    ///
+    /// [`ConfigurationFormat`]: ./enum.ConfigurationFormat.html
+    pub fn configuration_path(&self, format: ConfigurationFormat) -> PathBuf {
+        configuration_path(self.working_directory(), format)
+    }
+
+    /// Writes the settings of the `Environment` to the configuration file in the specified
+    /// format inside the working directory, so they are picked up by
+    /// [`EnvironmentBuilder::from_working_directory`]. Returns the path of the written file.
+    /// An error will be returned if writing to the file failed.
+    ///
+    /// # Parameters
+    ///
+    /// * `format` - the [`ConfigurationFormat`] of the file
+    ///
+    /// [`ConfigurationFormat`]: ./enum.ConfigurationFormat.html
+    /// [`EnvironmentBuilder::from_working_directory`]: ./struct.EnvironmentBuilder.html#method.from_working_directory
+    pub fn save_configuration(
+        &self,
+        format: ConfigurationFormat,
+    ) -> Result<PathBuf, Box<dyn Error>> {
+        let path = self.configuration_path(format);
+        std::fs::create_dir_all(self.working_directory())?;
+        EnvironmentBuilder::from(self).write_to_file(&path)?;
+        Ok(path)
+    }
+
     /// Initialises the environment.
     ///
     /// # Panics
@@ -531,6 +892,72 @@ impl Environment {
         self.create_subfolder(SUBFOLDER_GENOME);
         self.create_subfolder(SUBFOLDER_POPULATION);
         self.create_subfolder(SUBFOLDER_GENOME_EXTINCT);
+        self.create_subfolder(SUBFOLDER_METRICS);
+        self.create_subfolder(SUBFOLDER_GENEALOGY);
+    }
+
+    /// Sets the size in individuals a [`Population`] can grow to.
+    ///
+    /// # Parameters
+    ///
+    /// * `population_size` - the maximum size of the total population
+    ///
+    /// [`Population`]: ../population/struct.Population.html
+    pub(crate) fn set_population_size(&mut self, population_size: u32) {
+        self.population_size = population_size;
+    }
+
+    /// Sets the amount of time an [`Organism`] has to complete a task.
+    ///
+    /// # Parameters
+    ///
+    /// * `lifespan` - the lifespan of an [`Organism`]
+    ///
+    /// [`Organism`]: ../population/struct.Organism.html
+    pub(crate) fn set_lifespan(&mut self, lifespan: Duration) {
+        self.lifespan = lifespan;
+    }
+
+    /// Sets the maximum size a single [`Organism`] can grow to during testing in bit.
+    ///
+    /// # Parameters
+    ///
+    /// * `max_organism_size` - the maximum size of an [`Organism`]
+    ///
+    /// [`Organism`]: ../population/struct.Organism.html
+    pub(crate) fn set_max_organism_size(&mut self, max_organism_size: usize) {
+        self.max_organism_size = max_organism_size;
+    }
+
+    /// Sets the number of repetitions per testing cycle.
+    ///
+    /// # Parameters
+    ///
+    /// * `testing_repetitions` - the number of repetitions per testing cycle
+    pub(crate) fn set_testing_repetitions(&mut self, testing_repetitions: u32) {
+        self.testing_repetitions = testing_repetitions;
+    }
+
+    /// Sets the midpoint of the sigmoid determing the chance of death depending on the age
+    /// of an individual.
+    ///
+    /// # Parameters
+    ///
+    /// * `death_age_sigmoid_midpoint` - the midpoint of the death sigmoid
+    pub(crate) fn set_death_age_sigmoid_midpoint(&mut self, death_age_sigmoid_midpoint: f64) {
+        self.death_age_sigmoid_midpoint = death_age_sigmoid_midpoint;
+    }
+
+    /// Checks the settings of the `Environment` with the same rules as
+    /// [`build`](./struct.EnvironmentBuilder.html#method.build).
+    /// An error listing every invalid setting will be returned if any setting is invalid.
+    pub(crate) fn validate(&self) -> Result<(), EnvironmentError> {
+        let invalid_fields = EnvironmentBuilder::from(self).validate();
+        if invalid_fields.is_empty() {
+            Ok(())
+        } else {
+            Err(EnvironmentError::new(invalid_fields))
+        }
     }
 
     /// Creates the specified sub-folder based on the current working directory.
@@ -552,3 +979,183 @@ impl Environment {
         }
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+/// An `InvalidField` describes a single setting of an [`EnvironmentBuilder`] that is invalid.
+///
+/// [`EnvironmentBuilder`]: ./struct.EnvironmentBuilder.html
+pub struct InvalidField {
+    field: String,
+    value: String,
+    reason: String,
+}
+
+impl InvalidField {
+    /// Creates a new `InvalidField`.
+    ///
+    /// # Parameters
+    ///
+    /// * `field` - the name of the setting
+    /// * `value` - the invalid value
+    /// * `reason` - the requirement the value violates
+    fn new<F: Into<String>, V: Into<String>, R: Into<String>>(
+        field: F,
+        value: V,
+        reason: R,
+    ) -> Self {
+        InvalidField {
+            field: field.into(),
+            value: value.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Returns the name of the setting.
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    /// Returns the invalid value.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Returns the requirement the value violates.
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+}
+
+impl std::fmt::Display for InvalidField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({}) {}", self.field, self.value, self.reason)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+/// An `EnvironmentError` is returned when an [`EnvironmentBuilder`] contains invalid settings.
+/// It lists every [`InvalidField`].
+///
+/// [`EnvironmentBuilder`]: ./struct.EnvironmentBuilder.html
+/// [`InvalidField`]: ./struct.InvalidField.html
+pub struct EnvironmentError {
+    invalid_fields: Vec<InvalidField>,
+}
+
+impl EnvironmentError {
+    /// Creates a new `EnvironmentError` from the specified [`InvalidField`]s.
+    ///
+    /// # Parameters
+    ///
+    /// * `invalid_fields` - all invalid settings
+    ///
+    /// [`InvalidField`]: ./struct.InvalidField.html
+    fn new(invalid_fields: Vec<InvalidField>) -> Self {
+        EnvironmentError { invalid_fields }
+    }
+
+    /// Returns all invalid settings.
+    pub fn invalid_fields(&self) -> &[InvalidField] {
+        &self.invalid_fields
+    }
+}
+
+impl std::fmt::Display for EnvironmentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The environment configuration is invalid:")?;
+        for invalid_field in &self.invalid_fields {
+            write!(f, "\n  * {}", invalid_field)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for EnvironmentError {}
+
+/// Returns the path of the configuration file in the specified format inside the specified
+/// working directory.
+///
+/// # Parameters
+///
+/// * `working_directory` - the working directory
+/// * `format` - the [`ConfigurationFormat`] of the file
+///
+/// [`ConfigurationFormat`]: ./enum.ConfigurationFormat.html
+fn configuration_path(working_directory: &Path, format: ConfigurationFormat) -> PathBuf {
+    working_directory
+        .join(FILE_NAME_CONFIGURATION)
+        .with_extension(format.extension())
+}
+
+/// Parses the value of an overriding environment variable.
+///
+/// # Parameters
+///
+/// * `value` - the value to parse
+fn parse_override<T: FromStr>(value: &str) -> Result<T, String>
+where
+    T::Err: Display,
+{
+    value.trim().parse().map_err(|err: T::Err| err.to_string())
+}
+
+/// Parses the value of an overriding environment variable as duration in seconds.
+///
+/// # Parameters
+///
+/// * `value` - the value to parse
+fn parse_duration_override(value: &str) -> Result<Duration, String> {
+    Duration::try_from_secs_f64(parse_override(value)?).map_err(|err| err.to_string())
+}
+
+/// Parses the value of an overriding environment variable as UUID node of 12 hexadecimal
+/// digits, which may be separated by colons.
+///
+/// # Parameters
+///
+/// * `value` - the value to parse
+fn parse_uuid_node_override(value: &str) -> Result<[u8; 6], String> {
+    let digits: String = value.trim().chars().filter(|c| *c != ':').collect();
+    if digits.len() != 12 || !digits.is_ascii() {
+        return Err(format!("expected 12 hexadecimal digits, but got {}", value));
+    }
+    let mut node = [0u8; 6];
+    for (i, byte) in node.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&digits[2 * i..2 * i + 2], 16).map_err(|err| err.to_string())?;
+    }
+    Ok(node)
+}
+
+/// Parses the value of an overriding environment variable as [`ScalingFactor`] in the form
+/// `base` or `base^exponent`.
+///
+/// # Parameters
+///
+/// * `value` - the value to parse
+///
+/// [`ScalingFactor`]: ../helper/struct.ScalingFactor.html
+fn parse_scaling_factor_override(value: &str) -> Result<ScalingFactor, String> {
+    match value.split_once('^') {
+        Some((base, exponent)) => {
+            Ok(ScalingFactor::new_with_exponent(parse_override(base)?, parse_override(exponent)?))
+        },
+        None => parse_override(value).map(ScalingFactor::new),
+    }
+}
+
+/// The `duration_seconds` module serialises a `Duration` as floating point number of seconds.
+mod duration_seconds {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(duration.as_secs_f64())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        Duration::try_from_secs_f64(f64::deserialize(deserializer)?).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,210 @@
+use uuid::Uuid;
+
+use super::*;
+
+#[test]
+/// Tests if the function `build` of the [`EnvironmentBuilder`] struct correctly accepts the
+/// default settings.
+fn test_environment_builder_build_default() {
+    let environment = EnvironmentBuilder::new().build().unwrap();
+    assert_eq!(environment.population_size(), EnvironmentBuilder::new().population_size);
+}
+
+#[test]
+/// Tests if the function `build` of the [`EnvironmentBuilder`] struct correctly lists every
+/// invalid setting.
+fn test_environment_builder_build_invalid() {
+    let error = EnvironmentBuilder::new()
+        .population_size(0)
+        .resource_half_life(-1.0)
+        .lifespan(Duration::ZERO)
+        .death_age_sigmoid_midpoint(0.0)
+        .lateral_gene_transfer_chance(1.5)
+        .testing_chance_sigmoid_midpoint(f64::NAN)
+        .testing_repetitions(0)
+        .max_organism_size(0)
+        .build()
+        .unwrap_err();
+    let fields: Vec<&str> = error
+        .invalid_fields()
+        .iter()
+        .map(InvalidField::field)
+        .collect();
+    assert_eq!(
+        fields,
+        vec![
+            "population_size",
+            "resource_half_life",
+            "lifespan",
+            "death_age_sigmoid_midpoint",
+            "lateral_gene_transfer_chance",
+            "testing_chance_sigmoid_midpoint",
+            "testing_repetitions",
+            "max_organism_size",
+        ]
+    );
+    let message = error.to_string();
+    for field in fields {
+        assert!(message.contains(field));
+    }
+}
+
+#[test]
+/// Tests if the functions `write_to_file` and `load_from_file` of the [`EnvironmentBuilder`]
+/// struct correctly roundtrip the settings in every format.
+fn test_environment_builder_write_load() {
+    let mut builder = EnvironmentBuilder::new();
+    builder
+        .population_size(42)
+        .lifespan(Duration::from_millis(1500))
+        .max_testing_age(None)
+        .lateral_gene_transfer_chance(0.25)
+        .constant_organism_size(true);
+    for extension in ["json", "ron", "toml"] {
+        let path = std::env::temp_dir().join(format!(
+            "oben_environment_{}.{}",
+            Uuid::from_u128(rand::random()),
+            extension
+        ));
+        builder.write_to_file(&path).unwrap();
+        let loaded = EnvironmentBuilder::load_from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, builder);
+    }
+}
+
+#[test]
+/// Tests if the function `load_from_file` of the [`EnvironmentBuilder`] struct correctly
+/// applies defaults to missing settings and rejects unknown or negative ones.
+fn test_environment_builder_load_partial() {
+    let partial = ConfigurationFormat::Toml
+        .deserialize::<EnvironmentBuilder>("population_size = 7\nlifespan = 2.5\n")
+        .unwrap();
+    let mut expected = EnvironmentBuilder::new();
+    expected
+        .uuid_node(partial.uuid_node)
+        .population_size(7)
+        .lifespan(Duration::from_secs_f64(2.5));
+    assert_eq!(partial, expected);
+    assert!(ConfigurationFormat::Toml
+        .deserialize::<EnvironmentBuilder>("population_sise = 7\n")
+        .is_err());
+    assert!(ConfigurationFormat::Toml
+        .deserialize::<EnvironmentBuilder>("lifespan = -1.0\n")
+        .is_err());
+}
+
+#[test]
+/// Tests if the function `apply_overrides` of the [`EnvironmentBuilder`] struct correctly
+/// overrides settings and reports unparsable values.
+fn test_environment_builder_apply_overrides() {
+    let mut builder = EnvironmentBuilder::new();
+    builder
+        .max_testing_age(Some(3))
+        .apply_overrides(vec![
+            ("OBEN_POPULATION_SIZE".to_string(), "17".to_string()),
+            ("OBEN_LIFESPAN".to_string(), "3".to_string()),
+            ("OBEN_MAX_TESTING_AGE".to_string(), "".to_string()),
+            ("OBEN_CONSTANT_ORGANISM_SIZE".to_string(), "true".to_string()),
+            ("OBEN_UNKNOWN".to_string(), "x".to_string()),
+            ("POPULATION_SIZE".to_string(), "x".to_string()),
+        ])
+        .unwrap();
+    let mut expected = EnvironmentBuilder::new();
+    expected
+        .uuid_node(builder.uuid_node)
+        .population_size(17)
+        .lifespan(Duration::from_secs(3))
+        .max_testing_age(None)
+        .constant_organism_size(true);
+    assert_eq!(builder, expected);
+    let error = builder
+        .apply_overrides(vec![
+            ("OBEN_POPULATION_SIZE".to_string(), "-3".to_string()),
+            ("OBEN_LIFESPAN".to_string(), "-1".to_string()),
+        ])
+        .unwrap_err();
+    let fields: Vec<&str> = error
+        .invalid_fields()
+        .iter()
+        .map(InvalidField::field)
+        .collect();
+    assert_eq!(fields, vec!["population_size", "lifespan"]);
+}
+
+#[test]
+/// Tests if the function `apply_overrides` of the [`EnvironmentBuilder`] struct correctly
+/// overrides the UUID node, the initial fitness scaling factor and resets the lateral gene
+/// transfer chance.
+fn test_environment_builder_apply_overrides_all_fields() {
+    let mut builder = EnvironmentBuilder::new();
+    builder
+        .lateral_gene_transfer_chance(0.25)
+        .apply_overrides(vec![
+            ("OBEN_UUID_NODE".to_string(), "01:23:45:67:89:ab".to_string()),
+            ("OBEN_INITIAL_FITNESS_SCALING_FACTOR".to_string(), "1.5^2".to_string()),
+            ("OBEN_LATERAL_GENE_TRANSFER_CHANCE".to_string(), "".to_string()),
+        ])
+        .unwrap();
+    assert_eq!(builder.uuid_node, [0x01, 0x23, 0x45, 0x67, 0x89, 0xab]);
+    assert_eq!(builder.initial_fitness_scaling_factor, ScalingFactor::new_with_exponent(1.5, 2));
+    assert_eq!(builder.lateral_gene_transfer_chance, None);
+    builder
+        .apply_overrides(vec![
+            ("OBEN_UUID_NODE".to_string(), "0123456789AB".to_string()),
+            ("OBEN_INITIAL_FITNESS_SCALING_FACTOR".to_string(), "1.2".to_string()),
+        ])
+        .unwrap();
+    assert_eq!(builder.uuid_node, [0x01, 0x23, 0x45, 0x67, 0x89, 0xab]);
+    assert_eq!(builder.initial_fitness_scaling_factor, ScalingFactor::new(1.2));
+    let error = builder
+        .apply_overrides(vec![
+            ("OBEN_UUID_NODE".to_string(), "0123456789".to_string()),
+            ("OBEN_INITIAL_FITNESS_SCALING_FACTOR".to_string(), "1.2^x".to_string()),
+        ])
+        .unwrap_err();
+    let fields: Vec<&str> = error
+        .invalid_fields()
+        .iter()
+        .map(InvalidField::field)
+        .collect();
+    assert_eq!(fields, vec!["uuid_node", "initial_fitness_scaling_factor"]);
+}
+
+#[test]
+/// Tests if the conversion from an [`Environment`] into an [`EnvironmentBuilder`] correctly
+/// keeps every setting including an unset lateral gene transfer chance.
+fn test_environment_builder_from_environment() {
+    let mut builder = EnvironmentBuilder::new();
+    builder
+        .population_size(42)
+        .max_testing_age(Some(5))
+        .initial_fitness_scaling_factor(ScalingFactor::new_with_exponent(1.3, -1));
+    let environment = builder.build().unwrap();
+    assert_eq!(EnvironmentBuilder::from(&environment), builder);
+    builder.lateral_gene_transfer_chance(0.5);
+    let environment = builder.build().unwrap();
+    assert_eq!(EnvironmentBuilder::from(&environment), builder);
+}
+
+#[test]
+/// Tests if the functions `save_configuration` of the [`Environment`] struct and
+/// `from_working_directory` of the [`EnvironmentBuilder`] struct correctly roundtrip the
+/// settings through the working directory.
+fn test_environment_save_configuration() {
+    let working_directory =
+        std::env::temp_dir().join(format!("oben_environment_{}", Uuid::from_u128(rand::random())));
+    let environment = EnvironmentBuilder::new()
+        .working_directory(&working_directory)
+        .population_size(23)
+        .build()
+        .unwrap();
+    let path = environment
+        .save_configuration(ConfigurationFormat::Toml)
+        .unwrap();
+    assert_eq!(path, working_directory.join("environment.toml"));
+    let loaded = EnvironmentBuilder::from_working_directory(&working_directory).unwrap();
+    std::fs::remove_dir_all(&working_directory).unwrap();
+    assert_eq!(loaded.working_directory, working_directory);
+    assert_eq!(loaded.build().unwrap().population_size(), 23);
+}
@@ -6,18 +6,56 @@ use rand::{thread_rng, Rng};
 use rayon::prelude::*;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
+use std::collections::BTreeMap;
 use std::path::Path;
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, OnceLock, RwLock, RwLockReadGuard};
 use std::time::Instant;
 
 use super::super::chemistry::{Information, Reaction, State};
-use super::super::gene::Genome;
-use super::super::population::{Individual, Organism, OrganismInformation, Population};
+use super::super::fitness::SelectionCriterion;
+use super::super::gene::{Genome, RateDistribution};
+use super::super::genealogy::{GenealogyEvent, GenealogyLog, Lineage};
+use super::super::population::{
+    Individual, IndividualStatus, Organism, OrganismInformation, Population, PopulationStatistics,
+    SharedIndividual,
+};
 use super::super::resource::Resource;
 use super::configuration::Environment;
-use super::MutationCompendium;
+use super::layering::AgeLayering;
+use super::metrics::GenerationMetrics;
+use super::mortality::{AgeSigmoidMortality, MortalityModel};
+use super::retesting::{SigmoidTesting, TestingModel};
+use super::schedule::{Schedule, ScheduledParameter};
+use super::{
+    FailurePolicy, MutationCompendium, OperatorAdaptation, OperatorStatistics, SelfAdaptation,
+};
 use uuid::Uuid;
 
+/// A function generating random [`Genome`]s.
+///
+/// [`Genome`]: ../gene/struct.Genome.html
+type GenomeGenerator<
+    ReactionType,
+    StateType,
+    InformationType,
+    InputElementType,
+    InputSensorType,
+    OutputElementType,
+    OutputSensorType,
+> = Box<
+    dyn Fn() -> Genome<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        > + Send
+        + Sync
+        + 'static,
+>;
+
 /// An `EcologicalNiche` containing a [`Population`] and applying selective pressure.
 ///
 /// [`Population`]: ../population/struct.Population.html
@@ -115,15 +153,228 @@ impl<
     ) -> Self {
         EcologicalNiche {
             inner: Arc::new(InnerEcologicalNiche {
-                environment: Arc::new(environment),
+                environment: RwLock::new(Arc::new(environment)),
                 population: Arc::new(Mutex::new(population)),
                 supplier_function,
                 fitness_function,
-                mutations,
+                mutations: RwLock::new(mutations),
+                age_layering: None,
+                genome_generator: None,
+                schedules: Vec::new(),
+                mortality_model: Box::new(AgeSigmoidMortality),
+                testing_model: Box::new(SigmoidTesting),
+                genealogy_log: OnceLock::new(),
             }),
         }
     }
 
+    /// Splits the [`Population`] into age layers as specified. Mating only happens within a
+    /// layer and the layer below it and each layer is culled to its capacity based on fitness.
+    /// The [`MortalityModel`] is applied relative to the layer of an [`Individual`].
+    /// Offspring inherits the age of its older parent.
+    /// The bottom layer is periodically fed with [`Individual`]s of random [`Genome`]s.
+    ///
+    /// # Parameters
+    ///
+    /// * `age_layering` - the [`AgeLayering`] of the [`Population`]
+    /// * `genome_generator` - the function generating random [`Genome`]s to feed the bottom layer
+    ///
+    /// # Panics
+    ///
+    /// If the network is already running.
+    ///
+    /// [`AgeLayering`]: ./struct.AgeLayering.html
+    /// [`Genome`]: ../gene/struct.Genome.html
+    /// [`Individual`]: ../population/struct.Individual.html
+    /// [`MortalityModel`]: ./trait.MortalityModel.html
+    /// [`Population`]: ../population/struct.Population.html
+    pub fn age_layered(
+        &mut self,
+        age_layering: AgeLayering,
+        genome_generator: GenomeGenerator<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    ) -> &mut Self {
+        let inner = Arc::get_mut(&mut self.inner)
+            .expect("The ecological niche cannot be modified while the network is running.");
+        inner.age_layering = Some(age_layering);
+        inner.genome_generator = Some(genome_generator);
+        self
+    }
+
+    /// Schedules the specified parameter to change over the generations. All schedules are
+    /// applied at the start of each generation and their values are recorded in the
+    /// [`GenerationMetrics`].
+    ///
+    /// # Parameters
+    ///
+    /// * `parameter` - the [`ScheduledParameter`] to change
+    /// * `schedule` - the [`Schedule`] determining the value of the parameter
+    ///
+    /// # Panics
+    ///
+    /// If the network is already running.
+    ///
+    /// [`GenerationMetrics`]: ./struct.GenerationMetrics.html
+    /// [`Schedule`]: ./enum.Schedule.html
+    /// [`ScheduledParameter`]: ./enum.ScheduledParameter.html
+    pub fn schedule(&mut self, parameter: ScheduledParameter, schedule: Schedule) -> &mut Self {
+        let inner = Arc::get_mut(&mut self.inner)
+            .expect("The ecological niche cannot be modified while the network is running.");
+        inner.schedules.push((parameter, schedule));
+        self
+    }
+
+    /// Sets the [`MortalityModel`] determining the death of [`Individual`]s at the end of each
+    /// generation. By default [`Individual`]s die based on a sigmoid of their age.
+    /// In age layered populations the [`MortalityModel`] is applied within each layer.
+    ///
+    /// # Parameters
+    ///
+    /// * `mortality_model` - the [`MortalityModel`]
+    ///
+    /// # Panics
+    ///
+    /// If the network is already running.
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    /// [`MortalityModel`]: ./trait.MortalityModel.html
+    pub fn mortality_model<M: MortalityModel + 'static>(
+        &mut self,
+        mortality_model: M,
+    ) -> &mut Self {
+        let inner = Arc::get_mut(&mut self.inner)
+            .expect("The ecological niche cannot be modified while the network is running.");
+        inner.mortality_model = Box::new(mortality_model);
+        self
+    }
+
+    /// Sets the [`TestingModel`] determining if [`Individual`]s are tested in a generation.
+    /// By default [`Individual`]s are always tested until the maximum testing age and based on
+    /// a sigmoid of the number of times they were already tested afterwards.
+    ///
+    /// # Parameters
+    ///
+    /// * `testing_model` - the [`TestingModel`]
+    ///
+    /// # Panics
+    ///
+    /// If the network is already running.
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    /// [`TestingModel`]: ./trait.TestingModel.html
+    pub fn testing_model<T: TestingModel + 'static>(&mut self, testing_model: T) -> &mut Self {
+        let inner = Arc::get_mut(&mut self.inner)
+            .expect("The ecological niche cannot be modified while the network is running.");
+        inner.testing_model = Box::new(testing_model);
+        self
+    }
+
+    /// Sets the [`SelectionCriterion`] used to select [`Individual`]s for mating and
+    /// resource distribution. By default the mean fitness is used.
+    ///
+    /// # Parameters
+    ///
+    /// * `selection_criterion` - the [`SelectionCriterion`]
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding the population lock.
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    /// [`SelectionCriterion`]: ../fitness/enum.SelectionCriterion.html
+    pub fn selection_criterion(&mut self, selection_criterion: SelectionCriterion) -> &mut Self {
+        self.inner
+            .population
+            .lock()
+            .expect("A thread paniced while holding the population lock.")
+            .set_selection_criterion(selection_criterion);
+        self
+    }
+
+    /// Enables the adaptive reallocation of the [`Mutation`] chances at the start of every
+    /// generation according to the observed improvement of offspring. The sum of the current
+    /// mutation chances is preserved.
+    ///
+    /// # Parameters
+    ///
+    /// * `adaptation` - the [`OperatorAdaptation`]
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding the mutation lock.
+    ///
+    /// [`Mutation`]: ./struct.Mutation.html
+    /// [`OperatorAdaptation`]: ./struct.OperatorAdaptation.html
+    pub fn operator_adaptation(&mut self, adaptation: OperatorAdaptation) -> &mut Self {
+        self.inner
+            .mutations
+            .write()
+            .expect("A thread paniced while holding the mutation lock.")
+            .set_adaptation(adaptation);
+        self
+    }
+
+    /// Sets the [`FailurePolicy`] determining how a [`Mutation`] leaving a [`Genome`] in an
+    /// invalid state is handled. By default all mutations of the offspring are discarded.
+    ///
+    /// # Parameters
+    ///
+    /// * `failure_policy` - the [`FailurePolicy`]
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding the mutation lock.
+    ///
+    /// [`FailurePolicy`]: ./enum.FailurePolicy.html
+    /// [`Genome`]: ../gene/struct.Genome.html
+    /// [`Mutation`]: ./struct.Mutation.html
+    pub fn mutation_failure_policy(&mut self, failure_policy: FailurePolicy) -> &mut Self {
+        self.inner
+            .mutations
+            .write()
+            .expect("A thread paniced while holding the mutation lock.")
+            .set_failure_policy(failure_policy);
+        self
+    }
+
+    /// Lets every [`Genome`] carry its own modifiers of the [`Mutation`] chances, which are
+    /// inherited and adapted log-normally along with the [`Genome`].
+    ///
+    /// # Parameters
+    ///
+    /// * `self_adaptation` - the [`SelfAdaptation`]
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding the mutation lock.
+    ///
+    /// [`Genome`]: ../gene/struct.Genome.html
+    /// [`Mutation`]: ./struct.Mutation.html
+    /// [`SelfAdaptation`]: ./struct.SelfAdaptation.html
+    pub fn self_adaptation(&mut self, self_adaptation: SelfAdaptation) -> &mut Self {
+        self.inner
+            .mutations
+            .write()
+            .expect("A thread paniced while holding the mutation lock.")
+            .set_self_adaptation(self_adaptation);
+        self
+    }
+
+    /// Returns the [`OperatorStatistics`] of all [`Mutation`]s aggregated over the run by name.
+    ///
+    /// [`Mutation`]: ./struct.Mutation.html
+    /// [`OperatorStatistics`]: ./struct.OperatorStatistics.html
+    pub fn operator_statistics(&self) -> BTreeMap<String, OperatorStatistics> {
+        self.inner.mutations().operator_statistics()
+    }
+
     /// Initialises the network.
     fn initialise(&self) {
         self.environment().initialise();
@@ -133,7 +384,7 @@ impl<
     ///
     /// [`Environment`]: ./struct.Environment.html
     fn environment(&self) -> Arc<Environment> {
-        self.inner.environment.clone()
+        self.inner.environment()
     }
 
     pub fn breath_life(&self) {
@@ -142,6 +393,27 @@ impl<
         self.initialise();
         // Start the network.
         println!("Starting execution...");
+        let run_uuid = self.environment().generate_uuid();
+        let metrics_path = self.environment().metrics_path(&run_uuid);
+        let genealogy_path = self.environment().genealogy_path(&run_uuid);
+        let genealogy_log = GenealogyLog::open(&genealogy_path).unwrap_or_else(|err| {
+            panic!("The genealogy log {:?} could not be opened: {}", genealogy_path, err)
+        });
+        // A network is only started once, so the log cannot be set already.
+        let _ = self.inner.genealogy_log.set(genealogy_log);
+        // Record the individuals the population starts with.
+        for individual in self.inner.individuals() {
+            let birth = {
+                let ind = individual
+                    .lock()
+                    .expect("A thread paniced while holding the individual's lock.");
+                GenealogyEvent::Birth {
+                    uuid: *ind.uuid(),
+                    lineage: ind.lineage().clone(),
+                }
+            };
+            self.inner.record_genealogy(&birth);
+        }
         let mut generation: u64 = 0;
         let mut fitness_scaling: ScalingFactor =
             self.environment().initial_fitness_scaling_factor();
@@ -151,31 +423,57 @@ impl<
             let mating_counter = Arc::new(Mutex::new(0u32));
             generation += 1;
             println!("Generation {}", generation);
+            // Adjust the scheduled parameters to the current generation.
+            let mut parameters = self.inner.apply_schedules(generation);
+            // Reallocate the mutation chances based on the offspring of past generations.
+            parameters.extend(self.inner.adapt_mutations());
             // Age the population by a generation.
             self.inner.increment_age();
+            // Feed the bottom age layer with random individuals.
+            if let Some(age_layering) = &self.inner.age_layering {
+                if age_layering.is_injection_generation(generation) {
+                    self.inner
+                        .inject_random_individuals(age_layering, generation);
+                }
+            }
             // Challenge the organisms in the population.
+            let statistics = self.inner.population_statistics();
             self.inner.individuals().par_iter().for_each(|individual| {
-                Self::spawn_organism(self.inner.clone(), individual.clone(), fitness_scaling);
+                Self::spawn_organism(
+                    self.inner.clone(),
+                    individual.clone(),
+                    fitness_scaling,
+                    &statistics,
+                );
                 *spawn_counter.lock().unwrap() += 1;
                 if *spawn_counter.lock().unwrap() % 1000 == 0 {
                     println!("     Spawn Organism {}", *spawn_counter.lock().unwrap());
                 }
             });
+            // Cull the age layers to their respective capacity.
+            if let Some(age_layering) = &self.inner.age_layering {
+                self.inner.enforce_layer_capacities(age_layering);
+            }
             // Distribute resources neccesarry for mating based on fitness.
             self.inner.distribute_resources();
             // Mate the organisms of the population and add offspring to the population.
             self.inner.individuals().par_iter().for_each(|individual| {
-                Self::mate_organism(self.inner.clone(), individual.clone());
+                Self::mate_organism(self.inner.clone(), individual.clone(), generation);
                 *mating_counter.lock().unwrap() += 1;
                 if *mating_counter.lock().unwrap() % 1000 == 0 {
                     println!("     Mate Organism {}", *mating_counter.lock().unwrap());
                 }
             });
-            // Kill individuals on statistical basis.
+            // Kill individuals on statistical basis. In age layered populations individuals
+            // are judged relative to their layer.
+            let statistics = match &self.inner.age_layering {
+                Some(age_layering) => self.inner.layer_statistics(age_layering),
+                None => vec![self.inner.population_statistics()],
+            };
             self.inner
                 .individuals()
                 .par_iter()
-                .filter(|individual| self.inner.died((*individual).clone()))
+                .filter(|individual| self.inner.died((*individual).clone(), &statistics))
                 .for_each(|individual| {
                     self.inner.remove_individual(individual.clone());
                 });
@@ -210,6 +508,22 @@ impl<
                 fitness_scaling.exponent(),
                 res,
                 self.inner.resources());
+            if let Some(age_layering) = &self.inner.age_layering {
+                println!("Age layer sizes: {:?}", self.inner.layer_sizes(age_layering));
+            }
+            // Record the metrics of the generation.
+            GenerationMetrics::new(
+                generation,
+                self.inner.population_statistics(),
+                fitness_scaling.exponent(),
+                parameters,
+            )
+            .with_operator_statistics(self.inner.mutations().operator_statistics())
+            .with_rate_distributions(self.inner.rate_distributions())
+            .append_to_file(&metrics_path)
+            .unwrap_or_else(|err| {
+                panic!("The metrics could not be saved to {:?}: {}", metrics_path, err)
+            });
             // Save the population in regular intervalls with a timestamp and print some information.
             if start.elapsed() >= self.environment().population_save_intervall() {
                 self.save_population();
@@ -239,10 +553,14 @@ impl<
     ///
     /// * `inner` - the [`Environment`] the [`Organism`] is living in
     /// * `individual` - the [`Individual`] describing the [`Organism`] to test
+    /// * `fitness_scaling` - the current fitness [`ScalingFactor`]
+    /// * `statistics` - the [`PopulationStatistics`] at the start of testing
     ///
     /// [`Environment`]: ./struct.Environment.html
     /// [`Organism`]: ../population/struct.Organism.html
     /// [`Individual`]: ../population/struct.Individual.html
+    /// [`PopulationStatistics`]: ../population/struct.PopulationStatistics.html
+    /// [`ScalingFactor`]: ../helper/struct.ScalingFactor.html
     fn spawn_organism(
         inner: Arc<
             InnerEcologicalNiche<
@@ -270,14 +588,14 @@ impl<
             >,
         >,
         fitness_scaling: ScalingFactor,
+        statistics: &PopulationStatistics,
     ) {
-        let tested = inner.testing(individual.clone());
+        let tested = inner.testing(individual.clone(), statistics);
         if tested {
             // Transcribe / translate the genome and test the organism.
-            Self::add_fitness(
-                individual.clone(),
-                Self::test_organism(inner.clone(), individual.clone(), fitness_scaling),
-            );
+            let fitness = Self::test_organism(inner.clone(), individual.clone(), fitness_scaling);
+            Self::add_fitness(individual.clone(), fitness);
+            inner.record_fitness_delta(individual, fitness);
         }
     }
 
@@ -287,6 +605,7 @@ impl<
     ///
     /// * `inner` - the [`Environment`] the [`Organism`] is living in
     /// * `individual` - the [`Individual`] describing the [`Organism`] to test
+    /// * `generation` - the current generation
     ///
     /// [`Environment`]: ./struct.Environment.html
     /// [`Organism`]: ../population/struct.Organism.html
@@ -318,8 +637,15 @@ impl<
                 >,
             >,
         >,
+        generation: u64,
     ) {
-        let offspring = Self::get_offspring(individual, inner.clone());
+        let offspring = Self::get_offspring(individual, inner.clone(), generation);
+        for child in &offspring {
+            inner.record_genealogy(&GenealogyEvent::Birth {
+                uuid: *child.uuid(),
+                lineage: child.lineage().clone(),
+            });
+        }
         // Add the mutated offspring to the population.
         inner.append_population(offspring);
     }
@@ -362,27 +688,43 @@ impl<
         >,
         fitness_scaling: ScalingFactor,
     ) -> f64 {
-        let mut organism = inner.load_organism(individual.clone());
-        let mut organism_informations = Vec::new();
-        // Repeatedly test the organism and supply all the testing information to the fitness
-        // function.
-        for _ in 0..inner.environment.testing_repetitions() {
-            let (input, result_information) = (inner.supplier_function)();
-            organism.set_input(input);
-            let run_time = organism.live(&inner.environment);
-            let output = organism.get_result();
-            organism_informations.push(OrganismInformation::new(
-                output,
-                result_information,
-                inner.get_bytes(individual.clone()) * 8,
-                run_time,
-                *(&inner.environment.lifespan()),
-                inner.get_associated_inputs(individual.clone()),
-                inner.get_associated_outputs(individual.clone()),
-                organism.binary_size(),
-                *(&inner.environment.max_organism_size()),
-            ));
-        }
+        let environment = inner.environment();
+        let organism = inner.load_organism(individual.clone());
+        let genome_size = inner.get_bytes(individual.clone()) * 8;
+        let associated_inputs = inner.get_associated_inputs(individual.clone());
+        let associated_outputs = inner.get_associated_outputs(individual);
+        let repetitions = environment.testing_repetitions();
+        let (inputs, result_informations): (Vec<InputElementType>, Vec<_>) = (0..repetitions)
+            .map(|_| (inner.supplier_function)())
+            .unzip();
+        // Test independent copies of the freshly translated organism in parallel and supply
+        // all the testing information to the fitness function.
+        let runs: Vec<_> = inputs
+            .into_par_iter()
+            .map(|input| {
+                let mut organism = organism.clone();
+                organism.set_input(input);
+                let outcome = organism.live(&environment);
+                (organism.get_result(), outcome, organism.binary_size())
+            })
+            .collect();
+        let organism_informations = runs
+            .into_iter()
+            .zip(result_informations)
+            .map(|((output, outcome, organism_size), result_information)| {
+                OrganismInformation::new(
+                    output,
+                    result_information,
+                    genome_size,
+                    outcome,
+                    environment.lifespan(),
+                    associated_inputs,
+                    associated_outputs,
+                    organism_size,
+                    environment.max_organism_size(),
+                )
+            })
+            .collect();
         (inner.fitness_function)(organism_informations, fitness_scaling)
     }
 
@@ -426,6 +768,7 @@ impl<
     ///
     /// * `individual` - the [`Individual`]
     /// * `inner` - the inner environment
+    /// * `generation` - the current generation
     ///
     /// # Panics
     ///
@@ -458,6 +801,7 @@ impl<
                 OutputSensorType,
             >,
         >,
+        generation: u64,
     ) -> Vec<
         Individual<
             ReactionType,
@@ -471,22 +815,31 @@ impl<
     > {
         let mut offspring = Vec::new();
         // Use the accumulated resources to produce offspring.
-        let own_genome = {
-            individual
+        let (own_uuid, own_genome, own_age, own_fitness) = {
+            let ind = individual
                 .lock()
-                .expect("A thread paniced while holding the individual's lock.")
-                .genome()
+                .expect("A thread paniced while holding the individual's lock.");
+            (*ind.uuid(), ind.genome(), ind.age(), ind.fitness())
         };
         let number_of_offspring = inner.spend_resources_for_mating(individual.clone());
+        let environment = inner.environment();
+        let mutations = inner.mutations();
         for _ in 0..number_of_offspring {
-            let partner = inner.get_random_genome();
-
-            offspring.push(Individual::mate_and_mutate(
+            let partner = inner.get_random_partner(inner.age_layering.as_ref(), own_age);
+            let mut lineage = Lineage::new(vec![own_uuid, *partner.uuid()], generation);
+            lineage.set_parent_fitness(own_fitness);
+            let mut child = Individual::mate_and_mutate(
                 Arc::clone(&own_genome),
-                partner,
-                &inner.mutations,
-                &inner.environment,
-            ));
+                partner.genome(),
+                &mutations,
+                &environment,
+                lineage,
+            );
+            if inner.age_layering.is_some() {
+                // The age of genetic material is inherited from the older parent.
+                child.set_age(own_age.max(partner.age()));
+            }
+            offspring.push(child);
         }
         offspring
     }
@@ -502,7 +855,7 @@ struct InnerEcologicalNiche<
     OutputElementType,
     OutputSensorType,
 > {
-    environment: Arc<Environment>,
+    environment: RwLock<Arc<Environment>>,
     population: Arc<
         Mutex<
             Population<
@@ -527,15 +880,33 @@ struct InnerEcologicalNiche<
             + Sync
             + 'static,
     >,
-    mutations: MutationCompendium<
-        ReactionType,
-        StateType,
-        InformationType,
-        InputElementType,
-        InputSensorType,
-        OutputElementType,
-        OutputSensorType,
+    mutations: RwLock<
+        MutationCompendium<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    >,
+    age_layering: Option<AgeLayering>,
+    genome_generator: Option<
+        GenomeGenerator<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
     >,
+    schedules: Vec<(ScheduledParameter, Schedule)>,
+    mortality_model: Box<dyn MortalityModel>,
+    testing_model: Box<dyn TestingModel>,
+    genealogy_log: OnceLock<GenealogyLog>,
 }
 
 impl<
@@ -559,88 +930,166 @@ impl<
         OutputSensorType,
     >
 {
-    /// Checks if the specified [`Individual`] died of age.
+    /// Returns the current [`Environment`].
     ///
-    /// # Parameters
+    /// # Panics
     ///
-    /// * `individual` - the [`Individual`] to check for extinction
+    /// If another thread paniced while holding the environment lock.
+    ///
+    /// [`Environment`]: ./struct.Environment.html
+    fn environment(&self) -> Arc<Environment> {
+        self.environment
+            .read()
+            .expect("A thread paniced while holding the environment lock.")
+            .clone()
+    }
+
+    /// Returns the current [`MutationCompendium`].
     ///
     /// # Panics
     ///
-    /// If another thread paniced while holding the individual's lock.
+    /// If another thread paniced while holding the mutation lock.
     ///
-    /// [`Individual`]: ../population/struct.Individual.html
-    fn died(
+    /// [`MutationCompendium`]: ./struct.MutationCompendium.html
+    fn mutations(
         &self,
-        individual: Arc<
-            Mutex<
-                Individual<
-                    ReactionType,
-                    StateType,
-                    InformationType,
-                    InputElementType,
-                    InputSensorType,
-                    OutputElementType,
-                    OutputSensorType,
-                >,
-            >,
+    ) -> RwLockReadGuard<
+        '_,
+        MutationCompendium<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
         >,
-    ) -> bool {
-        let individual_age = individual
-            .lock()
-            .expect("A thread paniced while holding the individual's lock.")
-            .age();
-        self.environment.death_chance(individual_age) >= thread_rng().gen_range(0.0..=1.0)
+    > {
+        self.mutations
+            .read()
+            .expect("A thread paniced while holding the mutation lock.")
     }
 
-    /// Checks if the specified [`Individual`] is juvenil and still needs testing.
+    /// Applies all [`Schedule`]s for the specified generation and returns the values of the
+    /// scheduled parameters by name. If the population size changes, the total amount of
+    /// [`Resource`]s is adjusted accordingly. Scheduled values that would make the
+    /// [`Environment`] invalid are rejected and the parameter keeps its current value.
     ///
     /// # Parameters
     ///
-    /// * `individual` - the [`Individual`] to check
+    /// * `generation` - the current generation
     ///
     /// # Panics
     ///
-    /// If another thread paniced while holding the individual's lock.
+    /// If another thread paniced while holding the environment, mutation or population lock.
     ///
-    /// [`Individual`]: ../population/struct.Individual.html
-    fn is_juvenil(
-        &self,
-        individual: Arc<
-            Mutex<
-                Individual<
-                    ReactionType,
-                    StateType,
-                    InformationType,
-                    InputElementType,
-                    InputSensorType,
-                    OutputElementType,
-                    OutputSensorType,
-                >,
-            >,
-        >,
-    ) -> bool {
-        let individual_age = individual
-            .lock()
-            .expect("A thread paniced while holding the individual's lock.")
-            .age();
-        self.environment
-            .max_testing_age()
-            .map_or(true, |max_age| individual_age < max_age)
+    /// [`Environment`]: ./struct.Environment.html
+    /// [`Resource`]: ../resource/struct.Resource.html
+    /// [`Schedule`]: ./enum.Schedule.html
+    fn apply_schedules(&self, generation: u64) -> BTreeMap<String, f64> {
+        let mut parameters = BTreeMap::new();
+        if self.schedules.is_empty() {
+            return parameters;
+        }
+        let statistics = self.population_statistics();
+        let mut environment_lock = self
+            .environment
+            .write()
+            .expect("A thread paniced while holding the environment lock.");
+        let environment = Arc::make_mut(&mut environment_lock);
+        let mut mutations = self
+            .mutations
+            .write()
+            .expect("A thread paniced while holding the mutation lock.");
+        for (parameter, schedule) in &self.schedules {
+            let previous_population_size = environment.population_size();
+            let value = match parameter.apply(
+                schedule.value(generation, &statistics),
+                environment,
+                &mut mutations,
+            ) {
+                Ok(value) => value,
+                Err(err) => {
+                    // Invalid values are skipped, so the parameter keeps its current value.
+                    println!("The scheduled {} was rejected: {}", parameter.name(), err);
+                    continue;
+                },
+            };
+            if *parameter == ScheduledParameter::PopulationSize {
+                // The total amount of resources corresponds to the population size.
+                self.population
+                    .lock()
+                    .expect("A thread paniced while holding the population lock.")
+                    .adjust_resources(value - previous_population_size as f64);
+            }
+            parameters.insert(parameter.name(), value);
+        }
+        parameters
+    }
+
+    /// Summarises the self-adaptive mutation rate modifiers of the [`Population`] per
+    /// [`Mutation`] if self-adaptation is enabled.
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding the population or mutation lock.
+    ///
+    /// [`Mutation`]: ./struct.Mutation.html
+    /// [`Population`]: ../population/struct.Population.html
+    fn rate_distributions(&self) -> Vec<RateDistribution> {
+        let mutations = self.mutations();
+        if mutations.self_adaptation().is_some() {
+            self.population
+                .lock()
+                .expect("A thread paniced while holding the population lock.")
+                .rate_distributions(mutations.size())
+        } else {
+            Vec::new()
+        }
     }
 
-    /// Checks if the specified [`Individual`] should be testedby chance.
+    /// Reallocates the chances of the [`Mutation`]s if adaptation is enabled and returns the
+    /// new chances by parameter name.
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding the mutation lock.
+    ///
+    /// [`Mutation`]: ./struct.Mutation.html
+    fn adapt_mutations(&self) -> BTreeMap<String, f64> {
+        let mut mutations = self
+            .mutations
+            .write()
+            .expect("A thread paniced while holding the mutation lock.");
+        if mutations.adapt() {
+            mutations
+                .mutation_chances()
+                .into_iter()
+                .enumerate()
+                .map(|(index, chance)| (ScheduledParameter::MutationChance(index).name(), chance))
+                .collect()
+        } else {
+            BTreeMap::new()
+        }
+    }
+
+    /// Checks if the specified [`Individual`] died based on the [`MortalityModel`].
     ///
     /// # Parameters
     ///
-    /// * `individual` - the [`Individual`] to check
+    /// * `individual` - the [`Individual`] to check for extinction
+    /// * `statistics` - the current [`PopulationStatistics`] of each age layer or of the
+    ///   whole [`Population`] if it is not age layered
     ///
     /// # Panics
     ///
     /// If another thread paniced while holding the individual's lock.
     ///
     /// [`Individual`]: ../population/struct.Individual.html
-    fn testing_by_chance(
+    /// [`MortalityModel`]: ./trait.MortalityModel.html
+    /// [`Population`]: ../population/struct.Population.html
+    /// [`PopulationStatistics`]: ../population/struct.PopulationStatistics.html
+    fn died(
         &self,
         individual: Arc<
             Mutex<
@@ -655,24 +1104,36 @@ impl<
                 >,
             >,
         >,
+        statistics: &[PopulationStatistics],
     ) -> bool {
-        let ind = individual
+        let status = individual
             .lock()
-            .expect("A thread paniced while holding the individual's lock.");
-        thread_rng().gen_range(0.0..=1.0) <= self.environment.testing_chance(ind.times_tested())
+            .expect("A thread paniced while holding the individual's lock.")
+            .status();
+        let statistics = match &self.age_layering {
+            Some(age_layering) => &statistics[age_layering.layer(status.age())],
+            None => &statistics[0],
+        };
+        self.mortality_model
+            .death_chance(&status, statistics, &self.environment())
+            >= thread_rng().gen_range(0.0..=1.0)
     }
 
-    /// Checks if the specified [`Individual`] should be tested.
+    /// Checks if the specified [`Individual`] should be tested based on the
+    /// [`TestingModel`].
     ///
     /// # Parameters
     ///
     /// * `individual` - the [`Individual`] to check
+    /// * `statistics` - the current [`PopulationStatistics`]
     ///
     /// # Panics
     ///
     /// If another thread paniced while holding the individual's lock.
     ///
     /// [`Individual`]: ../population/struct.Individual.html
+    /// [`PopulationStatistics`]: ../population/struct.PopulationStatistics.html
+    /// [`TestingModel`]: ./trait.TestingModel.html
     fn testing(
         &self,
         individual: Arc<
@@ -688,8 +1149,16 @@ impl<
                 >,
             >,
         >,
+        statistics: &PopulationStatistics,
     ) -> bool {
-        self.is_juvenil(individual.clone()) || self.testing_by_chance(individual)
+        let status = individual
+            .lock()
+            .expect("A thread paniced while holding the individual's lock.")
+            .status();
+        thread_rng().gen_range(0.0..=1.0)
+            <= self
+                .testing_model
+                .testing_chance(&status, statistics, &self.environment())
     }
 
     /// Return the UUID of the specified [`Individual`].
@@ -893,34 +1362,233 @@ impl<
         ind.associated_outputs()
     }
 
-    /// Returns the copy of a [`Genome`] of a random [`Individual`]
-    /// where the chances for picking an individual correspond to its
+    /// Returns a copy of a random [`Individual`] that is allowed to mate with an [`Individual`]
+    /// of the specified age. The chances for picking an individual correspond to its
     /// respective fitness.
     ///
+    /// # Parameters
+    ///
+    /// * `age_layering` - the [`AgeLayering`] of the [`Population`] if any
+    /// * `age` - the age of the [`Individual`] looking for a mating partner
+    ///
     /// # Panics
     ///
     /// If another thread paniced while holding the individual's lock or the population is
     /// completely extinct.
     ///
-    /// [`Individual`]: ./struct.Individual.html
-    fn get_random_genome(
+    /// [`AgeLayering`]: ./struct.AgeLayering.html
+    /// [`Individual`]: ../population/struct.Individual.html
+    /// [`Population`]: ../population/struct.Population.html
+    fn get_random_partner(
         &self,
-    ) -> Arc<
-        Genome<
-            ReactionType,
-            StateType,
-            InformationType,
-            InputElementType,
-            InputSensorType,
-            OutputElementType,
-            OutputSensorType,
-        >,
+        age_layering: Option<&AgeLayering>,
+        age: u32,
+    ) -> Individual<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
     > {
-        self.population.lock()
-            .expect("A thread paniced while holding the population lock.")
-            .random_genome_fitness_based()
+        let population = self
+            .population
+            .lock()
+            .expect("A thread paniced while holding the population lock.");
+        let partner = population
+            .random_individual_fitness_based(|partner| {
+                age_layering.map_or(true, |layering| layering.can_mate(age, partner.age()))
+            })
+            // Fall back to the whole population should the compatible layers be empty.
+            .or_else(|| population.random_individual_fitness_based(|_| true))
             // Unwrapping is safe here since we cannot call this function on empty populations.
-            .unwrap()
+            .unwrap();
+        let partner = partner
+            .lock()
+            .expect("A thread paniced while holding the individual's lock.")
+            .clone();
+        partner
+    }
+
+    /// Adds [`Individual`]s with random [`Genome`]s to the bottom layer of the [`Population`].
+    /// The number of new [`Individual`]s is limited by the free capacity of the bottom layer
+    /// and the available [`Resource`]s.
+    ///
+    /// # Parameters
+    ///
+    /// * `age_layering` - the [`AgeLayering`] of the [`Population`]
+    /// * `generation` - the current generation
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding the population lock.
+    ///
+    /// [`AgeLayering`]: ./struct.AgeLayering.html
+    /// [`Genome`]: ../gene/struct.Genome.html
+    /// [`Individual`]: ../population/struct.Individual.html
+    /// [`Population`]: ../population/struct.Population.html
+    /// [`Resource`]: ../resource/struct.Resource.html
+    fn inject_random_individuals(&self, age_layering: &AgeLayering, generation: u64) {
+        if let Some(genome_generator) = &self.genome_generator {
+            let free_capacity = age_layering.layers()[0]
+                .capacity()
+                .saturating_sub(self.layer_sizes(age_layering)[0]);
+            let mut population = self
+                .population
+                .lock()
+                .expect("A thread paniced while holding the population lock.");
+            // An individual consumes 1.0 resources when being born.
+            let number_of_individuals =
+                (population.resources().available().floor() as usize).min(free_capacity);
+            population.claim_resources(number_of_individuals as f64);
+            let individuals = (0..number_of_individuals)
+                .map(|_| {
+                    let mut individual =
+                        Individual::new(self.environment().generate_uuid(), genome_generator());
+                    individual.set_lineage(Lineage::new(Vec::new(), generation));
+                    self.record_genealogy(&GenealogyEvent::Birth {
+                        uuid: *individual.uuid(),
+                        lineage: individual.lineage().clone(),
+                    });
+                    individual
+                })
+                .collect();
+            population.append(individuals);
+        }
+    }
+
+    /// Removes the least fit [`Individual`]s of each age layer that exceeds its capacity.
+    /// [`Individual`]s without fitness are considered least fit.
+    ///
+    /// # Parameters
+    ///
+    /// * `age_layering` - the [`AgeLayering`] of the [`Population`]
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding a lock.
+    ///
+    /// [`AgeLayering`]: ./struct.AgeLayering.html
+    /// [`Individual`]: ../population/struct.Individual.html
+    /// [`Population`]: ../population/struct.Population.html
+    fn enforce_layer_capacities(&self, age_layering: &AgeLayering) {
+        let mut layers: Vec<Vec<(f64, Arc<Mutex<_>>)>> =
+            vec![Vec::new(); age_layering.number_of_layers()];
+        for individual in self.individuals() {
+            let (age, fitness) = {
+                let ind = individual
+                    .lock()
+                    .expect("A thread paniced while holding the individual's lock.");
+                (ind.age(), ind.fitness().unwrap_or(f64::NEG_INFINITY))
+            };
+            layers[age_layering.layer(age)].push((fitness, individual));
+        }
+        for (layer, mut members) in layers.into_iter().enumerate() {
+            let capacity = age_layering.layers()[layer].capacity();
+            if members.len() > capacity {
+                members.sort_by(|(a, _), (b, _)| b.total_cmp(a));
+                for (_, individual) in members.into_iter().skip(capacity) {
+                    self.remove_individual(individual);
+                }
+            }
+        }
+    }
+
+    /// Returns the number of [`Individual`]s per age layer.
+    ///
+    /// # Parameters
+    ///
+    /// * `age_layering` - the [`AgeLayering`] of the [`Population`]
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding a lock.
+    ///
+    /// [`AgeLayering`]: ./struct.AgeLayering.html
+    /// [`Individual`]: ../population/struct.Individual.html
+    /// [`Population`]: ../population/struct.Population.html
+    fn layer_sizes(&self, age_layering: &AgeLayering) -> Vec<usize> {
+        let mut sizes = vec![0; age_layering.number_of_layers()];
+        for individual in self.individuals() {
+            let age = individual
+                .lock()
+                .expect("A thread paniced while holding the individual's lock.")
+                .age();
+            sizes[age_layering.layer(age)] += 1;
+        }
+        sizes
+    }
+
+    /// Returns the [`PopulationStatistics`] of each age layer. The [`Resource`]s are those
+    /// of the whole [`Population`].
+    ///
+    /// # Parameters
+    ///
+    /// * `age_layering` - the [`AgeLayering`] of the [`Population`]
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding a lock.
+    ///
+    /// [`AgeLayering`]: ./struct.AgeLayering.html
+    /// [`Population`]: ../population/struct.Population.html
+    /// [`PopulationStatistics`]: ../population/struct.PopulationStatistics.html
+    /// [`Resource`]: ../resource/struct.Resource.html
+    fn layer_statistics(&self, age_layering: &AgeLayering) -> Vec<PopulationStatistics> {
+        let mut layers: Vec<Vec<(IndividualStatus, usize)>> =
+            vec![Vec::new(); age_layering.number_of_layers()];
+        for individual in self.individuals() {
+            let (status, bytes) = {
+                let ind = individual
+                    .lock()
+                    .expect("A thread paniced while holding the individual's lock.");
+                (ind.status(), ind.bytes())
+            };
+            layers[age_layering.layer(status.age())].push((status, bytes));
+        }
+        let resources = self.resources();
+        layers
+            .into_iter()
+            .map(|members| {
+                let size = members.len();
+                let fitness: Vec<f64> = members
+                    .iter()
+                    .filter_map(|(status, _)| status.fitness())
+                    .collect();
+                // The mean fitness of a layer without tested individuals is zero.
+                let mean_fitness = if fitness.is_empty() {
+                    0.0
+                } else {
+                    fitness.iter().sum::<f64>() / fitness.len() as f64
+                };
+                let maximum_fitness = members
+                    .iter()
+                    .filter(|(status, _)| status.age() >= 1)
+                    .filter_map(|(status, _)| status.fitness())
+                    .reduce(f64::max);
+                let (mean_genome_size, mean_age) = if size > 0 {
+                    (
+                        members.iter().map(|(_, bytes)| *bytes as f64).sum::<f64>() / size as f64,
+                        members
+                            .iter()
+                            .map(|(status, _)| status.age() as f64)
+                            .sum::<f64>()
+                            / size as f64,
+                    )
+                } else {
+                    (0.0, 0.0)
+                };
+                PopulationStatistics::new(
+                    size,
+                    mean_fitness,
+                    maximum_fitness,
+                    mean_genome_size,
+                    mean_age,
+                    resources,
+                )
+            })
+            .collect()
     }
 
     /// Write a snapshot of the current [`Population`] to a JSON file.
@@ -972,6 +1640,20 @@ impl<
             .individuals()
     }
 
+    /// Returns the current [`PopulationStatistics`].
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding the population lock.
+    ///
+    /// [`PopulationStatistics`]: ../population/struct.PopulationStatistics.html
+    fn population_statistics(&self) -> PopulationStatistics {
+        self.population
+            .lock()
+            .expect("A thread paniced while holding the population lock.")
+            .statistics()
+    }
+
     /// Returns the number of [`Individual`]s in the [`Population`].
     ///
     /// # Panics
@@ -1096,6 +1778,17 @@ impl<
         >,
     ) {
         let uuid = self.get_uuid(individual.clone());
+        let death = {
+            let ind = individual
+                .lock()
+                .expect("A thread paniced while holding the individual's lock.");
+            GenealogyEvent::Death {
+                uuid,
+                fitness: ind.fitness(),
+                times_tested: ind.times_tested(),
+            }
+        };
+        self.record_genealogy(&death);
         // An individual consumes 1.0 resources when being born, so this has to be repatriated
         // additionally to the accumulated resources.
         let resources = Self::get_accumulated_resources(individual) + 1.0;
@@ -1114,6 +1807,64 @@ impl<
         }
     }
 
+    /// Credits the [`Mutation`]s that produced the specified [`Individual`] with the
+    /// difference between its fitness and the fitness of its parent after the first test.
+    ///
+    /// # Parameters
+    ///
+    /// * `individual` - the [`Individual`] that was just tested
+    /// * `fitness` - the fitness evaluated in the test
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding the individual's lock.
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    /// [`Mutation`]: ./struct.Mutation.html
+    fn record_fitness_delta(
+        &self,
+        individual: SharedIndividual<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+        fitness: f64,
+    ) {
+        let ind = individual
+            .lock()
+            .expect("A thread paniced while holding the individual's lock.");
+        if ind.times_tested() == 1 {
+            if let Some(parent_fitness) = ind.lineage().parent_fitness() {
+                self.mutations()
+                    .record_fitness_delta(ind.lineage().mutations(), fitness - parent_fitness);
+            }
+        }
+    }
+
+    /// Appends the specified [`GenealogyEvent`] to the genealogy log of the running network.
+    /// Nothing is recorded before the network was started.
+    ///
+    /// # Parameters
+    ///
+    /// * `event` - the [`GenealogyEvent`] to record
+    ///
+    /// # Panics
+    ///
+    /// If the event could not be written to the genealogy log.
+    ///
+    /// [`GenealogyEvent`]: ../genealogy/enum.GenealogyEvent.html
+    fn record_genealogy(&self, event: &GenealogyEvent) {
+        if let Some(genealogy_log) = self.genealogy_log.get() {
+            genealogy_log
+                .record(event)
+                .unwrap_or_else(|err| panic!("The genealogy could not be recorded: {}", err));
+        }
+    }
+
     /// Recycles inavailable [`Resource`]s at the end of a generation.
     ///
     /// [`Resource`]: ../resource/struct.Resource.html
@@ -1173,8 +1924,7 @@ impl<
         individual
             .lock()
             .expect("Another thread panicked while holding the individual lock.")
-            .genome()
-            .translate()
+            .organism()
     }
 
     /// Add the [`Individual`]s to the [`Population`].
@@ -1209,3 +1959,6 @@ impl<
             .append(individuals);
     }
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,81 @@
+use crate::evolution::{
+    environment::EnvironmentBuilder,
+    gene::{Gene, GenomicInputSensor, GenomicOutputSensor},
+    helper::{
+        noop::{NoOpInputElement, NoOpOutputElement},
+        testing::{TestGenome, TestInformation, TestInput, TestOutput, TestReaction, TestState},
+    },
+};
+
+use super::*;
+
+/// An [`EcologicalNiche`] of test individuals for testing purposes.
+type TestNiche = EcologicalNiche<
+    (),
+    TestReaction,
+    TestState,
+    TestInformation,
+    NoOpInputElement,
+    TestInput,
+    NoOpOutputElement,
+    TestOutput,
+>;
+
+/// Creates a [`Genome`] of a single test substrate.
+fn test_genome() -> TestGenome {
+    Genome::new(
+        GenomicInputSensor::default(),
+        GenomicOutputSensor::default(),
+        vec![Gene::new(vec![TestInformation { value: 0 }])],
+    )
+}
+
+/// Creates an age layered [`EcologicalNiche`] with the specified number of individuals and
+/// two layers of the specified capacity.
+fn test_niche(size: usize, capacity: usize) -> TestNiche {
+    let environment = EnvironmentBuilder::new().build().unwrap();
+    let individuals = (0..size)
+        .map(|_| Individual::new(environment.generate_uuid(), test_genome()))
+        .collect();
+    let population = Population::new(individuals, Resource::new(100.0, 1.0));
+    let mut niche = EcologicalNiche::new(
+        environment,
+        population,
+        Box::new(|| ((), ())),
+        Box::new(|_, _| 0.0),
+        MutationCompendium::new(),
+    );
+    niche.age_layered(AgeLayering::new(2, 5, capacity, 1), Box::new(test_genome));
+    niche
+}
+
+#[test]
+/// Tests if the function `inject_random_individuals` of the [`InnerEcologicalNiche`] struct
+/// correctly only fills the free capacity of the bottom layer.
+fn test_inject_random_individuals() {
+    let niche = test_niche(2, 3);
+    let age_layering = niche.inner.age_layering.clone().unwrap();
+    niche.inner.inject_random_individuals(&age_layering, 1);
+    assert_eq!(niche.inner.layer_sizes(&age_layering), vec![3, 0]);
+    niche.inner.inject_random_individuals(&age_layering, 2);
+    assert_eq!(niche.inner.layer_sizes(&age_layering), vec![3, 0]);
+}
+
+#[test]
+/// Tests if the function `layer_statistics` of the [`InnerEcologicalNiche`] struct correctly
+/// summarises each age layer.
+fn test_layer_statistics() {
+    let niche = test_niche(2, 3);
+    let age_layering = niche.inner.age_layering.clone().unwrap();
+    for (age, individual) in niche.inner.individuals().into_iter().enumerate() {
+        let mut individual = individual.lock().unwrap();
+        individual.set_age(age as u32 * 6);
+        individual.evaluate_new_fitness(0.5);
+    }
+    let statistics = niche.inner.layer_statistics(&age_layering);
+    assert_eq!(statistics.len(), 2);
+    for layer in statistics {
+        assert_eq!(layer.size(), 1);
+        assert_ulps_eq!(layer.mean_fitness(), 0.5);
+    }
+}
@@ -0,0 +1,136 @@
+//! The `format` module contains the human readable file formats configurations can be
+//! stored in.
+
+use std::error::Error;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::path::Path;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+/// A `ConfigurationFormat` is a human readable file format a configuration can be read
+/// from and written to.
+pub enum ConfigurationFormat {
+    /// The JSON format with the file extension `json`.
+    Json,
+    /// The Rusty Object Notation with the file extension `ron`.
+    Ron,
+    /// The TOML format with the file extension `toml`.
+    Toml,
+}
+
+impl ConfigurationFormat {
+    /// Returns the `ConfigurationFormat` matching the extension of the specified file
+    /// if it is supported.
+    ///
+    /// # Parameters
+    ///
+    /// * `path_to_file` - the file to determine the format of
+    pub fn from_path<P: AsRef<Path>>(path_to_file: P) -> Option<Self> {
+        let extension = path_to_file
+            .as_ref()
+            .extension()?
+            .to_str()?
+            .to_ascii_lowercase();
+        match extension.as_str() {
+            "json" => Some(ConfigurationFormat::Json),
+            "ron" => Some(ConfigurationFormat::Ron),
+            "toml" => Some(ConfigurationFormat::Toml),
+            _ => None,
+        }
+    }
+
+    /// Returns the file extension of the `ConfigurationFormat`.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ConfigurationFormat::Json => "json",
+            ConfigurationFormat::Ron => "ron",
+            ConfigurationFormat::Toml => "toml",
+        }
+    }
+
+    /// Parses the specified content.
+    /// An error will be returned if parsing failed.
+    ///
+    /// # Parameters
+    ///
+    /// * `content` - the content to parse
+    pub fn deserialize<T: DeserializeOwned>(&self, content: &str) -> Result<T, Box<dyn Error>> {
+        Ok(match self {
+            ConfigurationFormat::Json => serde_json::from_str(content)?,
+            ConfigurationFormat::Ron => ron::from_str(content)?,
+            ConfigurationFormat::Toml => toml::from_str(content)?,
+        })
+    }
+
+    /// Serialises the specified value.
+    /// An error will be returned if serialisation failed.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - the value to serialise
+    pub fn serialize<T: Serialize>(&self, value: &T) -> Result<String, Box<dyn Error>> {
+        Ok(match self {
+            ConfigurationFormat::Json => serde_json::to_string_pretty(value)?,
+            ConfigurationFormat::Ron => {
+                ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default())?
+            },
+            ConfigurationFormat::Toml => toml::to_string_pretty(value)?,
+        })
+    }
+
+    /// Loads a value from the specified file in the format matching its extension.
+    /// An error will be returned if the format is not supported or reading or parsing the
+    /// file failed.
+    ///
+    /// # Parameters
+    ///
+    /// * `path_to_file` - the file to load the value from
+    pub fn load_from_file<T: DeserializeOwned, P: AsRef<Path>>(
+        path_to_file: P,
+    ) -> Result<T, Box<dyn Error>> {
+        let format = Self::from_path_or_error(&path_to_file)?;
+        let mut content = String::new();
+        File::open(&path_to_file)?.read_to_string(&mut content)?;
+        format.deserialize(&content)
+    }
+
+    /// Writes a value to the specified file in the format matching its extension.
+    /// An error will be returned if the format is not supported or serialising or writing
+    /// failed.
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - the value to write
+    /// * `path_to_file` - the file to write the value to
+    pub fn write_to_file<T: Serialize, P: AsRef<Path>>(
+        value: &T,
+        path_to_file: P,
+    ) -> Result<(), Box<dyn Error>> {
+        let format = Self::from_path_or_error(&path_to_file)?;
+        let content = format.serialize(value)?;
+        let mut file = File::create(path_to_file)?;
+        file.write_all(content.as_bytes())?;
+        Ok(file.sync_all()?)
+    }
+
+    /// Returns the `ConfigurationFormat` matching the extension of the specified file or
+    /// an error if the extension is not supported.
+    ///
+    /// # Parameters
+    ///
+    /// * `path_to_file` - the file to determine the format of
+    fn from_path_or_error<P: AsRef<Path>>(path_to_file: P) -> Result<Self, Box<dyn Error>> {
+        Self::from_path(&path_to_file).ok_or_else(|| {
+            format!(
+                "The format of {} is not supported. Use a json, ron or toml file instead.",
+                path_to_file.as_ref().display()
+            )
+            .into()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,62 @@
+use std::collections::BTreeMap;
+
+use uuid::Uuid;
+
+use super::*;
+
+#[test]
+/// Tests if the function `from_path` of the [`ConfigurationFormat`] enum correctly
+/// detects the format by file extension.
+fn test_configuration_format_from_path() {
+    assert_eq!(ConfigurationFormat::from_path("config.json"), Some(ConfigurationFormat::Json));
+    assert_eq!(ConfigurationFormat::from_path("dir/config.RON"), Some(ConfigurationFormat::Ron));
+    assert_eq!(ConfigurationFormat::from_path("config.toml"), Some(ConfigurationFormat::Toml));
+    for format in [
+        ConfigurationFormat::Json,
+        ConfigurationFormat::Ron,
+        ConfigurationFormat::Toml,
+    ] {
+        assert_eq!(
+            ConfigurationFormat::from_path(format!("config.{}", format.extension())),
+            Some(format)
+        );
+    }
+    assert_eq!(ConfigurationFormat::from_path("config.yaml"), None);
+    assert_eq!(ConfigurationFormat::from_path("config"), None);
+}
+
+#[test]
+/// Tests if the functions `serialize` and `deserialize` of the [`ConfigurationFormat`]
+/// enum correctly restore values in every format.
+fn test_configuration_format_serialize() {
+    let mut value: BTreeMap<String, f64> = BTreeMap::new();
+    value.insert("a".to_string(), 0.25);
+    value.insert("b".to_string(), 4.0);
+    for format in [
+        ConfigurationFormat::Json,
+        ConfigurationFormat::Ron,
+        ConfigurationFormat::Toml,
+    ] {
+        let serialized = format.serialize(&value).unwrap();
+        let deserialized: BTreeMap<String, f64> = format.deserialize(&serialized).unwrap();
+        assert_eq!(deserialized, value);
+    }
+    assert!(ConfigurationFormat::Json
+        .deserialize::<BTreeMap<String, f64>>("{\"a\": true}")
+        .is_err());
+}
+
+#[test]
+/// Tests if the functions `write_to_file` and `load_from_file` of the
+/// [`ConfigurationFormat`] enum correctly persist values and reject unknown formats.
+fn test_configuration_format_write_to_file() {
+    let value = vec![1u32, 2, 3];
+    let path =
+        std::env::temp_dir().join(format!("oben_format_{}.ron", Uuid::from_u128(rand::random())));
+    ConfigurationFormat::write_to_file(&value, &path).unwrap();
+    let loaded: Vec<u32> = ConfigurationFormat::load_from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded, value);
+    let unsupported = path.with_extension("yaml");
+    assert!(ConfigurationFormat::write_to_file(&value, &unsupported).is_err());
+}
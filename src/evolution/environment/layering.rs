@@ -0,0 +1,219 @@
+//! The `layering` module contains the configuration of age-layered populations.
+
+use std::ops::RangeInclusive;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+/// An `AgeLayer` groups the [`Individual`]s of a [`Population`] up to a certain age.
+///
+/// [`Individual`]: ../population/struct.Individual.html
+/// [`Population`]: ../population/struct.Population.html
+pub struct AgeLayer {
+    maximum_age: Option<u32>,
+    capacity: usize,
+}
+
+impl AgeLayer {
+    /// Creates a new `AgeLayer`.
+    ///
+    /// # Parameters
+    ///
+    /// * `maximum_age` - the maximum age of [`Individual`]s in the layer or `None`
+    ///   if the age is unlimited
+    /// * `capacity` - the maximum number of [`Individual`]s in the layer
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn new(maximum_age: Option<u32>, capacity: usize) -> Self {
+        AgeLayer {
+            maximum_age,
+            capacity,
+        }
+    }
+
+    /// Returns the maximum age of [`Individual`]s in the layer or `None` if the age is
+    /// unlimited.
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn maximum_age(&self) -> Option<u32> {
+        self.maximum_age
+    }
+
+    /// Returns the maximum number of [`Individual`]s in the layer.
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Checks if an [`Individual`] of the specified age belongs to this layer or to a
+    /// lower one.
+    ///
+    /// # Parameters
+    ///
+    /// * `age` - the age of the [`Individual`]
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    fn admits(&self, age: u32) -> bool {
+        self.maximum_age
+            .map_or(true, |maximum_age| age <= maximum_age)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+/// An `AgeLayering` splits a [`Population`] into [`AgeLayer`]s in the style of the
+/// age-layered population structure (ALPS). Mating only happens within a layer and the layer
+/// below it. The bottom layer is periodically fed with randomly generated [`Genome`]s.
+///
+/// [`AgeLayer`]: ./struct.AgeLayer.html
+/// [`Genome`]: ../gene/struct.Genome.html
+/// [`Population`]: ../population/struct.Population.html
+pub struct AgeLayering {
+    layers: Vec<AgeLayer>,
+    injection_intervall: u64,
+}
+
+impl AgeLayering {
+    /// Creates a new `AgeLayering` with the specified number of layers of identical capacity.
+    /// The maximum age of the layers increases linearly by the specified age gap,
+    /// while the top layer has no maximum age.
+    ///
+    /// # Parameters
+    ///
+    /// * `number_of_layers` - the number of [`AgeLayer`]s
+    /// * `age_gap` - the age difference between two consecutive [`AgeLayer`]s
+    /// * `capacity` - the capacity of each [`AgeLayer`]
+    /// * `injection_intervall` - the number of generations between two injections of random
+    ///   [`Genome`]s into the bottom layer
+    ///
+    /// # Panics
+    ///
+    /// If the number of layers or the injection intervall is zero.
+    ///
+    /// [`AgeLayer`]: ./struct.AgeLayer.html
+    /// [`Genome`]: ../gene/struct.Genome.html
+    pub fn new(
+        number_of_layers: usize,
+        age_gap: u32,
+        capacity: usize,
+        injection_intervall: u64,
+    ) -> Self {
+        let layers = (0..number_of_layers)
+            .map(|layer| {
+                if layer + 1 < number_of_layers {
+                    AgeLayer::new(Some(age_gap * (layer as u32 + 1)), capacity)
+                } else {
+                    AgeLayer::new(None, capacity)
+                }
+            })
+            .collect();
+        Self::from_layers(layers, injection_intervall)
+    }
+
+    /// Creates a new `AgeLayering` from the specified layers. The layers are sorted by their
+    /// maximum age.
+    ///
+    /// # Parameters
+    ///
+    /// * `layers` - the [`AgeLayer`]s
+    /// * `injection_intervall` - the number of generations between two injections of random
+    ///   [`Genome`]s into the bottom layer
+    ///
+    /// # Panics
+    ///
+    /// If no layers are specified or the injection intervall is zero.
+    ///
+    /// [`AgeLayer`]: ./struct.AgeLayer.html
+    /// [`Genome`]: ../gene/struct.Genome.html
+    pub fn from_layers(mut layers: Vec<AgeLayer>, injection_intervall: u64) -> Self {
+        if layers.is_empty() {
+            panic!("An age layered population needs at least one layer.");
+        }
+        if injection_intervall == 0 {
+            panic!("The injection intervall of an age layered population must be positive.");
+        }
+        layers.sort_by_key(|layer| layer.maximum_age().unwrap_or(u32::MAX));
+        AgeLayering {
+            layers,
+            injection_intervall,
+        }
+    }
+
+    /// Returns the [`AgeLayer`]s ordered from the youngest to the oldest one.
+    ///
+    /// [`AgeLayer`]: ./struct.AgeLayer.html
+    pub fn layers(&self) -> &[AgeLayer] {
+        &self.layers
+    }
+
+    /// Returns the number of [`AgeLayer`]s.
+    ///
+    /// [`AgeLayer`]: ./struct.AgeLayer.html
+    pub fn number_of_layers(&self) -> usize {
+        self.layers.len()
+    }
+
+    /// Returns the number of generations between two injections of random [`Genome`]s into
+    /// the bottom layer.
+    ///
+    /// [`Genome`]: ../gene/struct.Genome.html
+    pub fn injection_intervall(&self) -> u64 {
+        self.injection_intervall
+    }
+
+    /// Checks if random [`Genome`]s should be injected into the bottom layer in the
+    /// specified generation.
+    ///
+    /// # Parameters
+    ///
+    /// * `generation` - the current generation
+    ///
+    /// [`Genome`]: ../gene/struct.Genome.html
+    pub fn is_injection_generation(&self, generation: u64) -> bool {
+        generation % self.injection_intervall == 0
+    }
+
+    /// Returns the index of the [`AgeLayer`] an [`Individual`] of the specified age belongs to.
+    /// [`Individual`]s that are older than the maximum age of the top layer are assigned to
+    /// the top layer.
+    ///
+    /// # Parameters
+    ///
+    /// * `age` - the age of the [`Individual`]
+    ///
+    /// [`AgeLayer`]: ./struct.AgeLayer.html
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn layer(&self, age: u32) -> usize {
+        self.layers
+            .iter()
+            .position(|layer| layer.admits(age))
+            .unwrap_or(self.layers.len() - 1)
+    }
+
+    /// Returns the indices of the [`AgeLayer`]s that contain possible mating partners for an
+    /// [`Individual`] of the specified layer, which are the layer itself and the one below it.
+    ///
+    /// # Parameters
+    ///
+    /// * `layer` - the index of the [`AgeLayer`] of the [`Individual`]
+    ///
+    /// [`AgeLayer`]: ./struct.AgeLayer.html
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn mating_layers(&self, layer: usize) -> RangeInclusive<usize> {
+        layer.saturating_sub(1)..=layer
+    }
+
+    /// Checks if [`Individual`]s of the specified ages are allowed to mate.
+    ///
+    /// # Parameters
+    ///
+    /// * `age` - the age of the [`Individual`] looking for a mating partner
+    /// * `partner_age` - the age of the potential mating partner
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn can_mate(&self, age: u32, partner_age: u32) -> bool {
+        self.mating_layers(self.layer(age))
+            .contains(&self.layer(partner_age))
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,87 @@
+use super::*;
+
+#[test]
+/// Tests if the function `new` of the [`AgeLayering`] struct correctly creates
+/// linearly increasing [`AgeLayer`]s.
+fn test_age_layering_new() {
+    let layering = AgeLayering::new(3, 5, 10, 7);
+    assert_eq!(layering.number_of_layers(), 3);
+    assert_eq!(layering.injection_intervall(), 7);
+    assert_eq!(
+        layering.layers(),
+        &[
+            AgeLayer::new(Some(5), 10),
+            AgeLayer::new(Some(10), 10),
+            AgeLayer::new(None, 10)
+        ]
+    );
+}
+
+#[test]
+/// Tests if the function `from_layers` of the [`AgeLayering`] struct correctly sorts the
+/// [`AgeLayer`]s.
+fn test_age_layering_from_layers() {
+    let layering = AgeLayering::from_layers(
+        vec![
+            AgeLayer::new(None, 3),
+            AgeLayer::new(Some(20), 2),
+            AgeLayer::new(Some(4), 1),
+        ],
+        1,
+    );
+    assert_eq!(
+        layering.layers(),
+        &[
+            AgeLayer::new(Some(4), 1),
+            AgeLayer::new(Some(20), 2),
+            AgeLayer::new(None, 3)
+        ]
+    );
+}
+
+#[test]
+#[should_panic]
+/// Tests if the function `from_layers` of the [`AgeLayering`] struct correctly panics
+/// if no layers are specified.
+fn test_age_layering_from_layers_empty() {
+    AgeLayering::from_layers(Vec::new(), 1);
+}
+
+#[test]
+/// Tests if the function `layer` of the [`AgeLayering`] struct correctly assigns ages to
+/// [`AgeLayer`]s.
+fn test_age_layering_layer() {
+    let layering = AgeLayering::new(3, 5, 10, 7);
+    assert_eq!(layering.layer(0), 0);
+    assert_eq!(layering.layer(5), 0);
+    assert_eq!(layering.layer(6), 1);
+    assert_eq!(layering.layer(10), 1);
+    assert_eq!(layering.layer(11), 2);
+    assert_eq!(layering.layer(u32::MAX), 2);
+    let bounded = AgeLayering::from_layers(vec![AgeLayer::new(Some(2), 1)], 1);
+    assert_eq!(bounded.layer(3), 0);
+}
+
+#[test]
+/// Tests if the function `can_mate` of the [`AgeLayering`] struct correctly restricts
+/// mating to the same and the layer below.
+fn test_age_layering_can_mate() {
+    let layering = AgeLayering::new(3, 5, 10, 7);
+    assert_eq!(layering.mating_layers(0), 0..=0);
+    assert_eq!(layering.mating_layers(2), 1..=2);
+    assert!(layering.can_mate(0, 4));
+    assert!(!layering.can_mate(0, 6));
+    assert!(layering.can_mate(6, 0));
+    assert!(layering.can_mate(12, 7));
+    assert!(!layering.can_mate(12, 3));
+}
+
+#[test]
+/// Tests if the function `is_injection_generation` of the [`AgeLayering`] struct correctly
+/// detects injection generations.
+fn test_age_layering_is_injection_generation() {
+    let layering = AgeLayering::new(3, 5, 10, 7);
+    assert!(layering.is_injection_generation(0));
+    assert!(!layering.is_injection_generation(1));
+    assert!(layering.is_injection_generation(14));
+}
@@ -0,0 +1,149 @@
+//! The `metrics` module contains the per generation metrics of a network run.
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::super::gene::RateDistribution;
+use super::super::population::PopulationStatistics;
+use super::OperatorStatistics;
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// `GenerationMetrics` record the state of a network at the end of a generation.
+pub struct GenerationMetrics {
+    generation: u64,
+    statistics: PopulationStatistics,
+    fitness_scaling_exponent: i32,
+    parameters: BTreeMap<String, f64>,
+    #[serde(default)]
+    operators: BTreeMap<String, OperatorStatistics>,
+    #[serde(default)]
+    rate_distributions: Vec<RateDistribution>,
+}
+
+impl GenerationMetrics {
+    /// Creates new `GenerationMetrics`.
+    ///
+    /// # Parameters
+    ///
+    /// * `generation` - the generation
+    /// * `statistics` - the [`PopulationStatistics`] at the end of the generation
+    /// * `fitness_scaling_exponent` - the exponent of the fitness
+    ///   [`ScalingFactor`](crate::evolution::helper::ScalingFactor)
+    /// * `parameters` - the values of all scheduled parameters during the generation
+    ///
+    /// [`PopulationStatistics`]: ../population/struct.PopulationStatistics.html
+    pub fn new(
+        generation: u64,
+        statistics: PopulationStatistics,
+        fitness_scaling_exponent: i32,
+        parameters: BTreeMap<String, f64>,
+    ) -> Self {
+        GenerationMetrics {
+            generation,
+            statistics,
+            fitness_scaling_exponent,
+            parameters,
+            operators: BTreeMap::new(),
+            rate_distributions: Vec::new(),
+        }
+    }
+
+    /// Adds the distributions of the self-adaptive mutation rate modifiers of the
+    /// [`Population`].
+    ///
+    /// # Parameters
+    ///
+    /// * `rate_distributions` - the [`RateDistribution`] per [`Mutation`] in the order the
+    ///   [`Mutation`]s were added
+    ///
+    /// [`Mutation`]: ./struct.Mutation.html
+    /// [`Population`]: ../population/struct.Population.html
+    /// [`RateDistribution`]: ../gene/struct.RateDistribution.html
+    pub fn with_rate_distributions(mut self, rate_distributions: Vec<RateDistribution>) -> Self {
+        self.rate_distributions = rate_distributions;
+        self
+    }
+
+    /// Adds the [`OperatorStatistics`] of all [`Mutation`]s aggregated up to the generation.
+    ///
+    /// # Parameters
+    ///
+    /// * `operators` - the [`OperatorStatistics`] by [`Mutation`] name
+    ///
+    /// [`Mutation`]: ./struct.Mutation.html
+    /// [`OperatorStatistics`]: ./struct.OperatorStatistics.html
+    pub fn with_operator_statistics(
+        mut self,
+        operators: BTreeMap<String, OperatorStatistics>,
+    ) -> Self {
+        self.operators = operators;
+        self
+    }
+
+    /// Returns the generation.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Returns the [`PopulationStatistics`] at the end of the generation.
+    ///
+    /// [`PopulationStatistics`]: ../population/struct.PopulationStatistics.html
+    pub fn statistics(&self) -> &PopulationStatistics {
+        &self.statistics
+    }
+
+    /// Returns the exponent of the fitness
+    /// [`ScalingFactor`](crate::evolution::helper::ScalingFactor).
+    pub fn fitness_scaling_exponent(&self) -> i32 {
+        self.fitness_scaling_exponent
+    }
+
+    /// Returns the values of all scheduled parameters by name.
+    pub fn parameters(&self) -> &BTreeMap<String, f64> {
+        &self.parameters
+    }
+
+    /// Returns the [`OperatorStatistics`] of all [`Mutation`]s by name.
+    ///
+    /// [`Mutation`]: ./struct.Mutation.html
+    /// [`OperatorStatistics`]: ./struct.OperatorStatistics.html
+    pub fn operators(&self) -> &BTreeMap<String, OperatorStatistics> {
+        &self.operators
+    }
+
+    /// Returns the distributions of the self-adaptive mutation rate modifiers per
+    /// [`Mutation`] if self-adaptation is enabled.
+    ///
+    /// [`Mutation`]: ./struct.Mutation.html
+    pub fn rate_distributions(&self) -> &[RateDistribution] {
+        &self.rate_distributions
+    }
+
+    /// Appends the `GenerationMetrics` as a single line of JSON to the specified file.
+    /// An error will be returned if writing to the file failed.
+    ///
+    /// # Parameters
+    ///
+    /// * `path_to_file` - the JSON lines file the `GenerationMetrics` should be appended to
+    pub fn append_to_file<P: AsRef<Path>>(
+        &self,
+        path_to_file: P,
+    ) -> Result<(), Box<dyn Error + 'static>> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path_to_file)?;
+        let mut line = serde_json::to_string(self)?;
+        line.push('\n');
+        file.write_all(line.as_bytes())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests;
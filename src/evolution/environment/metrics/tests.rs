@@ -0,0 +1,34 @@
+use std::fs;
+
+use uuid::Uuid;
+
+use super::*;
+
+#[test]
+/// Tests if the function `append_to_file` of the [`GenerationMetrics`] struct correctly
+/// appends a line of JSON per generation.
+fn test_generation_metrics_append_to_file() {
+    let path = std::env::temp_dir().join(format!("{}.jsonl", Uuid::from_u128(rand::random())));
+    let mut parameters = BTreeMap::new();
+    parameters.insert("lifespan".to_string(), 0.5);
+    let metrics: Vec<GenerationMetrics> = (1..=2)
+        .map(|generation| {
+            GenerationMetrics::new(
+                generation,
+                PopulationStatistics::default(),
+                generation as i32,
+                parameters.clone(),
+            )
+        })
+        .collect();
+    for generation_metrics in &metrics {
+        generation_metrics.append_to_file(&path).unwrap();
+    }
+    let content = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    let read_metrics: Vec<GenerationMetrics> = content
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(read_metrics, metrics);
+}
@@ -0,0 +1,220 @@
+//! The `mortality` module contains the models determining the death of [`Individual`]s.
+//!
+//! [`Individual`]: ../population/struct.Individual.html
+
+use super::super::population::{IndividualStatus, PopulationStatistics};
+use super::configuration::Environment;
+
+/// A `MortalityModel` determines the chance of an [`Individual`] to die at the end of
+/// a generation.
+///
+/// [`Individual`]: ../population/struct.Individual.html
+pub trait MortalityModel: Send + Sync {
+    /// Returns the chance of death of the specified [`Individual`].
+    ///
+    /// # Parameters
+    ///
+    /// * `individual` - the [`IndividualStatus`] of the [`Individual`]
+    /// * `population` - the current [`PopulationStatistics`]
+    /// * `environment` - the current [`Environment`]
+    ///
+    /// [`Environment`]: ./struct.Environment.html
+    /// [`Individual`]: ../population/struct.Individual.html
+    /// [`IndividualStatus`]: ../population/struct.IndividualStatus.html
+    /// [`PopulationStatistics`]: ../population/struct.PopulationStatistics.html
+    fn death_chance(
+        &self,
+        individual: &IndividualStatus,
+        population: &PopulationStatistics,
+        environment: &Environment,
+    ) -> f64;
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+/// The default `AgeSigmoidMortality` lets [`Individual`]s die based on a sigmoid of their
+/// age as specified by the [`Environment`].
+///
+/// [`Environment`]: ./struct.Environment.html
+/// [`Individual`]: ../population/struct.Individual.html
+pub struct AgeSigmoidMortality;
+
+impl MortalityModel for AgeSigmoidMortality {
+    fn death_chance(
+        &self,
+        individual: &IndividualStatus,
+        _population: &PopulationStatistics,
+        environment: &Environment,
+    ) -> f64 {
+        environment.death_chance(individual.age())
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+/// A `FitnessDependentMortality` lowers the age dependent chance of death of
+/// [`Individual`]s relative to their fitness compared to the fittest [`Individual`].
+/// Untested [`Individual`]s die solely based on their age.
+///
+/// [`Individual`]: ../population/struct.Individual.html
+pub struct FitnessDependentMortality {
+    selection_strength: f64,
+}
+
+impl FitnessDependentMortality {
+    /// Creates a new `FitnessDependentMortality`.
+    ///
+    /// # Parameters
+    ///
+    /// * `selection_strength` - the fraction by which the chance of death of the fittest
+    ///   [`Individual`] is lowered
+    ///
+    /// # Panics
+    ///
+    /// If the selection strength is not within the range of 0.0 to 1.0.
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn new(selection_strength: f64) -> Self {
+        if !(0.0..=1.0).contains(&selection_strength) {
+            panic!(
+                "The selection strength must be within 0.0 and 1.0, but was {}.",
+                selection_strength
+            );
+        }
+        FitnessDependentMortality { selection_strength }
+    }
+
+    /// Returns the fraction by which the chance of death of the fittest [`Individual`]
+    /// is lowered.
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn selection_strength(&self) -> f64 {
+        self.selection_strength
+    }
+}
+
+impl MortalityModel for FitnessDependentMortality {
+    fn death_chance(
+        &self,
+        individual: &IndividualStatus,
+        population: &PopulationStatistics,
+        environment: &Environment,
+    ) -> f64 {
+        let age_dependent_chance = environment.death_chance(individual.age());
+        match (individual.fitness(), population.maximum_fitness()) {
+            (Some(fitness), Some(maximum_fitness)) if maximum_fitness > 0.0 => {
+                let relative_fitness = (fitness / maximum_fitness).clamp(0.0, 1.0);
+                age_dependent_chance * (1.0 - self.selection_strength * relative_fitness)
+            },
+            _ => age_dependent_chance,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+/// A `MaximumAgeMortality` lets [`Individual`]s die as soon as they reach a maximum age.
+///
+/// [`Individual`]: ../population/struct.Individual.html
+pub struct MaximumAgeMortality {
+    maximum_age: u32,
+}
+
+impl MaximumAgeMortality {
+    /// Creates a new `MaximumAgeMortality`.
+    ///
+    /// # Parameters
+    ///
+    /// * `maximum_age` - the age at which [`Individual`]s die
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn new(maximum_age: u32) -> Self {
+        MaximumAgeMortality { maximum_age }
+    }
+
+    /// Returns the age at which [`Individual`]s die.
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn maximum_age(&self) -> u32 {
+        self.maximum_age
+    }
+}
+
+impl MortalityModel for MaximumAgeMortality {
+    fn death_chance(
+        &self,
+        individual: &IndividualStatus,
+        _population: &PopulationStatistics,
+        _environment: &Environment,
+    ) -> f64 {
+        if individual.age() >= self.maximum_age {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+/// A `DensityDependentMortality` lets [`Individual`]s die by chance if the [`Population`]
+/// exceeds the population size specified by the [`Environment`], so that on average the
+/// [`Population`] shrinks back to the specified size.
+///
+/// [`Environment`]: ./struct.Environment.html
+/// [`Individual`]: ../population/struct.Individual.html
+/// [`Population`]: ../population/struct.Population.html
+pub struct DensityDependentMortality;
+
+impl MortalityModel for DensityDependentMortality {
+    fn death_chance(
+        &self,
+        _individual: &IndividualStatus,
+        population: &PopulationStatistics,
+        environment: &Environment,
+    ) -> f64 {
+        let population_size = environment.population_size() as usize;
+        if population.size() > population_size {
+            (population.size() - population_size) as f64 / population.size() as f64
+        } else {
+            0.0
+        }
+    }
+}
+
+/// A `CombinedMortality` lets [`Individual`]s die if any of the underlying
+/// [`MortalityModel`]s leads to death, assuming all causes of death to be independent.
+///
+/// [`Individual`]: ../population/struct.Individual.html
+/// [`MortalityModel`]: ./trait.MortalityModel.html
+pub struct CombinedMortality {
+    models: Vec<Box<dyn MortalityModel>>,
+}
+
+impl CombinedMortality {
+    /// Creates a new `CombinedMortality`.
+    ///
+    /// # Parameters
+    ///
+    /// * `models` - the [`MortalityModel`]s to combine
+    ///
+    /// [`MortalityModel`]: ./trait.MortalityModel.html
+    pub fn new(models: Vec<Box<dyn MortalityModel>>) -> Self {
+        CombinedMortality { models }
+    }
+}
+
+impl MortalityModel for CombinedMortality {
+    fn death_chance(
+        &self,
+        individual: &IndividualStatus,
+        population: &PopulationStatistics,
+        environment: &Environment,
+    ) -> f64 {
+        let survival_chance: f64 = self
+            .models
+            .iter()
+            .map(|model| 1.0 - model.death_chance(individual, population, environment))
+            .product();
+        1.0 - survival_chance
+    }
+}
+
+#[cfg(test)]
+mod tests;
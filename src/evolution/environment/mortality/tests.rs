@@ -0,0 +1,114 @@
+use crate::evolution::{environment::EnvironmentBuilder, resource::Resource};
+
+use super::*;
+
+/// Creates [`PopulationStatistics`] of the specified size and maximum fitness.
+fn test_statistics(size: usize, maximum_fitness: Option<f64>) -> PopulationStatistics {
+    PopulationStatistics::new(size, 0.5, maximum_fitness, 1.0, 1.0, Resource::default())
+}
+
+#[test]
+/// Tests if the function `death_chance` of the [`AgeSigmoidMortality`] struct correctly
+/// delegates to the [`Environment`].
+fn test_age_sigmoid_mortality_death_chance() {
+    let environment = EnvironmentBuilder::new().build().unwrap();
+    let statistics = test_statistics(10, None);
+    for age in 0..10 {
+        let individual = IndividualStatus::new(age, 0, None, None);
+        assert_ulps_eq!(
+            AgeSigmoidMortality.death_chance(&individual, &statistics, &environment),
+            environment.death_chance(age)
+        );
+    }
+}
+
+#[test]
+/// Tests if the function `death_chance` of the [`FitnessDependentMortality`] struct correctly
+/// lowers the chance of death of fit individuals.
+fn test_fitness_dependent_mortality_death_chance() {
+    let environment = EnvironmentBuilder::new().build().unwrap();
+    let statistics = test_statistics(10, Some(0.8));
+    let mortality = FitnessDependentMortality::new(0.5);
+    let age_dependent_chance = environment.death_chance(4);
+    let untested = IndividualStatus::new(4, 0, None, None);
+    assert_ulps_eq!(
+        mortality.death_chance(&untested, &statistics, &environment),
+        age_dependent_chance
+    );
+    let fittest = IndividualStatus::new(4, 1, Some(0.8), None);
+    assert_ulps_eq!(
+        mortality.death_chance(&fittest, &statistics, &environment),
+        age_dependent_chance * 0.5
+    );
+    let average = IndividualStatus::new(4, 1, Some(0.4), None);
+    assert_ulps_eq!(
+        mortality.death_chance(&average, &statistics, &environment),
+        age_dependent_chance * 0.75
+    );
+}
+
+#[test]
+#[should_panic]
+/// Tests if the function `new` of the [`FitnessDependentMortality`] struct correctly panics
+/// on invalid selection strengths.
+fn test_fitness_dependent_mortality_new_invalid() {
+    FitnessDependentMortality::new(1.5);
+}
+
+#[test]
+/// Tests if the functions `death_chance` of the [`MaximumAgeMortality`] and
+/// [`DensityDependentMortality`] structs correctly calculate the chance of death.
+fn test_maximum_age_and_density_dependent_mortality_death_chance() {
+    let environment = EnvironmentBuilder::new()
+        .population_size(80)
+        .build()
+        .unwrap();
+    let individual = IndividualStatus::new(5, 0, None, None);
+    let mortality = MaximumAgeMortality::new(5);
+    assert_ulps_eq!(
+        mortality.death_chance(&individual, &test_statistics(10, None), &environment),
+        1.0
+    );
+    let young = IndividualStatus::new(4, 0, None, None);
+    assert_ulps_eq!(mortality.death_chance(&young, &test_statistics(10, None), &environment), 0.0);
+    assert_ulps_eq!(
+        DensityDependentMortality.death_chance(
+            &individual,
+            &test_statistics(80, None),
+            &environment
+        ),
+        0.0
+    );
+    assert_ulps_eq!(
+        DensityDependentMortality.death_chance(
+            &individual,
+            &test_statistics(100, None),
+            &environment
+        ),
+        0.2
+    );
+}
+
+#[test]
+/// Tests if the function `death_chance` of the [`CombinedMortality`] struct correctly
+/// combines independent causes of death.
+fn test_combined_mortality_death_chance() {
+    let environment = EnvironmentBuilder::new()
+        .population_size(80)
+        .build()
+        .unwrap();
+    let individual = IndividualStatus::new(5, 0, None, None);
+    let mortality = CombinedMortality::new(vec![
+        Box::new(DensityDependentMortality),
+        Box::new(DensityDependentMortality),
+    ]);
+    assert_ulps_eq!(
+        mortality.death_chance(&individual, &test_statistics(100, None), &environment),
+        0.36
+    );
+    let immortal = CombinedMortality::new(Vec::new());
+    assert_ulps_eq!(
+        immortal.death_chance(&individual, &test_statistics(100, None), &environment),
+        0.0
+    );
+}
@@ -1,12 +1,19 @@
 //! The `mutation` module contains structures relevant for mutating genomes.
 
+use std::collections::BTreeMap;
 use std::iter;
+use std::sync::Mutex;
 
-use rand::{prelude::SliceRandom, thread_rng, Rng};
-use serde::{de::DeserializeOwned, Serialize};
+use rand::{
+    prelude::{IteratorRandom, SliceRandom},
+    thread_rng, Rng,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
+use super::adaptation::{OperatorAdaptation, SelfAdaptation};
 use crate::evolution::{
     chemistry::{Information, Input, Output, Reaction, State},
+    fitness::FitnessStatistics,
     gene::Genome,
     helper::Nlbf64,
 };
@@ -15,8 +22,17 @@ use crate::evolution::{
 /// for a single [`Mutation`] type upon [`Genome`] duplication.
 const MAX_MUTATION_EVENTS: usize = 128;
 
-#[derive(Debug, PartialEq)]
-/// A list of [`Mutation`]s.
+/// The maximum chance a mutation chance can be scaled up to by a modifier,
+/// which corresponds to 9 expected mutation events.
+/// Higher chances are only kept if they were set without scaling.
+const MAX_SCALED_MUTATION_CHANCE: f64 = 0.9;
+
+#[derive(Debug)]
+/// A list of [`Mutation`]s. The compendium aggregates [`OperatorStatistics`] of all
+/// [`Mutation`]s it applied by name, so [`Mutation`]s sharing a name share their statistics.
+/// [`Mutation`]s without an explicit name are named after their index in the compendium.
+///
+/// [`OperatorStatistics`]: ./struct.OperatorStatistics.html
 pub struct MutationCompendium<
     ReactionType,
     StateType,
@@ -37,6 +53,10 @@ pub struct MutationCompendium<
             OutputSensorType,
         >,
     >,
+    statistics: Mutex<BTreeMap<String, OperatorStatistics>>,
+    adaptation: Option<(OperatorAdaptation, f64)>,
+    self_adaptation: Option<SelfAdaptation>,
+    failure_policy: FailurePolicy,
 }
 
 impl<
@@ -70,6 +90,10 @@ impl<
     > {
         MutationCompendium {
             mutations: Vec::new(),
+            statistics: Mutex::new(BTreeMap::new()),
+            adaptation: None,
+            self_adaptation: None,
+            failure_policy: FailurePolicy::default(),
         }
     }
 
@@ -95,17 +119,149 @@ impl<
             OutputSensorType,
         >,
     ) {
-        self.mutations.push(mutation);
+        let index = self.mutations.len();
+        self.mutations.push(mutation.indexed(index));
+    }
+
+    /// Sets the chance of the [`Mutation`] at the specified index.
+    ///
+    /// # Parameters
+    ///
+    /// * `index` - the index of the [`Mutation`] in the order it was added
+    /// * `mutation_chance` - the new chance of the mutation happening a single time during
+    ///   genome duplication
+    ///
+    /// # Panics
+    ///
+    /// If the index is out of bounds.
+    pub fn set_mutation_chance<N: Into<Nlbf64>>(&mut self, index: usize, mutation_chance: N) {
+        self.mutations[index].set_mutation_chance(mutation_chance);
+    }
+
+    /// Returns the chances of all [`Mutation`]s in the order they were added.
+    pub fn mutation_chances(&self) -> Vec<f64> {
+        self.mutations
+            .iter()
+            .map(|mutation| mutation.mutation_chance().value())
+            .collect()
+    }
+
+    /// Enables the adaptive reallocation of mutation chances by the specified
+    /// [`OperatorAdaptation`]. The sum of the current mutation chances is distributed
+    /// on every call to [`adapt`](Self::adapt).
+    ///
+    /// # Parameters
+    ///
+    /// * `adaptation` - the [`OperatorAdaptation`]
+    ///
+    /// [`OperatorAdaptation`]: ./struct.OperatorAdaptation.html
+    pub fn set_adaptation(&mut self, adaptation: OperatorAdaptation) {
+        let total_chance = self.mutation_chances().iter().sum();
+        self.adaptation = Some((adaptation, total_chance));
+    }
+
+    /// Returns the [`OperatorAdaptation`] if adaptive reallocation of mutation chances
+    /// is enabled.
+    ///
+    /// [`OperatorAdaptation`]: ./struct.OperatorAdaptation.html
+    pub fn adaptation(&self) -> Option<&OperatorAdaptation> {
+        self.adaptation.as_ref().map(|(adaptation, _)| adaptation)
+    }
+
+    /// Sets the [`FailurePolicy`] determining how a [`Mutation`] leaving a [`Genome`] in an
+    /// invalid state is handled. By default the whole mutation is aborted.
+    ///
+    /// # Parameters
+    ///
+    /// * `failure_policy` - the [`FailurePolicy`]
+    ///
+    /// [`FailurePolicy`]: ./enum.FailurePolicy.html
+    pub fn set_failure_policy(&mut self, failure_policy: FailurePolicy) {
+        self.failure_policy = failure_policy;
+    }
+
+    /// Returns the [`FailurePolicy`].
+    ///
+    /// [`FailurePolicy`]: ./enum.FailurePolicy.html
+    pub fn failure_policy(&self) -> FailurePolicy {
+        self.failure_policy
+    }
+
+    /// Enables the [`SelfAdaptation`] of mutation chances. Each [`Genome`] then scales the
+    /// chances by its own [`RateModifiers`], which are mutated on every call to
+    /// [`mutate`](Self::mutate) before being applied.
+    ///
+    /// # Parameters
+    ///
+    /// * `self_adaptation` - the [`SelfAdaptation`]
+    ///
+    /// [`RateModifiers`]: ../gene/struct.RateModifiers.html
+    /// [`SelfAdaptation`]: ./struct.SelfAdaptation.html
+    pub fn set_self_adaptation(&mut self, self_adaptation: SelfAdaptation) {
+        self.self_adaptation = Some(self_adaptation);
+    }
+
+    /// Returns the [`SelfAdaptation`] if the genomes adapt their own mutation chances.
+    ///
+    /// [`SelfAdaptation`]: ./struct.SelfAdaptation.html
+    pub fn self_adaptation(&self) -> Option<&SelfAdaptation> {
+        self.self_adaptation.as_ref()
+    }
+
+    /// Reallocates the mutation chances according to the [`OperatorStatistics`] if
+    /// adaptation is enabled and returns whether the chances were changed.
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding the mutation statistics lock.
+    ///
+    /// [`OperatorStatistics`]: ./struct.OperatorStatistics.html
+    pub fn adapt(&mut self) -> bool {
+        if let Some((adaptation, total_chance)) = &self.adaptation {
+            let chances = {
+                let statistics = self
+                    .statistics
+                    .lock()
+                    .expect("A thread paniced while holding the mutation statistics lock.");
+                let operators: Vec<Option<&OperatorStatistics>> = self
+                    .mutations
+                    .iter()
+                    .map(|mutation| statistics.get(mutation.name()))
+                    .collect();
+                adaptation.allocate(*total_chance, &operators)
+            };
+            for (mutation, chance) in self.mutations.iter_mut().zip(chances) {
+                mutation.set_mutation_chance(chance);
+            }
+            true
+        } else {
+            false
+        }
     }
 
     /// Mutates a [`Genome`] based on the [`Mutation`]s and
     /// their respective mutation frequencies and returns
-    /// the mutated [`Genome`] if any [`Mutation`] occured
-    /// and it is still in a valid state.
+    /// a [`MutationRecord`] containing the mutated [`Genome`]
+    /// if any [`Mutation`] occured and it is still in a valid state.
+    /// Failed [`Mutation`]s are handled according to the [`FailurePolicy`].
+    /// The applications and failures are added to the [`OperatorStatistics`].
+    /// If [`SelfAdaptation`] is enabled, the [`RateModifiers`] of the [`Genome`] are mutated
+    /// first and then scale the chances. A successfully mutated [`Genome`] carries the new
+    /// [`RateModifiers`] even if no other [`Mutation`] occured.
     ///
     /// # Parameters
     ///
     /// * `genome` - the [`Genome`] to mutate
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding the mutation statistics lock.
+    ///
+    /// [`FailurePolicy`]: ./enum.FailurePolicy.html
+    /// [`MutationRecord`]: ./struct.MutationRecord.html
+    /// [`OperatorStatistics`]: ./struct.OperatorStatistics.html
+    /// [`RateModifiers`]: ../gene/struct.RateModifiers.html
+    /// [`SelfAdaptation`]: ./struct.SelfAdaptation.html
     pub fn mutate(
         &self,
         genome: &Genome<
@@ -117,17 +273,23 @@ impl<
             OutputElementType,
             OutputSensorType,
         >,
-    ) -> Option<
-        Genome<
-            ReactionType,
-            StateType,
-            InformationType,
-            InputElementType,
-            InputSensorType,
-            OutputElementType,
-            OutputSensorType,
-        >,
+    ) -> MutationRecord<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
     > {
+        let rate_modifiers = self.self_adaptation.map(|self_adaptation| {
+            genome.rate_modifiers().self_adapt(
+                self.mutations.len(),
+                self_adaptation.learning_rate(),
+                self_adaptation.minimum_modifier(),
+                self_adaptation.maximum_modifier(),
+            )
+        });
         let mut applied_mutations: Vec<
             &Mutation<
                 ReactionType,
@@ -138,27 +300,193 @@ impl<
                 OutputElementType,
                 OutputSensorType,
             >,
-        > = self
-            .mutations
-            .iter()
-            .flat_map(|mutation| iter::repeat(mutation).take(mutation.number_of_mutation_events()))
-            .collect();
+        > =
+            self.mutations
+                .iter()
+                .enumerate()
+                .flat_map(|(index, mutation)| {
+                    let number_of_mutation_events = match &rate_modifiers {
+                        Some(rate_modifiers) => mutation
+                            .scaled_number_of_mutation_events(rate_modifiers.modifier(index)),
+                        None => mutation.number_of_mutation_events(),
+                    };
+                    iter::repeat_n(mutation, number_of_mutation_events)
+                })
+                .collect();
         // Randomise the order in which mutations are applied.
         applied_mutations.shuffle(&mut thread_rng());
         let mut mutated_genome = None;
-        for (i, mutation) in applied_mutations.into_iter().enumerate() {
-            if i == 0 {
-                // If it is the first mutation use the original genome.
-                mutated_genome = mutation.mutate(genome);
-            } else if let Some(g) = mutated_genome {
-                // On any subsequent mutation use the mutated genome.
-                mutated_genome = mutation.mutate(&g);
-            } else {
-                // If any of the mutations was not successful the rest of the loop can be skipped.
-                break;
+        let mut applied = Vec::with_capacity(applied_mutations.len());
+        let mut failed = Vec::new();
+        let mut aborted = false;
+        for mutation in applied_mutations {
+            // Use the original genome until the first mutation was successful.
+            let current_genome = mutated_genome.as_ref().unwrap_or(genome);
+            if let Some(g) = mutation.mutate(current_genome) {
+                mutated_genome = Some(g);
+                applied.push(mutation.name().to_string());
+                continue;
+            }
+            failed.push(mutation.name().to_string());
+            match self.failure_policy {
+                FailurePolicy::Abort => {
+                    // If any of the mutations was not successful the rest of the loop can be
+                    // skipped.
+                    mutated_genome = None;
+                    aborted = true;
+                    break;
+                },
+                FailurePolicy::Skip => {},
+                FailurePolicy::Retry(attempts) => {
+                    let mut rng = thread_rng();
+                    for _ in 0..attempts {
+                        let Some(alternative) = self
+                            .mutations
+                            .iter()
+                            .filter(|alternative| !std::ptr::eq(*alternative, mutation))
+                            .choose(&mut rng)
+                        else {
+                            break;
+                        };
+                        let current_genome = mutated_genome.as_ref().unwrap_or(genome);
+                        if let Some(g) = alternative.mutate(current_genome) {
+                            mutated_genome = Some(g);
+                            applied.push(alternative.name().to_string());
+                            break;
+                        }
+                        failed.push(alternative.name().to_string());
+                    }
+                },
+            }
+        }
+        if let Some(rate_modifiers) = rate_modifiers {
+            if !aborted {
+                // The adapted modifiers are a mutation on their own.
+                let mut adapted_genome = mutated_genome.unwrap_or_else(|| genome.duplicate());
+                adapted_genome.set_rate_modifiers(rate_modifiers);
+                mutated_genome = Some(adapted_genome);
             }
         }
-        mutated_genome
+        let record = MutationRecord {
+            genome: mutated_genome,
+            applied,
+            failed,
+            aborted,
+        };
+        self.record_applications(&record);
+        record
+    }
+
+    /// Adds the applications and failures of the specified [`MutationRecord`] to the
+    /// [`OperatorStatistics`]. The applications of an aborted mutation are not counted,
+    /// since the resulting [`Genome`] is discarded.
+    ///
+    /// # Parameters
+    ///
+    /// * `record` - the [`MutationRecord`] of a mutated [`Genome`]
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding the mutation statistics lock.
+    ///
+    /// [`Genome`]: ../gene/struct.Genome.html
+    /// [`MutationRecord`]: ./struct.MutationRecord.html
+    /// [`OperatorStatistics`]: ./struct.OperatorStatistics.html
+    fn record_applications(
+        &self,
+        record: &MutationRecord<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    ) {
+        let mut statistics = self
+            .statistics
+            .lock()
+            .expect("A thread paniced while holding the mutation statistics lock.");
+        if !record.is_aborted() {
+            for name in record.applied() {
+                statistics.entry(name.clone()).or_default().applications += 1;
+            }
+        }
+        for name in record.failed() {
+            statistics.entry(name.clone()).or_default().failures += 1;
+        }
+    }
+
+    /// Adds the difference between the fitness of an offspring and the fitness of its
+    /// parent to the [`OperatorStatistics`] of every [`Mutation`] applied to the offspring.
+    /// [`Mutation`]s applied multiple times are only credited once.
+    ///
+    /// # Parameters
+    ///
+    /// * `mutations` - the names of the [`Mutation`]s applied to the offspring
+    /// * `fitness_delta` - the fitness of the offspring minus the fitness of its parent
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding the mutation statistics lock.
+    ///
+    /// [`OperatorStatistics`]: ./struct.OperatorStatistics.html
+    pub fn record_fitness_delta(&self, mutations: &[String], fitness_delta: f64) {
+        let mut statistics = self
+            .statistics
+            .lock()
+            .expect("A thread paniced while holding the mutation statistics lock.");
+        let mut credited: Vec<&String> = Vec::with_capacity(mutations.len());
+        for name in mutations {
+            if !credited.contains(&name) {
+                credited.push(name);
+                statistics
+                    .entry(name.clone())
+                    .or_default()
+                    .fitness_delta
+                    .add(fitness_delta);
+            }
+        }
+    }
+
+    /// Returns a copy of the [`OperatorStatistics`] aggregated so far by [`Mutation`] name.
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding the mutation statistics lock.
+    ///
+    /// [`OperatorStatistics`]: ./struct.OperatorStatistics.html
+    pub fn operator_statistics(&self) -> BTreeMap<String, OperatorStatistics> {
+        self.statistics
+            .lock()
+            .expect("A thread paniced while holding the mutation statistics lock.")
+            .clone()
+    }
+}
+
+impl<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    > PartialEq
+    for MutationCompendium<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >
+{
+    fn eq(&self, other: &Self) -> bool {
+        // The statistics are a by-product of mutating and therefore not compared.
+        self.mutations == other.mutations
     }
 }
 
@@ -207,7 +535,17 @@ impl<
             >,
         >,
     ) -> Self {
-        MutationCompendium { mutations }
+        MutationCompendium {
+            mutations: mutations
+                .into_iter()
+                .enumerate()
+                .map(|(index, mutation)| mutation.indexed(index))
+                .collect(),
+            statistics: Mutex::new(BTreeMap::new()),
+            adaptation: None,
+            self_adaptation: None,
+            failure_policy: FailurePolicy::default(),
+        }
     }
 }
 
@@ -258,6 +596,212 @@ impl<
     }
 }
 
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+/// A `FailurePolicy` determines how a [`MutationCompendium`] handles a [`Mutation`] that
+/// would leave a [`Genome`] in an invalid state.
+///
+/// [`Genome`]: ../gene/struct.Genome.html
+/// [`Mutation`]: ./struct.Mutation.html
+/// [`MutationCompendium`]: ./struct.MutationCompendium.html
+pub enum FailurePolicy {
+    /// The whole mutation is aborted and all previously applied [`Mutation`]s are discarded.
+    ///
+    /// [`Mutation`]: ./struct.Mutation.html
+    #[default]
+    Abort,
+    /// The failed [`Mutation`] is skipped and the remaining ones are applied.
+    ///
+    /// [`Mutation`]: ./struct.Mutation.html
+    Skip,
+    /// Up to the specified number of randomly chosen other [`Mutation`]s are tried instead
+    /// of the failed one until one succeeds. The remaining ones are applied afterwards.
+    ///
+    /// [`Mutation`]: ./struct.Mutation.html
+    Retry(usize),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+/// A `MutationRecord` describes the outcome of mutating a [`Genome`] with a
+/// [`MutationCompendium`].
+///
+/// [`Genome`]: ../gene/struct.Genome.html
+/// [`MutationCompendium`]: ./struct.MutationCompendium.html
+pub struct MutationRecord<
+    ReactionType,
+    StateType,
+    InformationType,
+    InputElementType,
+    InputSensorType,
+    OutputElementType,
+    OutputSensorType,
+> {
+    genome: Option<
+        Genome<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    >,
+    applied: Vec<String>,
+    failed: Vec<String>,
+    aborted: bool,
+}
+
+impl<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >
+    MutationRecord<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >
+{
+    /// Returns the mutated [`Genome`] if any [`Mutation`] occured and it is still in a
+    /// valid state.
+    ///
+    /// [`Genome`]: ../gene/struct.Genome.html
+    /// [`Mutation`]: ./struct.Mutation.html
+    pub fn genome(
+        &self,
+    ) -> Option<
+        &Genome<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    > {
+        self.genome.as_ref()
+    }
+
+    /// Consumes the `MutationRecord` and returns the mutated [`Genome`] if any
+    /// [`Mutation`] occured and it is still in a valid state.
+    ///
+    /// [`Genome`]: ../gene/struct.Genome.html
+    /// [`Mutation`]: ./struct.Mutation.html
+    pub fn into_genome(
+        self,
+    ) -> Option<
+        Genome<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    > {
+        self.genome
+    }
+
+    /// Returns the names of all successfully applied [`Mutation`]s in the order of their
+    /// application. If the mutation was aborted, these are the ones applied before the
+    /// failure.
+    ///
+    /// [`Mutation`]: ./struct.Mutation.html
+    pub fn applied(&self) -> &[String] {
+        &self.applied
+    }
+
+    /// Returns the names of all [`Mutation`]s that would have left the [`Genome`] in an
+    /// invalid state in the order of their application.
+    ///
+    /// [`Genome`]: ../gene/struct.Genome.html
+    /// [`Mutation`]: ./struct.Mutation.html
+    pub fn failed(&self) -> &[String] {
+        &self.failed
+    }
+
+    /// Checks if the mutation was aborted due to a failure according to the
+    /// [`FailurePolicy`], so no [`Genome`] is returned.
+    ///
+    /// [`FailurePolicy`]: ./enum.FailurePolicy.html
+    /// [`Genome`]: ../gene/struct.Genome.html
+    pub fn is_aborted(&self) -> bool {
+        self.aborted
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+/// `OperatorStatistics` describe the effectiveness of a [`Mutation`] over a run.
+///
+/// [`Mutation`]: ./struct.Mutation.html
+pub struct OperatorStatistics {
+    applications: u64,
+    failures: u64,
+    fitness_delta: FitnessStatistics,
+}
+
+impl OperatorStatistics {
+    /// Creates new `OperatorStatistics`.
+    ///
+    /// # Parameters
+    ///
+    /// * `applications` - the number of successful applications of the [`Mutation`]
+    /// * `failures` - the number of failed applications of the [`Mutation`]
+    /// * `fitness_delta` - the [`FitnessStatistics`] of the difference between the fitness
+    ///   of tested offspring and their parents
+    ///
+    /// [`FitnessStatistics`]: ../fitness/struct.FitnessStatistics.html
+    /// [`Mutation`]: ./struct.Mutation.html
+    pub fn new(applications: u64, failures: u64, fitness_delta: FitnessStatistics) -> Self {
+        OperatorStatistics {
+            applications,
+            failures,
+            fitness_delta,
+        }
+    }
+
+    /// Returns the number of successful applications of the [`Mutation`] to [`Genome`]s that
+    /// were not discarded afterwards.
+    ///
+    /// [`Genome`]: ../gene/struct.Genome.html
+    /// [`Mutation`]: ./struct.Mutation.html
+    pub fn applications(&self) -> u64 {
+        self.applications
+    }
+
+    /// Returns the number of times the [`Mutation`] left a [`Genome`] in an invalid state.
+    ///
+    /// [`Genome`]: ../gene/struct.Genome.html
+    /// [`Mutation`]: ./struct.Mutation.html
+    pub fn failures(&self) -> u64 {
+        self.failures
+    }
+
+    /// Returns the [`FitnessStatistics`] of the difference between the fitness of tested
+    /// offspring and their parents.
+    ///
+    /// [`FitnessStatistics`]: ../fitness/struct.FitnessStatistics.html
+    pub fn fitness_delta(&self) -> &FitnessStatistics {
+        &self.fitness_delta
+    }
+
+    /// Returns the mean difference between the fitness of tested offspring and their
+    /// parents if any offspring was tested.
+    pub fn mean_fitness_delta(&self) -> Option<f64> {
+        self.fitness_delta.mean()
+    }
+}
+
 /// A `Mutation` that occurs with a specified frequency druing
 /// [`Genome`] duplication.
 pub struct Mutation<
@@ -269,6 +813,8 @@ pub struct Mutation<
     OutputElementType,
     OutputSensorType,
 > {
+    name: String,
+    named: bool,
     chance: Nlbf64,
     mutation: Box<
         dyn Fn(
@@ -360,16 +906,49 @@ impl<
         OutputSensorType,
     > {
         Mutation {
+            name: std::any::type_name::<F>().to_string(),
+            named: false,
             chance: mutation_chance.into(),
             mutation: Box::new(mutation),
         }
     }
 
+    /// Sets the name of this `Mutation`, which is recorded in the lineage of mutated
+    /// [`Genome`]s. By default the type name of the mutation function is used.
+    /// Since all closures of a function share the same type name, a [`MutationCompendium`]
+    /// appends the index of unnamed `Mutation`s.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - the name of the `Mutation`
+    ///
+    /// [`MutationCompendium`]: ./struct.MutationCompendium.html
+    pub fn with_name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = name.into();
+        self.named = true;
+        self
+    }
+
+    /// Returns the name of this `Mutation`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
     /// Returns the chance of this mutation happening during [`Genome`] duplication a single time.
     pub fn mutation_chance(&self) -> Nlbf64 {
         self.chance
     }
 
+    /// Sets the chance of this mutation happening during [`Genome`] duplication a single time.
+    ///
+    /// # Parameters
+    ///
+    /// * `mutation_chance` - the new chance of the mutation happening a single time during
+    ///   genome duplication
+    pub fn set_mutation_chance<N: Into<Nlbf64>>(&mut self, mutation_chance: N) {
+        self.chance = mutation_chance.into();
+    }
+
     /// Apply this `Mutation` to a [`Genome`]
     /// and return the mutated [`Genome`]
     /// if still in a valid state.
@@ -405,9 +984,37 @@ impl<
     /// Simulates how often a mutation event occurs on a statistical basis.
     /// This is an internal function with a random element.
     fn number_of_mutation_events(&self) -> usize {
-        if self.chance == Nlbf64::MIN {
+        Self::number_of_mutation_events_with_chance(self.chance)
+    }
+
+    /// Simulates how often a mutation event occurs on a statistical basis if the
+    /// mutation chance is scaled by the specified modifier.
+    /// The scaled chance never exceeds the unscaled chance or [`MAX_SCALED_MUTATION_CHANCE`],
+    /// whichever is higher, as a chance of 1.0 results in the maximum number of events.
+    /// This is an internal function with a random element.
+    ///
+    /// # Parameters
+    ///
+    /// * `modifier` - the factor the mutation chance is multiplied by
+    ///
+    /// [`MAX_SCALED_MUTATION_CHANCE`]: ./constant.MAX_SCALED_MUTATION_CHANCE.html
+    fn scaled_number_of_mutation_events(&self, modifier: f64) -> usize {
+        let chance = self.chance.value();
+        let scaled_chance = (chance * modifier).min(chance.max(MAX_SCALED_MUTATION_CHANCE));
+        Self::number_of_mutation_events_with_chance(scaled_chance.into())
+    }
+
+    /// Simulates how often a mutation event with the specified chance occurs on a
+    /// statistical basis.
+    /// This is an internal function with a random element.
+    ///
+    /// # Parameters
+    ///
+    /// * `chance` - the chance of the mutation happening a single time
+    fn number_of_mutation_events_with_chance(chance: Nlbf64) -> usize {
+        if chance == Nlbf64::MIN {
             0
-        } else if self.chance == Nlbf64::MAX {
+        } else if chance == Nlbf64::MAX {
             MAX_MUTATION_EVENTS
         } else {
             let random_chance: f64 = thread_rng().gen_range(0.0..1.0);
@@ -415,8 +1022,7 @@ impl<
             // corresponding to the generated uniform random percentage.
             //    P("n mutations in a single genome") = "mutation rate" ^ n
             // => n = log(base: "mutation rate", value: P)
-            let number_of_mutations =
-                random_chance.log(self.mutation_chance().value()).floor() as usize;
+            let number_of_mutations = random_chance.log(chance.value()).floor() as usize;
             // Limits the number of mutation events per mutation to
             // prevent overflow in rare statistical cases.
             if number_of_mutations > MAX_MUTATION_EVENTS {
@@ -428,6 +1034,42 @@ impl<
     }
 }
 
+impl<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >
+    Mutation<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >
+{
+    /// Appends the specified index to the name of this `Mutation` unless it was named
+    /// explicitly.
+    ///
+    /// # Parameters
+    ///
+    /// * `index` - the index of the `Mutation` in its [`MutationCompendium`]
+    ///
+    /// [`MutationCompendium`]: ./struct.MutationCompendium.html
+    fn indexed(mut self, index: usize) -> Self {
+        if !self.named {
+            self.name = format!("{}#{}", self.name, index);
+            self.named = true;
+        }
+        self
+    }
+}
+
 impl<
         ReactionType,
         StateType,
@@ -449,6 +1091,7 @@ impl<
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Mutation")
+            .field("name", &self.name)
             .field("chance", &self.chance)
             .field("mutation", &format!("{:p}", &self.mutation))
             .finish()
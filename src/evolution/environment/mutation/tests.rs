@@ -2,11 +2,13 @@ use crate::evolution::{
     gene::{Gene, GeneSubstrate, GenomicInputSensor, GenomicOutputSensor},
     helper::{
         noop::{NoOpInputElement, NoOpOutputElement},
-        testing::{TestGenome, TestInformation, TestInput, TestReaction, TestState, TestOutput},
+        testing::{TestGenome, TestInformation, TestInput, TestOutput, TestReaction, TestState},
     },
 };
 
+use super::super::adaptation::AdaptationStrategy;
 use super::*;
+use crate::evolution::gene::RateModifiers;
 
 #[test]
 /// Tests if the function `new` of the [`Mutation`] struct correctly creates a [`Mutation`].
@@ -22,6 +24,15 @@ fn test_mutation_new() {
     assert_eq!(mutation.mutate(&unmutated_genome), Some(unmutated_genome));
 }
 
+#[test]
+/// Tests if the function `set_mutation_chance` of the [`Mutation`] struct correctly
+/// changes the mutation chance.
+fn test_mutation_set_mutation_chance() {
+    let mut mutation = Mutation::new(0.17, |genome: &TestGenome| Some(genome.duplicate()));
+    mutation.set_mutation_chance(0.42);
+    assert_ulps_eq!(0.42, mutation.mutation_chance().value());
+}
+
 #[test]
 /// Tests if the function `eq` of the [`Mutation`] struct correctly compares [`Mutation`]s.
 fn test_mutation_eq() {
@@ -80,6 +91,24 @@ fn test_number_of_mutation_events() {
     }
 }
 
+#[test]
+/// Tests if the function `scaled_number_of_mutation_events` of the [`Mutation`] struct
+/// correctly bounds the number of mutation events if the scaled chance exceeds 1.0.
+fn test_scaled_number_of_mutation_events() {
+    let mutation = Mutation::new(0.6, |genome: &TestGenome| Some(genome.duplicate()));
+    let repetitions = 1000;
+    let events: usize = (0..repetitions)
+        .map(|_| mutation.scaled_number_of_mutation_events(2.0))
+        .sum();
+    // The scaled chance of 0.9 results in 9 expected events instead of the maximum.
+    assert!(events < 20 * repetitions);
+    assert!(events > 3 * repetitions);
+    let certain = Mutation::new(1.0, |genome: &TestGenome| Some(genome.duplicate()));
+    assert_eq!(certain.scaled_number_of_mutation_events(2.0), MAX_MUTATION_EVENTS);
+    let unscaled = Mutation::new(0.0, |genome: &TestGenome| Some(genome.duplicate()));
+    assert_eq!(unscaled.scaled_number_of_mutation_events(2.0), 0);
+}
+
 #[test]
 /// Tests if the function `new` of the [`MutationCompendium`] struct correctly creates an empty [`MutationCompendium`].
 fn test_mutation_compendium_new() {
@@ -98,7 +127,7 @@ fn test_mutation_compendium_new() {
         vec![Gene::new(vec![TestInformation { value: 0 }])],
     );
     assert_eq!(compendium.size(), 0);
-    assert!(compendium.mutate(&unmutated_genome).is_none());
+    assert!(compendium.mutate(&unmutated_genome).genome().is_none());
 }
 
 #[test]
@@ -143,7 +172,7 @@ fn test_mutation_compendium_mutate() {
             vec![Gene::new(vec![TestInformation { value: 0 }])],
         );
         assert_eq!(compendium.size(), 1);
-        assert!(compendium.mutate(&unmutated_genome).is_none());
+        assert!(compendium.mutate(&unmutated_genome).genome().is_none());
     }
     {
         // 100 percent chance mutations are always applied.
@@ -179,7 +208,10 @@ fn test_mutation_compendium_mutate() {
             }])],
         );
         assert_eq!(compendium.size(), 1);
-        assert_eq!(Some(expected_mutated_genome), compendium.mutate(&unmutated_genome));
+        assert_eq!(
+            Some(expected_mutated_genome),
+            compendium.mutate(&unmutated_genome).into_genome()
+        );
     }
     {
         // Other mutations are always applied on a statistical basis.
@@ -234,7 +266,7 @@ fn test_mutation_compendium_mutate() {
             vec![Gene::new(vec![TestInformation { value: 0 }])],
         );
         assert_eq!(compendium.size(), 3);
-        if let Some(mutated_genome) = compendium.mutate(&unmutated_genome) {
+        if let Some(mutated_genome) = compendium.mutate(&unmutated_genome).into_genome() {
             assert!(
                 mutated_genome
                     .get_substrate(GeneSubstrate::new(0, 0))
@@ -245,3 +277,284 @@ fn test_mutation_compendium_mutate() {
         }
     }
 }
+
+#[test]
+/// Tests if the function `mutate` of the [`MutationCompendium`] struct correctly records
+/// applied and failed [`Mutation`]s.
+fn test_mutation_compendium_mutate_record() {
+    let unmutated_genome: TestGenome = Genome::new(
+        GenomicInputSensor::default(),
+        GenomicOutputSensor::default(),
+        vec![Gene::new(vec![TestInformation { value: 0 }])],
+    );
+    let compendium: MutationCompendium<
+        TestReaction,
+        TestState,
+        TestInformation,
+        NoOpInputElement,
+        TestInput,
+        NoOpOutputElement,
+        TestOutput,
+    > = vec![
+        Mutation::new(1.0, |genome: &TestGenome| Some(genome.duplicate())).with_name("duplicate"),
+        Mutation::new(0.0, |genome: &TestGenome| Some(genome.duplicate())).with_name("never"),
+    ]
+    .into();
+    let record = compendium.mutate(&unmutated_genome);
+    assert_eq!(record.genome(), Some(&unmutated_genome));
+    assert_eq!(record.applied(), vec!["duplicate".to_string(); MAX_MUTATION_EVENTS]);
+    assert!(record.failed().is_empty());
+    assert!(!record.is_aborted());
+    let failing: MutationCompendium<
+        TestReaction,
+        TestState,
+        TestInformation,
+        NoOpInputElement,
+        TestInput,
+        NoOpOutputElement,
+        TestOutput,
+    > = vec![Mutation::new(1.0, |_: &TestGenome| None).with_name("failing")].into();
+    let record = failing.mutate(&unmutated_genome);
+    assert!(record.genome().is_none());
+    assert!(record.applied().is_empty());
+    assert_eq!(record.failed(), ["failing".to_string()]);
+    assert!(record.is_aborted());
+}
+
+#[test]
+/// Tests if the functions `from` and `add` of the [`MutationCompendium`] struct correctly
+/// name unnamed [`Mutation`]s after their index, so their statistics are kept apart.
+fn test_mutation_compendium_default_names() {
+    let mut compendium: MutationCompendium<
+        TestReaction,
+        TestState,
+        TestInformation,
+        NoOpInputElement,
+        TestInput,
+        NoOpOutputElement,
+        TestOutput,
+    > = vec![
+        Mutation::new(1.0, |genome: &TestGenome| Some(genome.duplicate())),
+        Mutation::new(1.0, |genome: &TestGenome| Some(genome.duplicate())).with_name("named"),
+    ]
+    .into();
+    compendium.add(Mutation::new(1.0, |genome: &TestGenome| Some(genome.duplicate())));
+    let mutations: Vec<Mutation<_, _, _, _, _, _, _>> = compendium.into();
+    assert!(mutations[0].name().ends_with("#0"));
+    assert_eq!(mutations[1].name(), "named");
+    assert!(mutations[2].name().ends_with("#2"));
+    assert_ne!(mutations[0].name(), mutations[2].name());
+}
+
+#[test]
+/// Tests if the functions `mutate` and `record_fitness_delta` of the [`MutationCompendium`]
+/// struct correctly aggregate the [`OperatorStatistics`].
+fn test_mutation_compendium_operator_statistics() {
+    let unmutated_genome: TestGenome = Genome::new(
+        GenomicInputSensor::default(),
+        GenomicOutputSensor::default(),
+        vec![Gene::new(vec![TestInformation { value: 0 }])],
+    );
+    let compendium: MutationCompendium<
+        TestReaction,
+        TestState,
+        TestInformation,
+        NoOpInputElement,
+        TestInput,
+        NoOpOutputElement,
+        TestOutput,
+    > = vec![
+        Mutation::new(1.0, |genome: &TestGenome| Some(genome.duplicate())).with_name("duplicate"),
+        Mutation::new(1.0, |_: &TestGenome| None).with_name("failing"),
+    ]
+    .into();
+    compendium.mutate(&unmutated_genome);
+    compendium.mutate(&unmutated_genome);
+    let statistics = compendium.operator_statistics();
+    // Each mutation stops at the first failure.
+    assert_eq!(statistics["failing"].failures(), 2);
+    assert_eq!(statistics["failing"].applications(), 0);
+    assert_eq!(
+        statistics
+            .get("duplicate")
+            .map_or(0, |duplicate| duplicate.failures()),
+        0
+    );
+    let names = vec!["duplicate".to_string(), "duplicate".to_string()];
+    compendium.record_fitness_delta(&names, 0.5);
+    compendium.record_fitness_delta(&names, -0.25);
+    let statistics = compendium.operator_statistics();
+    // Mutations applied multiple times are only credited once per offspring.
+    assert_eq!(statistics["duplicate"].fitness_delta().count(), 2);
+    assert_ulps_eq!(statistics["duplicate"].mean_fitness_delta().unwrap(), 0.125);
+}
+
+#[test]
+/// Tests if the function `adapt` of the [`MutationCompendium`] struct correctly reallocates
+/// the mutation chances based on the [`OperatorStatistics`].
+fn test_mutation_compendium_adapt() {
+    let mut compendium: MutationCompendium<
+        TestReaction,
+        TestState,
+        TestInformation,
+        NoOpInputElement,
+        TestInput,
+        NoOpOutputElement,
+        TestOutput,
+    > = vec![
+        Mutation::new(0.1, |genome: &TestGenome| Some(genome.duplicate())).with_name("good"),
+        Mutation::new(0.1, |genome: &TestGenome| Some(genome.duplicate())).with_name("bad"),
+    ]
+    .into();
+    // Adaptation is disabled by default.
+    assert!(!compendium.adapt());
+    compendium.set_adaptation(OperatorAdaptation::new(
+        AdaptationStrategy::ProbabilityMatching,
+        0.02,
+        1.0,
+    ));
+    compendium.record_fitness_delta(&["good".to_string()], 0.5);
+    compendium.record_fitness_delta(&["bad".to_string()], -0.5);
+    assert!(compendium.adapt());
+    // The total chance of 0.2 is kept while the bad operator is bound to the minimum.
+    let chances = compendium.mutation_chances();
+    assert_abs_diff_eq!(chances[0], 0.18, epsilon = 1e-9);
+    assert_abs_diff_eq!(chances[1], 0.02, epsilon = 1e-9);
+}
+
+#[test]
+/// Tests if the function `mutate` of the [`MutationCompendium`] struct correctly scales
+/// the mutation chances by the self-adaptive rate modifiers of the [`Genome`].
+fn test_mutation_compendium_mutate_self_adaptation() {
+    let mut compendium: MutationCompendium<
+        TestReaction,
+        TestState,
+        TestInformation,
+        NoOpInputElement,
+        TestInput,
+        NoOpOutputElement,
+        TestOutput,
+    > = vec![Mutation::new(1.0, |genome: &TestGenome| {
+        let mut mutated_genome = genome.duplicate();
+        let substrate = mutated_genome
+            .get_substrate_mut(GeneSubstrate::new(0, 0))
+            .unwrap();
+        *substrate = TestInformation {
+            value: substrate.value + 1,
+        };
+        Some(mutated_genome)
+    })]
+    .into();
+    compendium.set_self_adaptation(SelfAdaptation::new(0.0, 0.1, 10.0));
+    let mut unmutated_genome: TestGenome = Genome::new(
+        GenomicInputSensor::default(),
+        GenomicOutputSensor::default(),
+        vec![Gene::new(vec![TestInformation { value: 0 }])],
+    );
+    // A modifier of 2.0 keeps the certain chance.
+    unmutated_genome.set_rate_modifiers(RateModifiers::new(vec![2.0]));
+    let mutated_genome = compendium.mutate(&unmutated_genome).into_genome().unwrap();
+    assert_eq!(
+        mutated_genome
+            .get_substrate(GeneSubstrate::new(0, 0))
+            .unwrap()
+            .value,
+        MAX_MUTATION_EVENTS
+    );
+    assert_eq!(mutated_genome.rate_modifiers(), &RateModifiers::new(vec![2.0]));
+    // A modifier of 0.1 lowers the certain chance, while the modifiers are still inherited.
+    unmutated_genome.set_rate_modifiers(RateModifiers::new(vec![0.1]));
+    let adapted_genome = compendium.mutate(&unmutated_genome).into_genome().unwrap();
+    assert!(
+        adapted_genome
+            .get_substrate(GeneSubstrate::new(0, 0))
+            .unwrap()
+            .value
+            < MAX_MUTATION_EVENTS
+    );
+    assert_eq!(adapted_genome.rate_modifiers(), &RateModifiers::new(vec![0.1]));
+}
+
+#[test]
+/// Tests if the function `mutate` of the [`MutationCompendium`] struct correctly handles
+/// failed [`Mutation`]s according to the [`FailurePolicy`].
+fn test_mutation_compendium_mutate_failure_policy() {
+    let unmutated_genome: TestGenome = Genome::new(
+        GenomicInputSensor::default(),
+        GenomicOutputSensor::default(),
+        vec![Gene::new(vec![TestInformation { value: 0 }])],
+    );
+    let increment = |genome: &TestGenome| {
+        let mut mutated_genome = genome.duplicate();
+        let substrate = mutated_genome
+            .get_substrate_mut(GeneSubstrate::new(0, 0))
+            .unwrap();
+        *substrate = TestInformation {
+            value: substrate.value + 1,
+        };
+        Some(mutated_genome)
+    };
+    let mut compendium: MutationCompendium<
+        TestReaction,
+        TestState,
+        TestInformation,
+        NoOpInputElement,
+        TestInput,
+        NoOpOutputElement,
+        TestOutput,
+    > = vec![
+        Mutation::new(1.0, increment).with_name("increment"),
+        Mutation::new(1.0, |_: &TestGenome| None).with_name("failing"),
+    ]
+    .into();
+    assert_eq!(compendium.failure_policy(), FailurePolicy::Abort);
+    let record = compendium.mutate(&unmutated_genome);
+    assert!(record.is_aborted());
+    assert!(record.genome().is_none());
+    assert_eq!(record.failed().len(), 1);
+    // Applications to the discarded genome are not counted.
+    assert_eq!(
+        compendium
+            .operator_statistics()
+            .get("increment")
+            .map_or(0, |increment| increment.applications()),
+        0
+    );
+    // Skipping keeps all successful mutations.
+    compendium.set_failure_policy(FailurePolicy::Skip);
+    let record = compendium.mutate(&unmutated_genome);
+    assert!(!record.is_aborted());
+    assert_eq!(record.applied().len(), MAX_MUTATION_EVENTS);
+    assert_eq!(record.failed(), vec!["failing".to_string(); MAX_MUTATION_EVENTS]);
+    assert_eq!(
+        record
+            .genome()
+            .unwrap()
+            .get_substrate(GeneSubstrate::new(0, 0))
+            .unwrap()
+            .value,
+        MAX_MUTATION_EVENTS
+    );
+    // Retrying replaces every failure by the only other mutation.
+    compendium.set_failure_policy(FailurePolicy::Retry(1));
+    let record = compendium.mutate(&unmutated_genome);
+    assert_eq!(record.applied().len(), 2 * MAX_MUTATION_EVENTS);
+    assert_eq!(
+        record
+            .genome()
+            .unwrap()
+            .get_substrate(GeneSubstrate::new(0, 0))
+            .unwrap()
+            .value,
+        2 * MAX_MUTATION_EVENTS
+    );
+    // Every failure is reported per operator.
+    assert_eq!(
+        compendium.operator_statistics()["failing"].failures(),
+        (1 + 2 * MAX_MUTATION_EVENTS) as u64
+    );
+    assert_eq!(
+        compendium.operator_statistics()["increment"].applications(),
+        (3 * MAX_MUTATION_EVENTS) as u64
+    );
+}
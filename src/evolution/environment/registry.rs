@@ -0,0 +1,534 @@
+//! The `registry` module contains named [`Mutation`] operators, which can be combined into a
+//! [`MutationCompendium`] by a declarative [`MutationConfiguration`].
+//!
+//! [`Mutation`]: ./struct.Mutation.html
+//! [`MutationCompendium`]: ./struct.MutationCompendium.html
+//! [`MutationConfiguration`]: ./struct.MutationConfiguration.html
+
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::path::Path;
+use std::sync::Arc;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use super::format::ConfigurationFormat;
+use super::mutation::{FailurePolicy, Mutation, MutationCompendium};
+use crate::evolution::{
+    chemistry::{Information, Input, Output, Reaction, State},
+    gene::{self, Genome},
+};
+
+/// The shared function of a registered [`Mutation`] operator.
+///
+/// [`Mutation`]: ./struct.Mutation.html
+type SharedMutationFunction<
+    ReactionType,
+    StateType,
+    InformationType,
+    InputElementType,
+    InputSensorType,
+    OutputElementType,
+    OutputSensorType,
+> = Arc<
+    dyn Fn(
+            &Genome<
+                ReactionType,
+                StateType,
+                InformationType,
+                InputElementType,
+                InputSensorType,
+                OutputElementType,
+                OutputSensorType,
+            >,
+        ) -> Option<
+            Genome<
+                ReactionType,
+                StateType,
+                InformationType,
+                InputElementType,
+                InputSensorType,
+                OutputElementType,
+                OutputSensorType,
+            >,
+        > + Send
+        + Sync,
+>;
+
+/// The registered [`Mutation`] operators by name.
+///
+/// [`Mutation`]: ./struct.Mutation.html
+type MutationOperators<
+    ReactionType,
+    StateType,
+    InformationType,
+    InputElementType,
+    InputSensorType,
+    OutputElementType,
+    OutputSensorType,
+> = BTreeMap<
+    String,
+    SharedMutationFunction<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+>;
+
+/// The result of assembling a [`MutationCompendium`].
+///
+/// [`MutationCompendium`]: ./struct.MutationCompendium.html
+type CompendiumResult<
+    ReactionType,
+    StateType,
+    InformationType,
+    InputElementType,
+    InputSensorType,
+    OutputElementType,
+    OutputSensorType,
+    E,
+> = Result<
+    MutationCompendium<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+    E,
+>;
+
+/// A `MutationRegistry` maps names to [`Mutation`] operators, so a [`MutationCompendium`]
+/// can be assembled from a [`MutationConfiguration`] without recompiling.
+///
+/// [`Mutation`]: ./struct.Mutation.html
+/// [`MutationCompendium`]: ./struct.MutationCompendium.html
+/// [`MutationConfiguration`]: ./struct.MutationConfiguration.html
+pub struct MutationRegistry<
+    ReactionType,
+    StateType,
+    InformationType,
+    InputElementType,
+    InputSensorType,
+    OutputElementType,
+    OutputSensorType,
+> {
+    operators: MutationOperators<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+}
+
+impl<
+        ReactionType: Reaction<InformationType> + 'static,
+        StateType: State<InformationType> + 'static,
+        InformationType: Information + 'static,
+        InputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned + 'static,
+        InputSensorType: Input<InputElementType, InformationType> + 'static,
+        OutputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned + 'static,
+        OutputSensorType: Output<OutputElementType, InformationType> + 'static,
+    >
+    MutationRegistry<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >
+{
+    /// Creates an empty `MutationRegistry`.
+    pub fn new() -> Self {
+        MutationRegistry {
+            operators: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a `MutationRegistry` containing all generic structural mutations of the
+    /// [`gene`] module registered under the names used by [`structural_mutations`],
+    /// e.g. `gene_duplication`.
+    ///
+    /// [`gene`]: ../gene/index.html
+    /// [`structural_mutations`]: ../gene/fn.structural_mutations.html
+    pub fn with_structural_mutations() -> Self {
+        let mut registry = Self::new();
+        registry
+            .register("gene_duplication", gene::mutate_gene_duplication)
+            .register("gene_deletion", gene::mutate_gene_deletion)
+            .register("gene_fusion", gene::mutate_gene_fusion)
+            .register("association_insertion", gene::mutate_association_insertion)
+            .register("association_deletion", gene::mutate_association_deletion)
+            .register("association_gene_insertion", gene::mutate_association_gene_insertion)
+            .register("association_gene_deletion", gene::mutate_association_gene_deletion)
+            .register("input_association", gene::mutate_input_association)
+            .register("input_dissociation", gene::mutate_input_dissociation)
+            .register("output_association", gene::mutate_output_association)
+            .register("output_dissociation", gene::mutate_output_dissociation)
+            .register("receptor_insertion", gene::mutate_receptor_insertion)
+            .register("receptor_deletion", gene::mutate_receptor_deletion)
+            .register("receptor_trigger_insertion", gene::mutate_receptor_trigger_insertion)
+            .register("receptor_trigger_deletion", gene::mutate_receptor_trigger_deletion)
+            .register("receptor_state_replacement", gene::mutate_receptor_state_replacement)
+            .register("receptor_enzyme_replacement", gene::mutate_receptor_enzyme_replacement);
+        registry
+    }
+
+    /// Registers a [`Mutation`] operator under the specified name.
+    /// An operator previously registered under the same name is replaced.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - the name of the operator
+    /// * `mutation` - the underlying mutation function
+    ///
+    /// [`Mutation`]: ./struct.Mutation.html
+    pub fn register<
+        S: Into<String>,
+        F: Fn(
+                &Genome<
+                    ReactionType,
+                    StateType,
+                    InformationType,
+                    InputElementType,
+                    InputSensorType,
+                    OutputElementType,
+                    OutputSensorType,
+                >,
+            ) -> Option<
+                Genome<
+                    ReactionType,
+                    StateType,
+                    InformationType,
+                    InputElementType,
+                    InputSensorType,
+                    OutputElementType,
+                    OutputSensorType,
+                >,
+            > + Send
+            + Sync
+            + 'static,
+    >(
+        &mut self,
+        name: S,
+        mutation: F,
+    ) -> &mut Self {
+        self.operators.insert(name.into(), Arc::new(mutation));
+        self
+    }
+
+    /// Checks if an operator is registered under the specified name.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - the name of the operator
+    pub fn contains(&self, name: &str) -> bool {
+        self.operators.contains_key(name)
+    }
+
+    /// Returns the names of all registered operators in alphabetical order.
+    pub fn names(&self) -> Vec<&str> {
+        self.operators.keys().map(String::as_str).collect()
+    }
+
+    /// Creates a named [`Mutation`] from the operator registered under the specified name
+    /// if there is any.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - the name of the operator
+    /// * `mutation_chance` - the chance of the mutation happening a single time during
+    ///   genome duplication
+    ///
+    /// [`Mutation`]: ./struct.Mutation.html
+    pub fn mutation(
+        &self,
+        name: &str,
+        mutation_chance: f64,
+    ) -> Option<
+        Mutation<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    > {
+        self.operators.get(name).map(|operator| {
+            let operator = Arc::clone(operator);
+            Mutation::new(mutation_chance, move |genome| operator(genome)).with_name(name)
+        })
+    }
+
+    /// Assembles a [`MutationCompendium`] as specified by the [`MutationConfiguration`].
+    /// An error will be returned if an operator is unknown or has an invalid chance.
+    ///
+    /// # Parameters
+    ///
+    /// * `configuration` - the [`MutationConfiguration`] listing the operators
+    ///
+    /// [`MutationCompendium`]: ./struct.MutationCompendium.html
+    /// [`MutationConfiguration`]: ./struct.MutationConfiguration.html
+    pub fn compendium(
+        &self,
+        configuration: &MutationConfiguration,
+    ) -> CompendiumResult<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+        MutationConfigurationError,
+    > {
+        let mut compendium = MutationCompendium::new();
+        for operator in configuration.mutations() {
+            if !operator.chance().is_finite() || !(0.0..=1.0).contains(&operator.chance()) {
+                return Err(MutationConfigurationError::InvalidChance {
+                    name: operator.name().to_string(),
+                    chance: operator.chance(),
+                });
+            }
+            let mutation = self
+                .mutation(operator.name(), operator.chance())
+                .ok_or_else(|| {
+                    MutationConfigurationError::UnknownOperator(operator.name().to_string())
+                })?;
+            compendium.add(mutation);
+        }
+        compendium.set_failure_policy(configuration.failure_policy());
+        Ok(compendium)
+    }
+
+    /// Assembles a [`MutationCompendium`] as specified by the [`MutationConfiguration`]
+    /// stored in the specified JSON, RON or TOML file.
+    /// An error will be returned if loading the file failed, an operator is unknown or has
+    /// an invalid chance.
+    ///
+    /// # Parameters
+    ///
+    /// * `path_to_file` - the file containing the [`MutationConfiguration`]
+    ///
+    /// [`MutationCompendium`]: ./struct.MutationCompendium.html
+    /// [`MutationConfiguration`]: ./struct.MutationConfiguration.html
+    pub fn compendium_from_file<P: AsRef<Path>>(
+        &self,
+        path_to_file: P,
+    ) -> CompendiumResult<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+        Box<dyn Error>,
+    > {
+        let configuration = MutationConfiguration::load_from_file(path_to_file)?;
+        Ok(self.compendium(&configuration)?)
+    }
+}
+
+impl<
+        ReactionType: Reaction<InformationType> + 'static,
+        StateType: State<InformationType> + 'static,
+        InformationType: Information + 'static,
+        InputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned + 'static,
+        InputSensorType: Input<InputElementType, InformationType> + 'static,
+        OutputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned + 'static,
+        OutputSensorType: Output<OutputElementType, InformationType> + 'static,
+    > Default
+    for MutationRegistry<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    > std::fmt::Debug
+    for MutationRegistry<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MutationRegistry")
+            .field("operators", &self.operators.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+/// A `MutationConfiguration` declares the [`Mutation`] operators of a [`MutationCompendium`]
+/// by name and chance. It can be stored as JSON, RON or TOML file, e.g.
+///
+/// ```toml
+/// failure_policy = "Skip"
+///
+/// [[mutations]]
+/// name = "add_dendrite"
+/// chance = 0.1
+/// ```
+///
+/// [`Mutation`]: ./struct.Mutation.html
+/// [`MutationCompendium`]: ./struct.MutationCompendium.html
+pub struct MutationConfiguration {
+    #[serde(default)]
+    failure_policy: FailurePolicy,
+    #[serde(default)]
+    mutations: Vec<ConfiguredMutation>,
+}
+
+impl MutationConfiguration {
+    /// Creates an empty `MutationConfiguration` with the specified [`FailurePolicy`].
+    ///
+    /// # Parameters
+    ///
+    /// * `failure_policy` - the [`FailurePolicy`] of the assembled compendium
+    ///
+    /// [`FailurePolicy`]: ./enum.FailurePolicy.html
+    pub fn new(failure_policy: FailurePolicy) -> Self {
+        MutationConfiguration {
+            failure_policy,
+            mutations: Vec::new(),
+        }
+    }
+
+    /// Adds the operator registered under the specified name with the specified chance.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - the name of the operator
+    /// * `chance` - the chance of the mutation happening a single time during
+    ///   genome duplication
+    pub fn add<S: Into<String>>(&mut self, name: S, chance: f64) -> &mut Self {
+        self.mutations.push(ConfiguredMutation {
+            name: name.into(),
+            chance,
+        });
+        self
+    }
+
+    /// Returns the [`FailurePolicy`] of the assembled compendium.
+    ///
+    /// [`FailurePolicy`]: ./enum.FailurePolicy.html
+    pub fn failure_policy(&self) -> FailurePolicy {
+        self.failure_policy
+    }
+
+    /// Returns the configured operators in the order they are added to the compendium.
+    pub fn mutations(&self) -> &[ConfiguredMutation] {
+        &self.mutations
+    }
+
+    /// Loads a `MutationConfiguration` from a JSON, RON or TOML file depending on its
+    /// extension.
+    /// An error will be returned if reading or parsing the file failed.
+    ///
+    /// # Parameters
+    ///
+    /// * `path_to_file` - the file from which the `MutationConfiguration` should be loaded
+    pub fn load_from_file<P: AsRef<Path>>(path_to_file: P) -> Result<Self, Box<dyn Error>> {
+        ConfigurationFormat::load_from_file(path_to_file)
+    }
+
+    /// Writes the `MutationConfiguration` to a JSON, RON or TOML file depending on its
+    /// extension.
+    /// An error will be returned if writing to the file failed.
+    ///
+    /// # Parameters
+    ///
+    /// * `path_to_file` - the file the `MutationConfiguration` should be written to
+    pub fn write_to_file<P: AsRef<Path>>(&self, path_to_file: P) -> Result<(), Box<dyn Error>> {
+        ConfigurationFormat::write_to_file(self, path_to_file)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// A `ConfiguredMutation` is a single operator entry of a [`MutationConfiguration`].
+///
+/// [`MutationConfiguration`]: ./struct.MutationConfiguration.html
+pub struct ConfiguredMutation {
+    name: String,
+    chance: f64,
+}
+
+impl ConfiguredMutation {
+    /// Returns the name of the operator.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the chance of the mutation happening a single time during genome duplication.
+    pub fn chance(&self) -> f64 {
+        self.chance
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+/// A `MutationConfigurationError` is returned when a [`MutationConfiguration`] cannot be
+/// assembled into a [`MutationCompendium`].
+///
+/// [`MutationCompendium`]: ./struct.MutationCompendium.html
+/// [`MutationConfiguration`]: ./struct.MutationConfiguration.html
+pub enum MutationConfigurationError {
+    /// No operator is registered under the specified name.
+    UnknownOperator(String),
+    /// The chance of the named operator is not within `[0.0, 1.0]`.
+    InvalidChance { name: String, chance: f64 },
+}
+
+impl std::fmt::Display for MutationConfigurationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MutationConfigurationError::UnknownOperator(name) => {
+                write!(f, "No mutation operator is registered under the name {}.", name)
+            },
+            MutationConfigurationError::InvalidChance { name, chance } => write!(
+                f,
+                "The chance of mutation operator {} must be within [0.0, 1.0], but was {}.",
+                name, chance
+            ),
+        }
+    }
+}
+
+impl Error for MutationConfigurationError {}
+
+#[cfg(test)]
+mod tests;
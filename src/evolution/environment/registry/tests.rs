@@ -0,0 +1,150 @@
+use uuid::Uuid;
+
+use crate::evolution::{
+    gene::{Gene, GenomicInputSensor, GenomicOutputSensor},
+    helper::noop::{NoOpInputElement, NoOpOutputElement},
+    helper::testing::{
+        TestGenome, TestInformation, TestInput, TestOutput, TestReaction, TestState,
+    },
+};
+
+use super::*;
+
+/// A [`MutationRegistry`] for testing purposes.
+type TestRegistry = MutationRegistry<
+    TestReaction,
+    TestState,
+    TestInformation,
+    NoOpInputElement,
+    TestInput,
+    NoOpOutputElement,
+    TestOutput,
+>;
+
+/// Creates a [`TestGenome`] with a single [`Gene`].
+fn test_genome() -> TestGenome {
+    Genome::new(
+        GenomicInputSensor::default(),
+        GenomicOutputSensor::default(),
+        vec![Gene::new(vec![TestInformation { value: 0 }])],
+    )
+}
+
+#[test]
+/// Tests if the function `register` of the [`MutationRegistry`] struct correctly registers
+/// named operators.
+fn test_mutation_registry_register() {
+    let mut registry = TestRegistry::new();
+    assert!(registry.names().is_empty());
+    registry
+        .register("identity", |genome: &TestGenome| Some(genome.duplicate()))
+        .register("failure", |_: &TestGenome| None);
+    assert_eq!(registry.names(), vec!["failure", "identity"]);
+    assert!(registry.contains("identity"));
+    assert!(!registry.contains("unknown"));
+    let mutation = registry.mutation("identity", 0.25).unwrap();
+    assert_eq!(mutation.name(), "identity");
+    assert_ulps_eq!(mutation.mutation_chance().value(), 0.25);
+    assert_eq!(mutation.mutate(&test_genome()), Some(test_genome()));
+    assert!(registry.mutation("unknown", 0.25).is_none());
+}
+
+#[test]
+/// Tests if the function `with_structural_mutations` of the [`MutationRegistry`] struct
+/// correctly registers all generic mutations.
+fn test_mutation_registry_with_structural_mutations() {
+    let registry = TestRegistry::with_structural_mutations();
+    assert_eq!(registry.names().len(), 17);
+    assert!(registry.contains("gene_duplication"));
+    assert!(registry.contains("receptor_enzyme_replacement"));
+    let duplicated = registry
+        .mutation("gene_duplication", 1.0)
+        .unwrap()
+        .mutate(&test_genome())
+        .unwrap();
+    assert_eq!(duplicated.number_of_genes().get(), 2);
+}
+
+#[test]
+/// Tests if the function `compendium` of the [`MutationRegistry`] struct correctly
+/// assembles a [`MutationCompendium`] and reports invalid operators.
+fn test_mutation_registry_compendium() {
+    let registry = TestRegistry::with_structural_mutations();
+    let mut configuration = MutationConfiguration::new(FailurePolicy::Skip);
+    configuration
+        .add("gene_duplication", 0.5)
+        .add("gene_deletion", 0.25);
+    let compendium = registry.compendium(&configuration).unwrap();
+    assert_eq!(compendium.size(), 2);
+    assert_eq!(compendium.failure_policy(), FailurePolicy::Skip);
+    assert_ulps_eq!(compendium.mutation_chances()[0], 0.5);
+    assert_ulps_eq!(compendium.mutation_chances()[1], 0.25);
+    configuration.add("unknown", 0.5);
+    assert_eq!(
+        registry.compendium(&configuration).unwrap_err(),
+        MutationConfigurationError::UnknownOperator("unknown".to_string())
+    );
+    let mut configuration = MutationConfiguration::default();
+    configuration.add("gene_deletion", 1.5);
+    assert_eq!(
+        registry.compendium(&configuration).unwrap_err(),
+        MutationConfigurationError::InvalidChance {
+            name: "gene_deletion".to_string(),
+            chance: 1.5
+        }
+    );
+    let mut configuration = MutationConfiguration::default();
+    configuration.add("gene_deletion", f64::NAN);
+    assert!(registry.compendium(&configuration).is_err());
+}
+
+#[test]
+/// Tests if the function `compendium_from_file` of the [`MutationRegistry`] struct
+/// correctly parses configurations in every supported format.
+fn test_mutation_registry_compendium_from_file() {
+    let registry = TestRegistry::with_structural_mutations();
+    let files = [
+        (
+            "toml",
+            "failure_policy = { Retry = 3 }\n\n[[mutations]]\nname = \"gene_fusion\"\nchance = 0.1\n",
+        ),
+        (
+            "json",
+            "{\"failure_policy\": {\"Retry\": 3}, \"mutations\": [{\"name\": \"gene_fusion\", \"chance\": 0.1}]}",
+        ),
+        (
+            "ron",
+            "(failure_policy: Retry(3), mutations: [(name: \"gene_fusion\", chance: 0.1)])",
+        ),
+    ];
+    for (extension, content) in files {
+        let path = std::env::temp_dir().join(format!(
+            "oben_mutations_{}.{}",
+            Uuid::from_u128(rand::random()),
+            extension
+        ));
+        std::fs::write(&path, content).unwrap();
+        let compendium = registry.compendium_from_file(&path);
+        std::fs::remove_file(&path).unwrap();
+        let compendium = compendium.unwrap();
+        assert_eq!(compendium.size(), 1);
+        assert_eq!(compendium.failure_policy(), FailurePolicy::Retry(3));
+        assert_ulps_eq!(compendium.mutation_chances()[0], 0.1);
+    }
+}
+
+#[test]
+/// Tests if the functions `write_to_file` and `load_from_file` of the
+/// [`MutationConfiguration`] struct correctly persist a configuration.
+fn test_mutation_configuration_write_to_file() {
+    let mut configuration = MutationConfiguration::new(FailurePolicy::Retry(2));
+    configuration
+        .add("add_dendrite", 0.1)
+        .add("mutate_dendrite_weight", 0.2);
+    let path = std::env::temp_dir()
+        .join(format!("oben_mutations_{}.toml", Uuid::from_u128(rand::random())));
+    configuration.write_to_file(&path).unwrap();
+    let loaded = MutationConfiguration::load_from_file(&path);
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.unwrap(), configuration);
+}
@@ -0,0 +1,132 @@
+//! The `retesting` module contains the models determining if [`Individual`]s are tested
+//! again to refine their fitness.
+//!
+//! [`Individual`]: ../population/struct.Individual.html
+
+use super::super::population::{IndividualStatus, PopulationStatistics};
+use super::configuration::Environment;
+
+/// A `TestingModel` determines the chance of an [`Individual`] to be tested in a generation.
+///
+/// [`Individual`]: ../population/struct.Individual.html
+pub trait TestingModel: Send + Sync {
+    /// Returns the chance of the specified [`Individual`] to be tested.
+    ///
+    /// # Parameters
+    ///
+    /// * `individual` - the [`IndividualStatus`] of the [`Individual`]
+    /// * `population` - the current [`PopulationStatistics`]
+    /// * `environment` - the current [`Environment`]
+    ///
+    /// [`Environment`]: ./struct.Environment.html
+    /// [`Individual`]: ../population/struct.Individual.html
+    /// [`IndividualStatus`]: ../population/struct.IndividualStatus.html
+    /// [`PopulationStatistics`]: ../population/struct.PopulationStatistics.html
+    fn testing_chance(
+        &self,
+        individual: &IndividualStatus,
+        population: &PopulationStatistics,
+        environment: &Environment,
+    ) -> f64;
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+/// The default `SigmoidTesting` always tests [`Individual`]s younger than the maximum testing
+/// age of the [`Environment`] and older ones based on a sigmoid of the number of times they
+/// were already tested.
+///
+/// [`Environment`]: ./struct.Environment.html
+/// [`Individual`]: ../population/struct.Individual.html
+pub struct SigmoidTesting;
+
+impl TestingModel for SigmoidTesting {
+    fn testing_chance(
+        &self,
+        individual: &IndividualStatus,
+        _population: &PopulationStatistics,
+        environment: &Environment,
+    ) -> f64 {
+        let is_juvenil = environment
+            .max_testing_age()
+            .map_or(true, |max_age| individual.age() < max_age);
+        if is_juvenil {
+            1.0
+        } else {
+            environment.testing_chance(individual.times_tested())
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+/// A `ConfidenceTesting` tests [`Individual`]s until their mean fitness is known with
+/// the desired confidence. [`Individual`]s are always tested until they reach the minimum
+/// number of tests, afterwards the chance of testing is proportional to the standard error
+/// of their mean fitness.
+///
+/// [`Individual`]: ../population/struct.Individual.html
+pub struct ConfidenceTesting {
+    minimum_tests: u32,
+    target_standard_error: f64,
+}
+
+impl ConfidenceTesting {
+    /// Creates a new `ConfidenceTesting`.
+    ///
+    /// # Parameters
+    ///
+    /// * `minimum_tests` - the number of tests that are always performed
+    /// * `target_standard_error` - the standard error of the mean fitness at which
+    ///   [`Individual`]s are no longer tested with certainty
+    ///
+    /// # Panics
+    ///
+    /// If the target standard error is not a positive number.
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn new(minimum_tests: u32, target_standard_error: f64) -> Self {
+        if target_standard_error.is_nan() || target_standard_error <= 0.0 {
+            panic!(
+                "The target standard error must be positive, but was {}.",
+                target_standard_error
+            );
+        }
+        ConfidenceTesting {
+            minimum_tests,
+            target_standard_error,
+        }
+    }
+
+    /// Returns the number of tests that are always performed.
+    pub fn minimum_tests(&self) -> u32 {
+        self.minimum_tests
+    }
+
+    /// Returns the standard error of the mean fitness at which [`Individual`]s are no longer
+    /// tested with certainty.
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn target_standard_error(&self) -> f64 {
+        self.target_standard_error
+    }
+}
+
+impl TestingModel for ConfidenceTesting {
+    fn testing_chance(
+        &self,
+        individual: &IndividualStatus,
+        _population: &PopulationStatistics,
+        _environment: &Environment,
+    ) -> f64 {
+        match individual.fitness_variance() {
+            Some(variance) if individual.times_tested() >= self.minimum_tests => {
+                let standard_error = (variance / individual.times_tested() as f64).sqrt();
+                (standard_error / self.target_standard_error).min(1.0)
+            },
+            // Without a variance estimate the confidence is unknown.
+            _ => 1.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
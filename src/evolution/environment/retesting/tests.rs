@@ -0,0 +1,48 @@
+use crate::evolution::environment::EnvironmentBuilder;
+
+use super::*;
+
+#[test]
+/// Tests if the function `testing_chance` of the [`SigmoidTesting`] struct correctly tests
+/// juvenil individuals and delegates to the [`Environment`] otherwise.
+fn test_sigmoid_testing_testing_chance() {
+    let statistics = PopulationStatistics::default();
+    let unlimited = EnvironmentBuilder::new().build().unwrap();
+    let old = IndividualStatus::new(100, 100, Some(0.5), None);
+    assert_ulps_eq!(SigmoidTesting.testing_chance(&old, &statistics, &unlimited), 1.0);
+    let limited = EnvironmentBuilder::new()
+        .max_testing_age(Some(3))
+        .build()
+        .unwrap();
+    let young = IndividualStatus::new(2, 2, Some(0.5), None);
+    assert_ulps_eq!(SigmoidTesting.testing_chance(&young, &statistics, &limited), 1.0);
+    assert_ulps_eq!(
+        SigmoidTesting.testing_chance(&old, &statistics, &limited),
+        limited.testing_chance(100)
+    );
+}
+
+#[test]
+/// Tests if the function `testing_chance` of the [`ConfidenceTesting`] struct correctly
+/// prefers rarely tested individuals and individuals with a high fitness variance.
+fn test_confidence_testing_testing_chance() {
+    let statistics = PopulationStatistics::default();
+    let environment = EnvironmentBuilder::new().build().unwrap();
+    let testing = ConfidenceTesting::new(3, 0.1);
+    let rarely_tested = IndividualStatus::new(5, 2, Some(0.5), Some(0.0));
+    assert_ulps_eq!(testing.testing_chance(&rarely_tested, &statistics, &environment), 1.0);
+    let noisy = IndividualStatus::new(5, 4, Some(0.5), Some(0.16));
+    assert_ulps_eq!(testing.testing_chance(&noisy, &statistics, &environment), 1.0);
+    let moderate = IndividualStatus::new(5, 4, Some(0.5), Some(0.01));
+    assert_ulps_eq!(testing.testing_chance(&moderate, &statistics, &environment), 0.5);
+    let certain = IndividualStatus::new(5, 4, Some(0.5), Some(0.0));
+    assert_ulps_eq!(testing.testing_chance(&certain, &statistics, &environment), 0.0);
+}
+
+#[test]
+#[should_panic]
+/// Tests if the function `new` of the [`ConfidenceTesting`] struct correctly panics
+/// on invalid target standard errors.
+fn test_confidence_testing_new_invalid() {
+    ConfidenceTesting::new(3, 0.0);
+}
@@ -0,0 +1,265 @@
+//! The `schedule` module contains the scheduling of [`Environment`] parameters over
+//! the generations of a network.
+//!
+//! [`Environment`]: ../struct.Environment.html
+
+use std::f64::consts::PI;
+use std::time::Duration;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::super::chemistry::{Information, Input, Output, Reaction, State};
+use super::super::population::PopulationStatistics;
+use super::configuration::{Environment, EnvironmentError};
+use super::mutation::MutationCompendium;
+
+/// A function calculating the value of a parameter from the current generation and the
+/// [`PopulationStatistics`] at the start of the generation.
+///
+/// [`PopulationStatistics`]: ../population/struct.PopulationStatistics.html
+type ScheduleFunction = Box<dyn Fn(u64, &PopulationStatistics) -> f64 + Send + Sync + 'static>;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+/// A `ScheduledParameter` is a parameter of the network that may change over the generations.
+pub enum ScheduledParameter {
+    /// The size in individuals a [`Population`] can grow to.
+    ///
+    /// [`Population`]: ../population/struct.Population.html
+    PopulationSize,
+    /// The lifespan of an [`Organism`] in seconds.
+    ///
+    /// [`Organism`]: ../population/struct.Organism.html
+    Lifespan,
+    /// The maximum size of an [`Organism`] in bit.
+    ///
+    /// [`Organism`]: ../population/struct.Organism.html
+    MaxOrganismSize,
+    /// The number of repetitions per testing cycle.
+    TestingRepetitions,
+    /// The midpoint of the sigmoid determining the chance of death.
+    DeathAgeSigmoidMidpoint,
+    /// The chance of the [`Mutation`] at the specified index of the [`MutationCompendium`].
+    ///
+    /// [`Mutation`]: ./struct.Mutation.html
+    /// [`MutationCompendium`]: ./struct.MutationCompendium.html
+    MutationChance(usize),
+}
+
+impl ScheduledParameter {
+    /// Returns the name of the parameter as used in the metrics.
+    pub fn name(&self) -> String {
+        match self {
+            ScheduledParameter::PopulationSize => "population_size".to_string(),
+            ScheduledParameter::Lifespan => "lifespan".to_string(),
+            ScheduledParameter::MaxOrganismSize => "max_organism_size".to_string(),
+            ScheduledParameter::TestingRepetitions => "testing_repetitions".to_string(),
+            ScheduledParameter::DeathAgeSigmoidMidpoint => "death_age_sigmoid_midpoint".to_string(),
+            ScheduledParameter::MutationChance(index) => format!("mutation_chance_{}", index),
+        }
+    }
+
+    /// Sets the parameter to the specified value and returns the value that was actually set.
+    /// Values are rounded to the type of the respective parameter and mutation chances are
+    /// clamped to the range of 0.0 to 1.0.
+    /// An error will be returned and nothing is modified if the resulting [`Environment`]
+    /// would be rejected by the [`EnvironmentBuilder`].
+    ///
+    /// # Parameters
+    ///
+    /// * `value` - the new value of the parameter
+    /// * `environment` - the [`Environment`] to modify
+    /// * `mutations` - the [`MutationCompendium`] to modify
+    ///
+    /// # Panics
+    ///
+    /// If the index of a scheduled mutation chance is out of bounds.
+    ///
+    /// [`Environment`]: ./struct.Environment.html
+    /// [`EnvironmentBuilder`]: ./struct.EnvironmentBuilder.html
+    /// [`MutationCompendium`]: ./struct.MutationCompendium.html
+    pub fn apply<
+        ReactionType: Reaction<InformationType>,
+        StateType: State<InformationType>,
+        InformationType: Information,
+        InputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+        InputSensorType: Input<InputElementType, InformationType>,
+        OutputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+        OutputSensorType: Output<OutputElementType, InformationType>,
+    >(
+        &self,
+        value: f64,
+        environment: &mut Environment,
+        mutations: &mut MutationCompendium<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    ) -> Result<f64, EnvironmentError> {
+        let mut candidate = environment.clone();
+        let applied = match self {
+            ScheduledParameter::PopulationSize => {
+                let population_size = value.round() as u32;
+                candidate.set_population_size(population_size);
+                population_size as f64
+            },
+            ScheduledParameter::Lifespan => {
+                let lifespan = Duration::try_from_secs_f64(value.max(0.0)).unwrap_or(Duration::MAX);
+                candidate.set_lifespan(lifespan);
+                lifespan.as_secs_f64()
+            },
+            ScheduledParameter::MaxOrganismSize => {
+                let max_organism_size = value.round() as usize;
+                candidate.set_max_organism_size(max_organism_size);
+                max_organism_size as f64
+            },
+            ScheduledParameter::TestingRepetitions => {
+                let testing_repetitions = value.round() as u32;
+                candidate.set_testing_repetitions(testing_repetitions);
+                testing_repetitions as f64
+            },
+            ScheduledParameter::DeathAgeSigmoidMidpoint => {
+                candidate.set_death_age_sigmoid_midpoint(value);
+                value
+            },
+            ScheduledParameter::MutationChance(index) => {
+                mutations.set_mutation_chance(*index, value);
+                return Ok(value.clamp(0.0, 1.0));
+            },
+        };
+        candidate.validate()?;
+        *environment = candidate;
+        Ok(applied)
+    }
+}
+
+/// A `Schedule` determines the value of a [`ScheduledParameter`] in a certain generation.
+///
+/// [`ScheduledParameter`]: ./enum.ScheduledParameter.html
+pub enum Schedule {
+    /// Linearly interpolates from `start` to `end` over the specified number of generations
+    /// and keeps the `end` value afterwards.
+    Linear {
+        start: f64,
+        end: f64,
+        generations: u64,
+    },
+    /// Starts with the `initial` value and switches to the value of each step once its
+    /// generation is reached.
+    Step {
+        initial: f64,
+        steps: Vec<(u64, f64)>,
+    },
+    /// Interpolates from `start` to `end` over the specified number of generations along
+    /// half a cosine period and keeps the `end` value afterwards.
+    Cosine {
+        start: f64,
+        end: f64,
+        generations: u64,
+    },
+    /// Calculates the value from the current generation and the [`PopulationStatistics`].
+    ///
+    /// [`PopulationStatistics`]: ../population/struct.PopulationStatistics.html
+    Custom(ScheduleFunction),
+}
+
+impl Schedule {
+    /// Creates a new `Schedule` based on the specified function of the current generation
+    /// and the [`PopulationStatistics`].
+    ///
+    /// # Parameters
+    ///
+    /// * `schedule_function` - the function calculating the parameter value
+    ///
+    /// [`PopulationStatistics`]: ../population/struct.PopulationStatistics.html
+    pub fn custom<F: Fn(u64, &PopulationStatistics) -> f64 + Send + Sync + 'static>(
+        schedule_function: F,
+    ) -> Self {
+        Schedule::Custom(Box::new(schedule_function))
+    }
+
+    /// Returns the value of the scheduled parameter in the specified generation.
+    ///
+    /// # Parameters
+    ///
+    /// * `generation` - the current generation
+    /// * `statistics` - the [`PopulationStatistics`] at the start of the generation
+    ///
+    /// [`PopulationStatistics`]: ../population/struct.PopulationStatistics.html
+    pub fn value(&self, generation: u64, statistics: &PopulationStatistics) -> f64 {
+        match self {
+            Schedule::Linear {
+                start,
+                end,
+                generations,
+            } => start + (end - start) * Self::progress(generation, *generations),
+            Schedule::Step { initial, steps } => steps
+                .iter()
+                .filter(|(step_generation, _)| *step_generation <= generation)
+                .max_by_key(|(step_generation, _)| *step_generation)
+                .map_or(*initial, |(_, value)| *value),
+            Schedule::Cosine {
+                start,
+                end,
+                generations,
+            } => {
+                let progress = Self::progress(generation, *generations);
+                start + (end - start) * (1.0 - (PI * progress).cos()) / 2.0
+            },
+            Schedule::Custom(schedule_function) => schedule_function(generation, statistics),
+        }
+    }
+
+    /// Returns the fraction of the specified number of generations that has already passed.
+    ///
+    /// # Parameters
+    ///
+    /// * `generation` - the current generation
+    /// * `generations` - the number of generations the schedule spans
+    fn progress(generation: u64, generations: u64) -> f64 {
+        if generations == 0 {
+            1.0
+        } else {
+            (generation as f64 / generations as f64).min(1.0)
+        }
+    }
+}
+
+impl std::fmt::Debug for Schedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Schedule::Linear {
+                start,
+                end,
+                generations,
+            } => f
+                .debug_struct("Linear")
+                .field("start", start)
+                .field("end", end)
+                .field("generations", generations)
+                .finish(),
+            Schedule::Step { initial, steps } => f
+                .debug_struct("Step")
+                .field("initial", initial)
+                .field("steps", steps)
+                .finish(),
+            Schedule::Cosine {
+                start,
+                end,
+                generations,
+            } => f
+                .debug_struct("Cosine")
+                .field("start", start)
+                .field("end", end)
+                .field("generations", generations)
+                .finish(),
+            Schedule::Custom(_) => f.write_str("Custom"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,139 @@
+use crate::evolution::{
+    environment::{EnvironmentBuilder, Mutation},
+    helper::testing::TestGenome,
+};
+
+use super::*;
+
+#[test]
+/// Tests if the function `value` of the [`Schedule`] struct correctly interpolates
+/// linear schedules.
+fn test_schedule_value_linear() {
+    let statistics = PopulationStatistics::default();
+    let schedule = Schedule::Linear {
+        start: 10.0,
+        end: 20.0,
+        generations: 4,
+    };
+    assert_ulps_eq!(schedule.value(0, &statistics), 10.0);
+    assert_ulps_eq!(schedule.value(1, &statistics), 12.5);
+    assert_ulps_eq!(schedule.value(4, &statistics), 20.0);
+    assert_ulps_eq!(schedule.value(100, &statistics), 20.0);
+}
+
+#[test]
+/// Tests if the function `value` of the [`Schedule`] struct correctly interpolates
+/// cosine schedules.
+fn test_schedule_value_cosine() {
+    let statistics = PopulationStatistics::default();
+    let schedule = Schedule::Cosine {
+        start: 1.0,
+        end: 0.0,
+        generations: 10,
+    };
+    assert_ulps_eq!(schedule.value(0, &statistics), 1.0);
+    assert_ulps_eq!(schedule.value(5, &statistics), 0.5);
+    assert_ulps_eq!(schedule.value(10, &statistics), 0.0);
+    assert_ulps_eq!(schedule.value(11, &statistics), 0.0);
+}
+
+#[test]
+/// Tests if the function `value` of the [`Schedule`] struct correctly evaluates
+/// step and custom schedules.
+fn test_schedule_value_step_and_custom() {
+    let statistics = PopulationStatistics::default();
+    let step = Schedule::Step {
+        initial: 1.0,
+        steps: vec![(10, 3.0), (5, 2.0)],
+    };
+    assert_ulps_eq!(step.value(4, &statistics), 1.0);
+    assert_ulps_eq!(step.value(5, &statistics), 2.0);
+    assert_ulps_eq!(step.value(9, &statistics), 2.0);
+    assert_ulps_eq!(step.value(10, &statistics), 3.0);
+    let custom =
+        Schedule::custom(|generation, statistics| generation as f64 + statistics.size() as f64);
+    assert_ulps_eq!(custom.value(7, &statistics), 7.0);
+}
+
+#[test]
+/// Tests if the function `apply` of the [`ScheduledParameter`] enum correctly modifies the
+/// [`Environment`] and the [`MutationCompendium`].
+fn test_scheduled_parameter_apply() {
+    let mut environment = EnvironmentBuilder::new().build().unwrap();
+    let mut mutations: MutationCompendium<_, _, _, _, _, _, _> =
+        vec![Mutation::new(0.1, |genome: &TestGenome| {
+            Some(genome.duplicate())
+        })]
+        .into();
+    assert_ulps_eq!(
+        ScheduledParameter::PopulationSize
+            .apply(41.6, &mut environment, &mut mutations)
+            .unwrap(),
+        42.0
+    );
+    assert_eq!(environment.population_size(), 42);
+    assert_ulps_eq!(
+        ScheduledParameter::Lifespan
+            .apply(0.5, &mut environment, &mut mutations)
+            .unwrap(),
+        0.5
+    );
+    assert_eq!(environment.lifespan(), Duration::from_millis(500));
+    assert_ulps_eq!(
+        ScheduledParameter::MaxOrganismSize
+            .apply(64.0, &mut environment, &mut mutations)
+            .unwrap(),
+        64.0
+    );
+    assert_eq!(environment.max_organism_size(), 64);
+    assert_ulps_eq!(
+        ScheduledParameter::TestingRepetitions
+            .apply(1.8, &mut environment, &mut mutations)
+            .unwrap(),
+        2.0
+    );
+    assert_eq!(environment.testing_repetitions(), 2);
+    ScheduledParameter::DeathAgeSigmoidMidpoint
+        .apply(7.5, &mut environment, &mut mutations)
+        .unwrap();
+    assert_ulps_eq!(environment.death_age_sigmoid_midpoint(), 7.5);
+    ScheduledParameter::MutationChance(0)
+        .apply(0.25, &mut environment, &mut mutations)
+        .unwrap();
+    let mutations: Vec<Mutation<_, _, _, _, _, _, _>> = mutations.into();
+    assert_ulps_eq!(mutations[0].mutation_chance().value(), 0.25);
+}
+
+#[test]
+/// Tests if the function `apply` of the [`ScheduledParameter`] enum correctly rejects values
+/// that would make the [`Environment`] invalid without modifying it.
+fn test_scheduled_parameter_apply_invalid() {
+    let mut environment = EnvironmentBuilder::new().build().unwrap();
+    let mut mutations: MutationCompendium<_, _, _, _, _, _, _> =
+        vec![Mutation::new(0.1, |genome: &TestGenome| {
+            Some(genome.duplicate())
+        })]
+        .into();
+    let population_size = environment.population_size();
+    let error = ScheduledParameter::PopulationSize
+        .apply(0.2, &mut environment, &mut mutations)
+        .unwrap_err();
+    assert_eq!(error.invalid_fields()[0].field(), "population_size");
+    assert_eq!(environment.population_size(), population_size);
+    let max_organism_size = environment.max_organism_size();
+    assert!(ScheduledParameter::MaxOrganismSize
+        .apply(-3.0, &mut environment, &mut mutations)
+        .is_err());
+    assert_eq!(environment.max_organism_size(), max_organism_size);
+    let testing_repetitions = environment.testing_repetitions();
+    assert!(ScheduledParameter::TestingRepetitions
+        .apply(0.2, &mut environment, &mut mutations)
+        .is_err());
+    assert_eq!(environment.testing_repetitions(), testing_repetitions);
+    assert!(ScheduledParameter::Lifespan
+        .apply(-1.0, &mut environment, &mut mutations)
+        .is_err());
+    assert!(ScheduledParameter::DeathAgeSigmoidMidpoint
+        .apply(f64::NAN, &mut environment, &mut mutations)
+        .is_err());
+}
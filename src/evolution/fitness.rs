@@ -0,0 +1,135 @@
+//! The `fitness` module contains the statistics of repeated fitness evaluations.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Clone, Copy, Default, Serialize, Deserialize)]
+/// `FitnessStatistics` keep track of the number, mean and variance of fitness evaluations
+/// based on Welford's online algorithm.
+pub struct FitnessStatistics {
+    count: u32,
+    mean: f64,
+    m2: f64,
+}
+
+impl FitnessStatistics {
+    /// Creates new `FitnessStatistics` without any evaluations.
+    pub fn new() -> Self {
+        FitnessStatistics::default()
+    }
+
+    /// Creates new `FitnessStatistics` from previously aggregated evaluations.
+    ///
+    /// # Parameters
+    ///
+    /// * `count` - the number of fitness evaluations
+    /// * `mean` - the mean fitness
+    /// * `m2` - the sum of squared differences from the mean fitness
+    pub(crate) fn from_moments(count: u32, mean: f64, m2: f64) -> Self {
+        FitnessStatistics { count, mean, m2 }
+    }
+
+    /// Adds the specified fitness evaluation.
+    ///
+    /// # Parameters
+    ///
+    /// * `fitness` - the newly evaluated fitness
+    pub fn add(&mut self, fitness: f64) {
+        self.count += 1;
+        let delta = fitness - self.mean;
+        self.mean += delta / (self.count as f64);
+        self.m2 += delta * (fitness - self.mean);
+    }
+
+    /// Returns the number of fitness evaluations.
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Returns the mean fitness if there was any evaluation.
+    pub fn mean(&self) -> Option<f64> {
+        if self.count > 0 {
+            Some(self.mean)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the sum of squared differences from the mean fitness.
+    pub(crate) fn sum_of_squares(&self) -> f64 {
+        self.m2
+    }
+
+    /// Returns the sample variance of the fitness if there were at least two evaluations.
+    pub fn variance(&self) -> Option<f64> {
+        if self.count > 1 {
+            Some(self.m2 / ((self.count - 1) as f64))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the standard error of the mean fitness if there were at least two evaluations.
+    pub fn standard_error(&self) -> Option<f64> {
+        self.variance()
+            .map(|variance| (variance / (self.count as f64)).sqrt())
+    }
+
+    /// Returns the confidence interval of the mean fitness if there were at least two
+    /// evaluations.
+    ///
+    /// # Parameters
+    ///
+    /// * `z` - the number of standard errors the interval spans in each direction,
+    ///   e.g. 1.96 for a 95 % confidence interval
+    pub fn confidence_interval(&self, z: f64) -> Option<(f64, f64)> {
+        self.standard_error()
+            .map(|standard_error| (self.mean - z * standard_error, self.mean + z * standard_error))
+    }
+
+    /// Returns the lower bound of the confidence interval of the mean fitness if there
+    /// was any evaluation. If the variance is unknown, the mean fitness is returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `z` - the number of standard errors the interval spans in each direction
+    pub fn lower_confidence_bound(&self, z: f64) -> Option<f64> {
+        self.confidence_interval(z)
+            .map(|(lower, _)| lower)
+            .or_else(|| self.mean())
+    }
+
+    /// Returns the fitness estimate according to the specified [`SelectionCriterion`]
+    /// if there was any evaluation.
+    ///
+    /// # Parameters
+    ///
+    /// * `criterion` - the [`SelectionCriterion`]
+    ///
+    /// [`SelectionCriterion`]: ./enum.SelectionCriterion.html
+    pub fn estimate(&self, criterion: SelectionCriterion) -> Option<f64> {
+        match criterion {
+            SelectionCriterion::Mean => self.mean(),
+            SelectionCriterion::LowerConfidenceBound(z) => self.lower_confidence_bound(z),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Default, Serialize, Deserialize)]
+/// A `SelectionCriterion` determines which estimate of the fitness of an [`Individual`]
+/// is used for selection.
+///
+/// [`Individual`]: ../population/struct.Individual.html
+pub enum SelectionCriterion {
+    /// Selection is based on the mean fitness.
+    #[default]
+    Mean,
+    /// Selection is based on the lower bound of the confidence interval of the mean fitness
+    /// spanning the specified number of standard errors, which penalises
+    /// [`Individual`]s that were rarely evaluated or perform inconsistently.
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    LowerConfidenceBound(f64),
+}
+
+#[cfg(test)]
+mod tests;
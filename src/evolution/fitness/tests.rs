@@ -0,0 +1,55 @@
+use super::*;
+
+#[test]
+/// Tests if the function `new` of the [`FitnessStatistics`] struct correctly creates empty
+/// [`FitnessStatistics`].
+fn test_fitness_statistics_new() {
+    let statistics = FitnessStatistics::new();
+    assert_eq!(statistics.count(), 0);
+    assert_eq!(statistics.mean(), None);
+    assert_eq!(statistics.variance(), None);
+    assert_eq!(statistics.confidence_interval(1.96), None);
+    assert_eq!(statistics.lower_confidence_bound(1.96), None);
+}
+
+#[test]
+/// Tests if the function `add` of the [`FitnessStatistics`] struct correctly updates mean
+/// and variance.
+fn test_fitness_statistics_add() {
+    let mut statistics = FitnessStatistics::new();
+    statistics.add(0.5);
+    assert_eq!(statistics.count(), 1);
+    assert_ulps_eq!(statistics.mean().unwrap(), 0.5);
+    assert_eq!(statistics.variance(), None);
+    // Without a variance the lower confidence bound is the mean.
+    assert_ulps_eq!(statistics.lower_confidence_bound(1.96).unwrap(), 0.5);
+    for fitness in [0.2, 0.8, 0.1, 0.9] {
+        statistics.add(fitness);
+    }
+    assert_eq!(statistics.count(), 5);
+    assert_ulps_eq!(statistics.mean().unwrap(), 0.5);
+    assert_ulps_eq!(statistics.variance().unwrap(), 0.125);
+    assert_ulps_eq!(statistics.standard_error().unwrap(), 0.025f64.sqrt());
+}
+
+#[test]
+/// Tests if the functions `confidence_interval` and `estimate` of the [`FitnessStatistics`]
+/// struct correctly calculate the confidence interval.
+fn test_fitness_statistics_confidence_interval() {
+    let mut statistics = FitnessStatistics::new();
+    for fitness in [0.4, 0.6, 0.4, 0.6] {
+        statistics.add(fitness);
+    }
+    // The sample variance is 0.04 / 3, so the standard error is 0.1 / 3.0.sqrt().
+    let standard_error = 0.1 / 3.0f64.sqrt();
+    let (lower, upper) = statistics.confidence_interval(2.0).unwrap();
+    assert_ulps_eq!(lower, 0.5 - 2.0 * standard_error);
+    assert_ulps_eq!(upper, 0.5 + 2.0 * standard_error);
+    assert_ulps_eq!(statistics.estimate(SelectionCriterion::Mean).unwrap(), 0.5);
+    assert_ulps_eq!(
+        statistics
+            .estimate(SelectionCriterion::LowerConfidenceBound(2.0))
+            .unwrap(),
+        lower
+    );
+}
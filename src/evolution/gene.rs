@@ -4,17 +4,29 @@ extern crate rand;
 extern crate rmp_serde;
 extern crate serde;
 
+pub use diff::{GenomeChange, GenomeDiff};
+pub use export::{GenomeFormat, GenomeImportError};
+pub use pruning::PruningReport;
+pub use rates::{RateDistribution, RateModifiers};
 pub use sensor::GenomicInputSensor;
 pub use sensor::GenomicOutputSensor;
+pub use structural_mutation::{
+    mutate_association_deletion, mutate_association_gene_deletion,
+    mutate_association_gene_insertion, mutate_association_insertion, mutate_gene_deletion,
+    mutate_gene_duplication, mutate_gene_fusion, mutate_input_association,
+    mutate_input_dissociation, mutate_output_association, mutate_output_dissociation,
+    mutate_receptor_deletion, mutate_receptor_enzyme_replacement, mutate_receptor_insertion,
+    mutate_receptor_state_replacement, mutate_receptor_trigger_deletion,
+    mutate_receptor_trigger_insertion, structural_mutations,
+};
 
 use super::chemistry::{Information, Input, Output, Reaction, State};
-use super::helper::{a_or_b, do_a_or_b};
+use super::helper::{a_or_b, do_a_or_b, serialised_sequence_header_size, serialised_size, Cache};
 use super::population::Organism;
 use super::protein::{CatalyticCentre, Receptor, Substrate, SubstrateType};
 use rand::{thread_rng, Rng};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use std::cell::RefCell;
 use std::collections::HashMap;
 use std::error::Error;
 use std::fs::File;
@@ -22,7 +34,6 @@ use std::io::Write;
 use std::marker::PhantomData;
 use std::num::NonZeroUsize;
 use std::path::Path;
-use std::rc::Rc;
 
 /// A `Genome` is a collection of individual [`Gene`]s and associations between them.
 /// A `Genome` is required to consist of 1 or more genes.
@@ -61,6 +72,8 @@ pub struct Genome<
     >,
     genes: Vec<Gene<ReactionType, StateType, InformationType>>,
     associations: Vec<GeneAssociation<InformationType>>,
+    #[serde(default)]
+    rate_modifiers: RateModifiers,
 }
 
 impl<
@@ -126,9 +139,29 @@ impl<
             output,
             genes,
             associations: Vec::new(),
+            rate_modifiers: RateModifiers::default(),
         }
     }
 
+    /// Returns the [`RateModifiers`] scaling the mutation chances of this `Genome`'s
+    /// offspring.
+    ///
+    /// [`RateModifiers`]: ./struct.RateModifiers.html
+    pub fn rate_modifiers(&self) -> &RateModifiers {
+        &self.rate_modifiers
+    }
+
+    /// Sets the [`RateModifiers`] scaling the mutation chances of this `Genome`'s offspring.
+    ///
+    /// # Parameters
+    ///
+    /// * `rate_modifiers` - the new [`RateModifiers`]
+    ///
+    /// [`RateModifiers`]: ./struct.RateModifiers.html
+    pub fn set_rate_modifiers(&mut self, rate_modifiers: RateModifiers) {
+        self.rate_modifiers = rate_modifiers;
+    }
+
     /// Get the number of [`Gene`]s in this `Genome`.
     /// A `Genome` must encode 1 or more [`Gene`]s.
     ///
@@ -226,9 +259,10 @@ impl<
     ///
     /// [`Substrate`]: ../protein/struct.Substrate.html
     pub fn get_substrate_mut(&mut self, substrate: GeneSubstrate) -> Option<&mut InformationType> {
-        self.genes
-            .get_mut(substrate.gene)
-            .and_then(|gene| gene.substrates.get_mut(substrate.substrate))
+        self.genes.get_mut(substrate.gene).and_then(|gene| {
+            gene.reset_binary_size();
+            gene.substrates.get_mut(substrate.substrate)
+        })
     }
 
     /// Adds a [`Gene`] to the `Genome` if possible and returns the index of the
@@ -391,38 +425,6 @@ impl<
         &mut self.genes[gene]
     }
 
-    /// Returns all substrates of the specified [`Gene`] combined with their [`GeneSubstrate`] pointer.
-    ///
-    /// # Parameters
-    ///
-    /// * `gene` - the index of the gene
-    ///
-    /// # Panics
-    ///
-    /// If the index is out of bounds.
-    ///
-    /// [`Gene`]: ./struct.Gene.html
-    /// [`GeneSubstrate`]: ./struct.GeneSubstrate.html
-    fn translate_get_gene_substrates(
-        &self,
-        gene: usize,
-    ) -> Vec<(GeneSubstrate, Rc<RefCell<Substrate<ReactionType, StateType, InformationType>>>)>
-    {
-        let gene_reference = &self.genes[gene];
-        (0..gene_reference.number_of_substrates().get())
-            .map(|substrate| GeneSubstrate::new(gene, substrate))
-            .map(|gene_substrate| {
-                (
-                    gene_substrate,
-                    Rc::new(RefCell::new(Substrate::new(
-                        gene_reference.substrates[gene_substrate.substrate].clone(),
-                        SubstrateType::ConventionalSubstrate,
-                    ))),
-                )
-            })
-            .collect()
-    }
-
     /// Returns the index of a random [`Gene`].
     ///
     /// [`Gene`]: ./struct.Gene.html
@@ -573,11 +575,42 @@ impl<
     ///
     /// If the underlying serialisation fails.
     pub fn binary_size(&self) -> usize {
-        rmp_serde::to_vec(&self)
-            .expect("Serialisation of the genome failed.")
-            .len()
+        // Only measure the parts of the genome besides its genes, as every gene caches its
+        // own size, which is shared with the genes it was cloned from.
+        let parts = (
+            &self.phantom_reaction,
+            &self.phantom_state,
+            &self.phantom_information,
+            &self.phantom_input_element,
+            &self.phantom_input_sensor,
+            &self.phantom_output_element,
+            &self.phantom_output_sensor,
+            &self.input,
+            &self.output,
+            (),
+            &self.associations,
+            &self.rate_modifiers,
+        );
+        serialised_size(&parts) - serialised_size(&())
+            + serialised_sequence_header_size(self.genes.len())
+            + self.genes.iter().map(Gene::binary_size).sum::<usize>()
     }
 
+    /// Translates the `Genome` into an [`Organism`]. Every gene substrate is translated into
+    /// a [`Substrate`] in the order of the [`Gene`]s and their substrates, while all gene
+    /// substrates of a [`GeneAssociation`] share a single [`Substrate`]. The [`Receptor`]s
+    /// are translated in the order of the [`Gene`]s and their [`GenomicReceptor`]s.
+    /// The resulting ids are the positions in [`Organism::substrates`] and
+    /// [`Organism::receptors`].
+    ///
+    /// [`Gene`]: ./struct.Gene.html
+    /// [`GeneAssociation`]: ./struct.GeneAssociation.html
+    /// [`GenomicReceptor`]: ./struct.GenomicReceptor.html
+    /// [`Organism`]: ../population/struct.Organism.html
+    /// [`Organism::receptors`]: ../population/struct.Organism.html#method.receptors
+    /// [`Organism::substrates`]: ../population/struct.Organism.html#method.substrates
+    /// [`Receptor`]: ../protein/struct.Receptor.html
+    /// [`Substrate`]: ../protein/struct.Substrate.html
     pub fn translate(
         &self,
     ) -> Organism<
@@ -589,45 +622,87 @@ impl<
         OutputElementType,
         OutputSensorType,
     > {
-        let mut gene_substrate_map: HashMap<
-            GeneSubstrate,
-            Rc<RefCell<Substrate<ReactionType, StateType, InformationType>>>,
-        > = HashMap::new();
-        // Insert all genome level substrates.
-        for gene_association in &self.associations {
-            let genome_level_substrate = Rc::new(RefCell::new(Substrate::new(
-                gene_association.substrate.clone(),
-                SubstrateType::ConventionalSubstrate,
-            )));
+        // Map the gene substrates to the genome level substrates they are associated with.
+        let mut associated: HashMap<GeneSubstrate, usize> = HashMap::new();
+        for (association_index, gene_association) in self.associations.iter().enumerate() {
             for gene_substrate in &gene_association.associations {
-                gene_substrate_map
-                    .entry(gene_substrate.clone())
-                    .or_insert(genome_level_substrate.clone());
+                associated
+                    .entry(*gene_substrate)
+                    .or_insert(association_index);
             }
         }
-        // Insert all gene level substrates without overwriting genome level ones.
-        for gene_index in 0..self.number_of_genes().get() {
-            for (gene_substrate, substrate) in
-                self.translate_get_gene_substrates(gene_index).into_iter()
-            {
-                gene_substrate_map
-                    .entry(gene_substrate)
-                    .or_insert(substrate);
+        // Translate every substrate exactly once in the order of the genes and their
+        // substrates.
+        let mut substrates: Vec<Substrate<ReactionType, StateType, InformationType>> = Vec::new();
+        let mut substrate_lookup: HashMap<GeneSubstrate, usize> = HashMap::new();
+        let mut association_lookup: HashMap<usize, usize> = HashMap::new();
+        for (gene_index, gene) in self.genes.iter().enumerate() {
+            for (substrate_index, value) in gene.substrates.iter().enumerate() {
+                let gene_substrate = GeneSubstrate::new(gene_index, substrate_index);
+                let value = match associated.get(&gene_substrate) {
+                    Some(association) => {
+                        if let Some(id) = association_lookup.get(association) {
+                            substrate_lookup.insert(gene_substrate, *id);
+                            continue;
+                        }
+                        association_lookup.insert(*association, substrates.len());
+                        self.associations[*association].substrate.clone()
+                    },
+                    None => value.clone(),
+                };
+                substrate_lookup.insert(gene_substrate, substrates.len());
+                substrates.push(Substrate::new(value, SubstrateType::ConventionalSubstrate));
             }
         }
         // Translate receptors and catalytic centres.
-        for gene_index in 0..self.number_of_genes().get() {
-            for receptor in self.get_gene(gene_index).receptors.iter() {
-                receptor.translate(gene_index, &gene_substrate_map);
+        let mut receptors = Vec::new();
+        for (gene_index, gene) in self.genes.iter().enumerate() {
+            for receptor in &gene.receptors {
+                let receptor = receptor.translate(
+                    gene_index,
+                    receptors.len(),
+                    &substrate_lookup,
+                    &mut substrates,
+                );
+                receptors.push(receptor);
             }
         }
-        let substrates = gene_substrate_map.values().map(|sub| sub.clone()).collect();
-        let input = self.input.translate(&gene_substrate_map);
-        let output = self.output.translate(&gene_substrate_map);
-        Organism::new(substrates, input, output)
+        let input = self.input.translate(&substrate_lookup, &mut substrates);
+        let output = self.output.translate(&substrate_lookup, &mut substrates);
+        Organism::new(substrates, receptors, input, output)
     }
 }
 
+/// Returns the ids of the translated [`Substrate`]s of the specified gene substrates.
+///
+/// # Parameters
+///
+/// * `gene_index` - the index of the [`Gene`] containing the substrates
+/// * `indices` - the indices of the substrates inside the [`Gene`]
+/// * `substrate_lookup` - a map for lookup of the ids of all the translated [`Substrate`]s
+///
+/// # Panics
+///
+/// If the `substrate_lookup` map does not contain one of the requested [`Substrate`]s.
+///
+/// [`Gene`]: ./struct.Gene.html
+/// [`Substrate`]: ../protein/struct.Substrate.html
+fn lookup_substrates(
+    gene_index: usize,
+    indices: &[usize],
+    substrate_lookup: &HashMap<GeneSubstrate, usize>,
+) -> Vec<usize> {
+    indices
+        .iter()
+        .map(|substrate_index| GeneSubstrate::new(gene_index, *substrate_index))
+        .map(|gene_substrate| {
+            *substrate_lookup.get(&gene_substrate).unwrap_or_else(|| {
+                panic!("The substrate lookup map did not contain {:?}.", &gene_substrate)
+            })
+        })
+        .collect()
+}
+
 /// Checks if the specified [`Substrate`] is present in the specified set of [`Gene`]s.
 ///
 /// # Parameters
@@ -680,6 +755,7 @@ impl<
             output: GenomicOutputSensor::default(),
             genes: vec![Gene::<ReactionType, StateType, InformationType>::default()],
             associations: Vec::default(),
+            rate_modifiers: RateModifiers::default(),
         }
     }
 }
@@ -713,6 +789,7 @@ impl<
             let output = self.output.cross_over(&other.output);
             let genes = self.genes.cross_over(&other.genes);
             let associations = self.associations.cross_over(&other.associations);
+            let rate_modifiers = self.rate_modifiers.cross_over(&other.rate_modifiers);
             let mut recombined = Genome {
                 phantom_reaction: PhantomData,
                 phantom_state: PhantomData,
@@ -725,6 +802,7 @@ impl<
                 output,
                 genes,
                 associations,
+                rate_modifiers,
             };
             // Remove invalid gene-substrate-associations.
             recombined.validate_associations();
@@ -859,7 +937,15 @@ where
         self.associations.len()
     }
 
-    /// Remove all associations with the specified [`Gene`].
+    /// Returns the [`GeneSubstrate`]s this association references.
+    ///
+    /// [`GeneSubstrate`]: ./struct.GeneSubstrate.html
+    pub fn associations(&self) -> &[GeneSubstrate] {
+        &self.associations
+    }
+
+    /// Remove all associations with the specified [`Gene`] and adjusts the indices of
+    /// associations with subsequent [`Gene`]s.
     ///
     /// # Parameters
     ///
@@ -868,6 +954,11 @@ where
     /// [`Gene`]: ./struct.Gene.html
     pub fn remove_associated_gene(&mut self, gene: usize) {
         self.associations.retain(|a| a.gene != gene);
+        for association in &mut self.associations {
+            if association.gene > gene {
+                association.gene -= 1;
+            }
+        }
     }
 
     /// Returns the index of a random [`GeneSubstrate`] if there are any.
@@ -969,6 +1060,8 @@ pub struct Gene<R, S, T> {
     phantom_t: PhantomData<T>,
     substrates: Vec<T>,
     receptors: Vec<GenomicReceptor<R, S, T>>,
+    #[serde(skip)]
+    binary_size: Cache<usize>,
 }
 
 impl<R: Reaction<T>, S: State<T>, T: Information> Gene<R, S, T> {
@@ -992,6 +1085,7 @@ impl<R: Reaction<T>, S: State<T>, T: Information> Gene<R, S, T> {
             phantom_t: PhantomData,
             substrates,
             receptors: Vec::new(),
+            binary_size: Cache::default(),
         }
     }
 
@@ -1040,6 +1134,7 @@ impl<R: Reaction<T>, S: State<T>, T: Information> Gene<R, S, T> {
     /// [`Substrate`]: ../protein/struct.Substrate.html
     pub fn add_substrate(&mut self, substrate: T) -> Option<usize> {
         if let Some(new_index) = self.substrates.len().checked_add(1) {
+            self.reset_binary_size();
             self.substrates.push(substrate);
             Some(new_index)
         } else {
@@ -1058,6 +1153,7 @@ impl<R: Reaction<T>, S: State<T>, T: Information> Gene<R, S, T> {
     /// [`GenomicReceptor`]: ./struct.GenomicReceptor.html
     pub fn add_receptor(&mut self, receptor: GenomicReceptor<R, S, T>) -> Option<usize> {
         if let Some(new_index) = self.receptors.len().checked_add(1) {
+            self.reset_binary_size();
             self.receptors.push(receptor);
             Some(new_index)
         } else {
@@ -1076,6 +1172,7 @@ impl<R: Reaction<T>, S: State<T>, T: Information> Gene<R, S, T> {
     ///
     /// If the specified receptor's index is out of bounds.
     pub fn remove_receptor(&mut self, receptor_index: usize) -> GenomicReceptor<R, S, T> {
+        self.reset_binary_size();
         self.receptors.remove(receptor_index)
     }
 
@@ -1094,6 +1191,7 @@ impl<R: Reaction<T>, S: State<T>, T: Information> Gene<R, S, T> {
     ///
     /// * `receptor_index` - the receptor's index
     pub fn receptor_mut(&mut self, receptor_index: usize) -> Option<&mut GenomicReceptor<R, S, T>> {
+        self.reset_binary_size();
         self.receptors.get_mut(receptor_index)
     }
 
@@ -1116,6 +1214,7 @@ impl<R: Reaction<T>, S: State<T>, T: Information> Gene<R, S, T> {
                 "A genome needs to contain at least one substrate, so no substrate can be removed."
             );
         }
+        self.reset_binary_size();
         // Remove all receptors and catalytic centres referencing the substrate.
         self.receptors
             .retain(|r| !r.referes_to_substrate(substrate_index));
@@ -1147,6 +1246,7 @@ impl<R: Reaction<T>, S: State<T>, T: Information> Gene<R, S, T> {
             return None;
         };
         let mut fusion_gene = self.duplicate();
+        fusion_gene.reset_binary_size();
         fusion_gene
             .substrates
             .append(&mut other_gene.substrates.clone());
@@ -1186,6 +1286,23 @@ impl<R: Reaction<T>, S: State<T>, T: Information> Gene<R, S, T> {
         self.clone()
     }
 
+    /// Returns the binary size of this `Gene` in byte.
+    /// The size is only calculated once and shared with all unchanged clones of the `Gene`.
+    ///
+    /// # Panics
+    ///
+    /// If the underlying serialisation fails.
+    pub fn binary_size(&self) -> usize {
+        *self.binary_size.value.get_or_init(|| serialised_size(self))
+    }
+
+    /// Discards the binary size of this `Gene`, which must be called before every change.
+    /// The size is replaced instead of cleared, since it is shared with the clones of the
+    /// `Gene`.
+    fn reset_binary_size(&mut self) {
+        self.binary_size = Cache::default();
+    }
+
     /// Creates a random [`GenomicCatalyticCentre`] specific to this `Gene`.
     pub fn random_catalytic_centre(&self) -> GenomicCatalyticCentre<R, S, T> {
         let reaction = R::random();
@@ -1231,6 +1348,7 @@ impl<R: Reaction<T>, S: State<T>, T: Information + Default> Default for Gene<R,
             phantom_t: PhantomData,
             substrates: vec![T::default()],
             receptors: Vec::default(),
+            binary_size: Cache::default(),
         }
     }
 }
@@ -1250,6 +1368,7 @@ impl<R: Reaction<T>, S: State<T>, T: Information> CrossOver for Gene<R, S, T> {
                 phantom_t: PhantomData,
                 substrates,
                 receptors,
+                binary_size: Cache::default(),
             }
         } else {
             // If the two genes are not similar return a random one.
@@ -1456,6 +1575,11 @@ impl<R: Reaction<T>, S: State<T>, T: Information> GenomicReceptor<R, S, T> {
         &self.substrates
     }
 
+    /// Returns the triggering substrates of this receptor.
+    pub fn triggers(&self) -> &[usize] {
+        &self.triggers
+    }
+
     /// Returns the [`GenomicCatalyticCentre`] as mutable.
     ///
     /// [`GenomicCatalyticCentre`]: ./struct.GenomicCatalyticCentre.html
@@ -1536,13 +1660,16 @@ impl<R: Reaction<T>, S: State<T>, T: Information> GenomicReceptor<R, S, T> {
         self.enzyme.adjust_indices(removed_index);
     }
 
-    /// Translates the `GenomicReceptor` into a [`Receptor`] and directly associates them with their
-    /// triggering [`Substrate`]s.
+    /// Translates the `GenomicReceptor` into a [`Receptor`] and directly associates it with
+    /// its triggering [`Substrate`]s.
     ///
     /// # Parameters
     /// * `gene_index` - the index of the [`Gene`] containing this `Receptor` inside the
-    /// [`Genome`].
-    /// * `substrate_lookup` - a map for lookup of all the translated [`Substrate`]s of the containing [`Genome`]
+    ///   [`Genome`].
+    /// * `receptor_id` - the id the translated [`Receptor`] is stored at
+    /// * `substrate_lookup` - a map for lookup of the ids of all the translated [`Substrate`]s
+    ///   of the containing [`Genome`]
+    /// * `substrates` - all translated [`Substrate`]s of the containing [`Genome`]
     ///
     /// # Panics
     ///
@@ -1555,31 +1682,17 @@ impl<R: Reaction<T>, S: State<T>, T: Information> GenomicReceptor<R, S, T> {
     fn translate(
         &self,
         gene_index: usize,
-        substrate_lookup: &HashMap<GeneSubstrate, Rc<RefCell<Substrate<R, S, T>>>>,
-    ) {
-        let substrates = self
-            .substrates
-            .iter()
-            .map(|substrate_index| GeneSubstrate::new(gene_index, *substrate_index))
-            .map(|gene_substrate| {
-                substrate_lookup.get(&gene_substrate).expect(&format!(
-                    "The substrate lookup map did not contain {:?}.",
-                    &gene_substrate
-                ))
-            })
-            .map(|strong| Rc::downgrade(strong))
-            .collect();
+        receptor_id: usize,
+        substrate_lookup: &HashMap<GeneSubstrate, usize>,
+        substrates: &mut [Substrate<R, S, T>],
+    ) -> Receptor<R, S, T> {
+        let receptor_substrates = lookup_substrates(gene_index, &self.substrates, substrate_lookup);
         let enzyme = self.enzyme.translate(gene_index, substrate_lookup);
         let state = self.state.clone();
-        let receptor = Rc::new(Receptor::new(substrates, state, enzyme));
-        for trigger_index in self.triggers.iter() {
-            let gene_substrate = GeneSubstrate::new(gene_index, *trigger_index);
-            substrate_lookup
-                .get(&gene_substrate)
-                .expect(&format!("The substrate lookup map did not contain {:?}.", &gene_substrate))
-                .borrow_mut()
-                .add_receptor(receptor.clone());
+        for trigger in lookup_substrates(gene_index, &self.triggers, substrate_lookup) {
+            substrates[trigger].add_receptor(receptor_id);
         }
+        Receptor::new(receptor_substrates, state, enzyme)
     }
 }
 
@@ -1769,7 +1882,7 @@ impl<R: Reaction<T>, S: State<T>, T: Information> GenomicCatalyticCentre<R, S, T
     /// # Parameters
     /// * `gene_index` - the index of the [`Gene`] containing this `GenomicCatalyticCentre` inside the
     /// [`Genome`].
-    /// * `substrate_lookup` - a map for lookup of all the translated [`Substrate`]s of the containing [`Genome`]
+    /// * `substrate_lookup` - a map for lookup of the ids of all the translated [`Substrate`]s of the containing [`Genome`]
     ///
     /// # Panics
     ///
@@ -1782,32 +1895,10 @@ impl<R: Reaction<T>, S: State<T>, T: Information> GenomicCatalyticCentre<R, S, T
     fn translate(
         &self,
         gene_index: usize,
-        substrate_lookup: &HashMap<GeneSubstrate, Rc<RefCell<Substrate<R, S, T>>>>,
+        substrate_lookup: &HashMap<GeneSubstrate, usize>,
     ) -> CatalyticCentre<R, S, T> {
-        let educts = self
-            .educts
-            .iter()
-            .map(|substrate_index| GeneSubstrate::new(gene_index, *substrate_index))
-            .map(|gene_substrate| {
-                substrate_lookup.get(&gene_substrate).expect(&format!(
-                    "The substrate lookup map did not contain {:?}.",
-                    &gene_substrate
-                ))
-            })
-            .map(|strong| Rc::downgrade(strong))
-            .collect();
-        let products = self
-            .products
-            .iter()
-            .map(|substrate_index| GeneSubstrate::new(gene_index, *substrate_index))
-            .map(|gene_substrate| {
-                substrate_lookup.get(&gene_substrate).expect(&format!(
-                    "The substrate lookup map did not contain {:?}.",
-                    &gene_substrate
-                ))
-            })
-            .map(|strong| Rc::downgrade(strong))
-            .collect();
+        let educts = lookup_substrates(gene_index, &self.educts, substrate_lookup);
+        let products = lookup_substrates(gene_index, &self.products, substrate_lookup);
         let reaction = self.reaction.clone();
         CatalyticCentre::new(educts, products, reaction)
     }
@@ -1923,7 +2014,12 @@ where
     }
 }
 
+mod diff;
+mod export;
+mod pruning;
+mod rates;
 mod sensor;
+mod structural_mutation;
 
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,801 @@
+//! The `diff` module contains the structural comparison of two
+//! [`Genome`](crate::evolution::gene::Genome)s, e.g. of a descendant and its parent.
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::evolution::chemistry::{Information, Input, Output, Reaction, State};
+
+use super::{Gene, GeneAssociation, GeneSubstrate, Genome, GenomicReceptor, RateModifiers};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// A `GenomeChange` is a single structural difference between two [`Genome`]s.
+/// [`Gene`]s are aligned by content, so the indices of a modified [`Gene`] are
+/// specified before and after as `(before, after)`. All other elements are compared by
+/// their position.
+///
+/// [`Gene`]: ./struct.Gene.html
+/// [`Genome`]: ./struct.Genome.html
+pub enum GenomeChange<R, S, T> {
+    /// A [`Gene`] only present afterwards.
+    GeneAdded { gene: usize, added: Gene<R, S, T> },
+    /// A [`Gene`] only present before.
+    GeneRemoved { gene: usize, removed: Gene<R, S, T> },
+    /// A substrate only present afterwards.
+    SubstrateAdded {
+        gene: (usize, usize),
+        substrate: usize,
+        added: T,
+    },
+    /// A substrate only present before.
+    SubstrateRemoved {
+        gene: (usize, usize),
+        substrate: usize,
+        removed: T,
+    },
+    /// A substrate with a modified value.
+    SubstrateModified {
+        gene: (usize, usize),
+        substrate: usize,
+        before: T,
+        after: T,
+    },
+    /// A [`GenomicReceptor`] only present afterwards.
+    ReceptorAdded {
+        gene: (usize, usize),
+        receptor: usize,
+        added: GenomicReceptor<R, S, T>,
+    },
+    /// A [`GenomicReceptor`] only present before.
+    ReceptorRemoved {
+        gene: (usize, usize),
+        receptor: usize,
+        removed: GenomicReceptor<R, S, T>,
+    },
+    /// Modified triggers of a [`GenomicReceptor`].
+    TriggersModified {
+        gene: (usize, usize),
+        receptor: usize,
+        before: Vec<usize>,
+        after: Vec<usize>,
+    },
+    /// A modified [`State`](crate::evolution::chemistry::State) of a [`GenomicReceptor`].
+    StateModified {
+        gene: (usize, usize),
+        receptor: usize,
+        before: S,
+        after: S,
+    },
+    /// Modified substrates the state of a [`GenomicReceptor`] is checked for.
+    StateSubstratesModified {
+        gene: (usize, usize),
+        receptor: usize,
+        before: Vec<usize>,
+        after: Vec<usize>,
+    },
+    /// Modified educts of the catalytic centre of a [`GenomicReceptor`].
+    EductsModified {
+        gene: (usize, usize),
+        receptor: usize,
+        before: Vec<usize>,
+        after: Vec<usize>,
+    },
+    /// Modified products of the catalytic centre of a [`GenomicReceptor`].
+    ProductsModified {
+        gene: (usize, usize),
+        receptor: usize,
+        before: Vec<usize>,
+        after: Vec<usize>,
+    },
+    /// A modified [`Reaction`](crate::evolution::chemistry::Reaction) of the catalytic centre
+    /// of a [`GenomicReceptor`].
+    ReactionModified {
+        gene: (usize, usize),
+        receptor: usize,
+        before: R,
+        after: R,
+    },
+    /// A [`GeneAssociation`] only present afterwards.
+    AssociationAdded {
+        association: usize,
+        added: GeneAssociation<T>,
+    },
+    /// A [`GeneAssociation`] only present before.
+    AssociationRemoved {
+        association: usize,
+        removed: GeneAssociation<T>,
+    },
+    /// A modified [`GeneAssociation`].
+    AssociationModified {
+        association: usize,
+        before: GeneAssociation<T>,
+        after: GeneAssociation<T>,
+    },
+    /// A modified mapping of an input to a [`GeneSubstrate`].
+    InputModified {
+        input: usize,
+        before: Option<GeneSubstrate>,
+        after: Option<GeneSubstrate>,
+    },
+    /// A modified mapping of an input feedback to a [`GeneSubstrate`].
+    InputFeedbackModified {
+        feedback: usize,
+        before: Option<GeneSubstrate>,
+        after: Option<GeneSubstrate>,
+    },
+    /// A modified mapping of an output to a [`GeneSubstrate`].
+    OutputModified {
+        output: usize,
+        before: Option<GeneSubstrate>,
+        after: Option<GeneSubstrate>,
+    },
+    /// A modified mapping of an output feedback to a [`GeneSubstrate`].
+    OutputFeedbackModified {
+        feedback: usize,
+        before: Option<GeneSubstrate>,
+        after: Option<GeneSubstrate>,
+    },
+    /// A modified mapping of the output finish signal to a [`GeneSubstrate`].
+    FinishModified {
+        before: Option<GeneSubstrate>,
+        after: Option<GeneSubstrate>,
+    },
+    /// Modified [`RateModifiers`].
+    RateModifiersModified {
+        before: RateModifiers,
+        after: RateModifiers,
+    },
+}
+
+impl<R: Reaction<T>, S: State<T>, T: Information> Display for GenomeChange<R, S, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GenomeChange::GeneAdded { gene, added } => write!(
+                f,
+                "+ gene {} with {} substrates and {} receptors",
+                gene,
+                added.substrates.len(),
+                added.receptors.len()
+            ),
+            GenomeChange::GeneRemoved { gene, removed } => write!(
+                f,
+                "- gene {} with {} substrates and {} receptors",
+                gene,
+                removed.substrates.len(),
+                removed.receptors.len()
+            ),
+            GenomeChange::SubstrateAdded {
+                gene,
+                substrate,
+                added,
+            } => write!(f, "+ {} substrate {}: {}", gene_label(gene), substrate, added.label()),
+            GenomeChange::SubstrateRemoved {
+                gene,
+                substrate,
+                removed,
+            } => write!(f, "- {} substrate {}: {}", gene_label(gene), substrate, removed.label()),
+            GenomeChange::SubstrateModified {
+                gene,
+                substrate,
+                before,
+                after,
+            } => write!(
+                f,
+                "~ {} substrate {}: {} -> {}",
+                gene_label(gene),
+                substrate,
+                before.label(),
+                after.label()
+            ),
+            GenomeChange::ReceptorAdded {
+                gene,
+                receptor,
+                added,
+            } => {
+                write!(f, "+ {} receptor {}: {}", gene_label(gene), receptor, receptor_label(added))
+            },
+            GenomeChange::ReceptorRemoved {
+                gene,
+                receptor,
+                removed,
+            } => write!(
+                f,
+                "- {} receptor {}: {}",
+                gene_label(gene),
+                receptor,
+                receptor_label(removed)
+            ),
+            GenomeChange::TriggersModified {
+                gene,
+                receptor,
+                before,
+                after,
+            } => write!(
+                f,
+                "~ {} receptor {} triggers: {:?} -> {:?}",
+                gene_label(gene),
+                receptor,
+                before,
+                after
+            ),
+            GenomeChange::StateModified {
+                gene,
+                receptor,
+                before,
+                after,
+            } => write!(
+                f,
+                "~ {} receptor {} state: {} -> {}",
+                gene_label(gene),
+                receptor,
+                before.label(),
+                after.label()
+            ),
+            GenomeChange::StateSubstratesModified {
+                gene,
+                receptor,
+                before,
+                after,
+            } => write!(
+                f,
+                "~ {} receptor {} state substrates: {:?} -> {:?}",
+                gene_label(gene),
+                receptor,
+                before,
+                after
+            ),
+            GenomeChange::EductsModified {
+                gene,
+                receptor,
+                before,
+                after,
+            } => write!(
+                f,
+                "~ {} receptor {} educts: {:?} -> {:?}",
+                gene_label(gene),
+                receptor,
+                before,
+                after
+            ),
+            GenomeChange::ProductsModified {
+                gene,
+                receptor,
+                before,
+                after,
+            } => write!(
+                f,
+                "~ {} receptor {} products: {:?} -> {:?}",
+                gene_label(gene),
+                receptor,
+                before,
+                after
+            ),
+            GenomeChange::ReactionModified {
+                gene,
+                receptor,
+                before,
+                after,
+            } => write!(
+                f,
+                "~ {} receptor {} reaction: {} -> {}",
+                gene_label(gene),
+                receptor,
+                before.label(),
+                after.label()
+            ),
+            GenomeChange::AssociationAdded { association, added } => {
+                write!(f, "+ association {}: {}", association, association_label(added))
+            },
+            GenomeChange::AssociationRemoved {
+                association,
+                removed,
+            } => write!(f, "- association {}: {}", association, association_label(removed)),
+            GenomeChange::AssociationModified {
+                association,
+                before,
+                after,
+            } => write!(
+                f,
+                "~ association {}: {} -> {}",
+                association,
+                association_label(before),
+                association_label(after)
+            ),
+            GenomeChange::InputModified {
+                input,
+                before,
+                after,
+            } => write!(
+                f,
+                "~ input {}: {} -> {}",
+                input,
+                mapping_label(before),
+                mapping_label(after)
+            ),
+            GenomeChange::InputFeedbackModified {
+                feedback,
+                before,
+                after,
+            } => write!(
+                f,
+                "~ input feedback {}: {} -> {}",
+                feedback,
+                mapping_label(before),
+                mapping_label(after)
+            ),
+            GenomeChange::OutputModified {
+                output,
+                before,
+                after,
+            } => write!(
+                f,
+                "~ output {}: {} -> {}",
+                output,
+                mapping_label(before),
+                mapping_label(after)
+            ),
+            GenomeChange::OutputFeedbackModified {
+                feedback,
+                before,
+                after,
+            } => write!(
+                f,
+                "~ output feedback {}: {} -> {}",
+                feedback,
+                mapping_label(before),
+                mapping_label(after)
+            ),
+            GenomeChange::FinishModified { before, after } => {
+                write!(f, "~ finish: {} -> {}", mapping_label(before), mapping_label(after))
+            },
+            GenomeChange::RateModifiersModified { before, after } => {
+                write!(f, "~ rate modifiers: {:?} -> {:?}", before.modifiers(), after.modifiers())
+            },
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// A `GenomeDiff` lists all structural [`GenomeChange`]s between two [`Genome`]s.
+/// Its [`Display`](std::fmt::Display) implementation renders one change per line.
+///
+/// [`Genome`]: ./struct.Genome.html
+/// [`GenomeChange`]: ./enum.GenomeChange.html
+pub struct GenomeDiff<R, S, T> {
+    changes: Vec<GenomeChange<R, S, T>>,
+}
+
+impl<R: Reaction<T>, S: State<T>, T: Information> GenomeDiff<R, S, T> {
+    /// Compares the specified [`Genome`]s.
+    ///
+    /// # Parameters
+    ///
+    /// * `before` - the earlier [`Genome`], e.g. of the parent
+    /// * `after` - the later [`Genome`], e.g. of the descendant
+    ///
+    /// [`Genome`]: ./struct.Genome.html
+    pub fn between<
+        InputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+        InputSensorType: Input<InputElementType, T>,
+        OutputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+        OutputSensorType: Output<OutputElementType, T>,
+    >(
+        before: &Genome<
+            R,
+            S,
+            T,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+        after: &Genome<
+            R,
+            S,
+            T,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    ) -> Self {
+        let mut changes = Vec::new();
+        for alignment in align(&before.genes, &after.genes) {
+            match alignment {
+                (Some(gene), None) => changes.push(GenomeChange::GeneRemoved {
+                    gene,
+                    removed: before.genes[gene].clone(),
+                }),
+                (None, Some(gene)) => changes.push(GenomeChange::GeneAdded {
+                    gene,
+                    added: after.genes[gene].clone(),
+                }),
+                (Some(earlier), Some(later)) => diff_genes(
+                    (earlier, later),
+                    &before.genes[earlier],
+                    &after.genes[later],
+                    &mut changes,
+                ),
+                (None, None) => {},
+            }
+        }
+        for association in 0..before.associations.len().max(after.associations.len()) {
+            match (before.associations.get(association), after.associations.get(association)) {
+                (Some(earlier), Some(later)) if earlier != later => {
+                    changes.push(GenomeChange::AssociationModified {
+                        association,
+                        before: earlier.clone(),
+                        after: later.clone(),
+                    })
+                },
+                (Some(removed), None) => changes.push(GenomeChange::AssociationRemoved {
+                    association,
+                    removed: removed.clone(),
+                }),
+                (None, Some(added)) => changes.push(GenomeChange::AssociationAdded {
+                    association,
+                    added: added.clone(),
+                }),
+                _ => {},
+            }
+        }
+        for (input, before, after) in
+            diff_mappings(before.input.input_substrates(), after.input.input_substrates())
+        {
+            changes.push(GenomeChange::InputModified {
+                input,
+                before,
+                after,
+            });
+        }
+        for (feedback, before, after) in
+            diff_feedback(before.input.feedback_substrates(), after.input.feedback_substrates())
+        {
+            changes.push(GenomeChange::InputFeedbackModified {
+                feedback,
+                before,
+                after,
+            });
+        }
+        for (output, before, after) in
+            diff_mappings(before.output.output_substrates(), after.output.output_substrates())
+        {
+            changes.push(GenomeChange::OutputModified {
+                output,
+                before,
+                after,
+            });
+        }
+        for (feedback, before, after) in
+            diff_feedback(before.output.feedback_substrates(), after.output.feedback_substrates())
+        {
+            changes.push(GenomeChange::OutputFeedbackModified {
+                feedback,
+                before,
+                after,
+            });
+        }
+        if before.output.finish_substrate() != after.output.finish_substrate() {
+            changes.push(GenomeChange::FinishModified {
+                before: *before.output.finish_substrate(),
+                after: *after.output.finish_substrate(),
+            });
+        }
+        if before.rate_modifiers != after.rate_modifiers {
+            changes.push(GenomeChange::RateModifiersModified {
+                before: before.rate_modifiers.clone(),
+                after: after.rate_modifiers.clone(),
+            });
+        }
+        GenomeDiff { changes }
+    }
+
+    /// Returns all [`GenomeChange`]s ordered by genes, associations, sensors and
+    /// rate modifiers.
+    ///
+    /// [`GenomeChange`]: ./enum.GenomeChange.html
+    pub fn changes(&self) -> &[GenomeChange<R, S, T>] {
+        &self.changes
+    }
+
+    /// Checks if both [`Genome`]s are structurally identical.
+    ///
+    /// [`Genome`]: ./struct.Genome.html
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+impl<R: Reaction<T>, S: State<T>, T: Information> Display for GenomeDiff<R, S, T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.changes.is_empty() {
+            return write!(f, "The genomes are identical.");
+        }
+        let lines: Vec<String> = self.changes.iter().map(ToString::to_string).collect();
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+/// Appends the [`GenomeChange`]s between two aligned [`Gene`]s.
+///
+/// # Parameters
+///
+/// * `gene` - the indices of the [`Gene`] before and after
+/// * `before` - the earlier [`Gene`]
+/// * `after` - the later [`Gene`]
+/// * `changes` - the [`GenomeChange`]s to append to
+///
+/// [`Gene`]: ./struct.Gene.html
+/// [`GenomeChange`]: ./enum.GenomeChange.html
+fn diff_genes<R: Reaction<T>, S: State<T>, T: Information>(
+    gene: (usize, usize),
+    before: &Gene<R, S, T>,
+    after: &Gene<R, S, T>,
+    changes: &mut Vec<GenomeChange<R, S, T>>,
+) {
+    for substrate in 0..before.substrates.len().max(after.substrates.len()) {
+        match (before.substrates.get(substrate), after.substrates.get(substrate)) {
+            (Some(earlier), Some(later)) if earlier != later => {
+                changes.push(GenomeChange::SubstrateModified {
+                    gene,
+                    substrate,
+                    before: earlier.clone(),
+                    after: later.clone(),
+                })
+            },
+            (Some(removed), None) => changes.push(GenomeChange::SubstrateRemoved {
+                gene,
+                substrate,
+                removed: removed.clone(),
+            }),
+            (None, Some(added)) => changes.push(GenomeChange::SubstrateAdded {
+                gene,
+                substrate,
+                added: added.clone(),
+            }),
+            _ => {},
+        }
+    }
+    for receptor in 0..before.receptors.len().max(after.receptors.len()) {
+        match (before.receptors.get(receptor), after.receptors.get(receptor)) {
+            (Some(earlier), Some(later)) => diff_receptors(gene, receptor, earlier, later, changes),
+            (Some(removed), None) => changes.push(GenomeChange::ReceptorRemoved {
+                gene,
+                receptor,
+                removed: removed.clone(),
+            }),
+            (None, Some(added)) => changes.push(GenomeChange::ReceptorAdded {
+                gene,
+                receptor,
+                added: added.clone(),
+            }),
+            (None, None) => {},
+        }
+    }
+}
+
+/// Appends the [`GenomeChange`]s between two [`GenomicReceptor`]s at the same position.
+///
+/// # Parameters
+///
+/// * `gene` - the indices of the [`Gene`] before and after
+/// * `receptor` - the index of the [`GenomicReceptor`]
+/// * `before` - the earlier [`GenomicReceptor`]
+/// * `after` - the later [`GenomicReceptor`]
+/// * `changes` - the [`GenomeChange`]s to append to
+///
+/// [`Gene`]: ./struct.Gene.html
+/// [`GenomeChange`]: ./enum.GenomeChange.html
+/// [`GenomicReceptor`]: ./struct.GenomicReceptor.html
+fn diff_receptors<R: Reaction<T>, S: State<T>, T: Information>(
+    gene: (usize, usize),
+    receptor: usize,
+    before: &GenomicReceptor<R, S, T>,
+    after: &GenomicReceptor<R, S, T>,
+    changes: &mut Vec<GenomeChange<R, S, T>>,
+) {
+    if before.triggers != after.triggers {
+        changes.push(GenomeChange::TriggersModified {
+            gene,
+            receptor,
+            before: before.triggers.clone(),
+            after: after.triggers.clone(),
+        });
+    }
+    if before.state != after.state {
+        changes.push(GenomeChange::StateModified {
+            gene,
+            receptor,
+            before: before.state.clone(),
+            after: after.state.clone(),
+        });
+    }
+    if before.substrates != after.substrates {
+        changes.push(GenomeChange::StateSubstratesModified {
+            gene,
+            receptor,
+            before: before.substrates.clone(),
+            after: after.substrates.clone(),
+        });
+    }
+    if before.enzyme.educts != after.enzyme.educts {
+        changes.push(GenomeChange::EductsModified {
+            gene,
+            receptor,
+            before: before.enzyme.educts.clone(),
+            after: after.enzyme.educts.clone(),
+        });
+    }
+    if before.enzyme.products != after.enzyme.products {
+        changes.push(GenomeChange::ProductsModified {
+            gene,
+            receptor,
+            before: before.enzyme.products.clone(),
+            after: after.enzyme.products.clone(),
+        });
+    }
+    if before.enzyme.reaction != after.enzyme.reaction {
+        changes.push(GenomeChange::ReactionModified {
+            gene,
+            receptor,
+            before: before.enzyme.reaction.clone(),
+            after: after.enzyme.reaction.clone(),
+        });
+    }
+}
+
+/// Aligns two sequences along their longest common subsequence of equal elements.
+/// Unequal elements between two equal ones are paired in order, surplus elements are
+/// paired with `None`. Returns the index pairs of the alignment in order.
+///
+/// # Parameters
+///
+/// * `before` - the earlier sequence
+/// * `after` - the later sequence
+fn align<T: PartialEq>(before: &[T], after: &[T]) -> Vec<(Option<usize>, Option<usize>)> {
+    // lengths[i][j] is the length of the longest common subsequence of before[i..] and after[j..]
+    let mut lengths = vec![vec![0usize; after.len() + 1]; before.len() + 1];
+    for i in (0..before.len()).rev() {
+        for j in (0..after.len()).rev() {
+            lengths[i][j] = if before[i] == after[j] {
+                lengths[i + 1][j + 1] + 1
+            } else {
+                lengths[i + 1][j].max(lengths[i][j + 1])
+            };
+        }
+    }
+    let mut alignment = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    let (mut unmatched_before, mut unmatched_after) = (Vec::new(), Vec::new());
+    loop {
+        let matched = i < before.len() && j < after.len() && before[i] == after[j];
+        if matched || (i == before.len() && j == after.len()) {
+            // Pair all unmatched elements since the last match.
+            for k in 0..unmatched_before.len().max(unmatched_after.len()) {
+                alignment.push((unmatched_before.get(k).copied(), unmatched_after.get(k).copied()));
+            }
+            unmatched_before.clear();
+            unmatched_after.clear();
+            if !matched {
+                return alignment;
+            }
+            alignment.push((Some(i), Some(j)));
+            i += 1;
+            j += 1;
+        } else if j == after.len() || (i < before.len() && lengths[i + 1][j] >= lengths[i][j + 1]) {
+            unmatched_before.push(i);
+            i += 1;
+        } else {
+            unmatched_after.push(j);
+            j += 1;
+        }
+    }
+}
+
+/// Returns the positions and values of all differing sensor mappings.
+///
+/// # Parameters
+///
+/// * `before` - the earlier mappings
+/// * `after` - the later mappings
+fn diff_mappings(
+    before: &[Option<GeneSubstrate>],
+    after: &[Option<GeneSubstrate>],
+) -> Vec<(usize, Option<GeneSubstrate>, Option<GeneSubstrate>)> {
+    (0..before.len().max(after.len()))
+        .map(|index| {
+            (index, before.get(index).copied().flatten(), after.get(index).copied().flatten())
+        })
+        .filter(|(_, before, after)| before != after)
+        .collect()
+}
+
+/// Returns the feedback indices and values of all differing feedback mappings
+/// ordered by feedback index.
+///
+/// # Parameters
+///
+/// * `before` - the earlier feedback mappings
+/// * `after` - the later feedback mappings
+fn diff_feedback(
+    before: &HashMap<usize, GeneSubstrate>,
+    after: &HashMap<usize, GeneSubstrate>,
+) -> Vec<(usize, Option<GeneSubstrate>, Option<GeneSubstrate>)> {
+    let indices: BTreeSet<&usize> = before.keys().chain(after.keys()).collect();
+    indices
+        .into_iter()
+        .map(|index| (*index, before.get(index).copied(), after.get(index).copied()))
+        .filter(|(_, before, after)| before != after)
+        .collect()
+}
+
+/// Describes the [`Gene`] indices before and after.
+///
+/// # Parameters
+///
+/// * `gene` - the indices of the [`Gene`] before and after
+///
+/// [`Gene`]: ./struct.Gene.html
+fn gene_label(gene: &(usize, usize)) -> String {
+    if gene.0 == gene.1 {
+        format!("gene {}", gene.0)
+    } else {
+        format!("gene {} (now {})", gene.0, gene.1)
+    }
+}
+
+/// Describes a [`GenomicReceptor`].
+///
+/// # Parameters
+///
+/// * `receptor` - the [`GenomicReceptor`] to describe
+///
+/// [`GenomicReceptor`]: ./struct.GenomicReceptor.html
+fn receptor_label<R: Reaction<T>, S: State<T>, T: Information>(
+    receptor: &GenomicReceptor<R, S, T>,
+) -> String {
+    format!(
+        "triggers {:?}, state {} on {:?}, reaction {} of {:?} into {:?}",
+        receptor.triggers,
+        receptor.state.label(),
+        receptor.substrates,
+        receptor.enzyme.reaction.label(),
+        receptor.enzyme.educts,
+        receptor.enzyme.products
+    )
+}
+
+/// Describes a [`GeneAssociation`].
+///
+/// # Parameters
+///
+/// * `association` - the [`GeneAssociation`] to describe
+///
+/// [`GeneAssociation`]: ./struct.GeneAssociation.html
+fn association_label<T: Information>(association: &GeneAssociation<T>) -> String {
+    let associations: Vec<String> = association
+        .associations
+        .iter()
+        .map(|substrate| format!("{}.{}", substrate.gene(), substrate.substrate()))
+        .collect();
+    format!("{} shared by [{}]", association.substrate.label(), associations.join(", "))
+}
+
+/// Describes an optional mapping to a [`GeneSubstrate`].
+///
+/// # Parameters
+///
+/// * `mapping` - the mapped [`GeneSubstrate`] if any
+///
+/// [`GeneSubstrate`]: ./struct.GeneSubstrate.html
+fn mapping_label(mapping: &Option<GeneSubstrate>) -> String {
+    mapping.map_or_else(
+        || "none".to_string(),
+        |substrate| format!("gene {} substrate {}", substrate.gene(), substrate.substrate()),
+    )
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,172 @@
+use std::num::NonZeroU32;
+
+use crate::evolution::gene::{GenomicCatalyticCentre, GenomicInputSensor, GenomicOutputSensor};
+use crate::evolution::helper::testing::{
+    TestGenome, TestInformation, TestInput, TestOutput, TestReaction, TestState,
+};
+use crate::evolution::neuron::{random_genome, SimpleDendriteThreshold};
+
+use super::*;
+
+/// Creates a [`Gene`] for testing purposes with the specified substrate values and a
+/// receptor triggered by the first substrate.
+fn test_gene(values: &[usize]) -> Gene<TestReaction, TestState, TestInformation> {
+    let mut gene = Gene::new(
+        values
+            .iter()
+            .map(|value| TestInformation { value: *value })
+            .collect(),
+    );
+    gene.add_receptor(GenomicReceptor::new(
+        vec![0],
+        vec![],
+        TestState {},
+        GenomicCatalyticCentre::new(vec![], vec![], TestReaction {}),
+    ));
+    gene
+}
+
+/// Creates a [`TestGenome`] with the specified genes and an input mapped to the first
+/// substrate of the first gene.
+fn test_genome(genes: Vec<Gene<TestReaction, TestState, TestInformation>>) -> TestGenome {
+    Genome::new(
+        GenomicInputSensor::new(
+            vec![Some(GeneSubstrate::new(0, 0))],
+            HashMap::new(),
+            TestInput::default(),
+        ),
+        GenomicOutputSensor::new(vec![None], HashMap::new(), None, TestOutput::default()),
+        genes,
+    )
+}
+
+#[test]
+/// Tests if the function `between` of the [`GenomeDiff`] struct correctly aligns genes
+/// and detects added, removed and modified genes and substrates.
+fn test_genome_diff_between_genes() {
+    let before = test_genome(vec![test_gene(&[0]), test_gene(&[1]), test_gene(&[2, 3])]);
+    assert!(GenomeDiff::between(&before, &before).is_empty());
+    assert_eq!(GenomeDiff::between(&before, &before).to_string(), "The genomes are identical.");
+    let after = test_genome(vec![test_gene(&[0]), test_gene(&[2, 4, 5]), test_gene(&[6])]);
+    let diff = GenomeDiff::between(&before, &after);
+    assert_eq!(
+        diff.changes(),
+        &[
+            GenomeChange::SubstrateModified {
+                gene: (1, 1),
+                substrate: 0,
+                before: TestInformation { value: 1 },
+                after: TestInformation { value: 2 },
+            },
+            GenomeChange::SubstrateAdded {
+                gene: (1, 1),
+                substrate: 1,
+                added: TestInformation { value: 4 },
+            },
+            GenomeChange::SubstrateAdded {
+                gene: (1, 1),
+                substrate: 2,
+                added: TestInformation { value: 5 },
+            },
+            GenomeChange::SubstrateModified {
+                gene: (2, 2),
+                substrate: 0,
+                before: TestInformation { value: 2 },
+                after: TestInformation { value: 6 },
+            },
+            GenomeChange::SubstrateRemoved {
+                gene: (2, 2),
+                substrate: 1,
+                removed: TestInformation { value: 3 },
+            },
+        ]
+    );
+    // Removing a gene in the middle must not shift the comparison of subsequent genes.
+    let after = test_genome(vec![test_gene(&[0]), test_gene(&[2, 3])]);
+    let diff = GenomeDiff::between(&before, &after);
+    assert_eq!(
+        diff.changes(),
+        &[GenomeChange::GeneRemoved {
+            gene: 1,
+            removed: test_gene(&[1]),
+        }]
+    );
+    assert_eq!(diff.to_string(), "- gene 1 with 1 substrates and 1 receptors");
+    let diff = GenomeDiff::between(&after, &before);
+    assert_eq!(
+        diff.changes(),
+        &[GenomeChange::GeneAdded {
+            gene: 1,
+            added: test_gene(&[1]),
+        }]
+    );
+}
+
+#[test]
+/// Tests if the function `between` of the [`GenomeDiff`] struct correctly detects changes
+/// of receptors, associations, sensor mappings and rate modifiers.
+fn test_genome_diff_between_structure() {
+    let before = test_genome(vec![test_gene(&[0, 1])]);
+    let mut after = before.clone();
+    let gene = after.get_gene_mut(0);
+    gene.receptor_mut(0).unwrap().add_trigger(1);
+    gene.add_receptor(GenomicReceptor::new(
+        vec![1],
+        vec![],
+        TestState {},
+        GenomicCatalyticCentre::new(vec![], vec![], TestReaction {}),
+    ));
+    let mut association = GeneAssociation::new(TestInformation { value: 7 });
+    association.add_association(GeneSubstrate::new(0, 1));
+    after.add_association(association);
+    after
+        .input_mut()
+        .set_input_substrate(0, Some(GeneSubstrate::new(0, 1)));
+    after
+        .output_mut()
+        .add_feedback_substrate(2, GeneSubstrate::new(0, 0));
+    after.set_rate_modifiers(RateModifiers::new(vec![2.0]));
+    let diff = GenomeDiff::between(&before, &after);
+    let text = diff.to_string();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(diff.changes().len(), 6);
+    assert_eq!(lines.len(), 6);
+    assert_eq!(
+        diff.changes()[0],
+        GenomeChange::TriggersModified {
+            gene: (0, 0),
+            receptor: 0,
+            before: vec![0],
+            after: vec![0, 1],
+        }
+    );
+    assert_eq!(lines[0], "~ gene 0 receptor 0 triggers: [0] -> [0, 1]");
+    assert!(lines[1].starts_with("+ gene 0 receptor 1: triggers [1]"));
+    assert!(lines[2].starts_with("+ association 0: "));
+    assert_eq!(lines[3], "~ input 0: gene 0 substrate 0 -> gene 0 substrate 1");
+    assert_eq!(lines[4], "~ output feedback 2: none -> gene 0 substrate 0");
+    assert_eq!(lines[5], "~ rate modifiers: [] -> [2.0]");
+}
+
+#[test]
+/// Tests if the function `between` of the [`GenomeDiff`] struct correctly renders neuronal
+/// changes and serialises the diff in a machine readable form.
+fn test_genome_diff_neuron() {
+    let before = random_genome(NonZeroU32::new(4).unwrap(), NonZeroU32::new(3).unwrap(), 1, 1);
+    let mut after = before.clone();
+    let receptor = after.get_gene_mut(0).receptor_mut(0).unwrap();
+    let substrates = receptor.substrates().clone();
+    receptor.replace_state(SimpleDendriteThreshold::new(0.25), substrates);
+    let diff = GenomeDiff::between(&before, &after);
+    if before.get_gene(0).receptor(0).unwrap().state() == &SimpleDendriteThreshold::new(0.25) {
+        assert!(diff.is_empty());
+        return;
+    }
+    assert_eq!(diff.changes().len(), 1);
+    let text = diff.to_string();
+    assert!(text.starts_with("~ gene 0 receptor 0 state: threshold "));
+    assert!(text.ends_with(" -> threshold 0.250"));
+    let json = serde_json::to_string(&diff).unwrap();
+    assert!(json.contains("StateModified"));
+    assert_eq!(serde_json::from_str::<GenomeDiff<_, _, _>>(&json).unwrap(), diff);
+}
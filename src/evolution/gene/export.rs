@@ -0,0 +1,799 @@
+//! The `export` module contains the human readable representation of a
+//! [`Genome`](crate::evolution::gene::Genome), which can be reviewed and edited by hand.
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::error::Error;
+use std::fs::File;
+use std::io::{Read, Write};
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::evolution::chemistry::{Information, Input, Output, Reaction, State};
+use crate::evolution::helper::Cache;
+
+use super::{
+    has_substrate, Gene, GeneAssociation, GeneSubstrate, Genome, GenomicCatalyticCentre,
+    GenomicInputSensor, GenomicOutputSensor, GenomicReceptor, RateModifiers,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+/// A `GenomeFormat` is a human readable file format a [`Genome`] can be exported to and
+/// imported from.
+///
+/// [`Genome`]: ./struct.Genome.html
+pub enum GenomeFormat {
+    /// The JSON format with the file extension `json`.
+    Json,
+    /// The Rusty Object Notation with the file extension `ron`.
+    Ron,
+}
+
+impl GenomeFormat {
+    /// Returns the `GenomeFormat` matching the extension of the specified file if it is
+    /// supported.
+    ///
+    /// # Parameters
+    ///
+    /// * `path_to_file` - the file to determine the format of
+    pub fn from_path<P: AsRef<Path>>(path_to_file: P) -> Option<Self> {
+        let extension = path_to_file
+            .as_ref()
+            .extension()?
+            .to_str()?
+            .to_ascii_lowercase();
+        match extension.as_str() {
+            "json" => Some(GenomeFormat::Json),
+            "ron" => Some(GenomeFormat::Ron),
+            _ => None,
+        }
+    }
+
+    /// Returns the `GenomeFormat` matching the extension of the specified file or an error
+    /// if the extension is not supported.
+    ///
+    /// # Parameters
+    ///
+    /// * `path_to_file` - the file to determine the format of
+    fn from_path_or_error<P: AsRef<Path>>(path_to_file: P) -> Result<Self, Box<dyn Error>> {
+        Self::from_path(&path_to_file).ok_or_else(|| {
+            format!(
+                "The format of {} is not supported. Use a json or ron file instead.",
+                path_to_file.as_ref().display()
+            )
+            .into()
+        })
+    }
+}
+
+/// The [`Genome`] converted from a human readable representation or a description of every
+/// problem of the representation.
+///
+/// [`Genome`]: ./struct.Genome.html
+type ImportResult<
+    ReactionType,
+    StateType,
+    InformationType,
+    InputElementType,
+    InputSensorType,
+    OutputElementType,
+    OutputSensorType,
+> = Result<
+    Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+    Vec<String>,
+>;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+/// A `GenomeImportError` is returned when a human readable [`Genome`] could not be parsed
+/// or contains invalid references.
+///
+/// [`Genome`]: ./struct.Genome.html
+pub struct GenomeImportError {
+    path: Option<PathBuf>,
+    location: Option<(usize, usize)>,
+    problems: Vec<String>,
+}
+
+impl GenomeImportError {
+    /// Creates a `GenomeImportError` for content that could not be parsed.
+    ///
+    /// # Parameters
+    ///
+    /// * `line` - the line of the error starting at 1
+    /// * `column` - the column of the error starting at 1
+    /// * `message` - the description of the error
+    fn syntax<M: Into<String>>(line: usize, column: usize, message: M) -> Self {
+        GenomeImportError {
+            path: None,
+            location: Some((line, column)),
+            problems: vec![message.into()],
+        }
+    }
+
+    /// Creates a `GenomeImportError` for content containing invalid references.
+    ///
+    /// # Parameters
+    ///
+    /// * `problems` - the description of every invalid reference
+    fn invalid(problems: Vec<String>) -> Self {
+        GenomeImportError {
+            path: None,
+            location: None,
+            problems,
+        }
+    }
+
+    /// Sets the file the content was read from.
+    ///
+    /// # Parameters
+    ///
+    /// * `path` - the file the content was read from
+    fn with_path<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.path = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Returns the file the content was read from if any.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Returns the line and column of a syntax error if any.
+    pub fn location(&self) -> Option<(usize, usize)> {
+        self.location
+    }
+
+    /// Returns the description of every problem.
+    pub fn problems(&self) -> &[String] {
+        &self.problems
+    }
+}
+
+impl std::fmt::Display for GenomeImportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut prefix = self
+            .path
+            .as_ref()
+            .map(|path| path.display().to_string())
+            .unwrap_or_default();
+        if let Some((line, column)) = self.location {
+            if !prefix.is_empty() {
+                prefix.push(':');
+            }
+            prefix.push_str(&format!("{}:{}", line, column));
+        }
+        if !prefix.is_empty() {
+            write!(f, "{}: ", prefix)?;
+        }
+        match self.problems.as_slice() {
+            [problem] => write!(f, "{}", problem),
+            problems => {
+                write!(f, "The genome is invalid:")?;
+                for problem in problems {
+                    write!(f, "\n  * {}", problem)?;
+                }
+                Ok(())
+            },
+        }
+    }
+}
+
+impl Error for GenomeImportError {}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// The human readable representation of a [`Genome`].
+///
+/// [`Genome`]: ./struct.Genome.html
+struct GenomeDocument<ReactionType, StateType, InformationType, InputSensorType, OutputSensorType> {
+    input: InputDocument<InputSensorType>,
+    output: OutputDocument<OutputSensorType>,
+    genes: Vec<GeneDocument<ReactionType, StateType, InformationType>>,
+    #[serde(default = "Vec::new")]
+    associations: Vec<AssociationDocument<InformationType>>,
+    #[serde(default)]
+    rate_modifiers: Vec<f64>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// The human readable representation of a [`GenomicInputSensor`].
+///
+/// [`GenomicInputSensor`]: ./struct.GenomicInputSensor.html
+struct InputDocument<InputSensorType> {
+    substrates: Vec<Option<GeneSubstrate>>,
+    #[serde(default)]
+    feedback: BTreeMap<usize, GeneSubstrate>,
+    sensor: InputSensorType,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// The human readable representation of a [`GenomicOutputSensor`].
+///
+/// [`GenomicOutputSensor`]: ./struct.GenomicOutputSensor.html
+struct OutputDocument<OutputSensorType> {
+    substrates: Vec<Option<GeneSubstrate>>,
+    #[serde(default)]
+    feedback: BTreeMap<usize, GeneSubstrate>,
+    #[serde(default)]
+    finish: Option<GeneSubstrate>,
+    sensor: OutputSensorType,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// The human readable representation of a [`Gene`] annotated with its index.
+///
+/// [`Gene`]: ./struct.Gene.html
+struct GeneDocument<ReactionType, StateType, InformationType> {
+    #[serde(default)]
+    index: Option<usize>,
+    substrates: Vec<SubstrateDocument<InformationType>>,
+    #[serde(default = "Vec::new")]
+    receptors: Vec<ReceptorDocument<ReactionType, StateType>>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// The human readable representation of a substrate annotated with its index.
+struct SubstrateDocument<InformationType> {
+    #[serde(default)]
+    index: Option<usize>,
+    value: InformationType,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// The human readable representation of a [`GenomicReceptor`] and its
+/// [`GenomicCatalyticCentre`] annotated with its index.
+///
+/// [`GenomicCatalyticCentre`]: ./struct.GenomicCatalyticCentre.html
+/// [`GenomicReceptor`]: ./struct.GenomicReceptor.html
+struct ReceptorDocument<ReactionType, StateType> {
+    #[serde(default)]
+    index: Option<usize>,
+    triggers: Vec<usize>,
+    substrates: Vec<usize>,
+    state: StateType,
+    educts: Vec<usize>,
+    products: Vec<usize>,
+    reaction: ReactionType,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// The human readable representation of a [`GeneAssociation`] annotated with its index.
+///
+/// [`GeneAssociation`]: ./struct.GeneAssociation.html
+struct AssociationDocument<InformationType> {
+    #[serde(default)]
+    index: Option<usize>,
+    substrate: InformationType,
+    associations: Vec<GeneSubstrate>,
+}
+
+impl<
+        ReactionType: Reaction<InformationType>,
+        StateType: State<InformationType>,
+        InformationType: Information,
+        InputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+        InputSensorType: Input<InputElementType, InformationType>,
+        OutputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+        OutputSensorType: Output<OutputElementType, InformationType>,
+    >
+    Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >
+{
+    /// Exports the `Genome` to pretty printed human readable content, where genes,
+    /// substrates, receptors and associations are annotated with their index.
+    /// An error will be returned if serialisation failed.
+    ///
+    /// # Parameters
+    ///
+    /// * `format` - the [`GenomeFormat`] of the content
+    ///
+    /// [`GenomeFormat`]: ./enum.GenomeFormat.html
+    pub fn export(&self, format: GenomeFormat) -> Result<String, Box<dyn Error>> {
+        let document = GenomeDocument::from_genome(self);
+        Ok(match format {
+            GenomeFormat::Json => serde_json::to_string_pretty(&document)?,
+            GenomeFormat::Ron => {
+                ron::ser::to_string_pretty(&document, ron::ser::PrettyConfig::default())?
+            },
+        })
+    }
+
+    /// Imports a `Genome` from human readable content as created by [`export`].
+    /// Index annotations are optional, but must match the position if present.
+    /// All substrate references of receptors, associations and sensors are checked like
+    /// during sensor validation, as well as the number of substrates required by every
+    /// [`State`] and [`Reaction`].
+    /// A [`GenomeImportError`] listing every problem will be returned if parsing failed or
+    /// the content is invalid.
+    ///
+    /// # Parameters
+    ///
+    /// * `content` - the content to parse
+    /// * `format` - the [`GenomeFormat`] of the content
+    ///
+    /// [`export`]: #method.export
+    /// [`GenomeFormat`]: ./enum.GenomeFormat.html
+    /// [`GenomeImportError`]: ./struct.GenomeImportError.html
+    /// [`Reaction`]: ../chemistry/trait.Reaction.html
+    /// [`State`]: ../chemistry/trait.State.html
+    pub fn import(content: &str, format: GenomeFormat) -> Result<Self, GenomeImportError> {
+        let document: GenomeDocument<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputSensorType,
+            OutputSensorType,
+        > = match format {
+            GenomeFormat::Json => serde_json::from_str(content).map_err(|err| {
+                // The location is reported separately.
+                let message = err.to_string();
+                let message = message.split(" at line ").next().unwrap_or_default();
+                GenomeImportError::syntax(err.line(), err.column(), message)
+            })?,
+            GenomeFormat::Ron => ron::from_str(content).map_err(|err| {
+                GenomeImportError::syntax(err.position.line, err.position.col, err.code.to_string())
+            })?,
+        };
+        document.into_genome().map_err(GenomeImportError::invalid)
+    }
+
+    /// Exports the `Genome` to a human readable file in the format matching its extension
+    /// as described in [`export`].
+    /// An error will be returned if the format is not supported or writing to the file failed.
+    ///
+    /// # Parameters
+    ///
+    /// * `path_to_file` - the `json` or `ron` file the `Genome` should be written to
+    ///
+    /// [`export`]: #method.export
+    pub fn export_to_file<P: AsRef<Path>>(&self, path_to_file: P) -> Result<(), Box<dyn Error>> {
+        let content = self.export(GenomeFormat::from_path_or_error(&path_to_file)?)?;
+        let mut file = File::create(path_to_file)?;
+        file.write_all(content.as_bytes())?;
+        Ok(file.sync_all()?)
+    }
+
+    /// Imports a `Genome` from a human readable file in the format matching its extension
+    /// as described in [`import`].
+    /// An error will be returned if the format is not supported, reading the file failed
+    /// or the content is invalid.
+    ///
+    /// # Parameters
+    ///
+    /// * `path_to_file` - the `json` or `ron` file from which the `Genome` should be loaded
+    ///
+    /// [`import`]: #method.import
+    pub fn import_from_file<P: AsRef<Path>>(path_to_file: P) -> Result<Self, Box<dyn Error>> {
+        let format = GenomeFormat::from_path_or_error(&path_to_file)?;
+        let mut content = String::new();
+        File::open(&path_to_file)?.read_to_string(&mut content)?;
+        Ok(Self::import(&content, format).map_err(|err| err.with_path(&path_to_file))?)
+    }
+}
+
+impl<
+        ReactionType: Reaction<InformationType>,
+        StateType: State<InformationType>,
+        InformationType: Information,
+        InputSensorType: Clone + PartialEq,
+        OutputSensorType: Clone + PartialEq,
+    > GenomeDocument<ReactionType, StateType, InformationType, InputSensorType, OutputSensorType>
+{
+    /// Creates the human readable representation of the specified [`Genome`].
+    ///
+    /// # Parameters
+    ///
+    /// * `genome` - the [`Genome`] to represent
+    ///
+    /// [`Genome`]: ./struct.Genome.html
+    fn from_genome<
+        InputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+        OutputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    >(
+        genome: &Genome<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    ) -> Self
+    where
+        InputSensorType: Input<InputElementType, InformationType>,
+        OutputSensorType: Output<OutputElementType, InformationType>,
+    {
+        GenomeDocument {
+            input: InputDocument {
+                substrates: genome.input.input_substrates().clone(),
+                feedback: genome
+                    .input
+                    .feedback_substrates()
+                    .clone()
+                    .into_iter()
+                    .collect(),
+                sensor: genome.input.input().clone(),
+            },
+            output: OutputDocument {
+                substrates: genome.output.output_substrates().clone(),
+                feedback: genome
+                    .output
+                    .feedback_substrates()
+                    .clone()
+                    .into_iter()
+                    .collect(),
+                finish: *genome.output.finish_substrate(),
+                sensor: genome.output.output().clone(),
+            },
+            genes: genome
+                .genes
+                .iter()
+                .enumerate()
+                .map(|(index, gene)| GeneDocument {
+                    index: Some(index),
+                    substrates: gene
+                        .substrates
+                        .iter()
+                        .enumerate()
+                        .map(|(index, value)| SubstrateDocument {
+                            index: Some(index),
+                            value: value.clone(),
+                        })
+                        .collect(),
+                    receptors: gene
+                        .receptors
+                        .iter()
+                        .enumerate()
+                        .map(|(index, receptor)| ReceptorDocument {
+                            index: Some(index),
+                            triggers: receptor.triggers.clone(),
+                            substrates: receptor.substrates.clone(),
+                            state: receptor.state.clone(),
+                            educts: receptor.enzyme.educts.clone(),
+                            products: receptor.enzyme.products.clone(),
+                            reaction: receptor.enzyme.reaction.clone(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+            associations: genome
+                .associations
+                .iter()
+                .enumerate()
+                .map(|(index, association)| AssociationDocument {
+                    index: Some(index),
+                    substrate: association.substrate.clone(),
+                    associations: association.associations.clone(),
+                })
+                .collect(),
+            rate_modifiers: genome.rate_modifiers.modifiers().to_vec(),
+        }
+    }
+
+    /// Converts the human readable representation into a [`Genome`] and returns a
+    /// description of every problem if the representation is invalid.
+    ///
+    /// [`Genome`]: ./struct.Genome.html
+    fn into_genome<
+        InputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+        OutputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    >(
+        self,
+    ) -> ImportResult<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >
+    where
+        InputSensorType: Input<InputElementType, InformationType>,
+        OutputSensorType: Output<OutputElementType, InformationType>,
+    {
+        let mut problems = Vec::new();
+        let mut check_index = |index: Option<usize>, position: usize, description: String| {
+            if let Some(index) = index.filter(|index| *index != position) {
+                problems.push(format!(
+                    "{} is annotated with index {}, but is located at index {}.",
+                    description, index, position
+                ));
+            }
+        };
+        for (gene_index, gene) in self.genes.iter().enumerate() {
+            check_index(gene.index, gene_index, format!("Gene {}", gene_index));
+            for (index, substrate) in gene.substrates.iter().enumerate() {
+                check_index(
+                    substrate.index,
+                    index,
+                    format!("Substrate {} of gene {}", index, gene_index),
+                );
+            }
+            for (index, receptor) in gene.receptors.iter().enumerate() {
+                check_index(
+                    receptor.index,
+                    index,
+                    format!("Receptor {} of gene {}", index, gene_index),
+                );
+            }
+        }
+        for (index, association) in self.associations.iter().enumerate() {
+            check_index(association.index, index, format!("Association {}", index));
+        }
+        if self.genes.is_empty() {
+            problems.push("The genome must contain at least 1 gene.".to_string());
+        }
+        for (gene_index, gene) in self.genes.iter().enumerate() {
+            let number_of_substrates = gene.substrates.len();
+            if number_of_substrates == 0 {
+                problems.push(format!("Gene {} must contain at least 1 substrate.", gene_index));
+            }
+            for (index, receptor) in gene.receptors.iter().enumerate() {
+                let description = format!("Receptor {} of gene {}", index, gene_index);
+                check_substrate_number(
+                    &mut problems,
+                    &description,
+                    "substrates",
+                    receptor.substrates.len(),
+                    receptor.state.get_substrate_number(),
+                );
+                check_substrate_number(
+                    &mut problems,
+                    &description,
+                    "educts",
+                    receptor.educts.len(),
+                    receptor.reaction.get_educt_number(),
+                );
+                check_substrate_number(
+                    &mut problems,
+                    &description,
+                    "products",
+                    receptor.products.len(),
+                    receptor.reaction.get_product_number(),
+                );
+                let references = receptor
+                    .triggers
+                    .iter()
+                    .chain(&receptor.substrates)
+                    .chain(&receptor.educts)
+                    .chain(&receptor.products);
+                for reference in references.filter(|reference| **reference >= number_of_substrates)
+                {
+                    problems.push(format!(
+                        "{} references substrate {}, but the gene only contains {} substrates.",
+                        description, reference, number_of_substrates
+                    ));
+                }
+            }
+        }
+        let genes: Vec<Gene<ReactionType, StateType, InformationType>> = self
+            .genes
+            .into_iter()
+            .map(|gene| Gene {
+                phantom_r: PhantomData,
+                phantom_s: PhantomData,
+                phantom_t: PhantomData,
+                substrates: gene
+                    .substrates
+                    .into_iter()
+                    .map(|substrate| substrate.value)
+                    .collect(),
+                receptors: gene
+                    .receptors
+                    .into_iter()
+                    .map(|receptor| GenomicReceptor {
+                        phantom_r: PhantomData,
+                        phantom_t: PhantomData,
+                        triggers: receptor.triggers,
+                        substrates: receptor.substrates,
+                        state: receptor.state,
+                        enzyme: GenomicCatalyticCentre {
+                            phantom_s: PhantomData,
+                            phantom_t: PhantomData,
+                            educts: receptor.educts,
+                            products: receptor.products,
+                            reaction: receptor.reaction,
+                        },
+                    })
+                    .collect(),
+                binary_size: Cache::default(),
+            })
+            .collect();
+        for (index, association) in self.associations.iter().enumerate() {
+            for reference in association
+                .associations
+                .iter()
+                .filter(|reference| !has_substrate(&genes, reference))
+            {
+                problems.push(format!(
+                    "Association {} references the non-existent substrate {:?}.",
+                    index, reference
+                ));
+            }
+        }
+        // The sensors are validated the same way as after recombination and every
+        // reference to a non-existent substrate is reported. Duplicate references are kept,
+        // since they are valid parts of unvalidated genomes, e.g. random ones.
+        let input = GenomicInputSensor::new(
+            self.input.substrates,
+            self.input.feedback.into_iter().collect(),
+            self.input.sensor,
+        );
+        let mut validated_input = input.clone();
+        validated_input.validate(&genes);
+        check_sensor_substrates(
+            &mut problems,
+            "input",
+            input.input_substrates(),
+            validated_input.input_substrates(),
+        );
+        check_feedback_substrates(
+            &mut problems,
+            "input",
+            input.feedback_substrates(),
+            validated_input.feedback_substrates(),
+        );
+        let output = GenomicOutputSensor::new(
+            self.output.substrates,
+            self.output.feedback.into_iter().collect(),
+            self.output.finish,
+            self.output.sensor,
+        );
+        let mut validated_output = output.clone();
+        validated_output.validate(&genes);
+        check_sensor_substrates(
+            &mut problems,
+            "output",
+            output.output_substrates(),
+            validated_output.output_substrates(),
+        );
+        check_feedback_substrates(
+            &mut problems,
+            "output",
+            output.feedback_substrates(),
+            validated_output.feedback_substrates(),
+        );
+        if let Some(finish) = output.finish_substrate() {
+            if validated_output.finish_substrate().is_none() {
+                problems.push(format!(
+                    "The finish sensor references the non-existent substrate {:?}.",
+                    finish
+                ));
+            }
+        }
+        if self
+            .rate_modifiers
+            .iter()
+            .any(|modifier| !(modifier.is_finite() && *modifier > 0.0))
+        {
+            problems.push("Rate modifiers must be positive finite numbers.".to_string());
+        }
+        if !problems.is_empty() {
+            return Err(problems);
+        }
+        Ok(Genome {
+            phantom_reaction: PhantomData,
+            phantom_state: PhantomData,
+            phantom_information: PhantomData,
+            phantom_input_element: PhantomData,
+            phantom_input_sensor: PhantomData,
+            phantom_output_element: PhantomData,
+            phantom_output_sensor: PhantomData,
+            input,
+            output,
+            genes,
+            associations: self
+                .associations
+                .into_iter()
+                .map(|association| GeneAssociation {
+                    substrate: association.substrate,
+                    associations: association.associations,
+                })
+                .collect(),
+            rate_modifiers: RateModifiers::new(self.rate_modifiers),
+        })
+    }
+}
+
+/// Records a problem for every sensor reference to a non-existent substrate, which is
+/// removed by the validation of the sensor. Duplicate references, which are removed by the
+/// validation as well, are not recorded.
+///
+/// # Parameters
+///
+/// * `problems` - the problems to record to
+/// * `sensor` - the name of the sensor
+/// * `references` - the references before validation
+/// * `validated` - the references after validation
+fn check_sensor_substrates(
+    problems: &mut Vec<String>,
+    sensor: &str,
+    references: &[Option<GeneSubstrate>],
+    validated: &[Option<GeneSubstrate>],
+) {
+    let mut seen = HashSet::new();
+    for (reference, validated) in references.iter().zip(validated) {
+        if let Some(reference) = reference {
+            let duplicate = !seen.insert(*reference);
+            if validated.is_none() && !duplicate {
+                problems.push(format!(
+                    "The {} sensor references the non-existent substrate {:?}.",
+                    sensor, reference
+                ));
+            }
+        }
+    }
+}
+
+/// Records a problem for every feedback reference removed by the validation of the sensor,
+/// which are references to non-existent substrates.
+///
+/// # Parameters
+///
+/// * `problems` - the problems to record to
+/// * `sensor` - the name of the sensor
+/// * `references` - the feedback references before validation
+/// * `validated` - the feedback references after validation
+fn check_feedback_substrates(
+    problems: &mut Vec<String>,
+    sensor: &str,
+    references: &HashMap<usize, GeneSubstrate>,
+    validated: &HashMap<usize, GeneSubstrate>,
+) {
+    let mut removed: Vec<(&usize, &GeneSubstrate)> = references
+        .iter()
+        .filter(|(association, _)| !validated.contains_key(association))
+        .collect();
+    // Report the problems in a deterministic order.
+    removed.sort_by_key(|(association, _)| **association);
+    for (_, reference) in removed {
+        problems.push(format!(
+            "The {} feedback sensor references the non-existent substrate {:?}.",
+            sensor, reference
+        ));
+    }
+}
+
+/// Records a problem if the number of referenced substrates does not match the required
+/// number.
+///
+/// # Parameters
+///
+/// * `problems` - the problems to record to
+/// * `description` - the description of the referencing element
+/// * `kind` - the kind of the referenced substrates
+/// * `actual` - the number of referenced substrates
+/// * `required` - the required number of substrates
+fn check_substrate_number(
+    problems: &mut Vec<String>,
+    description: &str,
+    kind: &str,
+    actual: usize,
+    required: usize,
+) {
+    if actual != required {
+        problems.push(format!(
+            "{} references {} {}, but {} are required.",
+            description, actual, kind, required
+        ));
+    }
+}
+
+#[cfg(test)]
+mod tests;
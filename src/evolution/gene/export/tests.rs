@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+use std::num::NonZeroU32;
+
+use uuid::Uuid;
+
+use crate::evolution::gene::{Gene, GenomicInputSensor, GenomicOutputSensor};
+use crate::evolution::helper::testing::{
+    TestGenome, TestInformation, TestInput, TestOutput, TestReaction, TestState,
+};
+use crate::evolution::neuron::random_genome;
+
+use super::*;
+
+/// Creates a [`TestGenome`] with the specified input and output references and a single
+/// [`Gene`] containing 2 substrates and a receptor triggered by the specified substrate.
+fn test_genome(
+    input: Option<GeneSubstrate>,
+    output: Option<GeneSubstrate>,
+    trigger: usize,
+) -> TestGenome {
+    let mut gene = Gene::new(vec![TestInformation { value: 0 }, TestInformation { value: 1 }]);
+    gene.add_receptor(GenomicReceptor::new(
+        vec![trigger],
+        vec![],
+        TestState {},
+        GenomicCatalyticCentre::new(vec![], vec![], TestReaction {}),
+    ));
+    Genome::new(
+        GenomicInputSensor::new(vec![input], HashMap::new(), TestInput::default()),
+        GenomicOutputSensor::new(vec![output], HashMap::new(), None, TestOutput::default()),
+        vec![gene],
+    )
+}
+
+#[test]
+/// Tests if the functions `export` and `import` of the [`Genome`] struct correctly roundtrip
+/// a neuronal [`Genome`] in every [`GenomeFormat`].
+fn test_genome_export_import() {
+    let mut genome = random_genome(NonZeroU32::new(8).unwrap(), NonZeroU32::new(12).unwrap(), 3, 2);
+    genome.set_rate_modifiers(RateModifiers::new(vec![0.5, 1.25]));
+    for format in [GenomeFormat::Json, GenomeFormat::Ron] {
+        let content = genome.export(format).unwrap();
+        assert!(content.contains("index"));
+        assert!(content.lines().count() > 1);
+        assert_eq!(Genome::import(&content, format).unwrap(), genome);
+    }
+}
+
+#[test]
+/// Tests if the function `import` of the [`Genome`] struct correctly reports the location
+/// of syntax errors.
+fn test_genome_import_syntax_error() {
+    let content = test_genome(None, None, 0)
+        .export(GenomeFormat::Json)
+        .unwrap()
+        .replacen("\"genes\": [", "\"genes\": [,", 1);
+    let line = content
+        .lines()
+        .position(|line| line.contains("\"genes\""))
+        .unwrap()
+        + 1;
+    let error = TestGenome::import(&content, GenomeFormat::Json).unwrap_err();
+    assert_eq!(error.location().map(|(line, _)| line), Some(line));
+    assert!(error.to_string().starts_with(&format!("{}:", line)));
+    let error = TestGenome::import("(input: ", GenomeFormat::Ron).unwrap_err();
+    assert_eq!(error.location().map(|(line, _)| line), Some(1));
+}
+
+#[test]
+/// Tests if the function `import` of the [`Genome`] struct correctly rejects invalid
+/// references and index annotations.
+fn test_genome_import_invalid() {
+    let valid = test_genome(Some(GeneSubstrate::new(0, 1)), None, 1);
+    let content = valid.export(GenomeFormat::Json).unwrap();
+    assert_eq!(TestGenome::import(&content, GenomeFormat::Json).unwrap(), valid);
+    let invalid = test_genome(Some(GeneSubstrate::new(0, 2)), Some(GeneSubstrate::new(1, 0)), 2);
+    let content = invalid.export(GenomeFormat::Ron).unwrap();
+    let error = TestGenome::import(&content, GenomeFormat::Ron).unwrap_err();
+    assert_eq!(error.location(), None);
+    assert_eq!(error.problems().len(), 3);
+    let content =
+        valid
+            .export(GenomeFormat::Ron)
+            .unwrap()
+            .replacen("index: Some(1)", "index: Some(4)", 1);
+    let error = TestGenome::import(&content, GenomeFormat::Ron).unwrap_err();
+    assert_eq!(error.problems().len(), 1);
+    assert!(error.problems()[0].contains("index 4"));
+}
+
+#[test]
+/// Tests if the function `import` of the [`Genome`] struct correctly rejects sensor
+/// references to non-existent substrates, but keeps duplicate references.
+fn test_genome_import_invalid_sensor() {
+    let mut genome = test_genome(None, None, 1);
+    genome.input = GenomicInputSensor::new(
+        vec![
+            Some(GeneSubstrate::new(0, 0)),
+            Some(GeneSubstrate::new(0, 0)),
+        ],
+        HashMap::from([(0, GeneSubstrate::new(0, 5))]),
+        TestInput::default(),
+    );
+    genome.output = GenomicOutputSensor::new(
+        vec![Some(GeneSubstrate::new(0, 1))],
+        HashMap::new(),
+        Some(GeneSubstrate::new(2, 0)),
+        TestOutput::default(),
+    );
+    let content = genome.export(GenomeFormat::Json).unwrap();
+    let error = TestGenome::import(&content, GenomeFormat::Json).unwrap_err();
+    assert_eq!(error.problems().len(), 2);
+    assert!(error.problems()[0].contains("input feedback"));
+    assert!(error.problems()[1].contains("finish"));
+    genome.input = GenomicInputSensor::new(
+        vec![
+            Some(GeneSubstrate::new(0, 0)),
+            Some(GeneSubstrate::new(0, 0)),
+        ],
+        HashMap::new(),
+        TestInput::default(),
+    );
+    genome.output = GenomicOutputSensor::new(
+        vec![Some(GeneSubstrate::new(0, 1))],
+        HashMap::new(),
+        None,
+        TestOutput::default(),
+    );
+    let content = genome.export(GenomeFormat::Json).unwrap();
+    assert_eq!(TestGenome::import(&content, GenomeFormat::Json).unwrap(), genome);
+}
+
+#[test]
+/// Tests if the functions `export_to_file` and `import_from_file` of the [`Genome`] struct
+/// correctly roundtrip a [`Genome`] and report the file of errors.
+fn test_genome_export_import_file() {
+    let genome = test_genome(Some(GeneSubstrate::new(0, 0)), None, 1);
+    let path = std::env::temp_dir()
+        .join(format!("oben_genome_export_{}.ron", Uuid::from_u128(rand::random())));
+    genome.export_to_file(&path).unwrap();
+    assert_eq!(TestGenome::import_from_file(&path).unwrap(), genome);
+    std::fs::write(&path, "(input: ").unwrap();
+    let error = TestGenome::import_from_file(&path).unwrap_err();
+    std::fs::remove_file(&path).unwrap();
+    assert!(error
+        .to_string()
+        .starts_with(&format!("{}:1:", path.display())));
+    assert!(genome
+        .export_to_file(path.with_extension("genome"))
+        .is_err());
+}
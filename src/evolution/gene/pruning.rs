@@ -0,0 +1,348 @@
+//! The `pruning` module contains the simplification of a
+//! [`Genome`](crate::evolution::gene::Genome) by removal of dead structure, which can never
+//! influence the output of the translated [`Organism`](crate::evolution::population::Organism).
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::evolution::chemistry::{Information, Input, Output, Reaction, State};
+use crate::evolution::environment::Environment;
+
+use super::{GeneSubstrate, Genome};
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
+/// A `PruningReport` summarises the structure removed from a [`Genome`] while pruning.
+///
+/// [`Genome`]: ./struct.Genome.html
+pub struct PruningReport {
+    genes: usize,
+    substrates: usize,
+    receptors: usize,
+    associations: usize,
+}
+
+impl PruningReport {
+    /// Returns the number of removed [`Gene`]s.
+    ///
+    /// [`Gene`]: ./struct.Gene.html
+    pub fn removed_genes(&self) -> usize {
+        self.genes
+    }
+
+    /// Returns the number of removed substrates, excluding those of removed [`Gene`]s.
+    ///
+    /// [`Gene`]: ./struct.Gene.html
+    pub fn removed_substrates(&self) -> usize {
+        self.substrates
+    }
+
+    /// Returns the number of removed [`GenomicReceptor`]s.
+    ///
+    /// [`GenomicReceptor`]: ./struct.GenomicReceptor.html
+    pub fn removed_receptors(&self) -> usize {
+        self.receptors
+    }
+
+    /// Returns the number of removed [`GeneAssociation`]s.
+    ///
+    /// [`GeneAssociation`]: ./struct.GeneAssociation.html
+    pub fn removed_associations(&self) -> usize {
+        self.associations
+    }
+
+    /// Checks if nothing was removed.
+    pub fn is_empty(&self) -> bool {
+        *self == PruningReport::default()
+    }
+
+    /// Adds the removals of the other `PruningReport`.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - the `PruningReport` to add
+    fn add(&mut self, other: PruningReport) {
+        self.genes += other.genes;
+        self.substrates += other.substrates;
+        self.receptors += other.receptors;
+        self.associations += other.associations;
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+/// A substrate of the translated network. Substrates shared by a
+/// [`GeneAssociation`](crate::evolution::gene::GeneAssociation) are translated into a single
+/// substrate.
+enum Node {
+    /// A gene specific substrate.
+    Gene(GeneSubstrate),
+    /// A substrate shared by the association with the specified index.
+    Association(usize),
+}
+
+impl<
+        ReactionType: Reaction<InformationType>,
+        StateType: State<InformationType>,
+        InformationType: Information,
+        InputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+        InputSensorType: Input<InputElementType, InformationType>,
+        OutputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+        OutputSensorType: Output<OutputElementType, InformationType>,
+    >
+    Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >
+{
+    /// Removes all structure that cannot influence the output and returns a summary of the
+    /// removed structure. A static reachability analysis determines all receptors that can
+    /// be triggered by changes of the input substrates and whose products are, directly
+    /// or via further receptors, read by an output, feedback or finish substrate.
+    /// All other receptors are removed as well as all substrates, genes and associations
+    /// that are no longer referenced. A `Genome` always keeps at least one [`Gene`] with at
+    /// least one substrate.
+    ///
+    /// [`Gene`]: ./struct.Gene.html
+    pub fn prune(&mut self) -> PruningReport {
+        let live = self.live_receptors();
+        self.remove_receptors_except(&live)
+    }
+
+    /// Prunes the `Genome` as described for [`prune`](#method.prune) and additionally removes
+    /// all remaining receptors that were never activated while the translated
+    /// [`Organism`] processed any of the specified sample inputs. Without any samples only
+    /// the static pruning is performed.
+    /// Returns a summary of the removed structure.
+    ///
+    /// # Parameters
+    ///
+    /// * `samples` - the sample inputs
+    /// * `environment` - the [`Environment`] limiting the runs of the [`Organism`]
+    ///
+    /// [`Environment`]: ../environment/struct.Environment.html
+    /// [`Organism`]: ../population/struct.Organism.html
+    pub fn prune_with_samples(
+        &mut self,
+        samples: &[InputElementType],
+        environment: &Environment,
+    ) -> PruningReport {
+        let mut report = self.prune();
+        if samples.is_empty() {
+            return report;
+        }
+        let mut activated = HashSet::new();
+        for sample in samples {
+            let mut organism = self.translate();
+            organism.set_input(sample.clone());
+            organism.live(environment);
+            // Receptors are translated in gene and receptor order.
+            let indices = self
+                .genes
+                .iter()
+                .enumerate()
+                .flat_map(|(gene_index, gene)| {
+                    (0..gene.receptors.len())
+                        .map(move |receptor_index| (gene_index, receptor_index))
+                });
+            activated.extend(
+                indices
+                    .zip(organism.receptors())
+                    .filter(|(_, receptor)| receptor.activations() > 0)
+                    .map(|(index, _)| index),
+            );
+        }
+        report.add(self.remove_receptors_except(&activated));
+        // Removing receptors may render further structure unreachable.
+        report.add(self.prune());
+        report
+    }
+
+    /// Returns the indices of all receptors as `(gene, receptor)` that can be triggered by
+    /// input changes and contribute to an output.
+    fn live_receptors(&self) -> HashSet<(usize, usize)> {
+        let mut receptors = Vec::new();
+        for (gene_index, gene) in self.genes.iter().enumerate() {
+            for (receptor_index, receptor) in gene.receptors.iter().enumerate() {
+                let nodes = |indices: &[usize]| -> Vec<Node> {
+                    indices
+                        .iter()
+                        .map(|index| self.node(GeneSubstrate::new(gene_index, *index)))
+                        .collect()
+                };
+                let mut read = nodes(&receptor.triggers);
+                read.extend(nodes(&receptor.substrates));
+                read.extend(nodes(&receptor.enzyme.educts));
+                receptors.push((
+                    (gene_index, receptor_index),
+                    nodes(&receptor.triggers),
+                    read,
+                    nodes(&receptor.enzyme.products),
+                ));
+            }
+        }
+        // Forward analysis: only changes of the input substrates and substrates written by
+        // the output feedback start the cascade of receptors.
+        let mut changing: HashSet<Node> = self
+            .input
+            .input_substrates()
+            .iter()
+            .flatten()
+            .map(|substrate| self.node(*substrate))
+            .collect();
+        if !self.output.feedback_substrates().is_empty() {
+            changing.extend(
+                self.output
+                    .output_substrates()
+                    .iter()
+                    .flatten()
+                    .map(|substrate| self.node(*substrate)),
+            );
+        }
+        let mut triggered = vec![false; receptors.len()];
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (index, (_, triggers, _, products)) in receptors.iter().enumerate() {
+                if !triggered[index] && triggers.iter().any(|node| changing.contains(node)) {
+                    triggered[index] = true;
+                    changing.extend(products.iter().copied());
+                    changed = true;
+                }
+            }
+        }
+        // Backward analysis: only substrates read by the sensors or by contributing
+        // receptors are relevant.
+        let mut relevant: HashSet<Node> = self
+            .output
+            .output_substrates()
+            .iter()
+            .flatten()
+            .chain(self.output.finish_substrate().iter())
+            .chain(self.output.feedback_substrates().values())
+            .chain(self.input.feedback_substrates().values())
+            .map(|substrate| self.node(*substrate))
+            .collect();
+        let mut contributing = vec![false; receptors.len()];
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (index, (_, _, read, products)) in receptors.iter().enumerate() {
+                if triggered[index]
+                    && !contributing[index]
+                    && products.iter().any(|node| relevant.contains(node))
+                {
+                    contributing[index] = true;
+                    relevant.extend(read.iter().copied());
+                    changed = true;
+                }
+            }
+        }
+        receptors
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| triggered[*index] && contributing[*index])
+            .map(|(_, (receptor, _, _, _))| *receptor)
+            .collect()
+    }
+
+    /// Returns the [`Node`] the specified [`GeneSubstrate`] is translated into.
+    ///
+    /// # Parameters
+    ///
+    /// * `substrate` - the [`GeneSubstrate`] to look up
+    ///
+    /// [`GeneSubstrate`]: ./struct.GeneSubstrate.html
+    fn node(&self, substrate: GeneSubstrate) -> Node {
+        // The first association takes precedence as during translation.
+        self.associations
+            .iter()
+            .position(|association| association.associations.contains(&substrate))
+            .map_or(Node::Gene(substrate), Node::Association)
+    }
+
+    /// Removes all receptors except the specified ones and all substrates, genes and
+    /// associations that are no longer referenced afterwards.
+    /// Returns a summary of the removed structure.
+    ///
+    /// # Parameters
+    ///
+    /// * `keep` - the indices of the receptors to keep as `(gene, receptor)`
+    fn remove_receptors_except(&mut self, keep: &HashSet<(usize, usize)>) -> PruningReport {
+        let mut report = PruningReport::default();
+        for (gene_index, gene) in self.genes.iter_mut().enumerate() {
+            for receptor_index in (0..gene.receptors.len()).rev() {
+                if !keep.contains(&(gene_index, receptor_index)) {
+                    gene.remove_receptor(receptor_index);
+                    report.receptors += 1;
+                }
+            }
+        }
+        // Collect all substrates still referenced by receptors or sensors.
+        let mut referenced: HashMap<usize, HashSet<usize>> = HashMap::new();
+        for (gene_index, gene) in self.genes.iter().enumerate() {
+            let substrates = referenced.entry(gene_index).or_default();
+            for receptor in &gene.receptors {
+                substrates.extend(
+                    receptor
+                        .triggers
+                        .iter()
+                        .chain(receptor.substrates.iter())
+                        .chain(receptor.enzyme.educts.iter())
+                        .chain(receptor.enzyme.products.iter()),
+                );
+            }
+        }
+        for substrate in self
+            .input
+            .input_substrates()
+            .iter()
+            .flatten()
+            .chain(self.input.feedback_substrates().values())
+            .chain(self.output.output_substrates().iter().flatten())
+            .chain(self.output.feedback_substrates().values())
+            .chain(self.output.finish_substrate().iter())
+        {
+            referenced
+                .entry(substrate.gene())
+                .or_default()
+                .insert(substrate.substrate());
+        }
+        // Remove unreferenced substrates of genes that are still in use.
+        for gene_index in 0..self.genes.len() {
+            if referenced[&gene_index].is_empty() {
+                continue;
+            }
+            for substrate_index in (0..self.genes[gene_index].substrates.len()).rev() {
+                if !referenced[&gene_index].contains(&substrate_index)
+                    && self.genes[gene_index].substrates.len() > 1
+                {
+                    self.remove_substrate(GeneSubstrate::new(gene_index, substrate_index));
+                    report.substrates += 1;
+                }
+            }
+        }
+        // Remove genes that are not referenced at all.
+        for gene_index in (0..self.genes.len()).rev() {
+            if referenced[&gene_index].is_empty() && self.genes.len() > 1 {
+                self.remove_gene(gene_index);
+                report.genes += 1;
+            }
+        }
+        // Remove associations that do not associate any substrates anymore.
+        for association_index in (0..self.associations.len()).rev() {
+            if self.associations[association_index].associations.is_empty() {
+                self.remove_association(association_index);
+                report.associations += 1;
+            }
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests;
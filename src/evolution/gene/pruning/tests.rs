@@ -0,0 +1,107 @@
+use crate::evolution::environment::EnvironmentBuilder;
+use crate::evolution::gene::GeneAssociation;
+use crate::evolution::helper::testing::{
+    dendrite, neuron_gene, neuron_genome, threshold_dendrite, NeuronGenome,
+};
+use crate::evolution::helper::Nlbf64;
+use crate::evolution::neuron::SimpleNeuron;
+
+use super::*;
+
+/// Runs the translated [`NeuronGenome`] on the specified input and returns the output.
+fn run(genome: &NeuronGenome, input: f64) -> Vec<Nlbf64> {
+    let environment = EnvironmentBuilder::new().build().unwrap();
+    let mut organism = genome.translate();
+    organism.set_input(vec![input.into()]);
+    organism.live(&environment);
+    organism.get_result()
+}
+
+#[test]
+/// Tests if the function `prune` of the [`Genome`] struct correctly removes unreachable and
+/// unread structure while keeping all indices consistent.
+fn test_genome_prune() {
+    let gene = neuron_gene(
+        5,
+        vec![
+            dendrite(0, 1),
+            // The trigger is never changed.
+            dendrite(3, 2),
+            dendrite(1, 2),
+            // The product is never read.
+            dendrite(1, 4),
+        ],
+    );
+    let unused_gene = neuron_gene(1, vec![dendrite(0, 0)]);
+    let mut genome = neuron_genome(vec![unused_gene.clone(), gene]);
+    // Move the sensors to the second gene.
+    genome
+        .input_mut()
+        .set_input_substrate(0, Some(GeneSubstrate::new(1, 0)));
+    genome
+        .output_mut()
+        .set_output_substrate(0, Some(GeneSubstrate::new(1, 2)));
+    let mut association = GeneAssociation::new(SimpleNeuron::new(0.0, 10.0));
+    association.add_association(GeneSubstrate::new(0, 0));
+    association.add_association(GeneSubstrate::new(1, 3));
+    genome.add_association(association);
+    let mut pruned = genome.clone();
+    let report = pruned.prune();
+    assert_eq!(report.removed_genes(), 1);
+    assert_eq!(report.removed_substrates(), 2);
+    assert_eq!(report.removed_receptors(), 3);
+    assert_eq!(report.removed_associations(), 1);
+    assert!(!report.is_empty());
+    let expected = neuron_genome(vec![neuron_gene(3, vec![dendrite(0, 1), dendrite(1, 2)])]);
+    assert_eq!(pruned, expected);
+    assert_eq!(run(&pruned, 0.5), run(&genome, 0.5));
+    assert!(pruned.prune().is_empty());
+    // A genome always keeps a single gene.
+    let mut isolated = neuron_genome(vec![unused_gene.clone()]);
+    isolated.input_mut().set_input_substrate(0, None);
+    isolated.prune();
+    assert_eq!(isolated.number_of_genes().get(), 1);
+    assert_eq!(isolated.get_gene(0).number_of_receptors(), 0);
+}
+
+#[test]
+/// Tests if the function `prune_with_samples` of the [`Genome`] struct correctly removes
+/// receptors that were never activated by the sample inputs.
+fn test_genome_prune_with_samples() {
+    let environment = EnvironmentBuilder::new().build().unwrap();
+    let mut genome = neuron_genome(vec![neuron_gene(
+        3,
+        vec![
+            dendrite(0, 1),
+            dendrite(1, 2),
+            // The threshold is never reached by the samples.
+            threshold_dendrite(0, 2, 0.9),
+        ],
+    )]);
+    assert!(genome.clone().prune().is_empty());
+    let output = run(&genome, 0.5);
+    let report = genome.prune_with_samples(&[vec![0.25.into()], vec![0.5.into()]], &environment);
+    assert_eq!(report.removed_receptors(), 1);
+    assert_eq!(report.removed_substrates(), 0);
+    assert_eq!(genome.get_gene(0).number_of_receptors(), 2);
+    assert_eq!(run(&genome, 0.5), output);
+}
+
+#[test]
+/// Tests if the function `prune_with_samples` of the [`Genome`] struct correctly falls back to
+/// the static pruning without any samples.
+fn test_genome_prune_with_samples_empty() {
+    let environment = EnvironmentBuilder::new().build().unwrap();
+    let genome = neuron_genome(vec![neuron_gene(
+        3,
+        vec![
+            dendrite(0, 1),
+            dendrite(1, 2),
+            threshold_dendrite(0, 2, 0.9),
+        ],
+    )]);
+    let mut pruned = genome.clone();
+    let report = pruned.prune_with_samples(&[], &environment);
+    assert!(report.is_empty());
+    assert_eq!(pruned, genome);
+}
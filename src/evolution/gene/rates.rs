@@ -0,0 +1,190 @@
+//! The `rates` module contains self-adaptive mutation rate modifiers carried by a
+//! [`Genome`](crate::evolution::gene::Genome).
+use rand::thread_rng;
+use rand_distr::{Distribution, StandardNormal};
+use serde::{Deserialize, Serialize};
+
+use crate::evolution::helper::a_or_b;
+
+use super::CrossOver;
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+/// `RateModifiers` scale the chance of each
+/// [`Mutation`](crate::evolution::environment::Mutation) of a
+/// [`MutationCompendium`](crate::evolution::environment::MutationCompendium) in the order
+/// the mutations were added. Missing modifiers are treated as `1.0`, so an empty set of
+/// `RateModifiers` leaves all chances unchanged.
+pub struct RateModifiers {
+    modifiers: Vec<f64>,
+}
+
+impl RateModifiers {
+    /// Creates new `RateModifiers`.
+    ///
+    /// # Parameters
+    ///
+    /// * `modifiers` - the modifiers of the mutation chances in the order of the mutations
+    ///
+    /// # Panics
+    ///
+    /// If any modifier is not a positive finite number.
+    pub fn new(modifiers: Vec<f64>) -> Self {
+        for modifier in &modifiers {
+            assert!(
+                modifier.is_finite() && *modifier > 0.0,
+                "Rate modifiers must be positive finite numbers, but {} was specified.",
+                modifier
+            );
+        }
+        RateModifiers { modifiers }
+    }
+
+    /// Returns the modifier of the mutation at the specified index.
+    ///
+    /// # Parameters
+    ///
+    /// * `index` - the index of the mutation in the order it was added
+    pub fn modifier(&self, index: usize) -> f64 {
+        self.modifiers.get(index).copied().unwrap_or(1.0)
+    }
+
+    /// Returns all explicitly specified modifiers.
+    pub fn modifiers(&self) -> &[f64] {
+        &self.modifiers
+    }
+
+    /// Checks if no modifier is explicitly specified.
+    pub fn is_empty(&self) -> bool {
+        self.modifiers.is_empty()
+    }
+
+    /// Returns log-normally mutated `RateModifiers` for the specified number of mutations,
+    /// so each modifier is multiplied by `exp(learning_rate * N(0, 1))` and clamped to the
+    /// specified bounds.
+    ///
+    /// # Parameters
+    ///
+    /// * `number_of_mutations` - the number of mutations to provide modifiers for
+    /// * `learning_rate` - the standard deviation of the logarithmic change
+    /// * `minimum_modifier` - the lower bound of every modifier
+    /// * `maximum_modifier` - the upper bound of every modifier
+    pub fn self_adapt(
+        &self,
+        number_of_mutations: usize,
+        learning_rate: f64,
+        minimum_modifier: f64,
+        maximum_modifier: f64,
+    ) -> Self {
+        let mut rng = thread_rng();
+        let modifiers = (0..number_of_mutations)
+            .map(|index| {
+                let change: f64 = StandardNormal.sample(&mut rng);
+                (self.modifier(index) * (learning_rate * change).exp())
+                    .clamp(minimum_modifier, maximum_modifier)
+            })
+            .collect();
+        RateModifiers { modifiers }
+    }
+}
+
+impl CrossOver for RateModifiers {
+    fn is_similar(&self, _other: &Self) -> bool {
+        true
+    }
+
+    fn cross_over(&self, other: &Self) -> Self {
+        // Modifiers are recombined per mutation. In contrast to other vectors the surplus
+        // modifiers of the longer parent are kept, so indices keep referring to the same
+        // mutation.
+        let length = self.modifiers.len().max(other.modifiers.len());
+        let modifiers = (0..length)
+            .map(|index| {
+                match (self.modifiers.get(index), other.modifiers.get(index)) {
+                    (Some(a), Some(b)) => a_or_b(*a, *b),
+                    (Some(a), None) => *a,
+                    (None, Some(b)) => *b,
+                    // Unreachable since the index is smaller than the length of the longer
+                    // parent.
+                    (None, None) => 1.0,
+                }
+            })
+            .collect();
+        RateModifiers { modifiers }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Default, Serialize, Deserialize)]
+/// A `RateDistribution` summarises the modifiers of a single mutation across a
+/// [`Population`](crate::evolution::population::Population).
+pub struct RateDistribution {
+    mean: f64,
+    standard_deviation: f64,
+    minimum: f64,
+    maximum: f64,
+}
+
+impl RateDistribution {
+    /// Summarises the modifiers of every mutation across all specified [`RateModifiers`].
+    /// Returns a `RateDistribution` per mutation or an empty vector if no [`RateModifiers`]
+    /// were specified.
+    ///
+    /// # Parameters
+    ///
+    /// * `rate_modifiers` - the [`RateModifiers`] of all members of a population
+    /// * `number_of_mutations` - the number of mutations to summarise
+    ///
+    /// [`RateModifiers`]: ./struct.RateModifiers.html
+    pub fn summarise<'a, I: IntoIterator<Item = &'a RateModifiers>>(
+        rate_modifiers: I,
+        number_of_mutations: usize,
+    ) -> Vec<RateDistribution> {
+        let rate_modifiers: Vec<&RateModifiers> = rate_modifiers.into_iter().collect();
+        if rate_modifiers.is_empty() {
+            return Vec::new();
+        }
+        let count = rate_modifiers.len() as f64;
+        (0..number_of_mutations)
+            .map(|index| {
+                let values: Vec<f64> = rate_modifiers
+                    .iter()
+                    .map(|modifiers| modifiers.modifier(index))
+                    .collect();
+                let mean = values.iter().sum::<f64>() / count;
+                let variance = values
+                    .iter()
+                    .map(|value| (value - mean).powi(2))
+                    .sum::<f64>()
+                    / count;
+                RateDistribution {
+                    mean,
+                    standard_deviation: variance.sqrt(),
+                    minimum: values.iter().copied().fold(f64::INFINITY, f64::min),
+                    maximum: values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the mean modifier.
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Returns the population standard deviation of the modifiers.
+    pub fn standard_deviation(&self) -> f64 {
+        self.standard_deviation
+    }
+
+    /// Returns the smallest modifier.
+    pub fn minimum(&self) -> f64 {
+        self.minimum
+    }
+
+    /// Returns the largest modifier.
+    pub fn maximum(&self) -> f64 {
+        self.maximum
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,71 @@
+use super::*;
+
+#[test]
+/// Tests if the function `modifier` of the [`RateModifiers`] struct correctly defaults
+/// missing modifiers.
+fn test_rate_modifiers_modifier() {
+    let modifiers = RateModifiers::new(vec![0.5, 2.0]);
+    assert_ulps_eq!(modifiers.modifier(0), 0.5);
+    assert_ulps_eq!(modifiers.modifier(1), 2.0);
+    assert_ulps_eq!(modifiers.modifier(2), 1.0);
+    assert!(RateModifiers::default().is_empty());
+}
+
+#[test]
+#[should_panic]
+/// Tests if the function `new` of the [`RateModifiers`] struct correctly panics on
+/// non-positive modifiers.
+fn test_rate_modifiers_new_invalid() {
+    RateModifiers::new(vec![1.0, 0.0]);
+}
+
+#[test]
+/// Tests if the function `self_adapt` of the [`RateModifiers`] struct correctly mutates
+/// the modifiers within the bounds.
+fn test_rate_modifiers_self_adapt() {
+    let modifiers = RateModifiers::new(vec![0.5]);
+    // Without learning the modifiers are only extended.
+    assert_eq!(modifiers.self_adapt(2, 0.0, 0.1, 10.0).modifiers(), &[0.5, 1.0]);
+    for _ in 0..100 {
+        let adapted = modifiers.self_adapt(3, 5.0, 0.1, 10.0);
+        assert_eq!(adapted.modifiers().len(), 3);
+        assert!(adapted
+            .modifiers()
+            .iter()
+            .all(|modifier| (0.1..=10.0).contains(modifier)));
+    }
+}
+
+#[test]
+/// Tests if the function `cross_over` of the [`RateModifiers`] struct correctly keeps
+/// the modifiers of the longer parent.
+fn test_rate_modifiers_cross_over() {
+    let short = RateModifiers::new(vec![0.5]);
+    let long = RateModifiers::new(vec![2.0, 3.0, 4.0]);
+    for _ in 0..10 {
+        let recombined = short.cross_over(&long);
+        assert_eq!(recombined.modifiers().len(), 3);
+        assert!(recombined.modifier(0) == 0.5 || recombined.modifier(0) == 2.0);
+        assert_eq!(&recombined.modifiers()[1..], &[3.0, 4.0]);
+    }
+}
+
+#[test]
+/// Tests if the function `summarise` of the [`RateDistribution`] struct correctly
+/// summarises the modifiers of a population.
+fn test_rate_distribution_summarise() {
+    assert!(RateDistribution::summarise(Vec::new(), 2).is_empty());
+    let modifiers = [
+        RateModifiers::new(vec![1.0, 2.0]),
+        RateModifiers::new(vec![3.0]),
+    ];
+    let distributions = RateDistribution::summarise(&modifiers, 2);
+    assert_eq!(distributions.len(), 2);
+    assert_ulps_eq!(distributions[0].mean(), 2.0);
+    assert_ulps_eq!(distributions[0].standard_deviation(), 1.0);
+    assert_ulps_eq!(distributions[0].minimum(), 1.0);
+    assert_ulps_eq!(distributions[0].maximum(), 3.0);
+    // Missing modifiers are treated as 1.0.
+    assert_ulps_eq!(distributions[1].mean(), 1.5);
+    assert_ulps_eq!(distributions[1].standard_deviation(), 0.5);
+}
@@ -1,9 +1,7 @@
 //! The `sensor` module contains input-output related genetic processing structures.
 use std::{
-    cell::RefCell,
     collections::{HashMap, HashSet},
     marker::PhantomData,
-    rc::{Rc, Weak},
 };
 
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
@@ -215,7 +213,9 @@ impl<
     /// Translates the `GenomicInputSensor` into an [`InputSensor`](crate::evolution::protein::InputSensor).
     ///
     /// # Parameters
-    /// * `substrate_lookup` - a map for lookup of all the translated [`Substrate`](crate::evolution::protein::Substrate)s
+    /// * `substrate_lookup` - a map for lookup of the ids of all the translated [`Substrate`](crate::evolution::protein::Substrate)s
+    /// of the containing [`Genome`](crate::evolution::gene::Genome)
+    /// * `substrates` - all translated [`Substrate`](crate::evolution::protein::Substrate)s
     /// of the containing [`Genome`](crate::evolution::gene::Genome)
     ///
     /// # Panics
@@ -223,10 +223,8 @@ impl<
     /// If the `substrate_lookup` map does not contain one of the requested [`Substrate`](crate::evolution::protein::Substrate)s.
     pub fn translate(
         &self,
-        substrate_lookup: &HashMap<
-            GeneSubstrate,
-            Rc<RefCell<Substrate<ReactionType, StateType, InformationType>>>,
-        >,
+        substrate_lookup: &HashMap<GeneSubstrate, usize>,
+        substrates: &mut [Substrate<ReactionType, StateType, InformationType>],
     ) -> InputSensor<ReactionType, StateType, InformationType, InputElementType, InputSensorType>
     {
         let input_substrates = translate_substrates(&self.input_substrates, substrate_lookup);
@@ -241,7 +239,7 @@ impl<
                 map
             });
         // Sets the feedback associations.
-        set_input_substrate_type(transformed_input_associations, substrate_lookup);
+        set_input_substrate_type(transformed_input_associations, substrate_lookup, substrates);
         let input = self.input.clone();
         InputSensor::new(input, input_substrates)
     }
@@ -583,7 +581,9 @@ impl<
     /// Translates the `GenomicOutputSensor` into an [`OutputSensor`](crate::evolution::protein::OutputSensor).
     ///
     /// # Parameters
-    /// * `substrate_lookup` - a map for lookup of all the translated [`Substrate`](crate::evolution::protein::Substrate)s
+    /// * `substrate_lookup` - a map for lookup of the ids of all the translated [`Substrate`](crate::evolution::protein::Substrate)s
+    /// of the containing [`Genome`](crate::evolution::gene::Genome)
+    /// * `substrates` - all translated [`Substrate`](crate::evolution::protein::Substrate)s
     /// of the containing [`Genome`](crate::evolution::gene::Genome)
     ///
     /// # Panics
@@ -591,10 +591,8 @@ impl<
     /// If the `substrate_lookup` map does not contain one of the requested [`Substrate`](crate::evolution::protein::Substrate)s.
     pub fn translate(
         &self,
-        substrate_lookup: &HashMap<
-            GeneSubstrate,
-            Rc<RefCell<Substrate<ReactionType, StateType, InformationType>>>,
-        >,
+        substrate_lookup: &HashMap<GeneSubstrate, usize>,
+        substrates: &mut [Substrate<ReactionType, StateType, InformationType>],
     ) -> OutputSensor<ReactionType, StateType, InformationType, OutputElementType, OutputSensorType>
     {
         let output_substrates = translate_substrates(&self.output_substrates, substrate_lookup);
@@ -609,16 +607,10 @@ impl<
                 map
             });
         // Sets the feedback associations.
-        set_output_substrate_type(transformed_output_associations, substrate_lookup);
+        set_output_substrate_type(transformed_output_associations, substrate_lookup, substrates);
         // Sets the finsih associations.
         if let Some(finish_substrate) = self.finish_substrate {
-            substrate_lookup
-                .get(&finish_substrate)
-                .expect(&format!(
-                    "The substrate lookup map did not contain {:?}.",
-                    finish_substrate
-                ))
-                .borrow_mut()
+            substrates[lookup_substrate(&finish_substrate, substrate_lookup)]
                 .set_substrate_type(SubstrateType::OutputFinishSubstrate);
         }
         let output = self.output.clone();
@@ -762,44 +754,54 @@ fn adjust_single_substrate_after_gene_removal_from_substrates(
 /// # Parameters
 ///
 /// * `substrates` - the [`Substrate`](crate::evolution::protein::Substrate)s to transcribe
-/// * `substrate_lookup` - a map for lookup of all the translated [`Substrate`](crate::evolution::protein::Substrate)s
+/// * `substrate_lookup` - a map for lookup of the ids of all the translated [`Substrate`](crate::evolution::protein::Substrate)s
 /// of the containing [`Genome`](crate::evolution::gene::Genome)
 ///
 /// # Panics
 ///
 /// If the `substrate_lookup` map does not contain one of the requested [`Substrate`](crate::evolution::protein::Substrate)s.
-fn translate_substrates<
-    ReactionType: Reaction<InformationType>,
-    StateType: State<InformationType>,
-    InformationType: Information,
->(
-    substrates: &Vec<Option<GeneSubstrate>>,
-    substrate_lookup: &HashMap<
-        GeneSubstrate,
-        Rc<RefCell<Substrate<ReactionType, StateType, InformationType>>>,
-    >,
-) -> Vec<Option<Weak<RefCell<Substrate<ReactionType, StateType, InformationType>>>>> {
+fn translate_substrates(
+    substrates: &[Option<GeneSubstrate>],
+    substrate_lookup: &HashMap<GeneSubstrate, usize>,
+) -> Vec<Option<usize>> {
     substrates
         .iter()
-        .map(Option::as_ref)
         .map(|gene_substrate_option| {
-            gene_substrate_option.map(|gene_substrate| {
-                substrate_lookup.get(gene_substrate).expect(&format!(
-                    "The substrate lookup map did not contain {:?}.",
-                    gene_substrate
-                ))
-            })
+            gene_substrate_option
+                .as_ref()
+                .map(|gene_substrate| lookup_substrate(gene_substrate, substrate_lookup))
         })
-        .map(|strong_option| strong_option.map(|strong| Rc::downgrade(strong)))
         .collect()
 }
 
+/// Returns the id of the translated [`Substrate`](crate::evolution::protein::Substrate).
+///
+/// # Parameters
+///
+/// * `gene_substrate` - the [`GeneSubstrate`](crate::evolution::gene::GeneSubstrate) to look up
+/// * `substrate_lookup` - a map for lookup of the ids of all the translated [`Substrate`](crate::evolution::protein::Substrate)s
+/// of the containing [`Genome`](crate::evolution::gene::Genome)
+///
+/// # Panics
+///
+/// If the `substrate_lookup` map does not contain the requested [`Substrate`](crate::evolution::protein::Substrate).
+fn lookup_substrate(
+    gene_substrate: &GeneSubstrate,
+    substrate_lookup: &HashMap<GeneSubstrate, usize>,
+) -> usize {
+    *substrate_lookup
+        .get(gene_substrate)
+        .unwrap_or_else(|| panic!("The substrate lookup map did not contain {:?}.", gene_substrate))
+}
+
 /// Sets the specified input associations for the substrate lookup map.
 ///
 /// # Parameters
 ///
 /// * `input_feedback_associations` - the [`Substrate`](crate::evolution::protein::Substrate)s associated with specific input feedback functions
-/// * `substrate_lookup` - a map for lookup of all the translated [`Substrate`](crate::evolution::protein::Substrate)s
+/// * `substrate_lookup` - a map for lookup of the ids of all the translated [`Substrate`](crate::evolution::protein::Substrate)s
+/// of the containing [`Genome`](crate::evolution::gene::Genome)
+/// * `substrates` - all translated [`Substrate`](crate::evolution::protein::Substrate)s
 /// of the containing [`Genome`](crate::evolution::gene::Genome)
 ///
 /// # Panics
@@ -811,17 +813,11 @@ fn set_input_substrate_type<
     InformationType: Information,
 >(
     input_feedback_associations: HashMap<GeneSubstrate, Vec<usize>>,
-    substrate_lookup: &HashMap<
-        GeneSubstrate,
-        Rc<RefCell<Substrate<ReactionType, StateType, InformationType>>>,
-    >,
+    substrate_lookup: &HashMap<GeneSubstrate, usize>,
+    substrates: &mut [Substrate<ReactionType, StateType, InformationType>],
 ) {
     for (gene_substrate, associations) in input_feedback_associations.into_iter() {
-        let substrate = substrate_lookup
-            .get(&gene_substrate)
-            .expect(&format!("The substrate lookup map did not contain {:?}.", gene_substrate));
-        substrate
-            .borrow_mut()
+        substrates[lookup_substrate(&gene_substrate, substrate_lookup)]
             .set_substrate_type(SubstrateType::InputFeedbackSubstrate(associations));
     }
 }
@@ -831,7 +827,9 @@ fn set_input_substrate_type<
 /// # Parameters
 ///
 /// * `output_feedback_associations` - the [`Substrate`](crate::evolution::protein::Substrate)s associated with specific output feedback functions
-/// * `substrate_lookup` - a map for lookup of all the translated [`Substrate`](crate::evolution::protein::Substrate)s
+/// * `substrate_lookup` - a map for lookup of the ids of all the translated [`Substrate`](crate::evolution::protein::Substrate)s
+/// of the containing [`Genome`](crate::evolution::gene::Genome)
+/// * `substrates` - all translated [`Substrate`](crate::evolution::protein::Substrate)s
 /// of the containing [`Genome`](crate::evolution::gene::Genome)
 ///
 /// # Panics
@@ -843,17 +841,11 @@ fn set_output_substrate_type<
     InformationType: Information,
 >(
     output_feedback_associations: HashMap<GeneSubstrate, Vec<usize>>,
-    substrate_lookup: &HashMap<
-        GeneSubstrate,
-        Rc<RefCell<Substrate<ReactionType, StateType, InformationType>>>,
-    >,
+    substrate_lookup: &HashMap<GeneSubstrate, usize>,
+    substrates: &mut [Substrate<ReactionType, StateType, InformationType>],
 ) {
     for (gene_substrate, associations) in output_feedback_associations.into_iter() {
-        let substrate = substrate_lookup
-            .get(&gene_substrate)
-            .expect(&format!("The substrate lookup map did not contain {:?}.", gene_substrate));
-        substrate
-            .borrow_mut()
+        substrates[lookup_substrate(&gene_substrate, substrate_lookup)]
             .set_substrate_type(SubstrateType::OutputFeedbackSubstrate(associations));
     }
 }
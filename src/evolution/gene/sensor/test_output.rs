@@ -272,59 +272,56 @@ fn test_translate() {
         NoOpOutputSensor,
     > = GenomicOutputSensor::new(output_substrates, feedback_substrates, finish_substrate, output);
     let substrate_lookup = HashMap::from([
-        (GeneSubstrate::new(0, 0), new_noop_substrate()),
-        (GeneSubstrate::new(1, 0), new_noop_substrate()),
-        (GeneSubstrate::new(1, 3), new_noop_substrate()),
-        (GeneSubstrate::new(2, 1), new_noop_substrate()),
-        (GeneSubstrate::new(3, 3), new_noop_substrate()),
-        (GeneSubstrate::new(4, 4), new_noop_substrate()),
+        (GeneSubstrate::new(0, 0), 0),
+        (GeneSubstrate::new(1, 0), 1),
+        (GeneSubstrate::new(1, 3), 2),
+        (GeneSubstrate::new(2, 1), 3),
+        (GeneSubstrate::new(3, 3), 4),
+        (GeneSubstrate::new(4, 4), 5),
     ]);
-    let protein = output_sensor.translate(&substrate_lookup);
+    let mut substrates = vec![new_noop_substrate(); substrate_lookup.len()];
+    let protein = output_sensor.translate(&substrate_lookup, &mut substrates);
     assert_eq!(protein.output_substrates().len(), output_sensor.output_substrates().len());
     for (i, substrate_option) in output_sensor.output_substrates().iter().enumerate() {
         if let Some(substrate) = substrate_option {
-            assert!(Rc::downgrade(substrate_lookup.get(substrate).unwrap())
-                .ptr_eq(&protein.output_substrates()[i].clone().unwrap()));
+            assert_eq!(protein.output_substrates()[i], substrate_lookup.get(substrate).copied());
         } else {
             assert!(protein.output_substrates()[i].is_none());
         }
     }
     assert_eq!(
         SubstrateType::OutputFeedbackSubstrate(vec![0]),
-        get_substrate_reference_option_type(substrate_lookup.get(&GeneSubstrate::new(0, 0)))
+        get_substrate_type(&substrates, substrate_lookup.get(&GeneSubstrate::new(0, 0)))
     );
     assert_eq!(
         SubstrateType::OutputFeedbackSubstrate(vec![2]),
-        get_substrate_reference_option_type(substrate_lookup.get(&GeneSubstrate::new(3, 3)))
+        get_substrate_type(&substrates, substrate_lookup.get(&GeneSubstrate::new(3, 3)))
     );
     assert_eq!(
         SubstrateType::OutputFeedbackSubstrate(vec![3]),
-        get_substrate_reference_option_type(substrate_lookup.get(&GeneSubstrate::new(4, 4)))
+        get_substrate_type(&substrates, substrate_lookup.get(&GeneSubstrate::new(4, 4)))
     );
     assert_eq!(
         SubstrateType::ConventionalSubstrate,
-        get_substrate_reference_option_type(substrate_lookup.get(&GeneSubstrate::new(1, 0)))
+        get_substrate_type(&substrates, substrate_lookup.get(&GeneSubstrate::new(1, 0)))
     );
     assert_eq!(
         SubstrateType::OutputFinishSubstrate,
-        get_substrate_reference_option_type(substrate_lookup.get(&GeneSubstrate::new(1, 3)))
+        get_substrate_type(&substrates, substrate_lookup.get(&GeneSubstrate::new(1, 3)))
     );
     assert_eq!(
         SubstrateType::ConventionalSubstrate,
-        get_substrate_reference_option_type(substrate_lookup.get(&GeneSubstrate::new(2, 1)))
+        get_substrate_type(&substrates, substrate_lookup.get(&GeneSubstrate::new(2, 1)))
     );
 }
 
-fn new_noop_substrate() -> Rc<RefCell<Substrate<NoOpReaction, NoOpState, NoOpSubstrate>>> {
-    Rc::new(RefCell::new(Substrate::new((), SubstrateType::ConventionalSubstrate)))
+fn new_noop_substrate() -> Substrate<NoOpReaction, NoOpState, NoOpSubstrate> {
+    Substrate::new((), SubstrateType::ConventionalSubstrate)
 }
 
-fn get_substrate_reference_option_type(
-    substrate_reference: Option<&Rc<RefCell<Substrate<NoOpReaction, NoOpState, NoOpSubstrate>>>>,
+fn get_substrate_type(
+    substrates: &[Substrate<NoOpReaction, NoOpState, NoOpSubstrate>],
+    substrate: Option<&usize>,
 ) -> SubstrateType {
-    substrate_reference
-        .unwrap()
-        .borrow()
-        .substrate_type()
-        .clone()
+    substrates[*substrate.unwrap()].substrate_type().clone()
 }
@@ -0,0 +1,972 @@
+//! The `structural_mutation` module contains mutations of the structure of a [`Genome`]
+//! that only rely on the generic [`Reaction`], [`State`] and [`Information`] traits,
+//! so they can be used for any chemistry.
+//!
+//! [`Genome`]: ./struct.Genome.html
+//! [`Information`]: ../chemistry/trait.Information.html
+//! [`Reaction`]: ../chemistry/trait.Reaction.html
+//! [`State`]: ../chemistry/trait.State.html
+
+use std::fmt::Debug;
+
+use rand::{thread_rng, Rng};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::super::chemistry::{Information, Input, Output, Reaction, State};
+use super::super::environment::Mutation;
+use super::super::helper::Nlbf64;
+use super::{GeneAssociation, Genome};
+
+/// Duplicates the [`Genome`] and then duplicates a random [`Gene`] inside of the [`Genome`].
+/// Returns the altered [`Genome`] if the [`Gene`] could be added.
+///
+/// # Parameters
+///
+/// * `genome` - the [`Genome`] to mutate
+///
+/// [`Gene`]: ./struct.Gene.html
+/// [`Genome`]: ./struct.Genome.html
+pub fn mutate_gene_duplication<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    genome: &Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+) -> Option<
+    Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+> {
+    let mut mutated_genome = genome.duplicate();
+    mutated_genome
+        .add_gene(mutated_genome.duplicate_random_gene())
+        .map(|_| mutated_genome)
+}
+
+/// Duplicates the [`Genome`] and then removes a random [`Gene`] from it.
+/// Returns the altered [`Genome`] if there was more than 1 [`Gene`] in the [`Genome`].
+///
+/// # Parameters
+///
+/// * `genome` - the [`Genome`] to mutate
+///
+/// [`Gene`]: ./struct.Gene.html
+/// [`Genome`]: ./struct.Genome.html
+pub fn mutate_gene_deletion<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    genome: &Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+) -> Option<
+    Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+> {
+    // Only allow this mutation if there is more than one gene in the genome,
+    // otherwise the genome would end up in an invalid state.
+    if genome.number_of_genes().get() > 1 {
+        let mut mutated_genome = genome.duplicate();
+        let random_gene = mutated_genome.get_random_gene();
+        mutated_genome.remove_gene(random_gene);
+        Some(mutated_genome)
+    } else {
+        None
+    }
+}
+
+/// Duplicates the [`Genome`] and then fuses two different random [`Gene`]s of the [`Genome`].
+/// The fusion product is added as a new [`Gene`].
+/// Returns the altered [`Genome`] if there are 2 or more [`Gene`]s in the [`Genome`]
+/// and if the fusion process was successful.
+///
+/// # Parameters
+///
+/// * `genome` - the [`Genome`] to mutate
+///
+/// [`Gene`]: ./struct.Gene.html
+/// [`Genome`]: ./struct.Genome.html
+pub fn mutate_gene_fusion<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    genome: &Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+) -> Option<
+    Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+> {
+    // Only allow this mutation if there is more than one gene in the genome, since
+    // 2 different genes are needed for fusion.
+    if genome.number_of_genes().get() < 2 {
+        return None;
+    }
+    let mut mutated_genome = genome.duplicate();
+    let mut genes: Vec<usize> = (0..mutated_genome.number_of_genes().get()).collect();
+    let gene_a = genes.remove(thread_rng().gen_range(0..genes.len()));
+    let gene_b = genes.remove(thread_rng().gen_range(0..genes.len()));
+    let fusion_gene = mutated_genome
+        .get_gene(gene_a)
+        .fuse(mutated_genome.get_gene(gene_b))?;
+    mutated_genome.add_gene(fusion_gene).map(|_| mutated_genome)
+}
+
+/// Duplicates the [`Genome`] and adds a [`GeneAssociation`] to it, which initially
+/// associates a random [`Substrate`] of a random [`Gene`] and starts with its value.
+/// Returns the altered [`Genome`] if the [`GeneAssociation`] could be added.
+///
+/// # Parameters
+///
+/// * `genome` - the [`Genome`] to mutate
+///
+/// [`Gene`]: ./struct.Gene.html
+/// [`GeneAssociation`]: ./struct.GeneAssociation.html
+/// [`Genome`]: ./struct.Genome.html
+/// [`Substrate`]: ../protein/struct.Substrate.html
+pub fn mutate_association_insertion<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    genome: &Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+) -> Option<
+    Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+> {
+    let mut mutated_genome = genome.duplicate();
+    let gene_substrate = mutated_genome.random_gene_substrate();
+    // The value of an existing substrate is used, since there is no generic way
+    // to create random information.
+    let substrate = mutated_genome
+        .get_substrate(gene_substrate)
+        .expect("A random gene substrate must exist.")
+        .clone();
+    let mut association = GeneAssociation::new(substrate);
+    association.add_association(gene_substrate);
+    mutated_genome
+        .add_association(association)
+        .map(|_| mutated_genome)
+}
+
+/// Duplicates the [`Genome`] and removes a random [`GeneAssociation`] from it.
+/// Returns the altered [`Genome`] if 1 or more [`GeneAssociation`]s were present.
+///
+/// # Parameters
+///
+/// * `genome` - the [`Genome`] to mutate
+///
+/// [`GeneAssociation`]: ./struct.GeneAssociation.html
+/// [`Genome`]: ./struct.Genome.html
+pub fn mutate_association_deletion<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    genome: &Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+) -> Option<
+    Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+> {
+    genome.get_random_association().map(|association| {
+        let mut mutated_genome = genome.duplicate();
+        mutated_genome.remove_association(association);
+        mutated_genome
+    })
+}
+
+/// Duplicates the [`Genome`] and associates a random [`Substrate`] of a random [`Gene`]
+/// with a random [`GeneAssociation`].
+/// Returns the altered [`Genome`] if 1 or more [`GeneAssociation`]s were present and the
+/// [`Substrate`] was not associated before.
+///
+/// # Parameters
+///
+/// * `genome` - the [`Genome`] to mutate
+///
+/// [`Gene`]: ./struct.Gene.html
+/// [`GeneAssociation`]: ./struct.GeneAssociation.html
+/// [`Genome`]: ./struct.Genome.html
+/// [`Substrate`]: ../protein/struct.Substrate.html
+pub fn mutate_association_gene_insertion<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    genome: &Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+) -> Option<
+    Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+> {
+    let association = genome.get_random_association()?;
+    let gene_substrate = genome.random_gene_substrate();
+    let mut mutated_genome = genome.duplicate();
+    let association = mutated_genome
+        .association_mut(association)
+        .expect("A random association must exist.");
+    if association.associations().contains(&gene_substrate) {
+        return None;
+    }
+    association
+        .add_association(gene_substrate)
+        .map(|_| mutated_genome)
+}
+
+/// Duplicates the [`Genome`] and removes a random [`Substrate`] reference from a random
+/// [`GeneAssociation`].
+/// Returns the altered [`Genome`] if the selected [`GeneAssociation`] referenced any
+/// [`Substrate`].
+///
+/// # Parameters
+///
+/// * `genome` - the [`Genome`] to mutate
+///
+/// [`GeneAssociation`]: ./struct.GeneAssociation.html
+/// [`Genome`]: ./struct.Genome.html
+/// [`Substrate`]: ../protein/struct.Substrate.html
+pub fn mutate_association_gene_deletion<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    genome: &Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+) -> Option<
+    Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+> {
+    let association = genome.get_random_association()?;
+    let mut mutated_genome = genome.duplicate();
+    let association = mutated_genome
+        .association_mut(association)
+        .expect("A random association must exist.");
+    let gene_substrate = association.get_random_gene_substrate()?;
+    association.remove_association(gene_substrate);
+    Some(mutated_genome)
+}
+
+/// Duplicates the [`Genome`] and then associates a random input of the [`Genome`]
+/// with a random [`Substrate`] of a random [`Gene`].
+/// Returns the altered [`Genome`] if there are any inputs and the [`Substrate`] is not
+/// already used as input.
+///
+/// # Parameters
+///
+/// * `genome` - the [`Genome`] to mutate
+///
+/// [`Gene`]: ./struct.Gene.html
+/// [`Genome`]: ./struct.Genome.html
+/// [`Substrate`]: ../protein/struct.Substrate.html
+pub fn mutate_input_association<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    genome: &Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+) -> Option<
+    Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+> {
+    let number_of_input_substrates = genome.input().number_of_input_substrates();
+    if number_of_input_substrates == 0 {
+        return None;
+    }
+    let gene_substrate = Some(genome.random_gene_substrate());
+    // Do not allow two input substrates to be the same. This would violate the
+    // specification of an I/O-substrate.
+    if genome.input().input_substrates().contains(&gene_substrate) {
+        return None;
+    }
+    let mut mutated_genome = genome.duplicate();
+    mutated_genome
+        .input_mut()
+        .set_input_substrate(thread_rng().gen_range(0..number_of_input_substrates), gene_substrate);
+    Some(mutated_genome)
+}
+
+/// Duplicates the [`Genome`] and then dissociates a random input of the [`Genome`].
+/// Returns the altered [`Genome`] if the randomly selected input was previously associated.
+///
+/// # Parameters
+///
+/// * `genome` - the [`Genome`] to mutate
+///
+/// [`Genome`]: ./struct.Genome.html
+pub fn mutate_input_dissociation<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    genome: &Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+) -> Option<
+    Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+> {
+    let number_of_input_substrates = genome.input().number_of_input_substrates();
+    if number_of_input_substrates == 0 {
+        return None;
+    }
+    let mut mutated_genome = genome.duplicate();
+    mutated_genome
+        .input_mut()
+        .set_input_substrate(thread_rng().gen_range(0..number_of_input_substrates), None)
+        .map(|_| mutated_genome)
+}
+
+/// Duplicates the [`Genome`] and then associates a random output of the [`Genome`]
+/// with a random [`Substrate`] of a random [`Gene`].
+/// Returns the altered [`Genome`] if there are any outputs and the [`Substrate`] is not
+/// already used as output.
+///
+/// # Parameters
+///
+/// * `genome` - the [`Genome`] to mutate
+///
+/// [`Gene`]: ./struct.Gene.html
+/// [`Genome`]: ./struct.Genome.html
+/// [`Substrate`]: ../protein/struct.Substrate.html
+pub fn mutate_output_association<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    genome: &Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+) -> Option<
+    Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+> {
+    let number_of_output_substrates = genome.output().number_of_output_substrates();
+    if number_of_output_substrates == 0 {
+        return None;
+    }
+    let gene_substrate = Some(genome.random_gene_substrate());
+    // Do not allow two output substrates to be the same. This would violate the
+    // specification of an I/O-substrate.
+    if genome
+        .output()
+        .output_substrates()
+        .contains(&gene_substrate)
+    {
+        return None;
+    }
+    let mut mutated_genome = genome.duplicate();
+    mutated_genome.output_mut().set_output_substrate(
+        thread_rng().gen_range(0..number_of_output_substrates),
+        gene_substrate,
+    );
+    Some(mutated_genome)
+}
+
+/// Duplicates the [`Genome`] and then dissociates a random output of the [`Genome`].
+/// Returns the altered [`Genome`] if the randomly selected output was previously associated.
+///
+/// # Parameters
+///
+/// * `genome` - the [`Genome`] to mutate
+///
+/// [`Genome`]: ./struct.Genome.html
+pub fn mutate_output_dissociation<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    genome: &Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+) -> Option<
+    Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+> {
+    let number_of_output_substrates = genome.output().number_of_output_substrates();
+    if number_of_output_substrates == 0 {
+        return None;
+    }
+    let mut mutated_genome = genome.duplicate();
+    mutated_genome
+        .output_mut()
+        .set_output_substrate(thread_rng().gen_range(0..number_of_output_substrates), None)
+        .map(|_| mutated_genome)
+}
+
+/// Duplicates the [`Genome`] and adds a random [`GenomicReceptor`] to a random [`Gene`].
+/// Returns the altered [`Genome`] if the [`GenomicReceptor`] could be added.
+///
+/// # Parameters
+///
+/// * `genome` - the [`Genome`] to mutate
+///
+/// [`Gene`]: ./struct.Gene.html
+/// [`GenomicReceptor`]: ./struct.GenomicReceptor.html
+/// [`Genome`]: ./struct.Genome.html
+pub fn mutate_receptor_insertion<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    genome: &Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+) -> Option<
+    Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+> {
+    let mut mutated_genome = genome.duplicate();
+    let gene = mutated_genome.get_gene_mut(genome.get_random_gene());
+    let receptor = gene.random_receptor();
+    gene.add_receptor(receptor).map(|_| mutated_genome)
+}
+
+/// Duplicates the [`Genome`] and removes a random [`GenomicReceptor`] from a random [`Gene`].
+/// Returns the altered [`Genome`] if the selected [`Gene`] contained any [`GenomicReceptor`].
+///
+/// # Parameters
+///
+/// * `genome` - the [`Genome`] to mutate
+///
+/// [`Gene`]: ./struct.Gene.html
+/// [`GenomicReceptor`]: ./struct.GenomicReceptor.html
+/// [`Genome`]: ./struct.Genome.html
+pub fn mutate_receptor_deletion<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    genome: &Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+) -> Option<
+    Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+> {
+    let gene = genome.get_random_gene();
+    let receptor = genome.get_gene(gene).get_random_receptor()?;
+    let mut mutated_genome = genome.duplicate();
+    mutated_genome.get_gene_mut(gene).remove_receptor(receptor);
+    Some(mutated_genome)
+}
+
+/// Duplicates the [`Genome`] and adds a random triggering [`Substrate`] of the containing
+/// [`Gene`] to a random [`GenomicReceptor`].
+/// Returns the altered [`Genome`] if the selected [`Gene`] contained any [`GenomicReceptor`]
+/// and the [`Substrate`] was not a trigger before.
+///
+/// # Parameters
+///
+/// * `genome` - the [`Genome`] to mutate
+///
+/// [`Gene`]: ./struct.Gene.html
+/// [`GenomicReceptor`]: ./struct.GenomicReceptor.html
+/// [`Genome`]: ./struct.Genome.html
+/// [`Substrate`]: ../protein/struct.Substrate.html
+pub fn mutate_receptor_trigger_insertion<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    genome: &Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+) -> Option<
+    Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+> {
+    let gene = genome.get_random_gene();
+    let receptor = genome.get_gene(gene).get_random_receptor()?;
+    let trigger = genome.get_gene(gene).get_random_substrate();
+    let mut mutated_genome = genome.duplicate();
+    let receptor = mutated_genome
+        .get_gene_mut(gene)
+        .receptor_mut(receptor)
+        .expect("A random receptor must exist.");
+    if receptor.triggers().contains(&trigger) {
+        return None;
+    }
+    receptor.add_trigger(trigger).map(|_| mutated_genome)
+}
+
+/// Duplicates the [`Genome`] and removes a random triggering [`Substrate`] from a random
+/// [`GenomicReceptor`].
+/// Returns the altered [`Genome`] if the selected [`GenomicReceptor`] had more than 1 trigger,
+/// so every [`GenomicReceptor`] can still be triggered.
+///
+/// # Parameters
+///
+/// * `genome` - the [`Genome`] to mutate
+///
+/// [`GenomicReceptor`]: ./struct.GenomicReceptor.html
+/// [`Genome`]: ./struct.Genome.html
+/// [`Substrate`]: ../protein/struct.Substrate.html
+pub fn mutate_receptor_trigger_deletion<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    genome: &Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+) -> Option<
+    Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+> {
+    let gene = genome.get_random_gene();
+    let receptor = genome.get_gene(gene).get_random_receptor()?;
+    let mut mutated_genome = genome.duplicate();
+    let receptor = mutated_genome
+        .get_gene_mut(gene)
+        .receptor_mut(receptor)
+        .expect("A random receptor must exist.");
+    if receptor.triggers().len() < 2 {
+        return None;
+    }
+    let trigger = receptor.get_random_trigger()?;
+    receptor.remove_trigger(trigger);
+    Some(mutated_genome)
+}
+
+/// Duplicates the [`Genome`] and replaces the [`State`] of a random [`GenomicReceptor`]
+/// with a random one checking random [`Substrate`]s of the containing [`Gene`].
+/// Returns the altered [`Genome`] if the selected [`Gene`] contained any [`GenomicReceptor`].
+///
+/// # Parameters
+///
+/// * `genome` - the [`Genome`] to mutate
+///
+/// [`Gene`]: ./struct.Gene.html
+/// [`GenomicReceptor`]: ./struct.GenomicReceptor.html
+/// [`Genome`]: ./struct.Genome.html
+/// [`State`]: ../chemistry/trait.State.html
+/// [`Substrate`]: ../protein/struct.Substrate.html
+pub fn mutate_receptor_state_replacement<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    genome: &Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+) -> Option<
+    Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+> {
+    let gene = genome.get_random_gene();
+    let receptor = genome.get_gene(gene).get_random_receptor()?;
+    let state = StateType::random();
+    let substrates = (0..state.get_substrate_number())
+        .map(|_| genome.get_gene(gene).get_random_substrate())
+        .collect();
+    let mut mutated_genome = genome.duplicate();
+    mutated_genome
+        .get_gene_mut(gene)
+        .receptor_mut(receptor)
+        .expect("A random receptor must exist.")
+        .replace_state(state, substrates);
+    Some(mutated_genome)
+}
+
+/// Duplicates the [`Genome`] and replaces the [`GenomicCatalyticCentre`] of a random
+/// [`GenomicReceptor`] with a random one catalysing a random [`Reaction`] on
+/// [`Substrate`]s of the containing [`Gene`].
+/// Returns the altered [`Genome`] if the selected [`Gene`] contained any [`GenomicReceptor`].
+///
+/// # Parameters
+///
+/// * `genome` - the [`Genome`] to mutate
+///
+/// [`Gene`]: ./struct.Gene.html
+/// [`GenomicCatalyticCentre`]: ./struct.GenomicCatalyticCentre.html
+/// [`GenomicReceptor`]: ./struct.GenomicReceptor.html
+/// [`Genome`]: ./struct.Genome.html
+/// [`Reaction`]: ../chemistry/trait.Reaction.html
+/// [`Substrate`]: ../protein/struct.Substrate.html
+pub fn mutate_receptor_enzyme_replacement<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    genome: &Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+) -> Option<
+    Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+> {
+    let gene = genome.get_random_gene();
+    let receptor = genome.get_gene(gene).get_random_receptor()?;
+    let enzyme = genome.get_gene(gene).random_catalytic_centre();
+    let mut mutated_genome = genome.duplicate();
+    mutated_genome
+        .get_gene_mut(gene)
+        .receptor_mut(receptor)
+        .expect("A random receptor must exist.")
+        .replace_enzyme(enzyme);
+    Some(mutated_genome)
+}
+
+/// Returns all structural [`Mutation`]s of this module with the specified chance.
+/// Each [`Mutation`] is named after its function without the `mutate_` prefix,
+/// e.g. `gene_duplication`.
+///
+/// # Parameters
+///
+/// * `mutation_chance` - the chance of every mutation happening a single time during
+///   genome duplication
+///
+/// [`Mutation`]: ../environment/struct.Mutation.html
+pub fn structural_mutations<
+    ReactionType: Reaction<InformationType> + 'static,
+    StateType: State<InformationType> + 'static,
+    InformationType: Information + 'static,
+    InputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned + 'static,
+    InputSensorType: Input<InputElementType, InformationType> + 'static,
+    OutputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned + 'static,
+    OutputSensorType: Output<OutputElementType, InformationType> + 'static,
+>(
+    mutation_chance: Nlbf64,
+) -> Vec<
+    Mutation<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+> {
+    vec![
+        Mutation::new(mutation_chance, mutate_gene_duplication).with_name("gene_duplication"),
+        Mutation::new(mutation_chance, mutate_gene_deletion).with_name("gene_deletion"),
+        Mutation::new(mutation_chance, mutate_gene_fusion).with_name("gene_fusion"),
+        Mutation::new(mutation_chance, mutate_association_insertion)
+            .with_name("association_insertion"),
+        Mutation::new(mutation_chance, mutate_association_deletion)
+            .with_name("association_deletion"),
+        Mutation::new(mutation_chance, mutate_association_gene_insertion)
+            .with_name("association_gene_insertion"),
+        Mutation::new(mutation_chance, mutate_association_gene_deletion)
+            .with_name("association_gene_deletion"),
+        Mutation::new(mutation_chance, mutate_input_association).with_name("input_association"),
+        Mutation::new(mutation_chance, mutate_input_dissociation).with_name("input_dissociation"),
+        Mutation::new(mutation_chance, mutate_output_association).with_name("output_association"),
+        Mutation::new(mutation_chance, mutate_output_dissociation).with_name("output_dissociation"),
+        Mutation::new(mutation_chance, mutate_receptor_insertion).with_name("receptor_insertion"),
+        Mutation::new(mutation_chance, mutate_receptor_deletion).with_name("receptor_deletion"),
+        Mutation::new(mutation_chance, mutate_receptor_trigger_insertion)
+            .with_name("receptor_trigger_insertion"),
+        Mutation::new(mutation_chance, mutate_receptor_trigger_deletion)
+            .with_name("receptor_trigger_deletion"),
+        Mutation::new(mutation_chance, mutate_receptor_state_replacement)
+            .with_name("receptor_state_replacement"),
+        Mutation::new(mutation_chance, mutate_receptor_enzyme_replacement)
+            .with_name("receptor_enzyme_replacement"),
+    ]
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,206 @@
+use std::collections::HashMap;
+
+use crate::evolution::gene::{Gene, GenomicInputSensor, GenomicOutputSensor};
+use crate::evolution::helper::testing::{
+    TestGenome, TestInformation, TestInput, TestOutput, TestReaction, TestState,
+};
+
+use super::*;
+
+/// Creates a [`TestGenome`] with the specified number of [`Gene`]s, each containing
+/// 2 substrates, and 2 unassociated inputs and outputs.
+fn test_genome(number_of_genes: usize) -> TestGenome {
+    let genes = (0..number_of_genes)
+        .map(|gene| {
+            Gene::new(vec![
+                TestInformation { value: 2 * gene },
+                TestInformation {
+                    value: 2 * gene + 1,
+                },
+            ])
+        })
+        .collect();
+    Genome::new(
+        GenomicInputSensor::new(vec![None, None], HashMap::new(), TestInput::default()),
+        GenomicOutputSensor::new(vec![None, None], HashMap::new(), None, TestOutput::default()),
+        genes,
+    )
+}
+
+#[test]
+/// Tests if the functions `mutate_gene_duplication`, `mutate_gene_deletion` and
+/// `mutate_gene_fusion` correctly change the number of [`Gene`]s.
+fn test_gene_mutations() {
+    let genome = test_genome(1);
+    let duplicated = mutate_gene_duplication(&genome).unwrap();
+    assert_eq!(duplicated.number_of_genes().get(), 2);
+    assert_eq!(duplicated.get_gene(0), duplicated.get_gene(1));
+    // A genome must keep at least a single gene and fusion needs 2 genes.
+    assert!(mutate_gene_deletion(&genome).is_none());
+    assert!(mutate_gene_fusion(&genome).is_none());
+    let genome = test_genome(2);
+    assert_eq!(
+        mutate_gene_deletion(&genome)
+            .unwrap()
+            .number_of_genes()
+            .get(),
+        1
+    );
+    let fused = mutate_gene_fusion(&genome).unwrap();
+    assert_eq!(fused.number_of_genes().get(), 3);
+    assert_eq!(fused.get_gene(2).number_of_substrates().get(), 4);
+}
+
+#[test]
+/// Tests if the association mutations correctly add and remove [`GeneAssociation`]s
+/// and their references.
+fn test_association_mutations() {
+    let genome = test_genome(2);
+    assert!(mutate_association_deletion(&genome).is_none());
+    assert!(mutate_association_gene_insertion(&genome).is_none());
+    assert!(mutate_association_gene_deletion(&genome).is_none());
+    let associated = mutate_association_insertion(&genome).unwrap();
+    assert_eq!(associated.number_of_associations(), 1);
+    let association = associated.association(0).unwrap();
+    assert_eq!(association.number_of_associated_substrates(), 1);
+    // The association starts with the value of the associated substrate.
+    assert_eq!(
+        Some(association.substrate()),
+        associated.get_substrate(association.associations()[0])
+    );
+    let dissociated = mutate_association_gene_deletion(&associated).unwrap();
+    assert_eq!(
+        dissociated
+            .association(0)
+            .unwrap()
+            .number_of_associated_substrates(),
+        0
+    );
+    assert_eq!(
+        mutate_association_deletion(&associated)
+            .unwrap()
+            .number_of_associations(),
+        0
+    );
+    // Inserted references are always new.
+    for _ in 0..20 {
+        if let Some(inserted) = mutate_association_gene_insertion(&associated) {
+            let associations = inserted.association(0).unwrap().associations();
+            assert_eq!(associations.len(), 2);
+            assert_ne!(associations[0], associations[1]);
+        }
+    }
+}
+
+#[test]
+/// Tests if the input and output mutations correctly associate and dissociate
+/// distinct substrates.
+fn test_sensor_mutations() {
+    let genome = test_genome(1);
+    assert!(mutate_input_dissociation(&genome).is_none());
+    assert!(mutate_output_dissociation(&genome).is_none());
+    let mut associated = genome.clone();
+    // Once both inputs and outputs are associated no further association is possible.
+    for _ in 0..200 {
+        if let Some(mutated) = mutate_input_association(&associated) {
+            associated = mutated;
+        }
+        if let Some(mutated) = mutate_output_association(&associated) {
+            associated = mutated;
+        }
+    }
+    let inputs = associated.input().input_substrates();
+    assert_eq!(associated.input().number_of_associated_inputs(), 2);
+    assert_ne!(inputs[0], inputs[1]);
+    let outputs = associated.output().output_substrates();
+    assert_eq!(associated.output().number_of_associated_outputs(), 2);
+    assert_ne!(outputs[0], outputs[1]);
+    // Every input is associated, so dissociation must succeed.
+    let dissociated = mutate_input_dissociation(&associated).unwrap();
+    assert_eq!(dissociated.input().number_of_associated_inputs(), 1);
+    let dissociated = mutate_output_dissociation(&associated).unwrap();
+    assert_eq!(dissociated.output().number_of_associated_outputs(), 1);
+}
+
+#[test]
+/// Tests if the receptor mutations correctly add, remove and replace the parts of
+/// [`GenomicReceptor`]s.
+fn test_receptor_mutations() {
+    let genome = test_genome(1);
+    assert!(mutate_receptor_deletion(&genome).is_none());
+    assert!(mutate_receptor_trigger_insertion(&genome).is_none());
+    assert!(mutate_receptor_trigger_deletion(&genome).is_none());
+    assert!(mutate_receptor_state_replacement(&genome).is_none());
+    assert!(mutate_receptor_enzyme_replacement(&genome).is_none());
+    let with_receptor = mutate_receptor_insertion(&genome).unwrap();
+    assert_eq!(with_receptor.get_gene(0).number_of_receptors(), 1);
+    assert_eq!(
+        mutate_receptor_deletion(&with_receptor)
+            .unwrap()
+            .get_gene(0)
+            .number_of_receptors(),
+        0
+    );
+    // A receptor must keep a single trigger.
+    assert!(mutate_receptor_trigger_deletion(&with_receptor).is_none());
+    // The random trigger might already be present, so the insertion is repeated.
+    let with_triggers = (0..200)
+        .find_map(|_| mutate_receptor_trigger_insertion(&with_receptor))
+        .unwrap();
+    assert_eq!(
+        with_triggers
+            .get_gene(0)
+            .receptor(0)
+            .unwrap()
+            .triggers()
+            .len(),
+        2
+    );
+    // Both substrates of the gene are triggers now.
+    assert!(mutate_receptor_trigger_insertion(&with_triggers).is_none());
+    assert_eq!(
+        mutate_receptor_trigger_deletion(&with_triggers)
+            .unwrap()
+            .get_gene(0)
+            .receptor(0)
+            .unwrap()
+            .triggers()
+            .len(),
+        1
+    );
+    let replaced = mutate_receptor_state_replacement(&with_receptor).unwrap();
+    assert_eq!(replaced.get_gene(0).receptor(0).unwrap().state(), &TestState {});
+    let replaced = mutate_receptor_enzyme_replacement(&with_receptor).unwrap();
+    assert_eq!(
+        replaced
+            .get_gene(0)
+            .receptor(0)
+            .unwrap()
+            .enzyme()
+            .reaction(),
+        &TestReaction {}
+    );
+}
+
+#[test]
+/// Tests if the function `structural_mutations` correctly creates a named [`Mutation`]
+/// for every structural mutation.
+fn test_structural_mutations() {
+    let mutations: Vec<
+        Mutation<TestReaction, TestState, TestInformation, (), TestInput, (), TestOutput>,
+    > = structural_mutations(Nlbf64::from(0.5));
+    assert_eq!(mutations.len(), 17);
+    assert_eq!(mutations[0].name(), "gene_duplication");
+    assert_eq!(mutations[16].name(), "receptor_enzyme_replacement");
+    for mutation in &mutations {
+        assert_ulps_eq!(mutation.mutation_chance().value(), 0.5);
+    }
+    assert_eq!(
+        mutations[0]
+            .mutate(&test_genome(1))
+            .unwrap()
+            .number_of_genes()
+            .get(),
+        2
+    );
+}
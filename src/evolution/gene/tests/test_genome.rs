@@ -1,3 +1,5 @@
+use std::num::NonZeroU32;
+
 use crate::evolution::helper::noop::{NoOpGene, NoOpGenome};
 
 use super::*;
@@ -36,6 +38,7 @@ fn test_validate_associations() {
             output: output_sensor,
             genes: vec![gene],
             associations: vec![invalid_association],
+            rate_modifiers: RateModifiers::default(),
         };
         genome.validate_associations();
         assert_eq!(genome.input.input_substrates(), &vec!(None));
@@ -99,3 +102,51 @@ fn test_has_substrate() {
         assert!(!has_substrate(&genome.genes, &negative_b));
     }
 }
+
+#[test]
+/// Tests if the function `remove_gene` of the `Genome` struct correctly adjusts the gene
+/// indices of associations and sensors.
+fn test_remove_gene() {
+    let input_sensor =
+        GenomicInputSensor::new(vec![Some(GeneSubstrate::new(2, 0))], HashMap::new(), ());
+    let output_sensor =
+        GenomicOutputSensor::new(vec![Some(GeneSubstrate::new(1, 0))], HashMap::new(), None, ());
+    let mut genome: NoOpGenome = Genome::new(
+        input_sensor,
+        output_sensor,
+        vec![
+            Gene::new(vec![()]),
+            Gene::new(vec![()]),
+            Gene::new(vec![()]),
+        ],
+    );
+    let mut association = GeneAssociation::new(());
+    association.add_association(GeneSubstrate::new(1, 0));
+    association.add_association(GeneSubstrate::new(2, 0));
+    genome.add_association(association);
+    genome.remove_gene(1);
+    assert_eq!(genome.number_of_genes().get(), 2);
+    assert_eq!(genome.input.input_substrates(), &vec!(Some(GeneSubstrate::new(1, 0))));
+    assert_eq!(genome.output.output_substrates(), &vec!(None));
+    assert_eq!(genome.associations[0].associations(), &[GeneSubstrate::new(1, 0)]);
+}
+
+#[test]
+/// Tests if the function `binary_size` of the `Genome` struct correctly calculates the
+/// serialised size from the cached sizes of its genes, also after a clone was changed.
+fn test_binary_size() {
+    let genome = crate::evolution::neuron::random_genome(
+        NonZeroU32::new(10).unwrap(),
+        NonZeroU32::new(20).unwrap(),
+        2,
+        2,
+    );
+    assert_eq!(genome.binary_size(), rmp_serde::to_vec(&genome).unwrap().len());
+    let mut changed = genome.clone();
+    changed.get_gene_mut(0).add_substrate(thread_rng().gen());
+    changed.get_gene_mut(0).remove_receptor(0);
+    assert_eq!(changed.binary_size(), rmp_serde::to_vec(&changed).unwrap().len());
+    assert_eq!(genome.binary_size(), rmp_serde::to_vec(&genome).unwrap().len());
+    *changed.get_substrate_mut(GeneSubstrate::new(0, 0)).unwrap() = thread_rng().gen();
+    assert_eq!(changed.binary_size(), rmp_serde::to_vec(&changed).unwrap().len());
+}
@@ -0,0 +1,317 @@
+//! The `genealogy` module contains the lineage of [`Individual`]s and the genealogy log
+//! used to reconstruct how [`Genome`]s arose.
+//!
+//! [`Genome`]: ../gene/struct.Genome.html
+//! [`Individual`]: ../population/struct.Individual.html
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::error::Error;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(Debug, PartialEq, Clone, Default, Serialize, Deserialize)]
+/// The `Lineage` of an [`Individual`] records its parents, its birth generation and the
+/// [`Mutation`]s applied to its [`Genome`]. Founding [`Individual`]s have no parents.
+///
+/// [`Genome`]: ../gene/struct.Genome.html
+/// [`Individual`]: ../population/struct.Individual.html
+/// [`Mutation`]: ../environment/struct.Mutation.html
+pub struct Lineage {
+    parents: Vec<Uuid>,
+    birth_generation: u64,
+    mutations: Vec<String>,
+    #[serde(default)]
+    parent_fitness: Option<f64>,
+}
+
+impl Lineage {
+    /// Creates a new `Lineage` without any applied [`Mutation`]s.
+    ///
+    /// # Parameters
+    ///
+    /// * `parents` - the UUIDs of the parents, starting with the one that initiated mating
+    /// * `birth_generation` - the generation of birth
+    ///
+    /// [`Mutation`]: ../environment/struct.Mutation.html
+    pub fn new(parents: Vec<Uuid>, birth_generation: u64) -> Self {
+        Lineage {
+            parents,
+            birth_generation,
+            mutations: Vec::new(),
+            parent_fitness: None,
+        }
+    }
+
+    /// Returns the UUIDs of the parents, starting with the one that initiated mating.
+    pub fn parents(&self) -> &[Uuid] {
+        &self.parents
+    }
+
+    /// Returns the generation of birth.
+    pub fn birth_generation(&self) -> u64 {
+        self.birth_generation
+    }
+
+    /// Returns the names of all [`Mutation`]s applied during birth in the order of their
+    /// application.
+    ///
+    /// [`Mutation`]: ../environment/struct.Mutation.html
+    pub fn mutations(&self) -> &[String] {
+        &self.mutations
+    }
+
+    /// Sets the names of all [`Mutation`]s applied during birth.
+    ///
+    /// # Parameters
+    ///
+    /// * `mutations` - the names of the applied [`Mutation`]s
+    ///
+    /// [`Mutation`]: ../environment/struct.Mutation.html
+    pub fn set_mutations(&mut self, mutations: Vec<String>) {
+        self.mutations = mutations;
+    }
+
+    /// Returns the fitness of the parent that initiated mating at the time of birth if it
+    /// was already tested.
+    pub fn parent_fitness(&self) -> Option<f64> {
+        self.parent_fitness
+    }
+
+    /// Sets the fitness of the parent that initiated mating at the time of birth.
+    ///
+    /// # Parameters
+    ///
+    /// * `parent_fitness` - the fitness of the parent if it was already tested
+    pub fn set_parent_fitness(&mut self, parent_fitness: Option<f64>) {
+        self.parent_fitness = parent_fitness;
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(tag = "event")]
+/// A `GenealogyEvent` is a single entry of the genealogy log.
+pub enum GenealogyEvent {
+    /// An [`Individual`] was born or founded a [`Population`].
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    /// [`Population`]: ../population/struct.Population.html
+    Birth { uuid: Uuid, lineage: Lineage },
+    /// An [`Individual`] died with the specified fitness.
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    Death {
+        uuid: Uuid,
+        fitness: Option<f64>,
+        times_tested: u32,
+    },
+}
+
+/// A `GenealogyLog` appends [`GenealogyEvent`]s to a file as JSON lines.
+/// It can be shared between threads.
+///
+/// [`GenealogyEvent`]: ./enum.GenealogyEvent.html
+#[derive(Debug)]
+pub struct GenealogyLog {
+    file: Mutex<File>,
+}
+
+impl GenealogyLog {
+    /// Opens the genealogy log at the specified path. New events are appended to existing ones.
+    /// An error will be returned if opening the file failed.
+    ///
+    /// # Parameters
+    ///
+    /// * `path_to_file` - the JSON lines file to append to
+    pub fn open<P: AsRef<Path>>(path_to_file: P) -> Result<Self, Box<dyn Error + 'static>> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path_to_file)?;
+        Ok(GenealogyLog {
+            file: Mutex::new(file),
+        })
+    }
+
+    /// Appends the specified [`GenealogyEvent`] to the log.
+    /// An error will be returned if writing to the file failed.
+    ///
+    /// # Parameters
+    ///
+    /// * `event` - the [`GenealogyEvent`] to record
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding the log's lock.
+    ///
+    /// [`GenealogyEvent`]: ./enum.GenealogyEvent.html
+    pub fn record(&self, event: &GenealogyEvent) -> Result<(), Box<dyn Error + 'static>> {
+        let mut line = serde_json::to_string(event)?;
+        line.push('\n');
+        self.file
+            .lock()
+            .expect("A thread paniced while holding the genealogy log's lock.")
+            .write_all(line.as_bytes())?;
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+/// A `Genealogy` is the queryable family tree of all [`Individual`]s recorded in a
+/// genealogy log.
+///
+/// [`Individual`]: ../population/struct.Individual.html
+pub struct Genealogy {
+    lineages: HashMap<Uuid, Lineage>,
+    fitness: HashMap<Uuid, Option<f64>>,
+}
+
+impl Genealogy {
+    /// Creates an empty `Genealogy`.
+    pub fn new() -> Self {
+        Genealogy::default()
+    }
+
+    /// Reads a `Genealogy` from the specified genealogy log.
+    /// An error will be returned if reading or parsing the file failed.
+    ///
+    /// # Parameters
+    ///
+    /// * `path_to_file` - the JSON lines file containing the [`GenealogyEvent`]s
+    ///
+    /// [`GenealogyEvent`]: ./enum.GenealogyEvent.html
+    pub fn from_file<P: AsRef<Path>>(path_to_file: P) -> Result<Self, Box<dyn Error + 'static>> {
+        let mut genealogy = Genealogy::new();
+        for line in BufReader::new(File::open(path_to_file)?).lines() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                genealogy.record(serde_json::from_str(&line)?);
+            }
+        }
+        Ok(genealogy)
+    }
+
+    /// Adds the specified [`GenealogyEvent`] to the `Genealogy`.
+    ///
+    /// # Parameters
+    ///
+    /// * `event` - the [`GenealogyEvent`] to add
+    ///
+    /// [`GenealogyEvent`]: ./enum.GenealogyEvent.html
+    pub fn record(&mut self, event: GenealogyEvent) {
+        match event {
+            GenealogyEvent::Birth { uuid, lineage } => {
+                self.lineages.insert(uuid, lineage);
+            },
+            GenealogyEvent::Death { uuid, fitness, .. } => {
+                self.fitness.insert(uuid, fitness);
+            },
+        }
+    }
+
+    /// Returns the [`Lineage`] of the specified [`Individual`] if it was recorded.
+    ///
+    /// # Parameters
+    ///
+    /// * `uuid` - the UUID of the [`Individual`]
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    /// [`Lineage`]: ./struct.Lineage.html
+    pub fn lineage(&self, uuid: &Uuid) -> Option<&Lineage> {
+        self.lineages.get(uuid)
+    }
+
+    /// Returns the fitness the specified [`Individual`] had when it died if it was recorded.
+    ///
+    /// # Parameters
+    ///
+    /// * `uuid` - the UUID of the [`Individual`]
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn fitness(&self, uuid: &Uuid) -> Option<f64> {
+        self.fitness.get(uuid).copied().flatten()
+    }
+
+    /// Returns the UUIDs of all ancestors of the specified [`Individual`] ordered by their
+    /// distance in generations starting with the parents.
+    ///
+    /// # Parameters
+    ///
+    /// * `uuid` - the UUID of the [`Individual`]
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn ancestors(&self, uuid: &Uuid) -> Vec<Uuid> {
+        let mut ancestors = Vec::new();
+        let mut visited: HashSet<Uuid> = HashSet::new();
+        visited.insert(*uuid);
+        let mut queue: VecDeque<Uuid> = VecDeque::new();
+        queue.push_back(*uuid);
+        while let Some(current) = queue.pop_front() {
+            if let Some(lineage) = self.lineage(&current) {
+                for parent in lineage.parents() {
+                    if visited.insert(*parent) {
+                        ancestors.push(*parent);
+                        queue.push_back(*parent);
+                    }
+                }
+            }
+        }
+        ancestors
+    }
+
+    /// Returns the most recently born common ancestor of the specified [`Individual`]s if
+    /// there is any. An [`Individual`] is considered its own ancestor.
+    ///
+    /// # Parameters
+    ///
+    /// * `a` - the UUID of the first [`Individual`]
+    /// * `b` - the UUID of the second [`Individual`]
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn most_recent_common_ancestor(&self, a: &Uuid, b: &Uuid) -> Option<Uuid> {
+        let mut ancestors_a: HashSet<Uuid> = self.ancestors(a).into_iter().collect();
+        ancestors_a.insert(*a);
+        std::iter::once(*b)
+            .chain(self.ancestors(b))
+            .filter(|ancestor| ancestors_a.contains(ancestor))
+            .max_by_key(|ancestor| {
+                self.lineage(ancestor)
+                    .map_or(0, |lineage| lineage.birth_generation())
+            })
+    }
+
+    /// Returns the line of descent of the specified [`Individual`] following the parent that
+    /// initiated mating, starting with the founder and ending with the [`Individual`] itself.
+    /// Each entry contains the UUID and the fitness at death if it was recorded.
+    ///
+    /// # Parameters
+    ///
+    /// * `uuid` - the UUID of the [`Individual`]
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn fitness_trajectory(&self, uuid: &Uuid) -> Vec<(Uuid, Option<f64>)> {
+        let mut trajectory = vec![(*uuid, self.fitness(uuid))];
+        let mut visited: HashSet<Uuid> = HashSet::new();
+        visited.insert(*uuid);
+        let mut current = *uuid;
+        while let Some(parent) = self
+            .lineage(&current)
+            .and_then(|lineage| lineage.parents().first())
+        {
+            if !visited.insert(*parent) {
+                break;
+            }
+            trajectory.push((*parent, self.fitness(parent)));
+            current = *parent;
+        }
+        trajectory.reverse();
+        trajectory
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,106 @@
+use super::*;
+
+/// Creates a [`Genealogy`] with two founders `a` and `b`, their offspring `c` and `d`
+/// and the offspring `e` of `c` and `d`.
+fn test_genealogy() -> (Genealogy, [Uuid; 5]) {
+    let uuids = [
+        Uuid::from_u128(1),
+        Uuid::from_u128(2),
+        Uuid::from_u128(3),
+        Uuid::from_u128(4),
+        Uuid::from_u128(5),
+    ];
+    let [a, b, c, d, e] = uuids;
+    let mut genealogy = Genealogy::new();
+    genealogy.record(GenealogyEvent::Birth {
+        uuid: a,
+        lineage: Lineage::default(),
+    });
+    genealogy.record(GenealogyEvent::Birth {
+        uuid: b,
+        lineage: Lineage::default(),
+    });
+    genealogy.record(GenealogyEvent::Birth {
+        uuid: c,
+        lineage: Lineage::new(vec![a, b], 1),
+    });
+    genealogy.record(GenealogyEvent::Birth {
+        uuid: d,
+        lineage: Lineage::new(vec![b, b], 2),
+    });
+    genealogy.record(GenealogyEvent::Birth {
+        uuid: e,
+        lineage: Lineage::new(vec![c, d], 3),
+    });
+    genealogy.record(GenealogyEvent::Death {
+        uuid: a,
+        fitness: Some(0.25),
+        times_tested: 2,
+    });
+    genealogy.record(GenealogyEvent::Death {
+        uuid: c,
+        fitness: Some(0.5),
+        times_tested: 1,
+    });
+    (genealogy, uuids)
+}
+
+#[test]
+/// Tests if the function `ancestors` of the [`Genealogy`] struct correctly collects
+/// all ancestors.
+fn test_genealogy_ancestors() {
+    let (genealogy, [a, b, c, d, e]) = test_genealogy();
+    assert!(genealogy.ancestors(&a).is_empty());
+    assert_eq!(genealogy.ancestors(&d), vec![b]);
+    assert_eq!(genealogy.ancestors(&e), vec![c, d, a, b]);
+}
+
+#[test]
+/// Tests if the function `most_recent_common_ancestor` of the [`Genealogy`] struct correctly
+/// finds the most recently born common ancestor.
+fn test_genealogy_most_recent_common_ancestor() {
+    let (genealogy, [a, b, c, d, e]) = test_genealogy();
+    assert_eq!(genealogy.most_recent_common_ancestor(&c, &d), Some(b));
+    assert_eq!(genealogy.most_recent_common_ancestor(&e, &c), Some(c));
+    assert_eq!(genealogy.most_recent_common_ancestor(&a, &d), None);
+    assert_eq!(genealogy.most_recent_common_ancestor(&a, &e), Some(a));
+}
+
+#[test]
+/// Tests if the function `fitness_trajectory` of the [`Genealogy`] struct correctly follows
+/// the line of descent.
+fn test_genealogy_fitness_trajectory() {
+    let (genealogy, [a, _, c, _, e]) = test_genealogy();
+    assert_eq!(genealogy.fitness_trajectory(&e), vec![(a, Some(0.25)), (c, Some(0.5)), (e, None)]);
+}
+
+#[test]
+/// Tests if the functions `record` of the [`GenealogyLog`] struct and `from_file` of the
+/// [`Genealogy`] struct correctly persist [`GenealogyEvent`]s.
+fn test_genealogy_log_record() {
+    let path = std::env::temp_dir()
+        .join(format!("oben_genealogy_{}.jsonl", Uuid::from_u128(rand::random())));
+    let parent = Uuid::from_u128(1);
+    let child = Uuid::from_u128(2);
+    let mut lineage = Lineage::new(vec![parent, parent], 7);
+    lineage.set_mutations(vec!["duplicate".to_string()]);
+    {
+        let log = GenealogyLog::open(&path).unwrap();
+        log.record(&GenealogyEvent::Birth {
+            uuid: child,
+            lineage: lineage.clone(),
+        })
+        .unwrap();
+        log.record(&GenealogyEvent::Death {
+            uuid: child,
+            fitness: Some(0.75),
+            times_tested: 3,
+        })
+        .unwrap();
+    }
+    let genealogy = Genealogy::from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(genealogy.lineage(&child), Some(&lineage));
+    assert_eq!(genealogy.fitness(&child), Some(0.75));
+    assert_eq!(genealogy.ancestors(&child), vec![parent]);
+}
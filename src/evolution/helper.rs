@@ -6,7 +6,9 @@ use serde::{Deserialize, Serialize};
 use std::{
     borrow::Borrow,
     convert::TryFrom,
+    io::Write,
     ops::{Add, AddAssign, Sub, SubAssign},
+    sync::{Arc, OnceLock},
 };
 
 use super::{
@@ -174,6 +176,12 @@ impl<T: PartialEq> ActionChain<T> {
         }
     }
 
+    /// Returns all actions of the current [`Iteration`](crate::evolution::helper::Iteration)
+    /// cycle.
+    pub fn actions(&self) -> &[T] {
+        &self.actions
+    }
+
     /// Returns `true` if no actions are in the `ActionChain`.
     pub fn is_empty(&self) -> bool {
         self.actions.is_empty()
@@ -190,6 +198,93 @@ impl<T, I: Borrow<Iteration>> From<I> for ActionChain<T> {
     }
 }
 
+/// An `IndexedActionChain` is an [`ActionChain`] of actions identified by indices, e.g. the
+/// ids of [`Receptor`](crate::evolution::protein::Receptor)s. Instead of searching the
+/// pending actions, every index is stamped with the cycle it was last added in, so
+/// duplicates are detected in constant time.
+pub struct IndexedActionChain {
+    mean_size: usize,
+    iteration: Iteration,
+    actions: Vec<usize>,
+    cycle: u64,
+    stamps: Vec<u64>,
+}
+
+impl IndexedActionChain {
+    /// Creates a new `IndexedActionChain` starting a new
+    /// [`Iteration`](crate::evolution::helper::Iteration) cycle.
+    pub fn new() -> Self {
+        Iteration::new().into()
+    }
+
+    /// Returns the current [`Iteration`](crate::evolution::helper::Iteration) the actions
+    /// are performed at.
+    pub fn current_iteration(&self) -> Iteration {
+        self.iteration
+    }
+
+    /// Returns all actions and starts a new [`Iteration`](crate::evolution::helper::Iteration)
+    /// cycle.
+    pub fn pop_actions(&mut self) -> Vec<usize> {
+        self.mean_size = (self.mean_size + self.actions.len()) / 2;
+        self.iteration = self.iteration.increment();
+        // Stamps of the previous cycle are outdated from now on.
+        self.cycle += 1;
+        let mut new_actions = Vec::with_capacity(self.mean_size);
+        std::mem::swap(&mut self.actions, &mut new_actions);
+        new_actions
+    }
+
+    /// Adds an action to the `IndexedActionChain` if the action is not already present and
+    /// returns `true` if the action was successfully added.
+    ///
+    /// # Parameters
+    ///
+    /// * `action` - the index of the action to add
+    pub fn push_action(&mut self, action: usize) -> bool {
+        if action >= self.stamps.len() {
+            self.stamps.resize(action + 1, 0);
+        }
+        if self.stamps[action] == self.cycle {
+            false
+        } else {
+            self.stamps[action] = self.cycle;
+            self.actions.push(action);
+            true
+        }
+    }
+
+    /// Returns all actions of the current [`Iteration`](crate::evolution::helper::Iteration)
+    /// cycle.
+    pub fn actions(&self) -> &[usize] {
+        &self.actions
+    }
+
+    /// Returns `true` if no actions are in the `IndexedActionChain`.
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+}
+
+impl Default for IndexedActionChain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I: Borrow<Iteration>> From<I> for IndexedActionChain {
+    fn from(starting_iteration: I) -> Self {
+        Self {
+            mean_size: 0,
+            iteration: *starting_iteration.borrow(),
+            actions: Vec::new(),
+            // Unused indices are stamped with 0, so the first cycle must differ.
+            cycle: 1,
+            stamps: Vec::new(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 /// A scaling factor to scale the fitness function based on the current mean fitness of the population.
 pub struct ScalingFactor {
@@ -220,6 +315,11 @@ impl ScalingFactor {
         }
     }
 
+    /// Returns the base to scale by.
+    pub fn base(&self) -> f64 {
+        self.base
+    }
+
     /// Returns the scaling exponent.
     pub fn exponent(&self) -> i32 {
         self.factor
@@ -382,6 +482,109 @@ impl CrossOver for Nlbf64 {
     }
 }
 
+/// A `Cache` holds a value derived from an immutable structure, e.g. the translated
+/// [`Organism`] of an [`Individual`] or the binary size of a [`Gene`]. Since the value is
+/// fully determined by the structure, the `Cache` is shared between clones and ignored on
+/// comparison. Structures that change must replace their `Cache` instead of resetting it.
+///
+/// [`Gene`]: ../gene/struct.Gene.html
+/// [`Individual`]: ../population/struct.Individual.html
+/// [`Organism`]: ../population/struct.Organism.html
+pub(crate) struct Cache<ValueType> {
+    pub(crate) value: Arc<OnceLock<ValueType>>,
+}
+
+impl<ValueType> Default for Cache<ValueType> {
+    fn default() -> Self {
+        Cache {
+            value: Arc::new(OnceLock::new()),
+        }
+    }
+}
+
+impl<ValueType> From<ValueType> for Cache<ValueType> {
+    fn from(value: ValueType) -> Self {
+        Cache {
+            value: Arc::new(OnceLock::from(value)),
+        }
+    }
+}
+
+impl<ValueType> Clone for Cache<ValueType> {
+    fn clone(&self) -> Self {
+        Cache {
+            value: Arc::clone(&self.value),
+        }
+    }
+}
+
+impl<ValueType> PartialEq for Cache<ValueType> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<ValueType> std::fmt::Debug for Cache<ValueType> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Cache")
+            .field("cached", &self.value.get().is_some())
+            .finish()
+    }
+}
+
+#[derive(Debug, Default)]
+/// A `ByteCounter` is a [`Write`] sink that only counts the bytes written to it,
+/// so sizes can be determined without allocating the serialised data.
+///
+/// [`Write`]: https://doc.rust-lang.org/std/io/trait.Write.html
+struct ByteCounter {
+    bytes: usize,
+}
+
+impl Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.bytes += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Returns the number of bytes of the specified value serialised by `rmp_serde`
+/// without allocating the serialised data.
+///
+/// # Parameters
+///
+/// * `value` - the value to measure
+///
+/// # Panics
+///
+/// If the underlying serialisation fails.
+pub(crate) fn serialised_size<T: Serialize + ?Sized>(value: &T) -> usize {
+    let mut counter = ByteCounter::default();
+    rmp_serde::encode::write(&mut counter, value).expect("Serialisation failed.");
+    counter.bytes
+}
+
+/// Returns the number of bytes `rmp_serde` uses to announce a sequence of the specified
+/// length.
+///
+/// # Parameters
+///
+/// * `length` - the number of elements of the sequence
+///
+/// # Panics
+///
+/// If the length exceeds the maximum length of a serialised sequence.
+pub(crate) fn serialised_sequence_header_size(length: usize) -> usize {
+    let mut counter = ByteCounter::default();
+    let length = u32::try_from(length).expect("The sequence is too long to be serialised.");
+    rmp::encode::write_array_len(&mut counter, length).expect("Serialisation failed.");
+    counter.bytes
+}
+
 pub mod nlgbf;
 pub mod noop;
 pub mod testing;
@@ -4,10 +4,21 @@ use serde::{Deserialize, Serialize};
 
 use crate::evolution::{
     chemistry::{Information, Input, Reaction, State, Output},
-    gene::{CrossOver, Genome},
+    gene::{
+        CrossOver, Gene, GeneSubstrate, Genome, GenomicCatalyticCentre, GenomicInputSensor,
+        GenomicOutputSensor, GenomicReceptor,
+    },
+    neuron::{
+        SimpleDendriteActivationPotential, SimpleDendriteThreshold, SimpleNeuron,
+        SimpleNeuronParameterInputSensor, SimpleNeuronParameterOutputSensor,
+    },
+    population::Organism,
 };
 
-use super::noop::{NoOpInputElement, NoOpOutputElement};
+use super::{
+    noop::{NoOpInputElement, NoOpOutputElement},
+    Nlbf64,
+};
 
 /// A [`Genome`] for testing purposes.
 pub type TestGenome = Genome<TestReaction, TestState, TestInformation, NoOpInputElement, TestInput, NoOpOutputElement, TestOutput>;
@@ -204,3 +215,123 @@ impl State<TestInformation> for TestState {
         TestState {}
     }
 }
+
+/// A neuronal [`Genome`] for testing purposes.
+pub type NeuronGenome = Genome<
+    SimpleDendriteActivationPotential,
+    SimpleDendriteThreshold,
+    SimpleNeuron,
+    Vec<Nlbf64>,
+    SimpleNeuronParameterInputSensor,
+    Vec<Nlbf64>,
+    SimpleNeuronParameterOutputSensor,
+>;
+
+/// A neuronal [`Organism`] for testing purposes.
+pub type NeuronOrganism = Organism<
+    SimpleDendriteActivationPotential,
+    SimpleDendriteThreshold,
+    SimpleNeuron,
+    Vec<Nlbf64>,
+    SimpleNeuronParameterInputSensor,
+    Vec<Nlbf64>,
+    SimpleNeuronParameterOutputSensor,
+>;
+
+/// A neuronal [`Gene`] for testing purposes.
+pub type NeuronGene =
+    Gene<SimpleDendriteActivationPotential, SimpleDendriteThreshold, SimpleNeuron>;
+
+/// A dendrite of a [`NeuronGene`] for testing purposes.
+pub type Dendrite =
+    GenomicReceptor<SimpleDendriteActivationPotential, SimpleDendriteThreshold, SimpleNeuron>;
+
+/// Creates an excitatory dendrite from the specified neuron to the specified neuron, which
+/// fires whenever the potential changes.
+///
+/// # Parameters
+///
+/// * `from` - the index of the presynaptic neuron
+/// * `to` - the index of the postsynaptic neuron
+pub fn dendrite(from: usize, to: usize) -> Dendrite {
+    threshold_dendrite(from, to, 0.0)
+}
+
+/// Creates an excitatory dendrite from the specified neuron to the specified neuron, which
+/// fires if the potential reaches the specified threshold.
+///
+/// # Parameters
+///
+/// * `from` - the index of the presynaptic neuron
+/// * `to` - the index of the postsynaptic neuron
+/// * `threshold` - the potential the presynaptic neuron must reach
+pub fn threshold_dendrite(from: usize, to: usize, threshold: f64) -> Dendrite {
+    GenomicReceptor::new(
+        vec![from],
+        vec![from],
+        SimpleDendriteThreshold::new(threshold),
+        GenomicCatalyticCentre::new(
+            vec![from],
+            vec![to],
+            SimpleDendriteActivationPotential::new(0.25, false),
+        ),
+    )
+}
+
+/// Creates a [`NeuronGene`] with the specified number of resting neurons and dendrites.
+///
+/// # Parameters
+///
+/// * `neurons` - the number of neurons
+/// * `dendrites` - the dendrites connecting the neurons
+pub fn neuron_gene(neurons: usize, dendrites: Vec<Dendrite>) -> NeuronGene {
+    let mut gene = Gene::new(vec![SimpleNeuron::new(0.0, 10.0); neurons]);
+    for dendrite in dendrites {
+        gene.add_receptor(dendrite);
+    }
+    gene
+}
+
+/// Creates a [`NeuronGenome`] with the specified genes, whose input is the first and
+/// output the third neuron of the first gene.
+///
+/// # Parameters
+///
+/// * `genes` - the genes of the [`NeuronGenome`]
+pub fn neuron_genome(genes: Vec<NeuronGene>) -> NeuronGenome {
+    Genome::new(
+        GenomicInputSensor::new(
+            vec![Some(GeneSubstrate::new(0, 0))],
+            HashMap::new(),
+            SimpleNeuronParameterInputSensor {},
+        ),
+        GenomicOutputSensor::new(
+            vec![Some(GeneSubstrate::new(0, 2))],
+            HashMap::new(),
+            None,
+            SimpleNeuronParameterOutputSensor {},
+        ),
+        genes,
+    )
+}
+
+/// Creates a [`NeuronGenome`] of five neurons with the input at the first and the output
+/// at the third neuron. The first neuron excites the second, which excites the third and
+/// fifth.
+pub fn neuron_chain_genome() -> NeuronGenome {
+    neuron_genome(vec![neuron_gene(
+        5,
+        vec![dendrite(0, 1), dendrite(1, 2), dendrite(1, 4)],
+    )])
+}
+
+/// Translates the specified [`NeuronGenome`] into a [`NeuronOrganism`] with an input of 0.5.
+///
+/// # Parameters
+///
+/// * `genome` - the [`NeuronGenome`] to translate
+pub fn neuron_organism(genome: &NeuronGenome) -> NeuronOrganism {
+    let mut organism = genome.translate();
+    organism.set_input(vec![Nlbf64::from(0.5)]);
+    organism
+}
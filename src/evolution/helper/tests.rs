@@ -176,6 +176,42 @@ fn test_action_chain_from_iteration() {
     assert_eq!(action_chain.current_iteration() - Iteration::new(), 100);
 }
 
+#[test]
+/// Tests the basic functionality of `IndexedActionChain`.
+fn test_indexed_action_chain() {
+    let mut action_chain = IndexedActionChain::new();
+    let start_iter = action_chain.current_iteration();
+    assert!(action_chain.push_action(1));
+    assert!(action_chain.push_action(20));
+    assert!(action_chain.push_action(3));
+    assert!(!action_chain.push_action(1));
+    assert!(!action_chain.push_action(20));
+    assert_eq!(action_chain.actions(), &[1, 20, 3]);
+    assert_eq!(vec!(1, 20, 3), action_chain.pop_actions());
+    assert_eq!(action_chain.current_iteration() - start_iter, 1);
+    assert!(action_chain.is_empty());
+    assert!(action_chain.push_action(20));
+    assert!(action_chain.push_action(0));
+    assert!(!action_chain.push_action(0));
+    assert_eq!(vec!(20, 0), action_chain.pop_actions());
+    let empty: Vec<usize> = Vec::new();
+    assert_eq!(empty, action_chain.pop_actions());
+    assert_eq!(action_chain.current_iteration() - start_iter, 3);
+}
+
+#[test]
+/// Tests the creation of an `IndexedActionChain` from an `Iteration`.
+fn test_indexed_action_chain_from_iteration() {
+    let mut iter = Iteration::new();
+    for _ in 0..100 {
+        iter = iter.increment();
+    }
+    let mut action_chain: IndexedActionChain = iter.into();
+    assert_eq!(action_chain.current_iteration(), iter);
+    assert_eq!(action_chain.current_iteration() - Iteration::new(), 100);
+    assert!(action_chain.push_action(0));
+}
+
 #[test]
 /// Tests the `increment` function of the `ScalingFactor` struct.
 fn test_scaling_factor_increment() {
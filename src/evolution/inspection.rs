@@ -0,0 +1,302 @@
+//! The `inspection` module contains summaries of [`Population`] snapshots, which allow
+//! inspecting and comparing populations independently of their [`Genome`] type.
+//!
+//! [`Genome`]: ../gene/struct.Genome.html
+//! [`Population`]: ../population/struct.Population.html
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::chemistry::{Information, Input, Output, Reaction, State};
+use super::population::{Individual, Population, PopulationStatistics};
+
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+/// An `IndividualSummary` contains the key properties of an [`Individual`].
+///
+/// [`Individual`]: ../population/struct.Individual.html
+pub struct IndividualSummary {
+    uuid: Uuid,
+    fitness: Option<f64>,
+    age: u32,
+    bytes: usize,
+    times_tested: u32,
+}
+
+impl IndividualSummary {
+    /// Summarises the specified [`Individual`].
+    ///
+    /// # Parameters
+    ///
+    /// * `individual` - the [`Individual`] to summarise
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn from_individual<
+        ReactionType: Reaction<InformationType>,
+        StateType: State<InformationType>,
+        InformationType: Information,
+        InputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+        InputSensorType: Input<InputElementType, InformationType>,
+        OutputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+        OutputSensorType: Output<OutputElementType, InformationType>,
+    >(
+        individual: &Individual<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    ) -> Self {
+        IndividualSummary {
+            uuid: *individual.uuid(),
+            fitness: individual.fitness(),
+            age: individual.age(),
+            bytes: individual.bytes(),
+            times_tested: individual.times_tested(),
+        }
+    }
+
+    /// Returns the UUID of the [`Individual`].
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn uuid(&self) -> &Uuid {
+        &self.uuid
+    }
+
+    /// Returns the fitness of the [`Individual`] if it was already tested.
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn fitness(&self) -> Option<f64> {
+        self.fitness
+    }
+
+    /// Returns the age of the [`Individual`].
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn age(&self) -> u32 {
+        self.age
+    }
+
+    /// Returns the size of the [`Genome`] of the [`Individual`] in byte.
+    ///
+    /// [`Genome`]: ../gene/struct.Genome.html
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    /// Returns how often the [`Individual`] was already tested.
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn times_tested(&self) -> u32 {
+        self.times_tested
+    }
+
+    /// Orders `IndividualSummary`s by descending fitness, placing untested individuals last
+    /// and breaking ties by UUID.
+    fn fitness_order(&self, other: &Self) -> Ordering {
+        match (self.fitness, other.fitness) {
+            (Some(a), Some(b)) => b.total_cmp(&a),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+        .then_with(|| self.uuid.cmp(&other.uuid))
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// A `SnapshotSummary` contains the [`PopulationStatistics`] and an [`IndividualSummary`] of
+/// every [`Individual`] of a [`Population`].
+///
+/// [`Individual`]: ../population/struct.Individual.html
+/// [`IndividualSummary`]: ./struct.IndividualSummary.html
+/// [`Population`]: ../population/struct.Population.html
+/// [`PopulationStatistics`]: ../population/struct.PopulationStatistics.html
+pub struct SnapshotSummary {
+    statistics: PopulationStatistics,
+    individuals: Vec<IndividualSummary>,
+}
+
+impl SnapshotSummary {
+    /// Summarises the specified [`Population`].
+    ///
+    /// # Parameters
+    ///
+    /// * `population` - the [`Population`] to summarise
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding an individual's lock.
+    ///
+    /// [`Population`]: ../population/struct.Population.html
+    pub fn from_population<
+        ReactionType: Reaction<InformationType>,
+        StateType: State<InformationType>,
+        InformationType: Information,
+        InputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+        InputSensorType: Input<InputElementType, InformationType>,
+        OutputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+        OutputSensorType: Output<OutputElementType, InformationType>,
+    >(
+        population: &Population<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    ) -> Self {
+        let mut individuals: Vec<IndividualSummary> = population
+            .individuals()
+            .iter()
+            .map(|individual| {
+                IndividualSummary::from_individual(
+                    &*individual
+                        .lock()
+                        .expect("A thread paniced while holding the individual's lock."),
+                )
+            })
+            .collect();
+        individuals.sort_by(IndividualSummary::fitness_order);
+        SnapshotSummary {
+            statistics: population.statistics(),
+            individuals,
+        }
+    }
+
+    /// Returns the [`PopulationStatistics`] of the [`Population`].
+    ///
+    /// [`Population`]: ../population/struct.Population.html
+    /// [`PopulationStatistics`]: ../population/struct.PopulationStatistics.html
+    pub fn statistics(&self) -> &PopulationStatistics {
+        &self.statistics
+    }
+
+    /// Returns the [`IndividualSummary`]s ordered by descending fitness with untested
+    /// individuals last.
+    ///
+    /// [`IndividualSummary`]: ./struct.IndividualSummary.html
+    pub fn individuals(&self) -> &[IndividualSummary] {
+        &self.individuals
+    }
+
+    /// Returns the [`IndividualSummary`] with the specified UUID if present.
+    ///
+    /// # Parameters
+    ///
+    /// * `uuid` - the UUID of the [`Individual`]
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    /// [`IndividualSummary`]: ./struct.IndividualSummary.html
+    pub fn individual(&self, uuid: &Uuid) -> Option<&IndividualSummary> {
+        self.individuals
+            .iter()
+            .find(|individual| individual.uuid() == uuid)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// A `SnapshotDiff` describes the differences between two [`SnapshotSummary`]s of the same
+/// evolutionary run.
+///
+/// [`SnapshotSummary`]: ./struct.SnapshotSummary.html
+pub struct SnapshotDiff {
+    statistics: (PopulationStatistics, PopulationStatistics),
+    added: Vec<IndividualSummary>,
+    removed: Vec<IndividualSummary>,
+    changed: Vec<(IndividualSummary, IndividualSummary)>,
+}
+
+impl SnapshotDiff {
+    /// Compares the specified [`SnapshotSummary`]s.
+    ///
+    /// # Parameters
+    ///
+    /// * `before` - the earlier [`SnapshotSummary`]
+    /// * `after` - the later [`SnapshotSummary`]
+    ///
+    /// [`SnapshotSummary`]: ./struct.SnapshotSummary.html
+    pub fn between(before: &SnapshotSummary, after: &SnapshotSummary) -> Self {
+        let previous: HashMap<&Uuid, &IndividualSummary> = before
+            .individuals()
+            .iter()
+            .map(|individual| (individual.uuid(), individual))
+            .collect();
+        let following: HashMap<&Uuid, &IndividualSummary> = after
+            .individuals()
+            .iter()
+            .map(|individual| (individual.uuid(), individual))
+            .collect();
+        let mut added = Vec::new();
+        let mut changed = Vec::new();
+        for individual in after.individuals() {
+            match previous.get(individual.uuid()) {
+                None => added.push(*individual),
+                Some(earlier) if *earlier != individual => changed.push((**earlier, *individual)),
+                Some(_) => {},
+            }
+        }
+        let removed = before
+            .individuals()
+            .iter()
+            .filter(|individual| !following.contains_key(individual.uuid()))
+            .copied()
+            .collect();
+        SnapshotDiff {
+            statistics: (*before.statistics(), *after.statistics()),
+            added,
+            removed,
+            changed,
+        }
+    }
+
+    /// Returns the [`PopulationStatistics`] before and after.
+    ///
+    /// [`PopulationStatistics`]: ../population/struct.PopulationStatistics.html
+    pub fn statistics(&self) -> (&PopulationStatistics, &PopulationStatistics) {
+        (&self.statistics.0, &self.statistics.1)
+    }
+
+    /// Returns the [`IndividualSummary`]s of all [`Individual`]s only present afterwards.
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    /// [`IndividualSummary`]: ./struct.IndividualSummary.html
+    pub fn added(&self) -> &[IndividualSummary] {
+        &self.added
+    }
+
+    /// Returns the [`IndividualSummary`]s of all [`Individual`]s only present before.
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    /// [`IndividualSummary`]: ./struct.IndividualSummary.html
+    pub fn removed(&self) -> &[IndividualSummary] {
+        &self.removed
+    }
+
+    /// Returns the [`IndividualSummary`]s before and after of all [`Individual`]s present in
+    /// both snapshots, whose properties changed.
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    /// [`IndividualSummary`]: ./struct.IndividualSummary.html
+    pub fn changed(&self) -> &[(IndividualSummary, IndividualSummary)] {
+        &self.changed
+    }
+
+    /// Checks if the snapshots contain the same [`Individual`]s with the same properties.
+    ///
+    /// [`Individual`]: ../population/struct.Individual.html
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests;
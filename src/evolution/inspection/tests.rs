@@ -0,0 +1,96 @@
+use crate::evolution::{
+    gene::{Gene, Genome, GenomicInputSensor, GenomicOutputSensor},
+    helper::noop::{NoOpInputElement, NoOpOutputElement},
+    helper::testing::{
+        TestGenome, TestInformation, TestInput, TestOutput, TestReaction, TestState,
+    },
+    resource::Resource,
+};
+
+use super::*;
+
+/// A [`Population`] for testing purposes.
+type TestPopulation = Population<
+    TestReaction,
+    TestState,
+    TestInformation,
+    NoOpInputElement,
+    TestInput,
+    NoOpOutputElement,
+    TestOutput,
+>;
+
+/// Creates a [`TestPopulation`] with an individual per specified fitness value, whose UUID
+/// is its index.
+fn test_population(fitness: &[Option<f64>]) -> TestPopulation {
+    let individuals = fitness
+        .iter()
+        .enumerate()
+        .map(|(index, fitness)| {
+            let genome: TestGenome = Genome::new(
+                GenomicInputSensor::default(),
+                GenomicOutputSensor::default(),
+                vec![Gene::new(vec![TestInformation { value: 0 }])],
+            );
+            let mut individual = Individual::new(Uuid::from_u128(index as u128), genome);
+            if let Some(fitness) = fitness {
+                individual.evaluate_new_fitness(*fitness);
+            }
+            individual
+        })
+        .collect();
+    Population::new(individuals, Resource::default())
+}
+
+#[test]
+/// Tests if the function `from_population` of the [`SnapshotSummary`] struct correctly
+/// summarises and orders all individuals.
+fn test_snapshot_summary_from_population() {
+    let population = test_population(&[Some(0.25), None, Some(0.75), Some(0.5)]);
+    let summary = SnapshotSummary::from_population(&population);
+    assert_eq!(summary.statistics(), &population.statistics());
+    let order: Vec<Uuid> = summary
+        .individuals()
+        .iter()
+        .map(|individual| *individual.uuid())
+        .collect();
+    assert_eq!(
+        order,
+        vec![
+            Uuid::from_u128(2),
+            Uuid::from_u128(3),
+            Uuid::from_u128(0),
+            Uuid::from_u128(1)
+        ]
+    );
+    let untested = summary.individual(&Uuid::from_u128(1)).unwrap();
+    assert_eq!(untested.fitness(), None);
+    assert_eq!(untested.times_tested(), 0);
+    assert_eq!(untested.age(), 0);
+    assert!(untested.bytes() > 0);
+    assert!(summary.individual(&Uuid::from_u128(4)).is_none());
+}
+
+#[test]
+/// Tests if the function `between` of the [`SnapshotDiff`] struct correctly detects added,
+/// removed and changed individuals.
+fn test_snapshot_diff_between() {
+    let population = test_population(&[Some(0.25), None, Some(0.75)]);
+    let before = SnapshotSummary::from_population(&population);
+    assert!(SnapshotDiff::between(&before, &before).is_empty());
+    let mut population = test_population(&[Some(0.25), Some(0.5), Some(0.75), None]);
+    population.remove(Uuid::from_u128(2)).unwrap();
+    let after = SnapshotSummary::from_population(&population);
+    let diff = SnapshotDiff::between(&before, &after);
+    assert!(!diff.is_empty());
+    assert_eq!(diff.statistics(), (before.statistics(), after.statistics()));
+    assert_eq!(diff.added().len(), 1);
+    assert_eq!(diff.added()[0].uuid(), &Uuid::from_u128(3));
+    assert_eq!(diff.removed().len(), 1);
+    assert_eq!(diff.removed()[0].uuid(), &Uuid::from_u128(2));
+    assert_eq!(diff.changed().len(), 1);
+    let (earlier, later) = diff.changed()[0];
+    assert_eq!(earlier.uuid(), &Uuid::from_u128(1));
+    assert_eq!(earlier.fitness(), None);
+    assert_eq!(later.fitness(), Some(0.5));
+}
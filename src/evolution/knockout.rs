@@ -0,0 +1,451 @@
+//! The `knockout` module contains the sensitivity analysis of [`Genome`]s.
+//! Each [`Gene`], [`GenomicReceptor`] and [`GeneAssociation`] is knocked out in turn
+//! and the fitness of the resulting [`Genome`] is compared to the fitness of the
+//! unaltered one, which shows the parts of an evolved solution that matter.
+//!
+//! [`Gene`]: ../gene/struct.Gene.html
+//! [`GeneAssociation`]: ../gene/struct.GeneAssociation.html
+//! [`Genome`]: ../gene/struct.Genome.html
+//! [`GenomicReceptor`]: ../gene/struct.GenomicReceptor.html
+
+use std::cmp::Reverse;
+use std::fmt::{Display, Formatter};
+
+use rayon::prelude::*;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use super::chemistry::{Information, Input, Output, Reaction, State};
+use super::environment::Environment;
+use super::gene::Genome;
+use super::helper::ScalingFactor;
+use super::population::OrganismInformation;
+
+/// A function supplying test examples together with the related result information.
+type SupplierFunction<InputElementType, SupplierResultInformationType> =
+    Box<dyn Fn() -> (InputElementType, SupplierResultInformationType) + Send + Sync + 'static>;
+
+/// A function evaluating the fitness based on the results obtained after supplying
+/// examples.
+type FitnessFunction<SupplierResultInformationType, OutputElementType> = Box<
+    dyn Fn(
+            Vec<OrganismInformation<SupplierResultInformationType, OutputElementType>>,
+            ScalingFactor,
+        ) -> f64
+        + Send
+        + Sync
+        + 'static,
+>;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+/// A `Knockout` describes an element removed from a [`Genome`].
+///
+/// [`Genome`]: ../gene/struct.Genome.html
+pub enum Knockout {
+    /// The [`Gene`] with the specified index is removed.
+    ///
+    /// [`Gene`]: ../gene/struct.Gene.html
+    Gene(usize),
+    /// The [`GenomicReceptor`] with the specified index is removed from its [`Gene`].
+    ///
+    /// [`Gene`]: ../gene/struct.Gene.html
+    /// [`GenomicReceptor`]: ../gene/struct.GenomicReceptor.html
+    Receptor { gene: usize, receptor: usize },
+    /// The [`GeneAssociation`] with the specified index is removed.
+    ///
+    /// [`GeneAssociation`]: ../gene/struct.GeneAssociation.html
+    Association(usize),
+}
+
+impl Knockout {
+    /// Returns the key knockouts are applied in, so earlier removals never shift
+    /// the indices of later ones. Receptors are removed before associations and genes,
+    /// higher indices before lower ones.
+    fn application_order(&self) -> (u8, Reverse<usize>, Reverse<usize>) {
+        match self {
+            Knockout::Receptor { gene, receptor } => (0, Reverse(*gene), Reverse(*receptor)),
+            Knockout::Association(association) => (1, Reverse(*association), Reverse(0)),
+            Knockout::Gene(gene) => (2, Reverse(*gene), Reverse(0)),
+        }
+    }
+
+    /// Checks if the knockout is already covered by knocking out the specified other
+    /// element.
+    ///
+    /// # Parameters
+    ///
+    /// * `other` - the other knocked out element
+    fn is_covered_by(&self, other: &Knockout) -> bool {
+        match (self, other) {
+            (Knockout::Receptor { gene, .. }, Knockout::Gene(other_gene)) => gene == other_gene,
+            _ => false,
+        }
+    }
+}
+
+impl Display for Knockout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Knockout::Gene(gene) => write!(f, "gene {}", gene),
+            Knockout::Receptor { gene, receptor } => {
+                write!(f, "gene {} receptor {}", gene, receptor)
+            },
+            Knockout::Association(association) => write!(f, "association {}", association),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// A `KnockoutImpact` contains the fitness of a [`Genome`] after knocking out
+/// one or more elements.
+///
+/// [`Genome`]: ../gene/struct.Genome.html
+pub struct KnockoutImpact {
+    knockouts: Vec<Knockout>,
+    fitness: f64,
+    impact: f64,
+}
+
+impl KnockoutImpact {
+    /// Returns the knocked out elements.
+    pub fn knockouts(&self) -> &[Knockout] {
+        &self.knockouts
+    }
+
+    /// Returns the fitness of the [`Genome`] after the knockout.
+    ///
+    /// [`Genome`]: ../gene/struct.Genome.html
+    pub fn fitness(&self) -> f64 {
+        self.fitness
+    }
+
+    /// Returns the loss of fitness caused by the knockout. A positive impact means the
+    /// knocked out elements contribute to the fitness, a negative impact means the
+    /// [`Genome`] performs better without them.
+    ///
+    /// [`Genome`]: ../gene/struct.Genome.html
+    pub fn impact(&self) -> f64 {
+        self.impact
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// A `KnockoutReport` contains the result of a [`KnockoutAnalysis`].
+///
+/// [`KnockoutAnalysis`]: ./struct.KnockoutAnalysis.html
+pub struct KnockoutReport {
+    baseline_fitness: f64,
+    impacts: Vec<KnockoutImpact>,
+}
+
+impl KnockoutReport {
+    /// Returns the fitness of the unaltered [`Genome`].
+    ///
+    /// [`Genome`]: ../gene/struct.Genome.html
+    pub fn baseline_fitness(&self) -> f64 {
+        self.baseline_fitness
+    }
+
+    /// Returns the [`KnockoutImpact`]s of all single knockouts followed by those of all
+    /// pairwise knockouts if they were analysed.
+    ///
+    /// [`KnockoutImpact`]: ./struct.KnockoutImpact.html
+    pub fn impacts(&self) -> &[KnockoutImpact] {
+        &self.impacts
+    }
+
+    /// Returns the impact of knocking out only the specified element if it was analysed.
+    ///
+    /// # Parameters
+    ///
+    /// * `knockout` - the knocked out element
+    pub fn impact(&self, knockout: &Knockout) -> Option<f64> {
+        self.impacts
+            .iter()
+            .find(|impact| impact.knockouts() == [*knockout])
+            .map(KnockoutImpact::impact)
+    }
+
+    /// Returns the [`KnockoutImpact`]s ordered from the highest to the lowest impact.
+    ///
+    /// [`KnockoutImpact`]: ./struct.KnockoutImpact.html
+    pub fn ranked(&self) -> Vec<&KnockoutImpact> {
+        let mut ranked: Vec<&KnockoutImpact> = self.impacts.iter().collect();
+        ranked.sort_by(|a, b| b.impact().total_cmp(&a.impact()));
+        ranked
+    }
+}
+
+impl Display for KnockoutReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:<48} {:>14} {:>14}", "knockout", "fitness", "impact")?;
+        write!(f, "{:<48} {:>14.6} {:>14}", "none", self.baseline_fitness, "-")?;
+        for impact in self.ranked() {
+            let knockouts = impact
+                .knockouts()
+                .iter()
+                .map(Knockout::to_string)
+                .collect::<Vec<String>>()
+                .join(" + ");
+            write!(f, "\n{:<48} {:>14.6} {:>+14.6}", knockouts, impact.fitness(), impact.impact())?;
+        }
+        Ok(())
+    }
+}
+
+/// A `KnockoutAnalysis` determines the fitness impact of the elements of a [`Genome`]
+/// by removing each [`Gene`], [`GenomicReceptor`] and [`GeneAssociation`] in turn and
+/// optionally every pair of them. All variants are evaluated in parallel on the same
+/// test examples, so the impacts are comparable.
+///
+/// [`Gene`]: ../gene/struct.Gene.html
+/// [`GeneAssociation`]: ../gene/struct.GeneAssociation.html
+/// [`Genome`]: ../gene/struct.Genome.html
+/// [`GenomicReceptor`]: ../gene/struct.GenomicReceptor.html
+pub struct KnockoutAnalysis<SupplierResultInformationType, InputElementType, OutputElementType> {
+    environment: Environment,
+    supplier_function: SupplierFunction<InputElementType, SupplierResultInformationType>,
+    fitness_function: FitnessFunction<SupplierResultInformationType, OutputElementType>,
+    fitness_scaling: ScalingFactor,
+    pairs: bool,
+}
+
+impl<
+        SupplierResultInformationType: Clone + Send + Sync,
+        InputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+        OutputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    > KnockoutAnalysis<SupplierResultInformationType, InputElementType, OutputElementType>
+{
+    /// Creates a new `KnockoutAnalysis` evaluating [`Genome`]s the same way an
+    /// [`EcologicalNiche`] does, where every example is supplied to an independent copy of
+    /// the freshly translated [`Organism`]. Only single knockouts are analysed by default.
+    ///
+    /// # Parameters
+    ///
+    /// * `environment` - the [`Environment`] the [`Organism`]s are tested in
+    /// * `supplier_function` - the function supplying test examples
+    /// * `fitness_function` - the function evaluating the fitness based on the results
+    ///   obtained after supplying the examples
+    ///
+    /// [`EcologicalNiche`]: ../environment/struct.EcologicalNiche.html
+    /// [`Environment`]: ../environment/struct.Environment.html
+    /// [`Genome`]: ../gene/struct.Genome.html
+    /// [`Organism`]: ../population/struct.Organism.html
+    pub fn new(
+        environment: Environment,
+        supplier_function: SupplierFunction<InputElementType, SupplierResultInformationType>,
+        fitness_function: FitnessFunction<SupplierResultInformationType, OutputElementType>,
+    ) -> Self {
+        let fitness_scaling = environment.initial_fitness_scaling_factor();
+        KnockoutAnalysis {
+            environment,
+            supplier_function,
+            fitness_function,
+            fitness_scaling,
+            pairs: false,
+        }
+    }
+
+    /// Sets whether all pairs of elements are knocked out as well to detect redundant
+    /// elements, which only matter in combination.
+    ///
+    /// # Parameters
+    ///
+    /// * `pairs` - if pairwise knockouts should be analysed
+    pub fn pairs(&mut self, pairs: bool) -> &mut Self {
+        self.pairs = pairs;
+        self
+    }
+
+    /// Sets the [`ScalingFactor`] passed to the fitness function.
+    /// The initial fitness scaling factor of the [`Environment`] is used by default.
+    ///
+    /// # Parameters
+    ///
+    /// * `fitness_scaling` - the fitness [`ScalingFactor`]
+    ///
+    /// [`Environment`]: ../environment/struct.Environment.html
+    /// [`ScalingFactor`]: ../helper/struct.ScalingFactor.html
+    pub fn fitness_scaling(&mut self, fitness_scaling: ScalingFactor) -> &mut Self {
+        self.fitness_scaling = fitness_scaling;
+        self
+    }
+
+    /// Analyses the specified [`Genome`] and returns the fitness impact of all knockouts.
+    /// As a [`Genome`] needs to contain at least one [`Gene`], knockouts removing all
+    /// [`Gene`]s are skipped. Pairs of a [`Gene`] and one of its receptors are skipped as
+    /// well, since they equal the knockout of the [`Gene`].
+    ///
+    /// # Parameters
+    ///
+    /// * `genome` - the [`Genome`] to analyse
+    ///
+    /// [`Gene`]: ../gene/struct.Gene.html
+    /// [`Genome`]: ../gene/struct.Genome.html
+    pub fn analyse<
+        ReactionType: Reaction<InformationType>,
+        StateType: State<InformationType>,
+        InformationType: Information,
+        InputSensorType: Input<InputElementType, InformationType>,
+        OutputSensorType: Output<OutputElementType, InformationType>,
+    >(
+        &self,
+        genome: &Genome<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    ) -> KnockoutReport {
+        let examples: Vec<(InputElementType, SupplierResultInformationType)> =
+            (0..self.environment.testing_repetitions())
+                .map(|_| (self.supplier_function)())
+                .collect();
+        let singles = Self::knockouts(genome);
+        let mut variants: Vec<Vec<Knockout>> =
+            singles.iter().map(|knockout| vec![*knockout]).collect();
+        if self.pairs {
+            let genes = genome.number_of_genes().get();
+            for (index, first) in singles.iter().enumerate() {
+                for second in &singles[index + 1..] {
+                    let both_genes =
+                        matches!((first, second), (Knockout::Gene(_), Knockout::Gene(_)));
+                    if (!both_genes || genes > 2)
+                        && !first.is_covered_by(second)
+                        && !second.is_covered_by(first)
+                    {
+                        variants.push(vec![*first, *second]);
+                    }
+                }
+            }
+        }
+        let baseline_fitness = self.evaluate(genome, &examples);
+        let impacts = variants
+            .into_par_iter()
+            .map(|knockouts| {
+                let mut knocked_out = genome.clone();
+                let mut ordered = knockouts.clone();
+                ordered.sort_by_key(Knockout::application_order);
+                for knockout in ordered {
+                    match knockout {
+                        Knockout::Gene(gene) => {
+                            knocked_out.remove_gene(gene);
+                        },
+                        Knockout::Receptor { gene, receptor } => {
+                            knocked_out.get_gene_mut(gene).remove_receptor(receptor);
+                        },
+                        Knockout::Association(association) => {
+                            knocked_out.remove_association(association);
+                        },
+                    }
+                }
+                let fitness = self.evaluate(&knocked_out, &examples);
+                KnockoutImpact {
+                    knockouts,
+                    fitness,
+                    impact: baseline_fitness - fitness,
+                }
+            })
+            .collect();
+        KnockoutReport {
+            baseline_fitness,
+            impacts,
+        }
+    }
+
+    /// Returns all single knockouts possible for the specified [`Genome`].
+    ///
+    /// # Parameters
+    ///
+    /// * `genome` - the [`Genome`] to knock out elements of
+    ///
+    /// [`Genome`]: ../gene/struct.Genome.html
+    fn knockouts<
+        ReactionType: Reaction<InformationType>,
+        StateType: State<InformationType>,
+        InformationType: Information,
+        InputSensorType: Input<InputElementType, InformationType>,
+        OutputSensorType: Output<OutputElementType, InformationType>,
+    >(
+        genome: &Genome<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    ) -> Vec<Knockout> {
+        let genes = genome.number_of_genes().get();
+        let mut knockouts = Vec::new();
+        if genes > 1 {
+            knockouts.extend((0..genes).map(Knockout::Gene));
+        }
+        for gene in 0..genes {
+            knockouts.extend(
+                (0..genome.get_gene(gene).number_of_receptors())
+                    .map(|receptor| Knockout::Receptor { gene, receptor }),
+            );
+        }
+        knockouts.extend((0..genome.number_of_associations()).map(Knockout::Association));
+        knockouts
+    }
+
+    /// Tests independent copies of the translated [`Genome`] on the specified examples and
+    /// returns the evaluated fitness.
+    ///
+    /// # Parameters
+    ///
+    /// * `genome` - the [`Genome`] to test
+    /// * `examples` - the test examples
+    ///
+    /// [`Genome`]: ../gene/struct.Genome.html
+    fn evaluate<
+        ReactionType: Reaction<InformationType>,
+        StateType: State<InformationType>,
+        InformationType: Information,
+        InputSensorType: Input<InputElementType, InformationType>,
+        OutputSensorType: Output<OutputElementType, InformationType>,
+    >(
+        &self,
+        genome: &Genome<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+        examples: &[(InputElementType, SupplierResultInformationType)],
+    ) -> f64 {
+        let translated = genome.translate();
+        let genome_size = genome.binary_size() * 8;
+        let mut organism_informations = Vec::new();
+        for (input, result_information) in examples {
+            // State must not leak from one example to the next.
+            let mut organism = translated.clone();
+            organism.set_input(input.clone());
+            let outcome = organism.live(&self.environment);
+            let output = organism.get_result();
+            organism_informations.push(OrganismInformation::new(
+                output,
+                result_information.clone(),
+                genome_size,
+                outcome,
+                self.environment.lifespan(),
+                genome.input().number_of_associated_inputs(),
+                genome.output().number_of_associated_outputs(),
+                organism.binary_size(),
+                self.environment.max_organism_size(),
+            ));
+        }
+        (self.fitness_function)(organism_informations, self.fitness_scaling)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,165 @@
+use crate::evolution::environment::EnvironmentBuilder;
+use crate::evolution::gene::{GeneAssociation, GeneSubstrate};
+use crate::evolution::helper::testing::{
+    dendrite, neuron_gene, neuron_genome, Dendrite, NeuronGenome,
+};
+use crate::evolution::helper::Nlbf64;
+use crate::evolution::neuron::SimpleNeuron;
+use crate::evolution::visualisation::genome_to_dot_with_knockouts;
+
+use super::*;
+
+/// Creates a [`NeuronGenome`] with two genes. The first gene contains the chain of
+/// dendrites from the input to the output neuron followed by the specified dendrites.
+/// The second gene is unused, but associated with the first one.
+fn test_genome(dendrites: Vec<Dendrite>) -> NeuronGenome {
+    let mut receptors = vec![dendrite(0, 1), dendrite(1, 2)];
+    receptors.extend(dendrites);
+    let mut genome = neuron_genome(vec![
+        neuron_gene(5, receptors),
+        neuron_gene(1, vec![dendrite(0, 0)]),
+    ]);
+    let mut association = GeneAssociation::new(SimpleNeuron::new(0.0, 10.0));
+    association.add_association(GeneSubstrate::new(0, 4));
+    association.add_association(GeneSubstrate::new(1, 0));
+    genome.add_association(association);
+    genome
+}
+
+/// Creates a [`KnockoutAnalysis`] rating the potential of the output neuron.
+fn test_analysis() -> KnockoutAnalysis<(), Vec<Nlbf64>, Vec<Nlbf64>> {
+    KnockoutAnalysis::new(
+        EnvironmentBuilder::new().build().unwrap(),
+        Box::new(|| (vec![Nlbf64::from(0.5)], ())),
+        Box::new(|informations, _| {
+            informations
+                .iter()
+                .map(|information| information.result()[0].value())
+                .sum()
+        }),
+    )
+}
+
+#[test]
+/// Tests if the function `analyse` of the [`KnockoutAnalysis`] struct correctly determines
+/// the fitness impact of single knockouts.
+fn test_knockout_analysis_analyse() {
+    let genome = test_genome(vec![dendrite(3, 2)]);
+    let report = test_analysis().analyse(&genome);
+    assert!(report.baseline_fitness() > 0.0);
+    assert_eq!(report.impacts().len(), 7);
+    assert!(report
+        .impacts()
+        .iter()
+        .all(|impact| impact.knockouts().len() == 1));
+    for receptor in 0..2 {
+        let impact = report.impact(&Knockout::Receptor { gene: 0, receptor });
+        assert_eq!(impact, Some(report.baseline_fitness()));
+    }
+    assert_eq!(
+        report.impact(&Knockout::Receptor {
+            gene: 0,
+            receptor: 2
+        }),
+        Some(0.0)
+    );
+    assert_eq!(
+        report.impact(&Knockout::Receptor {
+            gene: 1,
+            receptor: 0
+        }),
+        Some(0.0)
+    );
+    assert_eq!(report.impact(&Knockout::Gene(1)), Some(0.0));
+    assert_eq!(report.impact(&Knockout::Association(0)), Some(0.0));
+    assert!(report.impact(&Knockout::Gene(0)).is_some());
+    let ranked = report.ranked();
+    assert!(ranked
+        .windows(2)
+        .all(|pair| pair[0].impact() >= pair[1].impact()));
+    let table = report.to_string();
+    let lines: Vec<&str> = table.lines().collect();
+    assert_eq!(lines.len(), 9);
+    assert!(lines[0].starts_with("knockout"));
+    assert!(lines[1].starts_with("none"));
+    assert!(lines[2].starts_with("gene 0"));
+    let json = serde_json::to_string(&report).unwrap();
+    assert_eq!(serde_json::from_str::<KnockoutReport>(&json).unwrap(), report);
+    let dot = genome_to_dot_with_knockouts(&genome, &report);
+    assert!(dot.contains("label=\"gene 1\\nimpact +0.000\";"));
+    assert!(dot.contains(&format!("impact {:+.3}", report.baseline_fitness())));
+}
+
+#[test]
+/// Tests if the function `analyse` of the [`KnockoutAnalysis`] struct correctly detects
+/// redundant elements by pairwise knockouts.
+fn test_knockout_analysis_analyse_pairs() {
+    // The redundant dendrite duplicates the first dendrite of the chain.
+    let genome = test_genome(vec![dendrite(0, 1)]);
+    let mut analysis = test_analysis();
+    let single_report = analysis.analyse(&genome);
+    assert_eq!(
+        single_report.impact(&Knockout::Receptor {
+            gene: 0,
+            receptor: 0
+        }),
+        Some(0.0)
+    );
+    assert_eq!(
+        single_report.impact(&Knockout::Receptor {
+            gene: 0,
+            receptor: 2
+        }),
+        Some(0.0)
+    );
+    let report = analysis.pairs(true).analyse(&genome);
+    // 7 single knockouts and 21 pairs without the pair of both genes and the 4 pairs of
+    // a gene with one of its receptors.
+    assert_eq!(report.impacts().len(), 23);
+    let redundant = report
+        .impacts()
+        .iter()
+        .find(|impact| {
+            impact.knockouts()
+                == [
+                    Knockout::Receptor {
+                        gene: 0,
+                        receptor: 0,
+                    },
+                    Knockout::Receptor {
+                        gene: 0,
+                        receptor: 2,
+                    },
+                ]
+        })
+        .unwrap();
+    assert_eq!(redundant.impact(), report.baseline_fitness());
+    assert!(redundant.impact() > 0.0);
+}
+
+#[test]
+/// Tests if the function `analyse` of the [`KnockoutAnalysis`] struct correctly tests every
+/// example on an independent copy of the translated [`Organism`].
+fn test_knockout_analysis_analyse_independent_examples() {
+    let analysis: KnockoutAnalysis<(), Vec<Nlbf64>, Vec<Nlbf64>> = KnockoutAnalysis::new(
+        EnvironmentBuilder::new()
+            .testing_repetitions(3)
+            .build()
+            .unwrap(),
+        Box::new(|| (vec![Nlbf64::from(0.5)], ())),
+        Box::new(|informations, _| {
+            // Identical examples yield identical results if no state is carried over.
+            let first = informations[0].result();
+            if informations
+                .iter()
+                .all(|information| information.result() == first)
+            {
+                1.0
+            } else {
+                0.0
+            }
+        }),
+    );
+    let report = analysis.analyse(&test_genome(vec![dendrite(3, 2)]));
+    assert_eq!(report.baseline_fitness(), 1.0);
+}
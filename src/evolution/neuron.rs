@@ -17,7 +17,7 @@ pub use simple_neuronal_mutation::{
     mutate_neuron_add_input_feedback, mutate_neuron_add_output_feedback,
     mutate_neuron_potential_halflife_time, mutate_neuron_remove_input_feedback,
     mutate_neuron_remove_output_feedback, mutate_neuron_value, mutation_associate_finish_substrate,
-    mutation_disociate_output, remove_dendrite, remove_neuron,
+    mutation_disociate_output, remove_dendrite, remove_neuron, simple_neuron_mutation_registry,
 };
 
 mod simple_dendrite;
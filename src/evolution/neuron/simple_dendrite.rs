@@ -62,6 +62,10 @@ impl State<SimpleNeuron> for SimpleDendriteThreshold {
     fn detect(&self, substrates: &[&SimpleNeuron], _detection_time: Iteration) -> bool {
         substrates[0].current_potential() >= self.threshold()
     }
+
+    fn label(&self) -> String {
+        format!("threshold {:.3}", self.threshold.value())
+    }
 }
 
 impl Distribution<SimpleDendriteThreshold> for Standard {
@@ -149,6 +153,14 @@ impl Reaction<SimpleNeuron> for SimpleDendriteActivationPotential {
             })
             .collect()
     }
+
+    fn label(&self) -> String {
+        if self.is_inhibitory {
+            format!("weight -{:.3} (inhibitory)", self.weight.value())
+        } else {
+            format!("weight +{:.3}", self.weight.value())
+        }
+    }
 }
 
 impl Distribution<SimpleDendriteActivationPotential> for Standard {
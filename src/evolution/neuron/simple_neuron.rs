@@ -125,6 +125,15 @@ impl Information for SimpleNeuron {
             self.current_potential = (self.base_potential.value() + change).into();
         }
     }
+
+    fn label(&self) -> String {
+        format!(
+            "potential {:.3} (base {:.3}, half-life {:.1})",
+            self.current_potential.value(),
+            self.base_potential.value(),
+            self.potential_halflife_time()
+        )
+    }
 }
 
 #[cfg(test)]
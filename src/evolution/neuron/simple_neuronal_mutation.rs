@@ -4,6 +4,7 @@ use std::fmt::Debug;
 
 use crate::evolution::binary::{as_f64, f64_to_binary, flip_random_bit};
 use crate::evolution::chemistry::{Input, Output};
+use crate::evolution::environment::MutationRegistry;
 use crate::evolution::gene::{GeneSubstrate, Genome};
 use crate::evolution::helper::Nlbf64;
 use rand::{thread_rng, Rng};
@@ -851,5 +852,58 @@ pub fn mutation_associate_finish_substrate<
     Some(mutated_genome)
 }
 
+/// Returns a [`MutationRegistry`] containing all generic structural mutations and all
+/// mutations of simple neuronal networks. The latter are registered under their function
+/// names, e.g. `add_dendrite` or `mutate_dendrite_weight`.
+/// The feedback mutations use the specified number of feedback identifiers.
+///
+/// [`MutationRegistry`]: ../environment/struct.MutationRegistry.html
+pub fn simple_neuron_mutation_registry<
+    InputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned + 'static,
+    InputSensorType: Input<InputElementType, SimpleNeuron> + 'static,
+    OutputElementType: Clone + Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned + 'static,
+    OutputSensorType: Output<OutputElementType, SimpleNeuron> + 'static,
+    const INPUT_FEEDBACK_SIZE: usize,
+    const OUTPUT_FEEDBACK_SIZE: usize,
+>() -> MutationRegistry<
+    SimpleDendriteActivationPotential,
+    SimpleDendriteThreshold,
+    SimpleNeuron,
+    InputElementType,
+    InputSensorType,
+    OutputElementType,
+    OutputSensorType,
+> {
+    let mut registry = MutationRegistry::with_structural_mutations();
+    registry
+        .register("add_dendrite", add_dendrite)
+        .register("remove_dendrite", remove_dendrite)
+        .register("mutate_neuron_value", mutate_neuron_value)
+        .register("mutate_neuron_potential_halflife_time", mutate_neuron_potential_halflife_time)
+        .register("mutate_dendrite_threshold", mutate_dendrite_threshold)
+        .register("mutate_dendrite_weight", mutate_dendrite_weight)
+        .register("mutate_dendrite_inhibitory_state", mutate_dendrite_inhibitory_state)
+        .register("mutate_dendrite_target", mutate_dendrite_target)
+        .register("mutate_dendrite_source", mutate_dendrite_source)
+        .register("add_neuron", add_neuron)
+        .register("remove_neuron", remove_neuron)
+        .register("mutate_associate_input", mutate_associate_input)
+        .register("mutate_disociate_input", mutate_disociate_input)
+        .register("mutate_associate_output", mutate_associate_output)
+        .register("mutation_disociate_output", mutation_disociate_output)
+        .register("mutation_associate_finish_substrate", mutation_associate_finish_substrate)
+        .register("mutate_neuron_remove_input_feedback", mutate_neuron_remove_input_feedback)
+        .register("mutate_neuron_remove_output_feedback", mutate_neuron_remove_output_feedback)
+        .register(
+            "mutate_neuron_add_input_feedback",
+            mutate_neuron_add_input_feedback::<_, _, _, _, INPUT_FEEDBACK_SIZE>,
+        )
+        .register(
+            "mutate_neuron_add_output_feedback",
+            mutate_neuron_add_output_feedback::<_, _, _, _, OUTPUT_FEEDBACK_SIZE>,
+        );
+    registry
+}
+
 // #[cfg(test)]
 // mod tests;
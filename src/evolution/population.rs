@@ -7,25 +7,59 @@ extern crate uuid;
 
 use super::chemistry::{Information, Input, Output, Reaction, State};
 use super::environment::{Environment, MutationCompendium};
-use super::gene::{CrossOver, Gene, Genome};
-use super::helper::{ActionChain, Iteration};
+use super::fitness::{FitnessStatistics, SelectionCriterion};
+use super::gene::{CrossOver, Gene, Genome, RateDistribution};
+use super::genealogy::Lineage;
+use super::helper::{Cache, IndexedActionChain, Iteration};
 use super::protein::{InputSensor, OutputSensor, Receptor, Substrate};
 use super::resource::Resource;
+use super::trace::{ExecutionTrace, Recorder, TraceRecorder};
 use rand::{thread_rng, Rng};
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use std::cell::RefCell;
 use std::collections::HashMap;
 use std::error::Error;
 use std::fs::File;
 use std::io::Write;
 use std::marker::PhantomData;
 use std::path::Path;
-use std::rc::Rc;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use uuid::Uuid;
 
+/// A thread-safe reference to an [`Individual`].
+///
+/// [`Individual`]: ./struct.Individual.html
+pub(crate) type SharedIndividual<
+    ReactionType,
+    StateType,
+    InformationType,
+    InputElementType,
+    InputSensorType,
+    OutputElementType,
+    OutputSensorType,
+> = Arc<
+    Mutex<
+        Individual<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    >,
+>;
+
+#[derive(Debug, Clone)]
+/// An `Organism` is the executable form of a [`Genome`]. Its [`Substrate`]s and
+/// [`Receptor`]s are stored in vectors and reference each other by their position,
+/// so an `Organism` can be sent to and shared between threads.
+///
+/// [`Genome`]: ../gene/struct.Genome.html
+/// [`Receptor`]: ../protein/struct.Receptor.html
+/// [`Substrate`]: ../protein/struct.Substrate.html
 pub struct Organism<
     ReactionType,
     StateType,
@@ -35,11 +69,13 @@ pub struct Organism<
     OutputElementType,
     OutputSensorType,
 > {
-    substrates: Vec<Rc<RefCell<Substrate<ReactionType, StateType, InformationType>>>>,
+    substrates: Vec<Substrate<ReactionType, StateType, InformationType>>,
+    receptors: Vec<Receptor<ReactionType, StateType, InformationType>>,
     input: InputSensor<ReactionType, StateType, InformationType, InputElementType, InputSensorType>,
     output:
         OutputSensor<ReactionType, StateType, InformationType, OutputElementType, OutputSensorType>,
     time_alive: Iteration,
+    size: usize,
 }
 
 impl<
@@ -61,17 +97,20 @@ impl<
         OutputSensorType,
     >
 {
-    /// Creates a new `Organism` from the specified [`Substrate`]s.
+    /// Creates a new `Organism` from the specified [`Substrate`]s and [`Receptor`]s.
     ///
     /// # Parameters
     ///
     /// * `substrates` - all [`Substrate`]s that make up the organism
+    /// * `receptors` - all [`Receptor`]s that make up the organism
     /// * `input` - the [`Substrate`]s linked to sensorical input
     /// * `output` - the [`Substrate`]s linked to the output
     ///
+    /// [`Receptor`]: ../protein/struct.Receptor.html
     /// [`Substrate`]: ../protein/struct.Substrate.html
     pub fn new(
-        substrates: Vec<Rc<RefCell<Substrate<ReactionType, StateType, InformationType>>>>,
+        mut substrates: Vec<Substrate<ReactionType, StateType, InformationType>>,
+        receptors: Vec<Receptor<ReactionType, StateType, InformationType>>,
         input: InputSensor<
             ReactionType,
             StateType,
@@ -87,17 +126,23 @@ impl<
             OutputSensorType,
         >,
     ) -> Self {
+        let size = substrates
+            .iter_mut()
+            .map(Substrate::cached_binary_size)
+            .sum();
         Organism {
             substrates,
+            receptors,
             input,
             output,
             time_alive: Iteration::new(),
+            size,
         }
     }
 
     /// Starts activity of all [`Receptor`]s and [`CatalyticCentre`]s linked to the [`Substrate`]s
     /// of this `Organism`. Execution will be aborted if the execution takes longer than the
-    /// lifespan defined by the [`Environment`]. Returns the execution time.
+    /// lifespan defined by the [`Environment`]. Returns the [`RunOutcome`] describing the run.
     ///
     /// # Parameters
     ///
@@ -107,81 +152,238 @@ impl<
     /// [`CatalyticCentre`]: ../protein/struct.CatalyticCentre.html
     /// [`Receptor`]: ../protein/struct.Receptor.html
     /// [`Environment`]: ../environment/struct.Environment.html
-    pub fn live(&mut self, environment: &Environment) -> Duration {
+    /// [`RunOutcome`]: ./struct.RunOutcome.html
+    pub fn live(&mut self, environment: &Environment) -> RunOutcome {
+        self.run(environment, &mut ())
+    }
+
+    /// Lives as described for [`live`](#method.live) while recording every [`Iteration`].
+    /// Returns the [`RunOutcome`] and the [`ExecutionTrace`] of the run.
+    ///
+    /// # Parameters
+    ///
+    /// * `environment` - the [`Environment`] the `Organism` lives in
+    ///
+    /// [`Environment`]: ../environment/struct.Environment.html
+    /// [`ExecutionTrace`]: ../trace/struct.ExecutionTrace.html
+    /// [`Iteration`]: ../helper/struct.Iteration.html
+    /// [`RunOutcome`]: ./struct.RunOutcome.html
+    pub fn live_traced(
+        &mut self,
+        environment: &Environment,
+    ) -> (RunOutcome, ExecutionTrace<InformationType>) {
+        let mut recorder = TraceRecorder::new();
+        let outcome = self.run(environment, &mut recorder);
+        (outcome, recorder.finish(outcome.termination()))
+    }
+
+    /// Runs all [`Receptor`]s until the run terminates and reports the execution to the
+    /// specified [`Recorder`]. Returns the [`RunOutcome`] describing the run.
+    ///
+    /// # Parameters
+    ///
+    /// * `environment` - the [`Environment`] the `Organism` lives in
+    /// * `recorder` - the [`Recorder`] observing the execution
+    ///
+    /// [`Environment`]: ../environment/struct.Environment.html
+    /// [`Receptor`]: ../protein/struct.Receptor.html
+    /// [`Recorder`]: ../trace/trait.Recorder.html
+    /// [`RunOutcome`]: ./struct.RunOutcome.html
+    fn run<R: Recorder<ReactionType, StateType, InformationType>>(
+        &mut self,
+        environment: &Environment,
+        recorder: &mut R,
+    ) -> RunOutcome {
         let birth = Instant::now();
-        // Set the current time to the last update, so the organsim can be reused (set_input()).
-        let mut actions: ActionChain<Rc<Receptor<ReactionType, StateType, InformationType>>> =
-            self.time_alive().into();
-        let mut finished = false;
-        // Add all receptors detecting changes to the input.
-        for receptor in self.input.cascading_receptors() {
-            actions.push_action(receptor);
-        }
+        let mut execution = self.begin_execution(environment);
         // Run all receptors and subsequently add receptors detecting substrates,
         // which were modified during the run.
         // If the task takes longer than the specified threshold,
         // the run will be aborted.
-        while !actions.is_empty()
-            && !finished
-            && birth.elapsed() < environment.lifespan()
-            && self.binary_size() < environment.max_organism_size()
-        {
-            let time = actions.current_iteration();
-            let mut input_feedback_changes: HashMap<usize, InformationType> = HashMap::new();
-            let mut output_feedback_changes: HashMap<usize, InformationType> = HashMap::new();
-            actions
-                .pop_actions()
-                .iter()
-                .filter(|receptor| receptor.detect(time))
-                .flat_map(|receptor| {
-                    let result = receptor.catalyse(time);
-                    // Fill the input feedback association map.
-                    for (associations, value) in result.input_feedback_associations.into_iter() {
-                        for association in associations {
-                            input_feedback_changes.insert(association, value.clone());
-                        }
+        let termination = loop {
+            execution.peak_organism_size = execution.peak_organism_size.max(self.size);
+            if let Some(termination) = self.termination(&execution, environment, birth.elapsed()) {
+                break termination;
+            }
+            self.iterate(&mut execution, recorder);
+        };
+        // Return the time it took to perform the task, but never more than the lifespan.
+        let run_time = birth.elapsed().min(environment.lifespan());
+        RunOutcome {
+            run_time,
+            termination,
+            iterations: execution.iterations,
+            receptors_fired: execution.receptors_fired,
+            peak_organism_size: execution.peak_organism_size,
+        }
+    }
+
+    /// Starts a new run of the `Organism` by scheduling all [`Receptor`]s detecting changes
+    /// to the input.
+    ///
+    /// # Parameters
+    ///
+    /// * `environment` - the [`Environment`] the `Organism` lives in
+    ///
+    /// [`Environment`]: ../environment/struct.Environment.html
+    /// [`Receptor`]: ../protein/struct.Receptor.html
+    pub(crate) fn begin_execution(&self, environment: &Environment) -> Execution {
+        // Set the current time to the last update, so the organsim can be reused (set_input()).
+        let mut execution = Execution {
+            actions: self.time_alive().into(),
+            finished: false,
+            iterations: 0,
+            receptors_fired: 0,
+            peak_organism_size: 0,
+            track_size: !environment.constant_organism_size(),
+        };
+        // Add all receptors detecting changes to the input.
+        execution.schedule(self.input.cascading_receptors(&self.substrates));
+        execution
+    }
+
+    /// Returns the reason the specified [`Execution`] must be terminated if any.
+    ///
+    /// # Parameters
+    ///
+    /// * `execution` - the current [`Execution`]
+    /// * `environment` - the [`Environment`] the `Organism` lives in
+    /// * `elapsed` - the time since the start of the run
+    ///
+    /// [`Environment`]: ../environment/struct.Environment.html
+    /// [`Execution`]: ./struct.Execution.html
+    pub(crate) fn termination(
+        &self,
+        execution: &Execution,
+        environment: &Environment,
+        elapsed: Duration,
+    ) -> Option<TerminationReason> {
+        if execution.finished {
+            Some(TerminationReason::Finished)
+        } else if execution.actions.is_empty() {
+            Some(TerminationReason::ActionsExhausted)
+        } else if elapsed >= environment.lifespan() {
+            Some(TerminationReason::LifespanExpired)
+        } else if execution.track_size && self.size >= environment.max_organism_size() {
+            Some(TerminationReason::SizeLimitExceeded)
+        } else {
+            None
+        }
+    }
+
+    /// Runs all [`Receptor`]s scheduled for the current [`Iteration`] of the specified
+    /// [`Execution`] and schedules the [`Receptor`]s detecting the resulting changes.
+    ///
+    /// # Parameters
+    ///
+    /// * `execution` - the current [`Execution`]
+    /// * `recorder` - the [`Recorder`] observing the execution
+    ///
+    /// [`Execution`]: ./struct.Execution.html
+    /// [`Iteration`]: ../helper/struct.Iteration.html
+    /// [`Receptor`]: ../protein/struct.Receptor.html
+    /// [`Recorder`]: ../trace/trait.Recorder.html
+    pub(crate) fn iterate<R: Recorder<ReactionType, StateType, InformationType>>(
+        &mut self,
+        execution: &mut Execution,
+        recorder: &mut R,
+    ) {
+        let time = execution.actions.current_iteration();
+        let mut input_feedback_changes: HashMap<usize, InformationType> = HashMap::new();
+        let mut output_feedback_changes: HashMap<usize, InformationType> = HashMap::new();
+        let scheduled = execution.actions.pop_actions();
+        recorder.scheduled(time, &scheduled);
+        for &id in &scheduled {
+            let receptor = &mut self.receptors[id];
+            let size_before = if execution.track_size {
+                binary_size_of(&mut self.substrates, receptor.accessed_substrates())
+            } else {
+                0
+            };
+            if receptor.detect(&mut self.substrates, time) {
+                recorder.detected(id, receptor, &self.substrates);
+                let result = receptor.catalyse(&mut self.substrates, time);
+                execution.receptors_fired += 1;
+                recorder.catalysed(id, receptor, &self.substrates);
+                // Fill the input feedback association map.
+                for (associations, value) in result.input_feedback_associations.into_iter() {
+                    for association in associations {
+                        input_feedback_changes.insert(association, value.clone());
                     }
-                    // Fill the output feedback association map.
-                    for (associations, value) in result.output_feedback_associations.into_iter() {
-                        for association in associations {
-                            output_feedback_changes.insert(association, value.clone());
-                        }
+                }
+                // Fill the output feedback association map.
+                for (associations, value) in result.output_feedback_associations.into_iter() {
+                    for association in associations {
+                        output_feedback_changes.insert(association, value.clone());
                     }
-                    // Set the finished flag if necessary.
-                    finished = finished || self.output.is_finished(result.output_finish_substrate);
-                    // Return the receptors to be processed in the subsequent step.
-                    result.cascading_receptors
-                })
-                .for_each(|cascading_receptor| {
-                    actions.push_action(cascading_receptor);
-                });
-            // Update input feedback substrates and add all receptors if changes to the input were detected.
-            for receptor in self.input.feedback_update(input_feedback_changes) {
-                actions.push_action(receptor);
+                }
+                // Set the finished flag if necessary.
+                execution.finished =
+                    execution.finished || self.output.is_finished(result.output_finish_substrate);
+                // Add the receptors to be processed in the subsequent step.
+                execution.schedule(result.cascading_receptors);
             }
-            // Update output feedback substrates and add all receptors if changes to the output were detected.
-            for receptor in self.output.feedback_update(output_feedback_changes, time) {
-                actions.push_action(receptor);
+            if execution.track_size {
+                self.size = self.size
+                    + binary_size_of(&mut self.substrates, receptor.accessed_substrates())
+                    - size_before;
             }
         }
-        self.time_alive = actions.current_iteration();
-        // Return the time it took to perform the task, but never more than the lifespan.
-        let run_time = birth.elapsed();
-        if run_time < environment.lifespan() {
-            run_time
+        recorder.feedback(&input_feedback_changes, &output_feedback_changes);
+        // Update input feedback substrates and add all receptors if changes to the input were detected.
+        let input_substrates = if execution.track_size {
+            distinct_substrates(self.input.input_substrates())
         } else {
-            environment.lifespan()
-        }
+            Vec::new()
+        };
+        execution.schedule(self.track_size(&input_substrates, |organism| {
+            organism
+                .input
+                .feedback_update(input_feedback_changes, &mut organism.substrates)
+        }));
+        // Update output feedback substrates and add all receptors if changes to the output were detected.
+        let output_substrates = if execution.track_size {
+            distinct_substrates(self.output.output_substrates())
+        } else {
+            Vec::new()
+        };
+        execution.schedule(self.track_size(&output_substrates, |organism| {
+            organism
+                .output
+                .feedback_update(output_feedback_changes, &mut organism.substrates, time)
+        }));
+        execution.iterations += 1;
+        self.time_alive = execution.actions.current_iteration();
     }
 
     /// Returns the number of bits of all [`Substrate`]s that are part of the `Organism`.
+    /// The size is tracked incrementally whenever [`Substrate`] values change, but not
+    /// during runs in an [`Environment`] with constant organism size.
     ///
+    /// [`Environment`]: ../environment/struct.Environment.html
     /// [`Substrate`]: ../protein/struct.Substrate.html
     pub fn binary_size(&self) -> usize {
-        self.substrates
-            .iter()
-            .map(|s| s.borrow().binary_size())
-            .sum()
+        self.size
+    }
+
+    /// Runs the specified operation and adjusts the tracked [`binary_size`](#method.binary_size)
+    /// by the size changes of the specified [`Substrate`]s.
+    ///
+    /// # Parameters
+    ///
+    /// * `substrates` - the distinct ids of all [`Substrate`]s the operation may change
+    /// * `operation` - the operation to run
+    ///
+    /// [`Substrate`]: ../protein/struct.Substrate.html
+    fn track_size<O, F: FnOnce(&mut Self) -> O>(
+        &mut self,
+        substrates: &[usize],
+        operation: F,
+    ) -> O {
+        let size_before = binary_size_of(&mut self.substrates, substrates);
+        let result = operation(self);
+        self.size = self.size + binary_size_of(&mut self.substrates, substrates) - size_before;
+        result
     }
 
     /// Returns the number of [`Substrate`]s this `Organism` consists of.
@@ -191,11 +393,82 @@ impl<
         self.substrates.len()
     }
 
+    /// Returns all [`Substrate`]s this `Organism` consists of.
+    ///
+    /// [`Substrate`]: ../protein/struct.Substrate.html
+    pub fn substrates(&self) -> &[Substrate<ReactionType, StateType, InformationType>] {
+        &self.substrates
+    }
+
+    /// Overwrites the value of the [`Substrate`] with the specified id and returns the ids
+    /// of all [`Receptor`]s detecting it. Returns `None` if the [`Substrate`] does not exist.
+    ///
+    /// # Parameters
+    ///
+    /// * `substrate` - the id of the [`Substrate`]
+    /// * `value` - the new value
+    ///
+    /// [`Receptor`]: ../protein/struct.Receptor.html
+    /// [`Substrate`]: ../protein/struct.Substrate.html
+    pub(crate) fn set_substrate_value(
+        &mut self,
+        substrate: usize,
+        value: InformationType,
+    ) -> Option<Vec<usize>> {
+        if substrate < self.substrates.len() {
+            Some(self.track_size(&[substrate], |organism| {
+                organism.substrates[substrate].set_value(value);
+                organism.substrates[substrate].receptors().to_vec()
+            }))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of [`Receptor`]s this `Organism` consists of.
+    ///
+    /// [`Receptor`]: ../protein/struct.Receptor.html
+    pub fn number_of_receptors(&self) -> usize {
+        self.receptors.len()
+    }
+
+    /// Returns all [`Receptor`]s this `Organism` consists of.
+    ///
+    /// [`Receptor`]: ../protein/struct.Receptor.html
+    pub fn receptors(&self) -> &[Receptor<ReactionType, StateType, InformationType>] {
+        &self.receptors
+    }
+
+    /// Returns the [`InputSensor`] of the `Organism`.
+    ///
+    /// [`InputSensor`]: ../protein/struct.InputSensor.html
+    pub fn input_sensor(
+        &self,
+    ) -> &InputSensor<ReactionType, StateType, InformationType, InputElementType, InputSensorType>
+    {
+        &self.input
+    }
+
+    /// Returns the [`OutputSensor`] of the `Organism`.
+    ///
+    /// [`OutputSensor`]: ../protein/struct.OutputSensor.html
+    pub fn output_sensor(
+        &self,
+    ) -> &OutputSensor<ReactionType, StateType, InformationType, OutputElementType, OutputSensorType>
+    {
+        &self.output
+    }
+
     /// Returns the values of output [`Substrate`]s if any.
     ///
     /// [`Substrate`]: ../protein/struct.Substrate.html
     pub fn get_result(&mut self) -> OutputElementType {
-        self.output.output_as_element(self.time_alive())
+        let output_substrates = distinct_substrates(self.output.output_substrates());
+        self.track_size(&output_substrates, |organism| {
+            organism
+                .output
+                .output_as_element(&mut organism.substrates, organism.time_alive)
+        })
     }
 
     /// Returns the time the `Organism` is alive.
@@ -209,7 +482,169 @@ impl<
     ///
     /// * `input` - the input values to set
     pub fn set_input(&mut self, input: InputElementType) {
-        self.input.set_input(input);
+        let input_substrates = distinct_substrates(self.input.input_substrates());
+        self.track_size(&input_substrates, |organism| {
+            organism.input.set_input(input, &mut organism.substrates)
+        });
+    }
+}
+
+/// Returns the summed [`cached_binary_size`] of the specified [`Substrate`]s.
+///
+/// # Parameters
+///
+/// * `substrates` - all [`Substrate`]s of the [`Organism`]
+/// * `ids` - the ids of the [`Substrate`]s to sum up
+///
+/// [`cached_binary_size`]: ../protein/struct.Substrate.html#method.cached_binary_size
+/// [`Organism`]: ./struct.Organism.html
+/// [`Substrate`]: ../protein/struct.Substrate.html
+fn binary_size_of<ReactionType, StateType, InformationType>(
+    substrates: &mut [Substrate<ReactionType, StateType, InformationType>],
+    ids: &[usize],
+) -> usize
+where
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+{
+    ids.iter()
+        .map(|id| substrates[*id].cached_binary_size())
+        .sum()
+}
+
+/// Returns the distinct ids of the specified sensor [`Substrate`]s.
+///
+/// # Parameters
+///
+/// * `ids` - the optional ids of the sensor [`Substrate`]s
+///
+/// [`Substrate`]: ../protein/struct.Substrate.html
+fn distinct_substrates(ids: &[Option<usize>]) -> Vec<usize> {
+    let mut distinct: Vec<usize> = ids.iter().flatten().copied().collect();
+    distinct.sort_unstable();
+    distinct.dedup();
+    distinct
+}
+
+/// The state of a single run of an [`Organism`].
+///
+/// [`Organism`]: ./struct.Organism.html
+pub(crate) struct Execution {
+    actions: IndexedActionChain,
+    finished: bool,
+    iterations: usize,
+    receptors_fired: usize,
+    peak_organism_size: usize,
+    track_size: bool,
+}
+
+impl Execution {
+    /// Schedules the specified [`Receptor`]s for the next [`Iteration`].
+    ///
+    /// # Parameters
+    ///
+    /// * `receptors` - the ids of the [`Receptor`]s to schedule
+    ///
+    /// [`Iteration`]: ../helper/struct.Iteration.html
+    /// [`Receptor`]: ../protein/struct.Receptor.html
+    pub(crate) fn schedule<I: IntoIterator<Item = usize>>(&mut self, receptors: I) {
+        for receptor in receptors {
+            self.actions.push_action(receptor);
+        }
+    }
+
+    /// Returns the ids of the [`Receptor`]s scheduled for the next [`Iteration`].
+    ///
+    /// [`Iteration`]: ../helper/struct.Iteration.html
+    /// [`Receptor`]: ../protein/struct.Receptor.html
+    pub(crate) fn scheduled(&self) -> &[usize] {
+        self.actions.actions()
+    }
+
+    /// Returns the [`Iteration`] the next scheduled [`Receptor`]s are executed at.
+    ///
+    /// [`Iteration`]: ../helper/struct.Iteration.html
+    /// [`Receptor`]: ../protein/struct.Receptor.html
+    pub(crate) fn current_iteration(&self) -> Iteration {
+        self.actions.current_iteration()
+    }
+
+    /// Resets the finished flag, so the run can continue after new input was supplied.
+    pub(crate) fn reset_finished(&mut self) {
+        self.finished = false;
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
+/// The reason the run of an [`Organism`] ended.
+///
+/// [`Organism`]: ./struct.Organism.html
+pub enum TerminationReason {
+    /// No further [`Receptor`]s were scheduled.
+    ///
+    /// [`Receptor`]: ../protein/struct.Receptor.html
+    ActionsExhausted,
+    /// The output signalled that the task was finished.
+    Finished,
+    /// The lifespan defined by the [`Environment`] expired.
+    ///
+    /// [`Environment`]: ../environment/struct.Environment.html
+    LifespanExpired,
+    /// The maximum organism size defined by the [`Environment`] was exceeded.
+    ///
+    /// [`Environment`]: ../environment/struct.Environment.html
+    SizeLimitExceeded,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+/// The outcome of a single run of an [`Organism`].
+///
+/// [`Organism`]: ./struct.Organism.html
+pub struct RunOutcome {
+    run_time: Duration,
+    termination: TerminationReason,
+    iterations: usize,
+    receptors_fired: usize,
+    peak_organism_size: usize,
+}
+
+impl RunOutcome {
+    /// The time it took the [`Organism`] to perform the task, but never more than the
+    /// lifespan defined by the [`Environment`].
+    ///
+    /// [`Environment`]: ../environment/struct.Environment.html
+    /// [`Organism`]: ./struct.Organism.html
+    pub fn run_time(&self) -> Duration {
+        self.run_time
+    }
+
+    /// The reason the run ended.
+    pub fn termination(&self) -> TerminationReason {
+        self.termination
+    }
+
+    /// The number of [`Iteration`]s executed during the run.
+    ///
+    /// [`Iteration`]: ../helper/struct.Iteration.html
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// The number of [`Receptor`]s that catalysed a reaction during the run.
+    ///
+    /// [`Receptor`]: ../protein/struct.Receptor.html
+    pub fn receptors_fired(&self) -> usize {
+        self.receptors_fired
+    }
+
+    /// The maximum size of the [`Organism`] in bit observed during the run.
+    /// This currently only takes [`Substrate`] values into account.
+    ///
+    /// [`Organism`]: ./struct.Organism.html
+    /// [`Substrate`]: ../protein/struct.Substrate.html
+    pub fn peak_organism_size(&self) -> usize {
+        self.peak_organism_size
     }
 }
 
@@ -221,7 +656,7 @@ pub struct OrganismInformation<SupplierResultInformationType, ExecutionResultEle
     result: ExecutionResultElementType,
     result_info: SupplierResultInformationType,
     genome_size: usize,
-    run_time: Duration,
+    outcome: RunOutcome,
     max_run_time: Duration,
     associated_inputs: usize,
     associated_outputs: usize,
@@ -240,7 +675,7 @@ impl<SupplierResultInformationType, ExecutionResultElementType>
         result: ExecutionResultElementType,
         result_info: SupplierResultInformationType,
         genome_size: usize,
-        run_time: Duration,
+        outcome: RunOutcome,
         max_run_time: Duration,
         associated_inputs: usize,
         associated_outputs: usize,
@@ -251,7 +686,7 @@ impl<SupplierResultInformationType, ExecutionResultElementType>
             result,
             result_info,
             genome_size,
-            run_time,
+            outcome,
             max_run_time,
             associated_inputs,
             associated_outputs,
@@ -303,7 +738,22 @@ impl<SupplierResultInformationType, ExecutionResultElementType>
     ///
     /// [`Organism`]: ./struct.Organism.html
     pub fn run_time(&self) -> Duration {
-        self.run_time
+        self.outcome.run_time()
+    }
+
+    /// The [`RunOutcome`] of the [`Organism`] performing a task.
+    ///
+    /// [`Organism`]: ./struct.Organism.html
+    /// [`RunOutcome`]: ./struct.RunOutcome.html
+    pub fn outcome(&self) -> &RunOutcome {
+        &self.outcome
+    }
+
+    /// The reason the run of the [`Organism`] performing a task ended.
+    ///
+    /// [`Organism`]: ./struct.Organism.html
+    pub fn termination(&self) -> TerminationReason {
+        self.outcome.termination()
     }
 
     /// The maximal time a [`Organism`] is allowed to spend before timing out.
@@ -343,6 +793,18 @@ impl<SupplierResultInformationType, ExecutionResultElementType>
 }
 
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(
+    into = "SerialisableIndividual<ReactionType, StateType, InformationType, InputElementType, \
+        InputSensorType, OutputElementType, OutputSensorType>",
+    from = "SerialisableIndividual<ReactionType, StateType, InformationType, InputElementType, \
+        InputSensorType, OutputElementType, OutputSensorType>",
+    bound = "ReactionType: Reaction<InformationType>, StateType: State<InformationType>, \
+        InformationType: Information, \
+        InputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned, \
+        InputSensorType: Input<InputElementType, InformationType>, \
+        OutputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned, \
+        OutputSensorType: Output<OutputElementType, InformationType>"
+)]
 /// A `Individual` is describes an [`Organism`] with a defined [`Genome`].
 ///
 /// [`Genome`]: ../gene/struct.Genome.html
@@ -375,11 +837,22 @@ pub struct Individual<
             OutputSensorType,
         >,
     >,
-    fitness: Option<f64>,
-    bytes: usize,
+    fitness: FitnessStatistics,
+    bytes: Cache<usize>,
     age: u32,
-    tested: u32,
     resources: f64,
+    lineage: Lineage,
+    organism: Cache<
+        Organism<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    >,
 }
 
 impl<
@@ -421,7 +894,6 @@ impl<
             OutputSensorType,
         >,
     ) -> Self {
-        let bytes = genome.binary_size();
         Individual {
             phantom_r: PhantomData,
             phantom_s: PhantomData,
@@ -432,11 +904,12 @@ impl<
             phantom_output_sensor: PhantomData,
             source: uuid,
             genome: Arc::new(genome),
-            fitness: None,
-            bytes,
+            fitness: FitnessStatistics::new(),
+            bytes: Cache::default(),
             age: 0,
-            tested: 0,
             resources: 0.0,
+            lineage: Lineage::default(),
+            organism: Cache::default(),
         }
     }
 
@@ -445,9 +918,30 @@ impl<
         &self.source
     }
 
+    /// Returns the [`Lineage`] of this `Individual`.
+    ///
+    /// [`Lineage`]: ../genealogy/struct.Lineage.html
+    pub fn lineage(&self) -> &Lineage {
+        &self.lineage
+    }
+
+    /// Sets the [`Lineage`] of this `Individual`.
+    ///
+    /// # Parameters
+    ///
+    /// * `lineage` - the new [`Lineage`]
+    ///
+    /// [`Lineage`]: ../genealogy/struct.Lineage.html
+    pub fn set_lineage(&mut self, lineage: Lineage) {
+        self.lineage = lineage;
+    }
+
     /// Returns the number of bytes this `Individual` contains.
+    /// The size of the [`Genome`] is only calculated once when first requested.
+    ///
+    /// [`Genome`]: ../gene/struct.Genome.html
     pub fn bytes(&self) -> usize {
-        self.bytes
+        *self.bytes.value.get_or_init(|| self.genome.binary_size())
     }
 
     /// Returns the age of this `Individual`.
@@ -455,9 +949,19 @@ impl<
         self.age
     }
 
+    /// Sets the age of this `Individual`. This is used if offspring inherits the age of its
+    /// parents instead of starting at age zero.
+    ///
+    /// # Parameters
+    ///
+    /// * `age` - the new age
+    pub fn set_age(&mut self, age: u32) {
+        self.age = age;
+    }
+
     /// Returns how often this `Individual` was already tested.
     pub fn times_tested(&self) -> u32 {
-        self.tested
+        self.fitness.count()
     }
 
     /// Returns the [`Resource`]s this `Individual` has accumulated.
@@ -467,14 +971,51 @@ impl<
         self.resources
     }
 
-    /// Returns the fitness of this `Individual` if any.
+    /// Returns the mean fitness of this `Individual` if any.
     pub fn fitness(&self) -> Option<f64> {
-        self.fitness
+        self.fitness.mean()
     }
 
     /// Checks whether the fitness value of this `Individual` was already set.
     pub fn has_fitness(&self) -> bool {
-        self.fitness.is_some()
+        self.fitness.count() > 0
+    }
+
+    /// Returns the sample variance of all fitness values evaluated for this `Individual`
+    /// if it was tested at least twice.
+    pub fn fitness_variance(&self) -> Option<f64> {
+        self.fitness.variance()
+    }
+
+    /// Returns the [`FitnessStatistics`] of all fitness evaluations of this `Individual`.
+    ///
+    /// [`FitnessStatistics`]: ../fitness/struct.FitnessStatistics.html
+    pub fn fitness_statistics(&self) -> &FitnessStatistics {
+        &self.fitness
+    }
+
+    /// Returns the fitness of this `Individual` according to the specified
+    /// [`SelectionCriterion`] if any.
+    ///
+    /// # Parameters
+    ///
+    /// * `criterion` - the [`SelectionCriterion`]
+    ///
+    /// [`SelectionCriterion`]: ../fitness/enum.SelectionCriterion.html
+    pub fn selection_fitness(&self, criterion: SelectionCriterion) -> Option<f64> {
+        self.fitness.estimate(criterion)
+    }
+
+    /// Returns the current [`IndividualStatus`] of this `Individual`.
+    ///
+    /// [`IndividualStatus`]: ./struct.IndividualStatus.html
+    pub fn status(&self) -> IndividualStatus {
+        IndividualStatus {
+            age: self.age(),
+            times_tested: self.times_tested(),
+            fitness: self.fitness(),
+            fitness_variance: self.fitness_variance(),
+        }
     }
 
     /// Returns the number of associated inputs for this `Individual` contains.
@@ -487,22 +1028,16 @@ impl<
         self.genome().output().number_of_associated_outputs()
     }
 
-    /// Adds the specified fitness value by setting the fitness to the mean of old and
-    /// and new fitness. If no fitness was set prevously, sets the specified fitness
-    /// as new fitness.
+    /// Adds the specified fitness value to the [`FitnessStatistics`], so the fitness is
+    /// the mean of all evaluated fitness values.
     ///
     /// # Parameters
     ///
     /// * `fitness` - the new fitness to add to the current fitness value
+    ///
+    /// [`FitnessStatistics`]: ../fitness/struct.FitnessStatistics.html
     fn add_fitness(&mut self, fitness: f64) {
-        if let Some(old_fitness) = self.fitness {
-            // Calculate the mean of the current and all previous fitness values.
-            let f_old = old_fitness * (self.tested as f64);
-            self.fitness = Some((fitness + f_old) / ((self.tested + 1) as f64));
-        } else {
-            self.fitness = Some(fitness);
-        }
-        self.tested += 1;
+        self.fitness.add(fitness);
     }
 
     /// Updates the `Population`'s fitness.
@@ -546,15 +1081,19 @@ impl<
     }
 
     /// Recombine the [`Genome`] of this `Individual` and its mating partner and return the
-    /// resulting `Individual`.
+    /// resulting `Individual`. The names of all applied [`Mutation`]s are added to the
+    /// specified [`Lineage`] of the offspring.
     ///
     /// # Parameters
     ///
     /// * `partner` - the mating partner's [`Genome`]
     /// * `environment` - the [`Environment`] the `Individual` is living in
+    /// * `lineage` - the [`Lineage`] of the offspring
     ///
     /// [`Environment`]: ../environment/struct.Environment.html
     /// [`Genome`]: ../gene/struct.Genome.html
+    /// [`Lineage`]: ../genealogy/struct.Lineage.html
+    /// [`Mutation`]: ../environment/struct.Mutation.html
     pub fn mate_and_mutate(
         own_genome: Arc<
             Genome<
@@ -588,6 +1127,7 @@ impl<
             OutputSensorType,
         >,
         environment: &Environment,
+        mut lineage: Lineage,
     ) -> Individual<
         ReactionType,
         StateType,
@@ -598,14 +1138,18 @@ impl<
         OutputSensorType,
     > {
         let offspring_genome = own_genome.cross_over(&partner);
-        if let Some(mutated_offspring_genome) = mutations.mutate(&offspring_genome) {
-            // If the mutation was successful, return the mutated individual.
-            Individual::new(environment.generate_uuid(), mutated_offspring_genome)
+        let record = mutations.mutate(&offspring_genome);
+        let mut offspring = if record.genome().is_some() {
+            lineage.set_mutations(record.applied().to_vec());
+            // Unwrapping is safe here since the mutation was successful.
+            Individual::new(environment.generate_uuid(), record.into_genome().unwrap())
         } else {
-            // If the mutation was not successful, return the recombined individual without any
+            // If the mutation was not successful, use the recombined genome without any
             // mutations.
             Individual::new(environment.generate_uuid(), offspring_genome)
-        }
+        };
+        offspring.set_lineage(lineage);
+        offspring
     }
 
     /// Returns the [`Genome`] of this [`Individual`].
@@ -631,6 +1175,29 @@ impl<
         Arc::clone(&self.genome)
     }
 
+    /// Returns the [`Organism`] translated from the [`Genome`] of this `Individual`.
+    /// The [`Genome`] is only translated once, so every returned [`Organism`] is a copy
+    /// of the freshly translated state.
+    ///
+    /// [`Genome`]: ../gene/struct.Genome.html
+    /// [`Organism`]: ./struct.Organism.html
+    pub fn organism(
+        &self,
+    ) -> Organism<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    > {
+        self.organism
+            .value
+            .get_or_init(|| self.genome.translate())
+            .clone()
+    }
+
     /// Spends the maximum amount of [`Resource`]s possible to generate offspring and returns the
     /// number of offspring generated this way.
     ///
@@ -653,7 +1220,192 @@ impl<
     }
 }
 
+#[derive(Serialize, Deserialize)]
+/// A `SerialisableIndividual` is the serialised form of an [`Individual`], which stores
+/// the size of the [`Genome`] if it was already calculated, so it is neither calculated
+/// upon saving nor upon loading.
+/// The fields keep the layout of earlier snapshots, so those can still be loaded.
+/// New fields must be appended with a default value.
+///
+/// [`Genome`]: ../gene/struct.Genome.html
+/// [`Individual`]: ./struct.Individual.html
+struct SerialisableIndividual<
+    ReactionType,
+    StateType,
+    InformationType,
+    InputElementType,
+    InputSensorType,
+    OutputElementType,
+    OutputSensorType,
+> {
+    phantom_r: PhantomData<ReactionType>,
+    phantom_s: PhantomData<StateType>,
+    phantom_t: PhantomData<InformationType>,
+    phantom_input_element: PhantomData<InputElementType>,
+    phantom_input_sensor: PhantomData<InputSensorType>,
+    phantom_output_element: PhantomData<OutputElementType>,
+    phantom_output_sensor: PhantomData<OutputSensorType>,
+    source: Uuid,
+    genome: Arc<
+        Genome<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    >,
+    fitness: Option<f64>,
+    bytes: Option<usize>,
+    age: u32,
+    tested: u32,
+    resources: f64,
+    /// The sum of squared differences from the mean fitness used to calculate the
+    /// fitness variance.
+    #[serde(default)]
+    fitness_m2: f64,
+    #[serde(default)]
+    lineage: Lineage,
+}
+
+impl<
+        ReactionType: Reaction<InformationType>,
+        StateType: State<InformationType>,
+        InformationType: Information,
+        InputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+        InputSensorType: Input<InputElementType, InformationType>,
+        OutputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+        OutputSensorType: Output<OutputElementType, InformationType>,
+    >
+    From<
+        Individual<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    >
+    for SerialisableIndividual<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >
+{
+    fn from(
+        individual: Individual<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    ) -> Self {
+        SerialisableIndividual {
+            phantom_r: PhantomData,
+            phantom_s: PhantomData,
+            phantom_t: PhantomData,
+            phantom_input_element: PhantomData,
+            phantom_input_sensor: PhantomData,
+            phantom_output_element: PhantomData,
+            phantom_output_sensor: PhantomData,
+            bytes: individual.bytes.value.get().copied(),
+            source: individual.source,
+            genome: individual.genome,
+            fitness: individual.fitness.mean(),
+            age: individual.age,
+            tested: individual.fitness.count(),
+            resources: individual.resources,
+            fitness_m2: individual.fitness.sum_of_squares(),
+            lineage: individual.lineage,
+        }
+    }
+}
+
+impl<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >
+    From<
+        SerialisableIndividual<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    >
+    for Individual<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >
+{
+    fn from(
+        individual: SerialisableIndividual<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    ) -> Self {
+        Individual {
+            phantom_r: PhantomData,
+            phantom_s: PhantomData,
+            phantom_t: PhantomData,
+            phantom_input_element: PhantomData,
+            phantom_input_sensor: PhantomData,
+            phantom_output_element: PhantomData,
+            phantom_output_sensor: PhantomData,
+            source: individual.source,
+            genome: individual.genome,
+            fitness: FitnessStatistics::from_moments(
+                individual.tested,
+                individual.fitness.unwrap_or(0.0),
+                individual.fitness_m2,
+            ),
+            bytes: individual.bytes.map(Cache::from).unwrap_or_default(),
+            age: individual.age,
+            resources: individual.resources,
+            lineage: individual.lineage,
+            organism: Cache::default(),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(
+    bound = "ReactionType: Reaction<InformationType>, StateType: State<InformationType>, \
+        InformationType: Information, \
+        InputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned, \
+        InputSensorType: Input<InputElementType, InformationType>, \
+        OutputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned, \
+        OutputSensorType: Output<OutputElementType, InformationType>"
+)]
 /// A `SerialisablePopulation` is a simplified version of a [`Population`] that can easily
 /// be serialised and deserialised.
 ///
@@ -733,10 +1485,10 @@ impl<
         for individual in filter {
             match fitness {
                 Some(fit)
-                    if (individual.fitness.is_none() || fit >= individual.fitness.unwrap()) => {},
+                    if individual.fitness().is_none() || fit >= individual.fitness().unwrap() => {},
                 _ => {
                     uuid = Some(*individual.uuid());
-                    fitness = individual.fitness;
+                    fitness = individual.fitness();
                     ind = Some(individual.clone());
                 },
             }
@@ -843,6 +1595,7 @@ pub struct Population<
         >,
     >,
     resources: Resource,
+    selection_criterion: SelectionCriterion,
 }
 
 impl<
@@ -894,6 +1647,7 @@ impl<
         Population {
             individuals,
             resources,
+            selection_criterion: SelectionCriterion::default(),
         }
     }
 
@@ -953,6 +1707,54 @@ impl<
         Ok(serialisable_population.into())
     }
 
+    /// Write this `Population` to a human readable JSON file if possible.
+    /// An error will be returned if writing to the file failed.
+    ///
+    /// # Parameters
+    ///
+    /// * `path_to_file` - the JSON file the `Population` should be written to
+    pub fn write_json_to_file<P>(&self, path_to_file: P) -> Result<(), Box<dyn Error>>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::create(&path_to_file)?;
+        let serialisable_population: SerialisablePopulation<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        > = self.into();
+        serde_json::to_writer_pretty(&file, &serialisable_population)?;
+        Ok(file.sync_all()?)
+    }
+
+    /// Returns the [`Individual`] with the specified UUID if it is part of this `Population`.
+    ///
+    /// # Parameters
+    ///
+    /// * `uuid` - the UUID of the [`Individual`]
+    ///
+    /// [`Individual`]: ./struct.Individual.html
+    pub fn individual(
+        &self,
+        uuid: &Uuid,
+    ) -> Option<
+        SharedIndividual<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    > {
+        self.individuals.get(uuid).map(Arc::clone)
+    }
+
     /// Returns the [`Individual`]s that are part of this `Population`.
     ///
     /// [`Individual`]: ./struct.Individual.html
@@ -1106,62 +1908,97 @@ impl<
             >,
         >,
     > {
-        if self.individuals.len() == 0 {
+        self.random_individual_fitness_based(|_| true).map(|value| {
+            value
+                .lock()
+                .expect("A thread paniced while holding the individual's lock.")
+                .genome()
+        })
+    }
+
+    /// Returns a random [`Individual`] matching the specified filter if there is any.
+    /// The chance of selecting an individual is based on its respective fitness.
+    ///
+    /// # Parameters
+    ///
+    /// * `filter` - the filter an [`Individual`] must match to be selected
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding the individual's lock.
+    ///
+    /// [`Individual`]: ./struct.Individual.html
+    pub fn random_individual_fitness_based<F>(
+        &self,
+        filter: F,
+    ) -> Option<
+        SharedIndividual<
+            ReactionType,
+            StateType,
+            InformationType,
+            InputElementType,
+            InputSensorType,
+            OutputElementType,
+            OutputSensorType,
+        >,
+    >
+    where
+        F: Fn(
+            &Individual<
+                ReactionType,
+                StateType,
+                InformationType,
+                InputElementType,
+                InputSensorType,
+                OutputElementType,
+                OutputSensorType,
+            >,
+        ) -> bool,
+    {
+        let mut candidates: Vec<
+            SharedIndividual<
+                ReactionType,
+                StateType,
+                InformationType,
+                InputElementType,
+                InputSensorType,
+                OutputElementType,
+                OutputSensorType,
+            >,
+        > = Vec::new();
+        let mut fitness_values: Vec<f64> = Vec::new();
+        for individual in self.individuals.values() {
+            let ind = individual
+                .lock()
+                .expect("A thread paniced while holding the individual's lock.");
+            if filter(&ind) {
+                candidates.push(Arc::clone(individual));
+                // Add a very tiny amount of fitness so that individuals with a fitness of
+                // zero also have a chance of being selected. This also prevents the first
+                // individual being constantly choosen when the mean fitness is zero,
+                // and thereby degenerating the population.
+                fitness_values.push(
+                    ind.selection_fitness(self.selection_criterion)
+                        .unwrap_or(0.0f64)
+                        .max(0.0)
+                        + 0.0000000001,
+                );
+            }
+        }
+        if candidates.is_empty() {
             None
         } else {
-            let individuals: Vec<
-                Arc<
-                    Mutex<
-                        Individual<
-                            ReactionType,
-                            StateType,
-                            InformationType,
-                            InputElementType,
-                            InputSensorType,
-                            OutputElementType,
-                            OutputSensorType,
-                        >,
-                    >,
-                >,
-            > = self
-                .individuals
-                .values()
-                .map(|arc| Arc::clone(arc))
-                .collect();
-            let fitness_values: Vec<f64> = individuals
-                .iter()
-                .map(|ind| {
-                    ind.lock()
-                        .expect("A thread paniced while holding the individual's lock.")
-                        .fitness()
-                        .unwrap_or(0.0f64)
-                        // Add a very tiny amount of fitness so that individuals with a fitness of 
-                        // zero also have a chance of being selected. This also prevents the first
-                        // individual being constantly choosen when the mean fitness is zero,
-                        // and thereby degenerating the population.
-                        + 0.0000000001
-                })
-                .collect();
             let random_fitness_sum: f64 = thread_rng().gen_range(0.0..=fitness_values.iter().sum());
-            let mut random_population_index: usize = 0;
             let mut fitness_sum: f64 = 0.0;
-            for fitness in fitness_values {
+            for (individual, fitness) in candidates.iter().zip(fitness_values) {
                 fitness_sum += fitness;
                 if random_fitness_sum <= fitness_sum {
-                    break;
-                } else {
-                    random_population_index += 1;
+                    return Some(Arc::clone(individual));
                 }
             }
-
-            individuals.get(random_population_index).and_then(|value| {
-                Some(
-                    value
-                        .lock()
-                        .expect("A thread paniced while holding the individual's lock.")
-                        .genome(),
-                )
-            })
+            // Rounding errors might prevent reaching the total sum, so default to the last
+            // candidate.
+            candidates.last().cloned()
         }
     }
 
@@ -1247,7 +2084,7 @@ impl<
                     .age()
                     >= minimum_age
             })
-            .map(|arc| Arc::clone(arc))
+            .map(Arc::clone)
             .collect();
         let mut fittest_individual = None;
         let mut max_fitness: f64 = 0.0;
@@ -1311,7 +2148,7 @@ impl<
             let mut ind = individual
                 .lock()
                 .expect("A thread paniced while holding the individual's lock.");
-            ind.fitness = None;
+            ind.fitness = FitnessStatistics::new();
             ind.age = 0;
         }
     }
@@ -1388,6 +2225,84 @@ impl<
         }
     }
 
+    /// Summarises the [`RateModifiers`] of the [`Genome`]s of all [`Individual`]s that are
+    /// part of this `Population` per mutation.
+    ///
+    /// # Parameters
+    ///
+    /// * `number_of_mutations` - the number of mutations to summarise
+    ///
+    /// # Panics
+    ///
+    /// If another thread paniced while holding the individual's lock.
+    ///
+    /// [`Genome`]: ../gene/struct.Genome.html
+    /// [`Individual`]: ./struct.Individual.html
+    /// [`RateModifiers`]: ../gene/struct.RateModifiers.html
+    pub fn rate_distributions(&self, number_of_mutations: usize) -> Vec<RateDistribution> {
+        let genomes: Vec<_> = self
+            .individuals
+            .values()
+            .map(|individual| {
+                individual
+                    .lock()
+                    .expect("A thread paniced while holding the individual's lock.")
+                    .genome()
+            })
+            .collect();
+        RateDistribution::summarise(
+            genomes.iter().map(|genome| genome.rate_modifiers()),
+            number_of_mutations,
+        )
+    }
+
+    /// Calculates the mean age of the [`Individual`]s that are part of this `Population`.
+    ///
+    /// [`Individual`]: ./struct.Individual.html
+    pub fn mean_age(&self) -> f64 {
+        if self.size() > 0 {
+            let total_age: f64 = self
+                .individuals
+                .values()
+                .map(|individual| {
+                    individual
+                        .lock()
+                        .expect("A thread paniced while holding the individual's lock.")
+                        .age() as f64
+                })
+                .sum();
+            total_age / (self.size() as f64)
+        } else {
+            // If the population is empty, the age is zero.
+            0.0
+        }
+    }
+
+    /// Returns the current [`PopulationStatistics`] of this `Population`.
+    ///
+    /// [`PopulationStatistics`]: ./struct.PopulationStatistics.html
+    pub fn statistics(&self) -> PopulationStatistics {
+        PopulationStatistics {
+            size: self.size(),
+            mean_fitness: self.mean_fitness(),
+            maximum_fitness: self.maximum_fitness(1),
+            mean_genome_size: self.mean_genome_size(),
+            mean_age: self.mean_age(),
+            resources: self.resources(),
+        }
+    }
+
+    /// Adjusts the total amount of [`Resource`]s by the specified amount.
+    ///
+    /// # Parameters
+    ///
+    /// * `amount` - the amount of [`Resource`]s to add or, if negative, to remove
+    ///
+    /// [`Resource`]: ../resource/struct.Resource.html
+    pub fn adjust_resources(&mut self, amount: f64) {
+        self.resources.adjust(amount);
+    }
+
     /// Add the specified amount of [`Resource`]s.
     ///
     /// # Parameters
@@ -1403,6 +2318,22 @@ impl<
         self.resources.repatriate_resources(amount);
     }
 
+    /// Retrieves the specified amount of [`Resource`]s if available, otherwise returns the
+    /// maximum available amount.
+    ///
+    /// # Parameters
+    ///
+    /// * `amount` - the amount of [`Resource`]s to be claimed
+    ///
+    /// # Panics
+    ///
+    /// If the specified `amount` is not a valid positive number.
+    ///
+    /// [`Resource`]: ../resource/struct.Resource.html
+    pub fn claim_resources(&mut self, amount: f64) -> f64 {
+        self.resources.claim_resources(amount)
+    }
+
     /// Recycles inavailable [`Resource`]s at the end of a generation.
     ///
     /// [`Resource`]: ../resource/struct.Resource.html
@@ -1417,6 +2348,28 @@ impl<
         self.resources
     }
 
+    /// Returns the [`SelectionCriterion`] used to select [`Individual`]s for mating and
+    /// resource distribution.
+    ///
+    /// [`Individual`]: ./struct.Individual.html
+    /// [`SelectionCriterion`]: ../fitness/enum.SelectionCriterion.html
+    pub fn selection_criterion(&self) -> SelectionCriterion {
+        self.selection_criterion
+    }
+
+    /// Sets the [`SelectionCriterion`] used to select [`Individual`]s for mating and
+    /// resource distribution. By default the mean fitness is used.
+    ///
+    /// # Parameters
+    ///
+    /// * `selection_criterion` - the [`SelectionCriterion`]
+    ///
+    /// [`Individual`]: ./struct.Individual.html
+    /// [`SelectionCriterion`]: ../fitness/enum.SelectionCriterion.html
+    pub fn set_selection_criterion(&mut self, selection_criterion: SelectionCriterion) {
+        self.selection_criterion = selection_criterion;
+    }
+
     /// Distributes available [`Resource`]s based on the fitness of the [`Individual`]s.
     ///
     /// [`Individual`]: ./struct.Individual.html
@@ -1444,7 +2397,8 @@ impl<
             if let Some(fitness) = individual
                 .lock()
                 .expect("Another thread panicked while holding the individual lock.")
-                .fitness()
+                .selection_fitness(self.selection_criterion)
+                .map(|fitness| fitness.max(0.0))
             {
                 // Aquire resources. The higher the fitness, the slighter the difference needed
                 // for significant resource advantage.
@@ -1516,6 +2470,165 @@ impl<
     }
 }
 
+#[derive(Debug, PartialEq, Clone, Copy, Default, Serialize, Deserialize)]
+/// An `IndividualStatus` summarises the state of an [`Individual`] that is relevant for its
+/// selection.
+///
+/// [`Individual`]: ./struct.Individual.html
+pub struct IndividualStatus {
+    age: u32,
+    times_tested: u32,
+    fitness: Option<f64>,
+    fitness_variance: Option<f64>,
+}
+
+impl IndividualStatus {
+    /// Creates a new `IndividualStatus`.
+    ///
+    /// # Parameters
+    ///
+    /// * `age` - the age of the [`Individual`]
+    /// * `times_tested` - how often the [`Individual`] was already tested
+    /// * `fitness` - the mean fitness of the [`Individual`] if any
+    /// * `fitness_variance` - the sample variance of the fitness of the [`Individual`] if any
+    ///
+    /// [`Individual`]: ./struct.Individual.html
+    pub fn new(
+        age: u32,
+        times_tested: u32,
+        fitness: Option<f64>,
+        fitness_variance: Option<f64>,
+    ) -> Self {
+        IndividualStatus {
+            age,
+            times_tested,
+            fitness,
+            fitness_variance,
+        }
+    }
+
+    /// Returns the age of the [`Individual`].
+    ///
+    /// [`Individual`]: ./struct.Individual.html
+    pub fn age(&self) -> u32 {
+        self.age
+    }
+
+    /// Returns how often the [`Individual`] was already tested.
+    ///
+    /// [`Individual`]: ./struct.Individual.html
+    pub fn times_tested(&self) -> u32 {
+        self.times_tested
+    }
+
+    /// Returns the mean fitness of the [`Individual`] if any.
+    ///
+    /// [`Individual`]: ./struct.Individual.html
+    pub fn fitness(&self) -> Option<f64> {
+        self.fitness
+    }
+
+    /// Returns the sample variance of the fitness of the [`Individual`] if it was tested
+    /// at least twice.
+    ///
+    /// [`Individual`]: ./struct.Individual.html
+    pub fn fitness_variance(&self) -> Option<f64> {
+        self.fitness_variance
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Default, Serialize, Deserialize)]
+/// `PopulationStatistics` summarise the state of a [`Population`] at a certain point in time.
+///
+/// [`Population`]: ./struct.Population.html
+pub struct PopulationStatistics {
+    size: usize,
+    mean_fitness: f64,
+    maximum_fitness: Option<f64>,
+    mean_genome_size: f64,
+    mean_age: f64,
+    resources: Resource,
+}
+
+impl PopulationStatistics {
+    /// Creates new `PopulationStatistics`.
+    ///
+    /// # Parameters
+    ///
+    /// * `size` - the number of [`Individual`]s
+    /// * `mean_fitness` - the mean fitness of all tested [`Individual`]s
+    /// * `maximum_fitness` - the fitness of the fittest [`Individual`] if any
+    /// * `mean_genome_size` - the mean [`Genome`] size in byte
+    /// * `mean_age` - the mean age of all [`Individual`]s
+    /// * `resources` - the [`Resource`]s of the [`Population`]
+    ///
+    /// [`Genome`]: ../gene/struct.Genome.html
+    /// [`Individual`]: ./struct.Individual.html
+    /// [`Population`]: ./struct.Population.html
+    /// [`Resource`]: ../resource/struct.Resource.html
+    pub fn new(
+        size: usize,
+        mean_fitness: f64,
+        maximum_fitness: Option<f64>,
+        mean_genome_size: f64,
+        mean_age: f64,
+        resources: Resource,
+    ) -> Self {
+        PopulationStatistics {
+            size,
+            mean_fitness,
+            maximum_fitness,
+            mean_genome_size,
+            mean_age,
+            resources,
+        }
+    }
+
+    /// Returns the number of [`Individual`]s.
+    ///
+    /// [`Individual`]: ./struct.Individual.html
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the mean fitness of all tested [`Individual`]s.
+    ///
+    /// [`Individual`]: ./struct.Individual.html
+    pub fn mean_fitness(&self) -> f64 {
+        self.mean_fitness
+    }
+
+    /// Returns the fitness of the fittest [`Individual`] that is at least one generation old
+    /// if there is any.
+    ///
+    /// [`Individual`]: ./struct.Individual.html
+    pub fn maximum_fitness(&self) -> Option<f64> {
+        self.maximum_fitness
+    }
+
+    /// Returns the mean [`Genome`] size in byte.
+    ///
+    /// [`Genome`]: ../gene/struct.Genome.html
+    pub fn mean_genome_size(&self) -> f64 {
+        self.mean_genome_size
+    }
+
+    /// Returns the mean age of all [`Individual`]s.
+    ///
+    /// [`Individual`]: ./struct.Individual.html
+    pub fn mean_age(&self) -> f64 {
+        self.mean_age
+    }
+
+    /// Returns the [`Resource`]s of the [`Population`].
+    ///
+    /// [`Population`]: ./struct.Population.html
+    /// [`Resource`]: ../resource/struct.Resource.html
+    pub fn resources(&self) -> Resource {
+        self.resources
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
 /// A `RemoveError` is returned when a UUID with no matching [`Individual`] is flagged for
 /// removal.
@@ -1547,3 +2660,6 @@ impl Error for RemoveError {
         &self.description
     }
 }
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,226 @@
+use crate::evolution::environment::EnvironmentBuilder;
+use crate::evolution::helper::testing::{
+    dendrite, neuron_gene, neuron_genome, neuron_organism, NeuronGenome, NeuronOrganism,
+};
+use crate::evolution::helper::Nlbf64;
+use crate::evolution::neuron::{
+    SimpleDendriteActivationPotential, SimpleDendriteThreshold, SimpleNeuron,
+    SimpleNeuronParameterInputSensor, SimpleNeuronParameterOutputSensor,
+};
+
+use super::*;
+
+/// Creates a [`NeuronGenome`] of four neurons with the input at the first and the
+/// output at the third neuron. The first neuron excites the second, which excites the
+/// third and fourth.
+fn test_genome() -> NeuronGenome {
+    neuron_genome(vec![neuron_gene(
+        4,
+        vec![dendrite(0, 1), dendrite(1, 2), dendrite(1, 3)],
+    )])
+}
+
+/// Creates a [`NeuronOrganism`] from the [`Genome`] of `test_genome` with an input set.
+fn test_organism() -> NeuronOrganism {
+    neuron_organism(&test_genome())
+}
+
+#[test]
+/// Tests if the function `live` of the [`Organism`] struct correctly reports the
+/// [`RunOutcome`].
+fn test_live() {
+    let environment = EnvironmentBuilder::new().build().unwrap();
+    let mut organism = test_organism();
+    let outcome = organism.live(&environment);
+    assert_eq!(outcome.termination(), TerminationReason::ActionsExhausted);
+    assert_eq!(outcome.iterations(), 2);
+    assert_eq!(outcome.receptors_fired(), 3);
+    assert_eq!(outcome.peak_organism_size(), organism.binary_size());
+    assert!(outcome.run_time() <= environment.lifespan());
+    let information = OrganismInformation::new(
+        organism.get_result(),
+        (),
+        0,
+        outcome,
+        environment.lifespan(),
+        1,
+        1,
+        organism.binary_size(),
+        environment.max_organism_size(),
+    );
+    assert_eq!(information.outcome(), &outcome);
+    assert_eq!(information.run_time(), outcome.run_time());
+    assert_eq!(information.termination(), TerminationReason::ActionsExhausted);
+    let environment = EnvironmentBuilder::new()
+        .max_organism_size(1)
+        .build()
+        .unwrap();
+    let mut organism = test_organism();
+    let outcome = organism.live(&environment);
+    assert_eq!(outcome.termination(), TerminationReason::SizeLimitExceeded);
+    assert_eq!(outcome.iterations(), 0);
+    assert_eq!(outcome.receptors_fired(), 0);
+    assert_eq!(outcome.peak_organism_size(), organism.binary_size());
+}
+
+#[test]
+/// Tests if the function `organism` of the [`Individual`] struct correctly returns copies
+/// of the cached translation, which can be tested on other threads.
+fn test_individual_organism() {
+    let organism = test_organism();
+    let individual: Individual<
+        SimpleDendriteActivationPotential,
+        SimpleDendriteThreshold,
+        SimpleNeuron,
+        Vec<Nlbf64>,
+        SimpleNeuronParameterInputSensor,
+        Vec<Nlbf64>,
+        SimpleNeuronParameterOutputSensor,
+    > = Individual::new(Uuid::nil(), test_genome());
+    let environment = EnvironmentBuilder::new().build().unwrap();
+    let mut copy = individual.organism();
+    copy.set_input(vec![Nlbf64::from(0.5)]);
+    copy.live(&environment);
+    assert_eq!(individual.organism().number_of_substrates(), organism.number_of_substrates());
+    assert_eq!(individual.organism().time_alive(), Iteration::new());
+    let outcomes: Vec<RunOutcome> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let mut organism = individual.organism();
+                let environment = &environment;
+                scope.spawn(move || {
+                    organism.set_input(vec![Nlbf64::from(0.5)]);
+                    organism.live(environment)
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    });
+    assert!(outcomes
+        .iter()
+        .all(|outcome| outcome.receptors_fired() == 3));
+    assert_eq!(individual.clone(), individual);
+}
+
+#[test]
+/// Tests if the function `live` of the [`Organism`] struct correctly tracks the size of the
+/// [`Organism`] and skips the size limit for constant size organisms.
+fn test_live_size_tracking() {
+    let environment = EnvironmentBuilder::new().build().unwrap();
+    let mut organism = test_organism();
+    organism.live(&environment);
+    let size: usize = organism
+        .substrates()
+        .iter()
+        .map(Substrate::binary_size)
+        .sum();
+    assert_eq!(organism.binary_size(), size);
+    let environment = EnvironmentBuilder::new()
+        .max_organism_size(1)
+        .constant_organism_size(true)
+        .build()
+        .unwrap();
+    let mut organism = test_organism();
+    let outcome = organism.live(&environment);
+    assert_eq!(outcome.termination(), TerminationReason::ActionsExhausted);
+    assert_eq!(outcome.receptors_fired(), 3);
+}
+
+#[test]
+/// Tests if the function `bytes` of the [`Individual`] struct correctly calculates the size
+/// of the [`Genome`] and keeps it through serialisation.
+fn test_individual_bytes() {
+    let individual: Individual<
+        SimpleDendriteActivationPotential,
+        SimpleDendriteThreshold,
+        SimpleNeuron,
+        Vec<Nlbf64>,
+        SimpleNeuronParameterInputSensor,
+        Vec<Nlbf64>,
+        SimpleNeuronParameterOutputSensor,
+    > = Individual::new(Uuid::nil(), test_genome());
+    let bytes = rmp_serde::to_vec(&test_genome()).unwrap().len();
+    assert_eq!(individual.genome().binary_size(), bytes);
+    assert_eq!(individual.bytes(), bytes);
+    let serialised = rmp_serde::to_vec(&individual).unwrap();
+    let deserialised: Individual<
+        SimpleDendriteActivationPotential,
+        SimpleDendriteThreshold,
+        SimpleNeuron,
+        Vec<Nlbf64>,
+        SimpleNeuronParameterInputSensor,
+        Vec<Nlbf64>,
+        SimpleNeuronParameterOutputSensor,
+    > = rmp_serde::from_slice(&serialised).unwrap();
+    assert_eq!(deserialised.bytes(), bytes);
+    assert_eq!(deserialised.genome(), individual.genome());
+}
+
+#[test]
+/// Tests if the serialisation of the [`Individual`] struct correctly stores the size of the
+/// [`Genome`] only if it was already calculated.
+fn test_individual_bytes_lazy() {
+    let individual: Individual<
+        SimpleDendriteActivationPotential,
+        SimpleDendriteThreshold,
+        SimpleNeuron,
+        Vec<Nlbf64>,
+        SimpleNeuronParameterInputSensor,
+        Vec<Nlbf64>,
+        SimpleNeuronParameterOutputSensor,
+    > = Individual::new(Uuid::nil(), test_genome());
+    let serialised = rmp_serde::to_vec(&individual).unwrap();
+    assert!(individual.bytes.value.get().is_none());
+    let deserialised: Individual<
+        SimpleDendriteActivationPotential,
+        SimpleDendriteThreshold,
+        SimpleNeuron,
+        Vec<Nlbf64>,
+        SimpleNeuronParameterInputSensor,
+        Vec<Nlbf64>,
+        SimpleNeuronParameterOutputSensor,
+    > = rmp_serde::from_slice(&serialised).unwrap();
+    assert!(deserialised.bytes.value.get().is_none());
+    assert_eq!(deserialised.bytes(), individual.genome().binary_size());
+}
+
+#[test]
+/// Tests if the function `load_from_file` of the [`Population`] struct correctly loads
+/// snapshots written before fitness statistics and lineages were tracked.
+fn test_load_legacy_snapshot() {
+    let uuid = Uuid::from_u128(1);
+    let genome = test_genome();
+    // The positional layout of an individual before fitness statistics were introduced.
+    let legacy_individual =
+        ((), (), (), (), (), (), (), uuid, &genome, Some(0.5), 42usize, 3u32, 2u32, 1.5);
+    let path =
+        std::env::temp_dir().join(format!("oben_population_{}", Uuid::from_u128(rand::random())));
+    std::fs::write(
+        &path,
+        rmp_serde::to_vec(&(vec![legacy_individual], Resource::new(10.0, 1.0))).unwrap(),
+    )
+    .unwrap();
+    let population: Population<
+        SimpleDendriteActivationPotential,
+        SimpleDendriteThreshold,
+        SimpleNeuron,
+        Vec<Nlbf64>,
+        SimpleNeuronParameterInputSensor,
+        Vec<Nlbf64>,
+        SimpleNeuronParameterOutputSensor,
+    > = Population::load_from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let individual = population.individual(&uuid).unwrap();
+    let individual = individual.lock().unwrap();
+    assert_eq!(individual.fitness(), Some(0.5));
+    assert_eq!(individual.times_tested(), 2);
+    assert_eq!(individual.fitness_variance(), Some(0.0));
+    assert_eq!(individual.age(), 3);
+    assert_eq!(individual.bytes(), 42);
+    assert_eq!(individual.resources(), 1.5);
+    assert_eq!(individual.lineage(), &Lineage::default());
+    assert_eq!(individual.genome().as_ref(), &genome);
+}
@@ -5,21 +5,25 @@ extern crate rmp_serde;
 pub use sensor::{InputSensor, OutputSensor};
 
 use super::chemistry::{Information, Reaction, State};
-use super::helper::Iteration;
-use std::borrow::Borrow;
-use std::cell::{RefCell, RefMut};
-use std::rc::{Rc, Weak};
+use super::helper::{serialised_size, Iteration};
+use std::marker::PhantomData;
 
 /// A `Substrate` represents a chemical entity of a specific value. Additionally
-/// a `Substrate` is aware of all [`Receptor`]s detecting its changes.
+/// a `Substrate` is aware of the ids of all [`Receptor`]s detecting its changes.
+/// `Substrate`s and [`Receptor`]s are stored in the arena of an [`Organism`] and
+/// address each other by their position in the respective vector.
 ///
+/// [`Organism`]: ../population/struct.Organism.html
 /// [`Receptor`]: ./struct.Receptor.html
 #[derive(Debug, Clone)]
 pub struct Substrate<ReactionType, StateType, InformationType> {
+    phantom_r: PhantomData<ReactionType>,
+    phantom_s: PhantomData<StateType>,
     value: InformationType,
-    receptors: Vec<Rc<Receptor<ReactionType, StateType, InformationType>>>,
+    receptors: Vec<usize>,
     last_updated: Iteration,
     substrate_type: SubstrateType,
+    cached_binary_size: Option<usize>,
 }
 
 impl<
@@ -36,10 +40,13 @@ impl<
     /// * `substrate_type` - the type of the substrate
     pub fn new(value: InformationType, substrate_type: SubstrateType) -> Self {
         Substrate {
+            phantom_r: PhantomData,
+            phantom_s: PhantomData,
             value,
             receptors: vec![],
             last_updated: Iteration::new(),
             substrate_type,
+            cached_binary_size: None,
         }
     }
 
@@ -67,6 +74,7 @@ impl<
     /// [`Receptor`]: ./struct.Receptor.html
     pub fn set_value(&mut self, value: InformationType) {
         self.value = value;
+        self.cached_binary_size = None;
     }
 
     /// Returns the value of this substrate at the specified timepoint.
@@ -79,31 +87,53 @@ impl<
             let time_passed = time - self.last_updated;
             self.value.update_value(time_passed);
             self.last_updated = time;
+            self.cached_binary_size = None;
         }
         &self.value
     }
 
+    /// Returns the value of this substrate as of its last update without
+    /// advancing it in time.
+    pub fn last_value(&self) -> &InformationType {
+        &self.value
+    }
+
     /// Returns the number of bits encoded by this `Substrate`.
+    ///
+    /// # Panics
+    ///
+    /// If the underlying serialisation fails.
     pub fn binary_size(&self) -> usize {
-        // TODO: Revise this implementation as it is potentially very expensive.
-        rmp_serde::to_vec(&self.value)
-            .expect("Serialisation of the genome failed.")
-            .len()
-            * 8
+        serialised_size(&self.value) * 8
+    }
+
+    /// Returns the number of bits encoded by this `Substrate` as described for
+    /// [`binary_size`](#method.binary_size), but only recalculates it if the value
+    /// changed since the last call.
+    pub fn cached_binary_size(&mut self) -> usize {
+        match self.cached_binary_size {
+            Some(size) => size,
+            None => {
+                let size = self.binary_size();
+                self.cached_binary_size = Some(size);
+                size
+            },
+        }
     }
 
-    /// Returns all receptors detecting this substrate.
-    pub fn receptors(&self) -> Vec<Rc<Receptor<ReactionType, StateType, InformationType>>> {
-        self.receptors.iter().map(Rc::clone).collect()
+    /// Returns the ids of all receptors detecting this substrate.
+    pub fn receptors(&self) -> &[usize] {
+        &self.receptors
     }
 
-    /// Adds a [`Receptor`] to the substrate that should be notified upon change.
+    /// Adds the id of a [`Receptor`] that should be notified upon change.
+    ///
+    /// # Parameters
+    ///
+    /// * `receptor` - the id of the [`Receptor`]
     ///
     /// [`Receptor`]: ./struct.Receptor.html
-    pub fn add_receptor(
-        &mut self,
-        receptor: Rc<Receptor<ReactionType, StateType, InformationType>>,
-    ) {
+    pub fn add_receptor(&mut self, receptor: usize) {
         self.receptors.push(receptor);
     }
 
@@ -170,15 +200,17 @@ pub enum SubstrateType {
 }
 
 /// A `Receptor` is a sensor for [`Substrate`] changes and a trigger
-/// for [`Reaction`]s.
+/// for [`Reaction`]s. It references its [`Substrate`]s by their ids.
 ///
 /// [`Substrate`]: ./struct.Substrate.html
 /// [`Reaction`]: ../chemistry/struct.Reaction.html
 #[derive(Debug, Clone)]
 pub struct Receptor<ReactionType, StateType, InformationType> {
-    substrates: Vec<Weak<RefCell<Substrate<ReactionType, StateType, InformationType>>>>,
+    substrates: Vec<usize>,
     state: StateType,
     enzyme: CatalyticCentre<ReactionType, StateType, InformationType>,
+    activations: usize,
+    accessed_substrates: Vec<usize>,
 }
 
 impl<
@@ -192,7 +224,7 @@ impl<
     ///
     /// # Parameters
     ///
-    /// * `substrates` - the [`Substrate`]s the [`State`] should check
+    /// * `substrates` - the ids of the [`Substrate`]s the [`State`] should check
     /// * `state` - the [`State`] to check for
     /// * `enzyme` - the [`CatalyticCentre`] to trigger if the [`State`] is appropriate
     ///
@@ -204,75 +236,126 @@ impl<
     /// [`State`]: ../chemistry/struct.State.html
     /// [`CatalyticCentre`]: ./struct.CatalyticCentre.html
     pub fn new(
-        substrates: Vec<Weak<RefCell<Substrate<ReactionType, StateType, InformationType>>>>,
+        substrates: Vec<usize>,
         state: StateType,
         enzyme: CatalyticCentre<ReactionType, StateType, InformationType>,
     ) -> Self {
         assert_eq!(substrates.len(), state.get_substrate_number(),
             "The number of required substrates to check for state {:?} is {}, but {} substrates were supplied.",
             state, state.get_substrate_number(), substrates.len());
+        let mut accessed_substrates =
+            [&substrates[..], enzyme.educts(), enzyme.products()].concat();
+        accessed_substrates.sort_unstable();
+        accessed_substrates.dedup();
         Receptor {
             substrates,
             state,
             enzyme,
+            activations: 0,
+            accessed_substrates,
         }
     }
 
+    /// Returns the ids of the [`Substrate`]s the [`State`] is checked for.
+    ///
+    /// [`State`]: ../chemistry/struct.State.html
+    /// [`Substrate`]: ./struct.Substrate.html
+    pub fn substrates(&self) -> &[usize] {
+        &self.substrates
+    }
+
+    /// Returns the distinct ids of all [`Substrate`]s the `Receptor` and its
+    /// [`CatalyticCentre`] read or modify.
+    ///
+    /// [`CatalyticCentre`]: ./struct.CatalyticCentre.html
+    /// [`Substrate`]: ./struct.Substrate.html
+    pub fn accessed_substrates(&self) -> &[usize] {
+        &self.accessed_substrates
+    }
+
+    /// Returns the [`State`] the `Receptor` detects.
+    ///
+    /// [`State`]: ../chemistry/struct.State.html
+    pub fn state(&self) -> &StateType {
+        &self.state
+    }
+
+    /// Returns how often the `Receptor` triggered its [`CatalyticCentre`] so far.
+    ///
+    /// [`CatalyticCentre`]: ./struct.CatalyticCentre.html
+    pub fn activations(&self) -> usize {
+        self.activations
+    }
+
+    /// Returns the [`CatalyticCentre`] triggered by the `Receptor`.
+    ///
+    /// [`CatalyticCentre`]: ./struct.CatalyticCentre.html
+    pub fn enzyme(&self) -> &CatalyticCentre<ReactionType, StateType, InformationType> {
+        &self.enzyme
+    }
+
     /// Detects the [`State`] of its substrates and determines if triggering the
     /// [`CatalyticCentre`]'s reaction is appropriate.
     ///
     /// # Parameters
     ///
+    /// * `substrates` - all [`Substrate`]s of the [`Organism`]
     /// * `time_of_detection` - the timepoint at which the catalysis happens
-    /// as [`Iteration`](crate::evolution::helper::Iteration)
+    ///   as [`Iteration`](crate::evolution::helper::Iteration)
     ///
     /// [`State`]: ../chemistry/struct.State.html
     /// [`CatalyticCentre`]: ./struct.CatalyticCentre.html
-    pub fn detect(&self, time_of_detection: Iteration) -> bool {
-        // TODO: refactor this ugly code
-        let strong: Vec<Rc<RefCell<Substrate<ReactionType, StateType, InformationType>>>> = self.substrates.iter()
-            // This unwrap must succeed as the containing structure will always be dropped first
-            // and no substrate references are leaked.
-            .map(|weak| weak.upgrade().unwrap())
-            .collect();
-        let mut substrates: Vec<RefMut<Substrate<ReactionType, StateType, InformationType>>> =
-            strong.iter().map(|sub| sub.borrow_mut()).collect();
-        let substrate_values: Vec<&InformationType> = substrates
-            .iter_mut()
-            .map(|sub| sub.value(time_of_detection))
-            .collect();
+    /// [`Organism`]: ../population/struct.Organism.html
+    /// [`Substrate`]: ./struct.Substrate.html
+    pub fn detect(
+        &self,
+        substrates: &mut [Substrate<ReactionType, StateType, InformationType>],
+        time_of_detection: Iteration,
+    ) -> bool {
+        let substrate_values = substrate_values(&self.substrates, substrates, time_of_detection);
         self.state.detect(&substrate_values, time_of_detection)
     }
 
-    /// Triggers a [`CatalyticCentre`]'s reaction. Subsequent ("cascading")
+    /// Triggers a [`CatalyticCentre`]'s reaction. The ids of subsequent ("cascading")
     /// receptors, which are supposed to be checked after the reaction was triggered,
-    /// and returned together with other associations.
+    /// are returned together with other associations.
     ///
     /// # Parameters
     ///
+    /// * `substrates` - all [`Substrate`]s of the [`Organism`]
     /// * `time_of_catalysis` - the timepoint at which the catalysis happens
-    /// as [`Iteration`](crate::evolution::helper::Iteration)
+    ///   as [`Iteration`](crate::evolution::helper::Iteration)
     ///
     /// [`CatalyticCentre`]: ./struct.CatalyticCentre.html
+    /// [`Organism`]: ../population/struct.Organism.html
+    /// [`Substrate`]: ./struct.Substrate.html
     pub fn catalyse(
-        &self,
+        &mut self,
+        substrates: &mut [Substrate<ReactionType, StateType, InformationType>],
         time_of_catalysis: Iteration,
-    ) -> CatalysisResult<ReactionType, StateType, InformationType> {
-        self.enzyme.catalyse(time_of_catalysis);
+    ) -> CatalysisResult<InformationType> {
+        self.activations += 1;
+        self.enzyme.catalyse(substrates, time_of_catalysis);
         CatalysisResult {
-            cascading_receptors: self.enzyme.cascading_receptors(),
-            input_feedback_associations: self.enzyme.input_feedback_associations(time_of_catalysis),
-            output_feedback_associations: self.enzyme.output_feedback_associations(time_of_catalysis),
-            output_finish_substrate: self.enzyme.output_finish_substrate(time_of_catalysis),
+            cascading_receptors: self.enzyme.cascading_receptors(substrates),
+            input_feedback_associations: self
+                .enzyme
+                .input_feedback_associations(substrates, time_of_catalysis),
+            output_feedback_associations: self
+                .enzyme
+                .output_feedback_associations(substrates, time_of_catalysis),
+            output_finish_substrate: self
+                .enzyme
+                .output_finish_substrate(substrates, time_of_catalysis),
         }
     }
 }
 
 #[derive(Debug, Clone)]
 /// The result of a catalysis.
-pub struct CatalysisResult<ReactionType, StateType, InformationType> {
-    /// The [`Receptor`]s affected by this catalysis.
-    pub cascading_receptors: Vec<Rc<Receptor<ReactionType, StateType, InformationType>>>,
+pub struct CatalysisResult<InformationType> {
+    /// The ids of the [`Receptor`]s affected by this catalysis.
+    pub cascading_receptors: Vec<usize>,
     /// The input feedback associations affected by this catalysis.
     pub input_feedback_associations: Vec<(Vec<usize>, InformationType)>,
     /// The input feedback associations affected by this catalysis.
@@ -283,32 +366,23 @@ pub struct CatalysisResult<ReactionType, StateType, InformationType> {
 
 impl<R: Reaction<T>, S: State<T>, T: Information> PartialEq for Receptor<R, S, T> {
     fn eq(&self, other: &Self) -> bool {
-        if self.state != other.state {
-            return false;
-        }
-        if self.enzyme != other.enzyme {
-            return false;
-        }
-        if self.substrates.len() != other.substrates.len() {
-            return false;
-        }
-        // Compare the raw pointers.
-        self.substrates
-            .iter()
-            .zip(other.substrates.iter())
-            .all(|(a, b)| a.ptr_eq(b))
+        self.state == other.state
+            && self.enzyme == other.enzyme
+            && self.substrates == other.substrates
     }
 }
 
 /// A `CatalyticCentre` produces products from educt [`Substrate`]s
-/// by performing a [`Reaction`].
+/// by performing a [`Reaction`]. It references its [`Substrate`]s by their ids.
 ///
 /// [`Substrate`]: ./struct.Substrate.html
 /// [`Reaction`]: ../chemistry/struct.Reaction.html
 #[derive(Debug, Clone)]
 pub struct CatalyticCentre<ReactionType, StateType, InformationType> {
-    educts: Vec<Weak<RefCell<Substrate<ReactionType, StateType, InformationType>>>>,
-    products: Vec<Weak<RefCell<Substrate<ReactionType, StateType, InformationType>>>>,
+    phantom_s: PhantomData<StateType>,
+    phantom_t: PhantomData<InformationType>,
+    educts: Vec<usize>,
+    products: Vec<usize>,
     reaction: ReactionType,
 }
 
@@ -323,8 +397,8 @@ impl<
     ///
     /// # Parameters
     ///
-    /// * `educts` - the educt [`Substrate`]s for the [`Reaction`]
-    /// * `products` - the product [`Substrate`]s for the [`Reaction`]
+    /// * `educts` - the ids of the educt [`Substrate`]s for the [`Reaction`]
+    /// * `products` - the ids of the product [`Substrate`]s for the [`Reaction`]
     /// * `reaction` - the [`Reaction`] to catalyse
     ///
     /// # Panics
@@ -334,11 +408,7 @@ impl<
     ///
     /// [`Substrate`]: ./struct.Substrate.html
     /// [`Reaction`]: ../chemistry/struct.Reaction.html
-    pub fn new(
-        educts: Vec<Weak<RefCell<Substrate<ReactionType, StateType, InformationType>>>>,
-        products: Vec<Weak<RefCell<Substrate<ReactionType, StateType, InformationType>>>>,
-        reaction: ReactionType,
-    ) -> Self {
+    pub fn new(educts: Vec<usize>, products: Vec<usize>, reaction: ReactionType) -> Self {
         assert_eq!(
             educts.len(),
             reaction.get_educt_number(),
@@ -351,93 +421,94 @@ impl<
             "The number of required products for reaction {:?} is {}, but {} products were supplied.",
             reaction, reaction.get_product_number(), products.len());
         CatalyticCentre {
+            phantom_s: PhantomData,
+            phantom_t: PhantomData,
             educts,
             products,
             reaction,
         }
     }
 
-    /// Calculates the values of the products after performing the
-    /// [`Reaction`] specific for this catalytic centre.
+    /// Catalyses the [`Reaction`] specific for this catalytic centre.
     ///
     /// # Parameters
     ///
+    /// * `substrates` - all [`Substrate`]s of the [`Organism`]
     /// * `time_of_catalysis` - the timepoint at which the catalysis happens
-    /// as [`Iteration`](crate::evolution::helper::Iteration)
+    ///   as [`Iteration`](crate::evolution::helper::Iteration)
     ///
+    /// [`Organism`]: ../population/struct.Organism.html
     /// [`Reaction`]: ../chemistry/struct.Reaction.html
-    fn calculate_product_values(&self, time_of_catalysis: Iteration) -> Vec<InformationType> {
-        // TODO: refactor this ugly code
-        let strong: Vec<Rc<RefCell<Substrate<ReactionType, StateType, InformationType>>>> = self.educts.iter()
-            // This unwrap must succeed as the containing structure will always be dropped first
-            // and no substrate references are leaked.
-            .map(|weak| weak.upgrade().unwrap())
-            .collect();
-        let mut educts: Vec<RefMut<Substrate<ReactionType, StateType, InformationType>>> =
-            strong.iter().map(|sub| sub.borrow_mut()).collect();
-        let educts: Vec<&InformationType> = educts
-            .iter_mut()
-            .map(|sub| sub.value(time_of_catalysis))
-            .collect();
-        self.reaction.react(&educts, time_of_catalysis)
+    /// [`Substrate`]: ./struct.Substrate.html
+    pub fn catalyse(
+        &self,
+        substrates: &mut [Substrate<ReactionType, StateType, InformationType>],
+        time_of_catalysis: Iteration,
+    ) {
+        let educts = substrate_values(&self.educts, substrates, time_of_catalysis);
+        let product_values = self.reaction.react(&educts, time_of_catalysis);
+        for (product, value) in self.products.iter().zip(product_values) {
+            substrates[*product].set_value(value);
+        }
     }
 
-    /// Catalyses the [`Reaction`] specific for this catalytic centre.
+    /// Returns the ids of the educt [`Substrate`]s of the [`Reaction`].
     ///
-    /// # Parameters
+    /// [`Reaction`]: ../chemistry/struct.Reaction.html
+    /// [`Substrate`]: ./struct.Substrate.html
+    pub fn educts(&self) -> &[usize] {
+        &self.educts
+    }
+
+    /// Returns the ids of the product [`Substrate`]s of the [`Reaction`].
     ///
-    /// * `time_of_catalysis` - the timepoint at which the catalysis happens
-    /// as [`Iteration`](crate::evolution::helper::Iteration)
+    /// [`Reaction`]: ../chemistry/struct.Reaction.html
+    /// [`Substrate`]: ./struct.Substrate.html
+    pub fn products(&self) -> &[usize] {
+        &self.products
+    }
+
+    /// Returns the [`Reaction`] the `CatalyticCentre` catalyses.
     ///
     /// [`Reaction`]: ../chemistry/struct.Reaction.html
-    pub fn catalyse(&self, time_of_catalysis: Iteration) {
-        let mut product_values = self.calculate_product_values(time_of_catalysis);
-        for product in &self.products {
-            // TODO: maybe switch to VecDeque and use pop_first()
-            // This unwrap must succeed as the containing structure will always be dropped first
-            // and no substrate references are leaked.
-            product
-                .upgrade()
-                .unwrap()
-                .borrow_mut()
-                .set_value(product_values.remove(0));
-        }
+    pub fn reaction(&self) -> &ReactionType {
+        &self.reaction
     }
 
-    /// Returns all receptors that detect the product [`Substrate`]s
+    /// Returns the ids of all receptors that detect the product [`Substrate`]s
     /// of the catalytic centre.
     ///
+    /// # Parameters
+    ///
+    /// * `substrates` - all [`Substrate`]s of the [`Organism`]
+    ///
+    /// [`Organism`]: ../population/struct.Organism.html
     /// [`Substrate`]: ./struct.Substrate.html
     pub fn cascading_receptors(
         &self,
-    ) -> Vec<Rc<Receptor<ReactionType, StateType, InformationType>>> {
-        // This unwrap must succeed as the containing structure will always be dropped first
-        // and no substrate references are leaked.
-        self.products
-            .iter()
-            .flat_map(substrate_reference_to_receptors)
-            .collect()
+        substrates: &[Substrate<ReactionType, StateType, InformationType>],
+    ) -> Vec<usize> {
+        receptors_of(&self.products, substrates)
     }
 
     /// Returns all input feedback associations and values.
     ///
     /// # Parameters
     ///
+    /// * `substrates` - all [`Substrate`]s of the [`Organism`]
     /// * `time` - the timepoint at which the [`Substrate`] values are requested
-    /// as [`Iteration`](crate::evolution::helper::Iteration)
+    ///   as [`Iteration`](crate::evolution::helper::Iteration)
+    ///
+    /// [`Organism`]: ../population/struct.Organism.html
+    /// [`Substrate`]: ./struct.Substrate.html
     pub fn input_feedback_associations(
         &self,
+        substrates: &mut [Substrate<ReactionType, StateType, InformationType>],
         time: Iteration,
     ) -> Vec<(Vec<usize>, InformationType)> {
         self.products
             .iter()
-            .map(|product| {
-                (*product.upgrade().unwrap())
-                    .borrow_mut()
-                    .get_input_feedback_associations(time)
-            })
-            .filter(Option::is_some)
-            .map(Option::unwrap)
+            .filter_map(|product| substrates[*product].get_input_feedback_associations(time))
             .collect()
     }
 
@@ -445,21 +516,20 @@ impl<
     ///
     /// # Parameters
     ///
+    /// * `substrates` - all [`Substrate`]s of the [`Organism`]
     /// * `time` - the timepoint at which the [`Substrate`] values are requested
-    /// as [`Iteration`](crate::evolution::helper::Iteration)
+    ///   as [`Iteration`](crate::evolution::helper::Iteration)
+    ///
+    /// [`Organism`]: ../population/struct.Organism.html
+    /// [`Substrate`]: ./struct.Substrate.html
     pub fn output_feedback_associations(
         &self,
+        substrates: &mut [Substrate<ReactionType, StateType, InformationType>],
         time: Iteration,
     ) -> Vec<(Vec<usize>, InformationType)> {
         self.products
             .iter()
-            .map(|product| {
-                (*product.upgrade().unwrap())
-                    .borrow_mut()
-                    .get_output_feedback_associations(time)
-            })
-            .filter(Option::is_some)
-            .map(Option::unwrap)
+            .filter_map(|product| substrates[*product].get_output_feedback_associations(time))
             .collect()
     }
 
@@ -468,64 +538,80 @@ impl<
     ///
     /// # Parameters
     ///
+    /// * `substrates` - all [`Substrate`]s of the [`Organism`]
     /// * `time` - the timepoint at which the [`Substrate`] values are requested
-    /// as [`Iteration`](crate::evolution::helper::Iteration)
-    pub fn output_finish_substrate(&self, time: Iteration) -> Option<InformationType> {
-        for product in &self.products {
-            let finish_value = (*product.upgrade().unwrap())
-                .borrow_mut()
-                .get_output_finish_value(time);
-            if finish_value.is_some() {
-                return finish_value;
-            }
-        }
-        None
+    ///   as [`Iteration`](crate::evolution::helper::Iteration)
+    ///
+    /// [`Organism`]: ../population/struct.Organism.html
+    /// [`Substrate`]: ./struct.Substrate.html
+    pub fn output_finish_substrate(
+        &self,
+        substrates: &mut [Substrate<ReactionType, StateType, InformationType>],
+        time: Iteration,
+    ) -> Option<InformationType> {
+        self.products
+            .iter()
+            .find_map(|product| substrates[*product].get_output_finish_value(time))
     }
 }
 
 impl<R: Reaction<T>, S: State<T>, T: Information> PartialEq for CatalyticCentre<R, S, T> {
     fn eq(&self, other: &Self) -> bool {
-        if self.reaction != other.reaction {
-            return false;
-        }
-        if self.educts.len() != other.educts.len() {
-            return false;
-        }
-        if self.products.len() != other.products.len() {
-            return false;
-        }
-        // Compare the raw pointers.
-        let educt_pairs_not_matching = self
-            .educts
-            .iter()
-            .zip(other.educts.iter())
-            .any(|(a, b)| !a.ptr_eq(b));
-        if educt_pairs_not_matching {
-            return false;
-        }
-        self.products
-            .iter()
-            .zip(other.products.iter())
-            .all(|(a, b)| a.ptr_eq(b))
+        self.reaction == other.reaction
+            && self.educts == other.educts
+            && self.products == other.products
+    }
+}
+
+/// Returns the values of the specified [`Substrate`]s at the specified timepoint.
+///
+/// # Parameters
+///
+/// * `ids` - the ids of the requested [`Substrate`]s
+/// * `substrates` - all [`Substrate`]s of the [`Organism`]
+/// * `time` - the timepoint at which the [`Substrate`] values are requested
+///
+/// [`Organism`]: ../population/struct.Organism.html
+/// [`Substrate`]: ./struct.Substrate.html
+fn substrate_values<
+    'a,
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+>(
+    ids: &[usize],
+    substrates: &'a mut [Substrate<ReactionType, StateType, InformationType>],
+    time: Iteration,
+) -> Vec<&'a InformationType> {
+    // Advance all substrates in time first, since the same substrate may be requested
+    // several times.
+    for id in ids {
+        substrates[*id].value(time);
     }
+    let substrates: &'a [Substrate<ReactionType, StateType, InformationType>] = substrates;
+    ids.iter().map(|id| substrates[*id].last_value()).collect()
 }
 
-/// Returns the receptors of a weak substrate reference.
+/// Returns the ids of the receptors detecting the specified [`Substrate`]s.
 ///
 /// # Parameters
 ///
-/// * `substrate` - the substrate reference
-fn substrate_reference_to_receptors<
+/// * `ids` - the ids of the [`Substrate`]s
+/// * `substrates` - all [`Substrate`]s of the [`Organism`]
+///
+/// [`Organism`]: ../population/struct.Organism.html
+/// [`Substrate`]: ./struct.Substrate.html
+fn receptors_of<
     ReactionType: Reaction<InformationType>,
     StateType: State<InformationType>,
     InformationType: Information,
 >(
-    substrate: &Weak<RefCell<Substrate<ReactionType, StateType, InformationType>>>,
-) -> Vec<Rc<Receptor<ReactionType, StateType, InformationType>>> {
-    let upgrade: Rc<RefCell<Substrate<ReactionType, StateType, InformationType>>> =
-        substrate.upgrade().unwrap();
-    let s: &RefCell<Substrate<ReactionType, StateType, InformationType>> = upgrade.borrow();
-    return s.borrow().receptors();
+    ids: &[usize],
+    substrates: &[Substrate<ReactionType, StateType, InformationType>],
+) -> Vec<usize> {
+    ids.iter()
+        .flat_map(|id| substrates[*id].receptors().iter().copied())
+        .collect()
 }
 
 mod sensor;
@@ -1,30 +1,29 @@
 //! The `sensor` module contains the executive part of the evolutionary network
 //! that connects to external information.
 
-use std::{
-    cell::RefCell,
-    marker::PhantomData,
-    rc::{Rc, Weak}, collections::{HashMap},
-};
+use std::{collections::HashMap, marker::PhantomData};
 
 use serde::{de::DeserializeOwned, Serialize};
 
 use crate::evolution::{
-    chemistry::{Information, Input, Reaction, State, Output},
+    chemistry::{Information, Input, Output, Reaction, State},
     helper::Iteration,
 };
 
-use super::{substrate_reference_to_receptors, Receptor, Substrate};
+use super::{receptors_of, Substrate};
 
 /// An `InputSensor` registers and transforms inputs into internal [`Substrate`] information
 /// for further processing. It can also operate on the input via feedback [`Substrate`]s.
+/// The input [`Substrate`]s are referenced by their ids.
 #[derive(Debug, Clone)]
 pub struct InputSensor<ReactionType, StateType, InformationType, InputElementType, InputSensorType>
 {
+    phantom_r: PhantomData<ReactionType>,
+    phantom_s: PhantomData<StateType>,
+    phantom_t: PhantomData<InformationType>,
     phantom_i: PhantomData<InputElementType>,
     input: InputSensorType,
-    input_substrates:
-        Vec<Option<Weak<RefCell<Substrate<ReactionType, StateType, InformationType>>>>>,
+    input_substrates: Vec<Option<usize>>,
 }
 
 impl<
@@ -47,25 +46,20 @@ impl<
     /// # Parameters
     ///
     /// * `input` - the genetic input sensor definition
-    /// * `input_substrates` - the input information processing [`Substrate`]s
-    pub fn new(
-        input: InputSensorType,
-        input_substrates: Vec<
-            Option<Weak<RefCell<Substrate<ReactionType, StateType, InformationType>>>>,
-        >,
-    ) -> Self
-    {
+    /// * `input_substrates` - the ids of the input information processing [`Substrate`]s
+    pub fn new(input: InputSensorType, input_substrates: Vec<Option<usize>>) -> Self {
         InputSensor {
+            phantom_r: PhantomData,
+            phantom_s: PhantomData,
+            phantom_t: PhantomData,
             phantom_i: PhantomData,
             input,
             input_substrates,
         }
     }
 
-    /// Returns the input substrates.
-    pub fn input_substrates(
-        &self,
-    ) -> &Vec<Option<Weak<RefCell<Substrate<ReactionType, StateType, InformationType>>>>> {
+    /// Returns the ids of the input substrates.
+    pub fn input_substrates(&self) -> &Vec<Option<usize>> {
         &self.input_substrates
     }
 
@@ -79,9 +73,16 @@ impl<
     /// # Parameters
     ///
     /// * `input` - the current input
-    pub fn set_input(&mut self, input: InputElementType) {
+    /// * `substrates` - all [`Substrate`]s of the [`Organism`]
+    ///
+    /// [`Organism`]: ../population/struct.Organism.html
+    pub fn set_input(
+        &mut self,
+        input: InputElementType,
+        substrates: &mut [Substrate<ReactionType, StateType, InformationType>],
+    ) {
         let input_representation = self.input.set_input(input);
-        self.set_input_substrates(input_representation);
+        self.set_input_substrates(input_representation, substrates);
     }
 
     /// Updates the input [´Substrate´]s based on the supplied information.
@@ -89,11 +90,18 @@ impl<
     /// # Parameters
     /// 
     /// * ´input_representation´ - the internal information representation to update
+    /// * `substrates` - all [`Substrate`]s of the [`Organism`]
     /// 
     /// # Panics
     /// 
     /// If the specified representation is not of the same length as the internal input substrate vector.
-    fn set_input_substrates(&mut self, input_representation: Vec<InformationType>) {
+    ///
+    /// [`Organism`]: ../population/struct.Organism.html
+    fn set_input_substrates(
+        &mut self,
+        input_representation: Vec<InformationType>,
+        substrates: &mut [Substrate<ReactionType, StateType, InformationType>],
+    ) {
         if input_representation.len() != self.input_substrates.len() {
             panic!("{} input substrates were specified, but the input was transformed into {} substrates: {:?}", 
                 self.input_substrates.len(), 
@@ -101,53 +109,66 @@ impl<
                 input_representation
         );
         }
-        for (i, info) in input_representation.into_iter().enumerate() {
-            if let Some(substrate) = (&self.input_substrates[i]).as_ref() {
-                substrate.upgrade().unwrap().borrow_mut().set_value(info);
+        for (substrate, info) in self.input_substrates.iter().zip(input_representation) {
+            if let Some(substrate) = substrate {
+                substrates[*substrate].set_value(info);
             }
         }
     }
 
     /// Checks all feedback [`Substrate`]s for changens and passes the changes on to the underlying input sensor representation.
-    /// Updates the input [`Substrate`]s if necessary and returns if changes were detected.
+    /// Updates the input [`Substrate`]s if necessary and returns the ids of all [`Receptor`]s
+    /// affected by the changes.
     /// 
     /// # Parameters
     /// 
     /// * `changes` - the changed input [`Substrate`] values
-    pub fn feedback_update(&mut self, changes: HashMap<usize, InformationType>) -> Vec<Rc<Receptor<ReactionType, StateType, InformationType>>> {
+    /// * `substrates` - all [`Substrate`]s of the [`Organism`]
+    ///
+    /// [`Organism`]: ../population/struct.Organism.html
+    /// [`Receptor`]: ./struct.Receptor.html
+    pub fn feedback_update(
+        &mut self,
+        changes: HashMap<usize, InformationType>,
+        substrates: &mut [Substrate<ReactionType, StateType, InformationType>],
+    ) -> Vec<usize> {
         if !changes.is_empty() {
             if let Some(changed_input_substrates) = self.input.handle_feedback_substrate_changes(changes) {
-                self.set_input_substrates(changed_input_substrates);
-                return self.cascading_receptors();
+                self.set_input_substrates(changed_input_substrates, substrates);
+                return self.cascading_receptors(substrates);
             }
         }
         Vec::new()
     }
 
-    /// Returns all [`Receptor`]s affected by input changes.
+    /// Returns the ids of all [`Receptor`]s affected by input changes.
+    ///
+    /// # Parameters
+    ///
+    /// * `substrates` - all [`Substrate`]s of the [`Organism`]
+    ///
+    /// [`Organism`]: ../population/struct.Organism.html
+    /// [`Receptor`]: ./struct.Receptor.html
     pub fn cascading_receptors(
         &self,
-    ) -> Vec<Rc<Receptor<ReactionType, StateType, InformationType>>> {
-        // This unwrap must succeed as the containing structure will always be dropped first
-        // and no substrate references are leaked.
-        self.input_substrates
-            .iter()
-            .filter(|i| i.is_some())
-            .map(|i| i.as_ref())
-            .map(|i| i.unwrap())
-            .flat_map(substrate_reference_to_receptors)
-            .collect()
+        substrates: &[Substrate<ReactionType, StateType, InformationType>],
+    ) -> Vec<usize> {
+        let input_substrates: Vec<usize> = self.input_substrates.iter().flatten().copied().collect();
+        receptors_of(&input_substrates, substrates)
     }
 }
 
 /// An `OutputSensor` registers and transforms internal [`Substrate`] information into output elements.
+/// The output [`Substrate`]s are referenced by their ids.
 #[derive(Debug, Clone)]
 pub struct OutputSensor<ReactionType, StateType, InformationType, OutputElementType, OutputSensorType>
 {
+    phantom_r: PhantomData<ReactionType>,
+    phantom_s: PhantomData<StateType>,
+    phantom_t: PhantomData<InformationType>,
     phantom_i: PhantomData<OutputElementType>,
     output: OutputSensorType,
-    output_substrates:
-        Vec<Option<Weak<RefCell<Substrate<ReactionType, StateType, InformationType>>>>>,
+    output_substrates: Vec<Option<usize>>,
 }
 
 impl<
@@ -170,15 +191,12 @@ impl<
     /// # Parameters
     ///
     /// * `input` - the genetic input sensor definition
-    /// * `input_substrates` - the input information processing [`Substrate`]s
-    pub fn new(
-        output: OutputSensorType,
-        output_substrates: Vec<
-            Option<Weak<RefCell<Substrate<ReactionType, StateType, InformationType>>>>,
-        >,
-    ) -> Self
-    {
+    /// * `input_substrates` - the ids of the input information processing [`Substrate`]s
+    pub fn new(output: OutputSensorType, output_substrates: Vec<Option<usize>>) -> Self {
         OutputSensor {
+            phantom_r: PhantomData,
+            phantom_s: PhantomData,
+            phantom_t: PhantomData,
             phantom_i: PhantomData,
             output,
             output_substrates,
@@ -194,24 +212,36 @@ impl<
     /// 
     /// # Parameters
     ///
+    /// * `substrates` - all [`Substrate`]s of the [`Organism`]
     /// * `time` - the time of the conversion
-    pub fn output_as_element(&mut self, time: Iteration) -> OutputElementType {
-        self.output.get_output(substrates_as_information(&self.output_substrates, time))
+    ///
+    /// [`Organism`]: ../population/struct.Organism.html
+    pub fn output_as_element(
+        &mut self,
+        substrates: &mut [Substrate<ReactionType, StateType, InformationType>],
+        time: Iteration,
+    ) -> OutputElementType {
+        self.output.get_output(substrates_as_information(&self.output_substrates, substrates, time))
     }
 
     /// Returns the output substrates at the specified timepoint as owned.
     /// 
     /// # Parameters
     ///
+    /// * `substrates` - all [`Substrate`]s of the [`Organism`]
     /// * `time` - the time of the query
-    pub fn output_substrates_as_owned(&self, time: Iteration) -> Vec<Option<InformationType>> {
-        substrates_as_information(&self.output_substrates, time)
+    ///
+    /// [`Organism`]: ../population/struct.Organism.html
+    pub fn output_substrates_as_owned(
+        &self,
+        substrates: &mut [Substrate<ReactionType, StateType, InformationType>],
+        time: Iteration,
+    ) -> Vec<Option<InformationType>> {
+        substrates_as_information(&self.output_substrates, substrates, time)
     }
 
-    /// Returns the output substrates.
-    pub fn output_substrates(
-        &self,
-    ) -> &Vec<Option<Weak<RefCell<Substrate<ReactionType, StateType, InformationType>>>>> {
+    /// Returns the ids of the output substrates.
+    pub fn output_substrates(&self) -> &Vec<Option<usize>> {
         &self.output_substrates
     }
 
@@ -233,11 +263,18 @@ impl<
     /// # Parameters
     /// 
     /// * ´output_representation´ - the internal information representation to update
+    /// * `substrates` - all [`Substrate`]s of the [`Organism`]
     /// 
     /// # Panics
     /// 
     /// If the specified representation is not of the same length as the internal output substrate vector.
-    fn set_output_substrates(&mut self, output_representation: Vec<InformationType>) {
+    ///
+    /// [`Organism`]: ../population/struct.Organism.html
+    fn set_output_substrates(
+        &mut self,
+        output_representation: Vec<InformationType>,
+        substrates: &mut [Substrate<ReactionType, StateType, InformationType>],
+    ) {
         if output_representation.len() != self.output_substrates.len() {
             panic!("{} output substrates were specified, but the output was transformed into {} substrates: {:?}", 
                 self.output_substrates.len(), 
@@ -245,85 +282,82 @@ impl<
                 output_representation
         );
         }
-        for (i, info) in output_representation.into_iter().enumerate() {
-            if let Some(substrate) = (&self.output_substrates[i]).as_ref() {
-                substrate.upgrade().unwrap().borrow_mut().set_value(info);
+        for (substrate, info) in self.output_substrates.iter().zip(output_representation) {
+            if let Some(substrate) = substrate {
+                substrates[*substrate].set_value(info);
             }
         }
     }
 
     /// Checks all feedback [`Substrate`]s for changens and passes the changes on to the underlying output sensor representation.
-    /// Updates the output [`Substrate`]s if necessary and returns if changes were detected.
+    /// Updates the output [`Substrate`]s if necessary and returns the ids of all [`Receptor`]s
+    /// affected by the changes.
     /// 
     /// # Parameters
     /// 
     /// * `changes` - the changed output [`Substrate`] values
+    /// * `substrates` - all [`Substrate`]s of the [`Organism`]
     /// * `time` - the timepoint of the update
-    pub fn feedback_update(&mut self, changes: HashMap<usize, InformationType>, time: Iteration) -> Vec<Rc<Receptor<ReactionType, StateType, InformationType>>> {
+    ///
+    /// [`Organism`]: ../population/struct.Organism.html
+    /// [`Receptor`]: ./struct.Receptor.html
+    pub fn feedback_update(
+        &mut self,
+        changes: HashMap<usize, InformationType>,
+        substrates: &mut [Substrate<ReactionType, StateType, InformationType>],
+        time: Iteration,
+    ) -> Vec<usize> {
         if !changes.is_empty() {
-            if let Some(changed_input_substrates) = self.output.handle_feedback_substrate_changes(changes, self.output_substrates_as_owned(time)) {
-                self.set_output_substrates(changed_input_substrates);
-                return self.cascading_receptors();
+            let current_output = self.output_substrates_as_owned(substrates, time);
+            if let Some(changed_input_substrates) = self.output.handle_feedback_substrate_changes(changes, current_output) {
+                self.set_output_substrates(changed_input_substrates, substrates);
+                return self.cascading_receptors(substrates);
             }
         }
         Vec::new()
     }
 
-    /// Returns all [`Receptor`]s affected by output changes.
+    /// Returns the ids of all [`Receptor`]s affected by output changes.
+    ///
+    /// # Parameters
+    ///
+    /// * `substrates` - all [`Substrate`]s of the [`Organism`]
+    ///
+    /// [`Organism`]: ../population/struct.Organism.html
+    /// [`Receptor`]: ./struct.Receptor.html
     pub fn cascading_receptors(
         &self,
-    ) -> Vec<Rc<Receptor<ReactionType, StateType, InformationType>>> {
-        // This unwrap must succeed as the containing structure will always be dropped first
-        // and no substrate references are leaked.
-        self.output_substrates
-            .iter()
-            .filter(|i| i.is_some())
-            .map(|i| i.as_ref())
-            .map(|i| i.unwrap())
-            .flat_map(substrate_reference_to_receptors)
-            .collect()
+        substrates: &[Substrate<ReactionType, StateType, InformationType>],
+    ) -> Vec<usize> {
+        let output_substrates: Vec<usize> = self.output_substrates.iter().flatten().copied().collect();
+        receptors_of(&output_substrates, substrates)
     }
 }
 
-/// Converts a vector of references to underlying [`Information`]s.
+/// Converts a vector of [`Substrate`] ids to the underlying [`Information`]s.
 ///
 /// # Parameters
 ///
-/// * `substrates` - the [`Substrate`] references
+/// * `ids` - the optional [`Substrate`] ids
+/// * `substrates` - all [`Substrate`]s of the [`Organism`]
 /// * `time` - the time of the conversion
+///
+/// [`Organism`]: ../population/struct.Organism.html
 fn substrates_as_information<
     ReactionType: Reaction<InformationType>,
     StateType: State<InformationType>,
     InformationType: Information,
 >(
-    substrates: &Vec<Option<Weak<RefCell<Substrate<ReactionType, StateType, InformationType>>>>>,
+    ids: &[Option<usize>],
+    substrates: &mut [Substrate<ReactionType, StateType, InformationType>],
     time: Iteration,
 ) -> Vec<Option<InformationType>> {
-    substrates
-        .iter()
-        .map(|substrate| substrate.as_ref())
-        .map(|substrate| substrate.map(|s|substrate_reference_to_information(s, time)))
+    ids.iter()
+        .map(|id| id.map(|id| substrates[id].value(time).clone()))
         .collect()
 }
 
-/// Converts a reference to a the underlying [`Information`].
-///
-/// # Parameters
-///
-/// * `substrate_reference` - the [`Substrate`] reference
-/// * `time` - the time of the conversion
-fn substrate_reference_to_information<
-    ReactionType: Reaction<InformationType>,
-    StateType: State<InformationType>,
-    InformationType: Information,
->(
-    substrate_reference: &Weak<RefCell<Substrate<ReactionType, StateType, InformationType>>>,
-    time: Iteration,
-) -> InformationType {
-    (*(substrate_reference.upgrade().unwrap())).borrow_mut().value(time).clone()
-}
-
 #[cfg(test)]
 mod test_input;
 #[cfg(test)]
-mod test_output;
\ No newline at end of file
+mod test_output;
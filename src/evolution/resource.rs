@@ -111,6 +111,32 @@ impl Resource {
             panic!("{} resources cannot be repatriated.", amount);
         }
     }
+
+    /// Adjusts the total amount of `Resource`s. Positive amounts are added to the available
+    /// `Resource`s, while negative amounts are removed from the available `Resource`s first
+    /// and from the recycling `Resource`s afterwards. The amount of `Resource`s never drops
+    /// below zero.
+    ///
+    /// # Parameters
+    ///
+    /// * `amount` - the amount of `Resource`s to add or, if negative, to remove
+    ///
+    /// # Panics
+    ///
+    /// If the specified `amount` is not a valid number.
+    pub fn adjust(&mut self, amount: f64) {
+        if amount.is_nan() {
+            panic!("Resources cannot be adjusted by {}.", amount);
+        }
+        if amount >= 0.0 {
+            self.available += amount;
+        } else {
+            let removed_from_available = self.available.min(-amount);
+            self.available -= removed_from_available;
+            let removed_from_recycling = self.recycling.min(-amount - removed_from_available);
+            self.recycling -= removed_from_recycling;
+        }
+    }
 }
 
 impl Default for Resource {
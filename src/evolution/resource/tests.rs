@@ -258,4 +258,27 @@ fn test_recycle_resources() {
         assert!(ulps_eq!(resource.recycling(), 0.0));
         
     }
-}
\ No newline at end of file
+}
+#[test]
+#[should_panic]
+/// Tests if the function `adjust` correctly panics on invalid values.
+fn test_adjust_nan() {
+    let mut resource = Resource::new(4.0, 1.0);
+    resource.adjust(f64::NAN);
+}
+
+#[test]
+/// Tests if the function `adjust` works correctly.
+fn test_adjust() {
+    let mut resource = Resource::new(4.0, 1.0);
+    resource.claim_resources(3.0);
+    resource.repatriate_resources(2.0);
+    resource.adjust(1.5);
+    assert!(ulps_eq!(resource.available(), 2.5));
+    assert!(ulps_eq!(resource.recycling(), 2.0));
+    resource.adjust(-3.0);
+    assert!(ulps_eq!(resource.available(), 0.0));
+    assert!(ulps_eq!(resource.recycling(), 1.5));
+    resource.adjust(-10.0);
+    assert!(ulps_eq!(resource.total(), 0.0));
+}
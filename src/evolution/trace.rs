@@ -0,0 +1,440 @@
+//! The `trace` module contains the opt-in recording of the execution of an [`Organism`].
+//! A trace captures every [`Iteration`] of [`Organism::live_traced`] including the scheduled,
+//! detecting and catalysing [`Receptor`]s, the resulting [`Substrate`] changes, the
+//! feedback updates of the sensors and the reason the run ended.
+//!
+//! [`Substrate`]s and [`Receptor`]s are identified by their ids, which are their positions
+//! in [`Organism::substrates`] and [`Organism::receptors`].
+//!
+//! [`Iteration`]: ../helper/struct.Iteration.html
+//! [`Organism`]: ../population/struct.Organism.html
+//! [`Organism::live_traced`]: ../population/struct.Organism.html#method.live_traced
+//! [`Organism::receptors`]: ../population/struct.Organism.html#method.receptors
+//! [`Organism::substrates`]: ../population/struct.Organism.html#method.substrates
+//! [`Receptor`]: ../protein/struct.Receptor.html
+//! [`Substrate`]: ../protein/struct.Substrate.html
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::chemistry::{Information, Reaction, State};
+use super::helper::Iteration;
+use super::population::TerminationReason;
+use super::protein::{Receptor, Substrate};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// A `SubstrateChange` records the value of a [`Substrate`] before and after it was
+/// overwritten as product of a catalysis.
+///
+/// [`Substrate`]: ../protein/struct.Substrate.html
+pub struct SubstrateChange<InformationType> {
+    substrate: usize,
+    before: InformationType,
+    after: InformationType,
+}
+
+impl<InformationType> SubstrateChange<InformationType> {
+    /// Returns the id of the changed [`Substrate`].
+    ///
+    /// [`Substrate`]: ../protein/struct.Substrate.html
+    pub fn substrate(&self) -> usize {
+        self.substrate
+    }
+
+    /// Returns the value of the [`Substrate`] before the catalysis.
+    ///
+    /// [`Substrate`]: ../protein/struct.Substrate.html
+    pub fn before(&self) -> &InformationType {
+        &self.before
+    }
+
+    /// Returns the value of the [`Substrate`] after the catalysis.
+    ///
+    /// [`Substrate`]: ../protein/struct.Substrate.html
+    pub fn after(&self) -> &InformationType {
+        &self.after
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// A `CatalysisTrace` records a single catalysis of a [`Receptor`].
+///
+/// [`Receptor`]: ../protein/struct.Receptor.html
+pub struct CatalysisTrace<InformationType> {
+    receptor: usize,
+    products: Vec<SubstrateChange<InformationType>>,
+}
+
+impl<InformationType> CatalysisTrace<InformationType> {
+    /// Returns the id of the catalysing [`Receptor`].
+    ///
+    /// [`Receptor`]: ../protein/struct.Receptor.html
+    pub fn receptor(&self) -> usize {
+        self.receptor
+    }
+
+    /// Returns the changes of the product [`Substrate`]s.
+    ///
+    /// [`Substrate`]: ../protein/struct.Substrate.html
+    pub fn products(&self) -> &[SubstrateChange<InformationType>] {
+        &self.products
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// A `FeedbackUpdate` records a value passed to a feedback association of a sensor.
+pub struct FeedbackUpdate<InformationType> {
+    association: usize,
+    value: InformationType,
+}
+
+impl<InformationType> FeedbackUpdate<InformationType> {
+    /// Returns the index of the feedback association.
+    pub fn association(&self) -> usize {
+        self.association
+    }
+
+    /// Returns the value passed to the feedback association.
+    pub fn value(&self) -> &InformationType {
+        &self.value
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// An `IterationTrace` records everything that happened during a single [`Iteration`].
+///
+/// [`Iteration`]: ../helper/struct.Iteration.html
+pub struct IterationTrace<InformationType> {
+    iteration: Iteration,
+    scheduled: Vec<usize>,
+    detected: Vec<usize>,
+    catalyses: Vec<CatalysisTrace<InformationType>>,
+    input_feedback: Vec<FeedbackUpdate<InformationType>>,
+    output_feedback: Vec<FeedbackUpdate<InformationType>>,
+}
+
+impl<InformationType> IterationTrace<InformationType> {
+    /// Returns the [`Iteration`] the receptors were executed at.
+    ///
+    /// [`Iteration`]: ../helper/struct.Iteration.html
+    pub fn iteration(&self) -> Iteration {
+        self.iteration
+    }
+
+    /// Returns the ids of all [`Receptor`]s scheduled for the [`Iteration`].
+    ///
+    /// [`Iteration`]: ../helper/struct.Iteration.html
+    /// [`Receptor`]: ../protein/struct.Receptor.html
+    pub fn scheduled(&self) -> &[usize] {
+        &self.scheduled
+    }
+
+    /// Returns the ids of all scheduled [`Receptor`]s that detected their state.
+    ///
+    /// [`Receptor`]: ../protein/struct.Receptor.html
+    pub fn detected(&self) -> &[usize] {
+        &self.detected
+    }
+
+    /// Returns the catalyses in the order they were performed.
+    pub fn catalyses(&self) -> &[CatalysisTrace<InformationType>] {
+        &self.catalyses
+    }
+
+    /// Returns the updates of the input sensor's feedback associations.
+    pub fn input_feedback(&self) -> &[FeedbackUpdate<InformationType>] {
+        &self.input_feedback
+    }
+
+    /// Returns the updates of the output sensor's feedback associations.
+    pub fn output_feedback(&self) -> &[FeedbackUpdate<InformationType>] {
+        &self.output_feedback
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// A `TraceEvent` is a single line of an exported [`ExecutionTrace`].
+///
+/// [`ExecutionTrace`]: ./struct.ExecutionTrace.html
+pub enum TraceEvent<InformationType> {
+    /// An [`Iteration`] was executed.
+    ///
+    /// [`Iteration`]: ../helper/struct.Iteration.html
+    Iteration(IterationTrace<InformationType>),
+    /// The run ended for the specified reason.
+    Termination { reason: TerminationReason },
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+/// An `ExecutionTrace` records a single run of an [`Organism`].
+///
+/// [`Organism`]: ../population/struct.Organism.html
+pub struct ExecutionTrace<InformationType> {
+    iterations: Vec<IterationTrace<InformationType>>,
+    termination: TerminationReason,
+}
+
+impl<InformationType: Clone + Serialize> ExecutionTrace<InformationType> {
+    /// Returns the traces of all executed [`Iteration`]s.
+    ///
+    /// [`Iteration`]: ../helper/struct.Iteration.html
+    pub fn iterations(&self) -> &[IterationTrace<InformationType>] {
+        &self.iterations
+    }
+
+    /// Returns the reason the run ended.
+    pub fn termination(&self) -> TerminationReason {
+        self.termination
+    }
+
+    /// Returns the trace as sequence of [`TraceEvent`]s, one per [`Iteration`] followed
+    /// by the termination.
+    ///
+    /// [`Iteration`]: ../helper/struct.Iteration.html
+    /// [`TraceEvent`]: ./enum.TraceEvent.html
+    pub fn events(&self) -> Vec<TraceEvent<InformationType>> {
+        self.iterations
+            .iter()
+            .cloned()
+            .map(TraceEvent::Iteration)
+            .chain(std::iter::once(TraceEvent::Termination {
+                reason: self.termination,
+            }))
+            .collect()
+    }
+
+    /// Writes the [`TraceEvent`]s as JSON lines to the specified writer.
+    /// An error will be returned if serialisation or writing failed.
+    ///
+    /// # Parameters
+    ///
+    /// * `writer` - the writer to write to
+    ///
+    /// [`TraceEvent`]: ./enum.TraceEvent.html
+    pub fn write_json_lines<W: Write>(&self, mut writer: W) -> Result<(), Box<dyn Error>> {
+        for event in self.events() {
+            serde_json::to_writer(&mut writer, &event)?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Writes the [`TraceEvent`]s as JSON lines to the specified file.
+    /// An error will be returned if serialisation or writing failed.
+    ///
+    /// # Parameters
+    ///
+    /// * `path_to_file` - the path of the JSON lines file
+    ///
+    /// [`TraceEvent`]: ./enum.TraceEvent.html
+    pub fn write_json_lines_to_file<P: AsRef<Path>>(
+        &self,
+        path_to_file: P,
+    ) -> Result<(), Box<dyn Error>> {
+        self.write_json_lines(BufWriter::new(File::create(path_to_file)?))
+    }
+}
+
+/// A `Recorder` observes the execution of an [`Organism`]. All methods do nothing by
+/// default, so the execution is not slowed down if no recording is required.
+///
+/// [`Organism`]: ../population/struct.Organism.html
+pub(crate) trait Recorder<ReactionType, StateType, InformationType> {
+    /// Called with the ids of all [`Receptor`]s scheduled at the start of an [`Iteration`].
+    ///
+    /// [`Iteration`]: ../helper/struct.Iteration.html
+    /// [`Receptor`]: ../protein/struct.Receptor.html
+    fn scheduled(&mut self, _iteration: Iteration, _receptors: &[usize]) {}
+
+    /// Called before a [`Receptor`] that detected its state catalyses.
+    ///
+    /// [`Receptor`]: ../protein/struct.Receptor.html
+    fn detected(
+        &mut self,
+        _id: usize,
+        _receptor: &Receptor<ReactionType, StateType, InformationType>,
+        _substrates: &[Substrate<ReactionType, StateType, InformationType>],
+    ) {
+    }
+
+    /// Called after a [`Receptor`] catalysed.
+    ///
+    /// [`Receptor`]: ../protein/struct.Receptor.html
+    fn catalysed(
+        &mut self,
+        _id: usize,
+        _receptor: &Receptor<ReactionType, StateType, InformationType>,
+        _substrates: &[Substrate<ReactionType, StateType, InformationType>],
+    ) {
+    }
+
+    /// Called with the feedback changes passed to the sensors at the end of an [`Iteration`].
+    ///
+    /// [`Iteration`]: ../helper/struct.Iteration.html
+    fn feedback(
+        &mut self,
+        _input_feedback: &HashMap<usize, InformationType>,
+        _output_feedback: &HashMap<usize, InformationType>,
+    ) {
+    }
+}
+
+impl<ReactionType, StateType, InformationType> Recorder<ReactionType, StateType, InformationType>
+    for ()
+{
+}
+
+/// Returns the current values of the product [`Substrate`]s of the specified [`Receptor`].
+///
+/// # Parameters
+///
+/// * `receptor` - the [`Receptor`] to read the products of
+/// * `substrates` - all [`Substrate`]s of the [`Organism`]
+///
+/// [`Organism`]: ../population/struct.Organism.html
+/// [`Receptor`]: ../protein/struct.Receptor.html
+/// [`Substrate`]: ../protein/struct.Substrate.html
+pub(crate) fn product_values<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+>(
+    receptor: &Receptor<ReactionType, StateType, InformationType>,
+    substrates: &[Substrate<ReactionType, StateType, InformationType>],
+) -> Vec<InformationType> {
+    receptor
+        .enzyme()
+        .products()
+        .iter()
+        .map(|product| substrates[*product].last_value().clone())
+        .collect()
+}
+
+/// A `TraceRecorder` is a [`Recorder`] building an [`ExecutionTrace`].
+///
+/// [`ExecutionTrace`]: ./struct.ExecutionTrace.html
+/// [`Recorder`]: ./trait.Recorder.html
+pub(crate) struct TraceRecorder<InformationType> {
+    iterations: Vec<IterationTrace<InformationType>>,
+    products_before: Vec<InformationType>,
+}
+
+impl<InformationType: Information> TraceRecorder<InformationType> {
+    /// Creates a new empty `TraceRecorder`.
+    pub(crate) fn new() -> Self {
+        TraceRecorder {
+            iterations: Vec::new(),
+            products_before: Vec::new(),
+        }
+    }
+
+    /// Finishes the recording and returns the [`ExecutionTrace`].
+    ///
+    /// # Parameters
+    ///
+    /// * `termination` - the reason the run ended
+    ///
+    /// [`ExecutionTrace`]: ./struct.ExecutionTrace.html
+    pub(crate) fn finish(self, termination: TerminationReason) -> ExecutionTrace<InformationType> {
+        ExecutionTrace {
+            iterations: self.iterations,
+            termination,
+        }
+    }
+
+    /// Returns the trace of the current [`Iteration`].
+    ///
+    /// # Panics
+    ///
+    /// If no [`Iteration`] was scheduled yet.
+    ///
+    /// [`Iteration`]: ../helper/struct.Iteration.html
+    fn current(&mut self) -> &mut IterationTrace<InformationType> {
+        self.iterations
+            .last_mut()
+            .expect("No iteration was scheduled yet.")
+    }
+}
+
+impl<
+        ReactionType: Reaction<InformationType>,
+        StateType: State<InformationType>,
+        InformationType: Information,
+    > Recorder<ReactionType, StateType, InformationType> for TraceRecorder<InformationType>
+{
+    fn scheduled(&mut self, iteration: Iteration, receptors: &[usize]) {
+        self.iterations.push(IterationTrace {
+            iteration,
+            scheduled: receptors.to_vec(),
+            detected: Vec::new(),
+            catalyses: Vec::new(),
+            input_feedback: Vec::new(),
+            output_feedback: Vec::new(),
+        });
+    }
+
+    fn detected(
+        &mut self,
+        id: usize,
+        receptor: &Receptor<ReactionType, StateType, InformationType>,
+        substrates: &[Substrate<ReactionType, StateType, InformationType>],
+    ) {
+        self.current().detected.push(id);
+        self.products_before = product_values(receptor, substrates);
+    }
+
+    fn catalysed(
+        &mut self,
+        id: usize,
+        receptor: &Receptor<ReactionType, StateType, InformationType>,
+        substrates: &[Substrate<ReactionType, StateType, InformationType>],
+    ) {
+        let products_before = std::mem::take(&mut self.products_before);
+        let products = receptor
+            .enzyme()
+            .products()
+            .iter()
+            .zip(products_before)
+            .zip(product_values(receptor, substrates))
+            .map(|((product, before), after)| SubstrateChange {
+                substrate: *product,
+                before,
+                after,
+            })
+            .collect();
+        self.current().catalyses.push(CatalysisTrace {
+            receptor: id,
+            products,
+        });
+    }
+
+    fn feedback(
+        &mut self,
+        input_feedback: &HashMap<usize, InformationType>,
+        output_feedback: &HashMap<usize, InformationType>,
+    ) {
+        let updates = |feedback: &HashMap<usize, InformationType>| {
+            let mut updates: Vec<FeedbackUpdate<InformationType>> = feedback
+                .iter()
+                .map(|(association, value)| FeedbackUpdate {
+                    association: *association,
+                    value: value.clone(),
+                })
+                .collect();
+            updates.sort_by_key(FeedbackUpdate::association);
+            updates
+        };
+        let current = self.current();
+        current.input_feedback = updates(input_feedback);
+        current.output_feedback = updates(output_feedback);
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,95 @@
+use crate::evolution::environment::EnvironmentBuilder;
+use crate::evolution::gene::GeneSubstrate;
+use crate::evolution::helper::testing::{neuron_chain_genome, neuron_organism, NeuronOrganism};
+use crate::evolution::helper::Nlbf64;
+use crate::evolution::neuron::SimpleNeuron;
+
+use super::*;
+
+/// Creates a [`NeuronOrganism`] of five neurons with the input at the first and the
+/// output at the third neuron. The first neuron excites the second, which excites the
+/// third and fifth.
+///
+/// # Parameters
+///
+/// * `finish` - if the fifth neuron is the finish substrate instead of an output
+///   feedback substrate
+fn test_organism(finish: bool) -> NeuronOrganism {
+    let mut genome = neuron_chain_genome();
+    if finish {
+        genome
+            .output_mut()
+            .set_finish_substrate(Some(GeneSubstrate::new(0, 4)));
+    } else {
+        genome
+            .output_mut()
+            .add_feedback_substrate(0, GeneSubstrate::new(0, 4));
+    }
+    neuron_organism(&genome)
+}
+
+#[test]
+/// Tests if the function `live_traced` of the [`Organism`] struct correctly records
+/// all iterations and exports them as JSON lines.
+fn test_organism_live_traced() {
+    let environment = EnvironmentBuilder::new().build().unwrap();
+    let mut untraced = test_organism(false);
+    untraced.live(&environment);
+    let mut organism = test_organism(false);
+    let (_, trace) = organism.live_traced(&environment);
+    assert_eq!(organism.get_result(), untraced.get_result());
+    assert_eq!(organism.time_alive(), untraced.time_alive());
+    assert_eq!(trace.termination(), TerminationReason::ActionsExhausted);
+    assert_eq!(trace.iterations().len(), 2);
+    let first = &trace.iterations()[0];
+    assert_eq!(first.iteration(), Iteration::new());
+    assert_eq!(first.scheduled(), &[0]);
+    assert_eq!(first.detected(), &[0]);
+    assert_eq!(first.catalyses().len(), 1);
+    assert_eq!(first.catalyses()[0].receptor(), 0);
+    let change = &first.catalyses()[0].products()[0];
+    assert_eq!(change.substrate(), 1);
+    assert_eq!(change.before().current_potential(), Nlbf64::from(0.0));
+    assert_eq!(change.after().current_potential(), Nlbf64::from(0.75));
+    assert!(first.input_feedback().is_empty());
+    assert!(first.output_feedback().is_empty());
+    let second = &trace.iterations()[1];
+    assert_eq!(second.iteration(), Iteration::new().increment());
+    assert_eq!(second.scheduled(), &[1, 2]);
+    assert_eq!(second.detected(), &[1, 2]);
+    assert_eq!(second.catalyses()[1].products()[0].substrate(), 4);
+    assert_eq!(second.output_feedback().len(), 1);
+    assert_eq!(second.output_feedback()[0].association(), 0);
+    assert_eq!(second.output_feedback()[0].value(), second.catalyses()[1].products()[0].after());
+    let mut json = Vec::new();
+    trace.write_json_lines(&mut json).unwrap();
+    let json = String::from_utf8(json).unwrap();
+    let events: Vec<TraceEvent<SimpleNeuron>> = json
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(events, trace.events());
+    assert_eq!(events.len(), 3);
+    assert!(json
+        .lines()
+        .last()
+        .unwrap()
+        .starts_with("{\"Termination\":"));
+}
+
+#[test]
+/// Tests if the function `live_traced` of the [`Organism`] struct correctly records the
+/// reason the run ended.
+fn test_organism_live_traced_termination() {
+    let environment = EnvironmentBuilder::new().build().unwrap();
+    let (_, trace) = test_organism(true).live_traced(&environment);
+    assert_eq!(trace.termination(), TerminationReason::Finished);
+    assert_eq!(trace.iterations().len(), 2);
+    let environment = EnvironmentBuilder::new()
+        .max_organism_size(1)
+        .build()
+        .unwrap();
+    let (_, trace) = test_organism(false).live_traced(&environment);
+    assert_eq!(trace.termination(), TerminationReason::SizeLimitExceeded);
+    assert!(trace.iterations().is_empty());
+}
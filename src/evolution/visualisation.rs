@@ -0,0 +1,545 @@
+//! The `visualisation` module renders [`Genome`]s and translated [`Organism`]s as
+//! [Graphviz](https://graphviz.org) DOT graphs.
+//!
+//! [`Substrate`]s are drawn as nodes, while [`Receptor`]s and their [`CatalyticCentre`]s
+//! are drawn as edges from the triggering to the product [`Substrate`]s labelled with
+//! the [`State`] and [`Reaction`]. [`Substrate`]s linked to the input, output, feedback
+//! or finish of the sensors are highlighted.
+//!
+//! [`CatalyticCentre`]: ../protein/struct.CatalyticCentre.html
+//! [`Genome`]: ../gene/struct.Genome.html
+//! [`Organism`]: ../population/struct.Organism.html
+//! [`Reaction`]: ../chemistry/trait.Reaction.html
+//! [`Receptor`]: ../protein/struct.Receptor.html
+//! [`State`]: ../chemistry/trait.State.html
+//! [`Substrate`]: ../protein/struct.Substrate.html
+
+use std::collections::HashMap;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::chemistry::{Information, Input, Output, Reaction, State};
+use super::gene::{GeneSubstrate, Genome};
+use super::knockout::{Knockout, KnockoutReport};
+use super::population::Organism;
+use super::protein::SubstrateType;
+
+/// The fill colour of input [`Substrate`]s.
+///
+/// [`Substrate`]: ../protein/struct.Substrate.html
+const COLOUR_INPUT: &str = "lightblue";
+/// The fill colour of output [`Substrate`]s.
+///
+/// [`Substrate`]: ../protein/struct.Substrate.html
+const COLOUR_OUTPUT: &str = "lightsalmon";
+/// The fill colour of feedback [`Substrate`]s.
+///
+/// [`Substrate`]: ../protein/struct.Substrate.html
+const COLOUR_FEEDBACK: &str = "khaki";
+/// The fill colour of the finish [`Substrate`].
+///
+/// [`Substrate`]: ../protein/struct.Substrate.html
+const COLOUR_FINISH: &str = "palegreen";
+
+/// Renders the [`Genome`] as DOT graph. Every [`Gene`] is drawn as cluster of its
+/// [`Substrate`]s, genome level associations as separate nodes connected to their
+/// associated [`Substrate`]s.
+///
+/// # Parameters
+///
+/// * `genome` - the [`Genome`] to render
+///
+/// [`Gene`]: ../gene/struct.Gene.html
+/// [`Genome`]: ../gene/struct.Genome.html
+/// [`Substrate`]: ../protein/struct.Substrate.html
+pub fn genome_to_dot<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    genome: &Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+) -> String {
+    genome_dot(genome, None)
+}
+
+/// Renders the [`Genome`] as DOT graph as described for [`genome_to_dot`] and overlays
+/// the fitness impact of the single knockouts contained in the [`KnockoutReport`].
+/// The impacts are appended to the labels of the knocked out [`Gene`]s, receptors and
+/// associations.
+///
+/// # Parameters
+///
+/// * `genome` - the [`Genome`] to render
+/// * `report` - the [`KnockoutReport`] of the [`Genome`]
+///
+/// [`Gene`]: ../gene/struct.Gene.html
+/// [`Genome`]: ../gene/struct.Genome.html
+/// [`genome_to_dot`]: ./fn.genome_to_dot.html
+/// [`KnockoutReport`]: ../knockout/struct.KnockoutReport.html
+pub fn genome_to_dot_with_knockouts<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    genome: &Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+    report: &KnockoutReport,
+) -> String {
+    genome_dot(genome, Some(report))
+}
+
+/// Renders the [`Genome`] as DOT graph optionally annotated with knockout impacts.
+///
+/// # Parameters
+///
+/// * `genome` - the [`Genome`] to render
+/// * `report` - the optional [`KnockoutReport`] of the [`Genome`]
+///
+/// [`Genome`]: ../gene/struct.Genome.html
+/// [`KnockoutReport`]: ../knockout/struct.KnockoutReport.html
+fn genome_dot<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    genome: &Genome<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+    report: Option<&KnockoutReport>,
+) -> String {
+    // Appends the knockout impact of the specified element to a label.
+    let annotate = |label: String, knockout: Knockout| match report
+        .and_then(|report| report.impact(&knockout))
+    {
+        Some(impact) => format!("{}\nimpact {:+.3}", label, impact),
+        None => label,
+    };
+    let node =
+        |substrate: &GeneSubstrate| format!("g{}s{}", substrate.gene(), substrate.substrate());
+    let mut dot = DotGraph::new("genome");
+    let mut colours: HashMap<String, &str> = HashMap::new();
+    for (index, substrate) in genome.input().input_substrates().iter().enumerate() {
+        if let Some(substrate) = substrate {
+            dot.sensor(&format!("input{}", index), &format!("input {}", index), "invhouse");
+            dot.edge(&format!("input{}", index), &node(substrate), "");
+            colours.insert(node(substrate), COLOUR_INPUT);
+        }
+    }
+    for (index, substrate) in sorted(genome.input().feedback_substrates()) {
+        let sensor = format!("input_feedback{}", index);
+        dot.sensor(&sensor, &format!("input feedback {}", index), "invhouse");
+        dot.edge(&node(substrate), &sensor, "style=dashed");
+        colours.insert(node(substrate), COLOUR_FEEDBACK);
+    }
+    for (index, substrate) in genome.output().output_substrates().iter().enumerate() {
+        if let Some(substrate) = substrate {
+            dot.sensor(&format!("output{}", index), &format!("output {}", index), "house");
+            dot.edge(&node(substrate), &format!("output{}", index), "");
+            colours.insert(node(substrate), COLOUR_OUTPUT);
+        }
+    }
+    for (index, substrate) in sorted(genome.output().feedback_substrates()) {
+        let sensor = format!("output_feedback{}", index);
+        dot.sensor(&sensor, &format!("output feedback {}", index), "house");
+        dot.edge(&node(substrate), &sensor, "style=dashed");
+        colours.insert(node(substrate), COLOUR_FEEDBACK);
+    }
+    if let Some(substrate) = genome.output().finish_substrate() {
+        dot.sensor("finish", "finish", "doubleoctagon");
+        dot.edge(&node(substrate), "finish", "");
+        colours.insert(node(substrate), COLOUR_FINISH);
+    }
+    for association_index in 0..genome.number_of_associations() {
+        if let Some(association) = genome.association(association_index) {
+            let id = format!("a{}", association_index);
+            dot.node(
+                &id,
+                &annotate(
+                    format!(
+                        "association {}\n{}",
+                        association_index,
+                        association.substrate().label()
+                    ),
+                    Knockout::Association(association_index),
+                ),
+                "shape=hexagon",
+            );
+            for substrate in association.associations() {
+                dot.edge(&id, &node(substrate), "style=dashed, arrowhead=none");
+            }
+        }
+    }
+    for gene_index in 0..genome.number_of_genes().get() {
+        let gene = genome.get_gene(gene_index);
+        dot.begin_cluster(
+            &format!("gene{}", gene_index),
+            &annotate(format!("gene {}", gene_index), Knockout::Gene(gene_index)),
+        );
+        for substrate_index in 0..gene.number_of_substrates().get() {
+            let substrate = GeneSubstrate::new(gene_index, substrate_index);
+            if let Some(value) = genome.get_substrate(substrate) {
+                let id = node(&substrate);
+                let attributes = substrate_attributes(colours.get(&id).copied());
+                dot.node(&id, &format!("{}\n{}", substrate_index, value.label()), &attributes);
+            }
+        }
+        for receptor_index in 0..gene.number_of_receptors() {
+            if let Some(receptor) = gene.receptor(receptor_index) {
+                let ids = |substrates: &[usize]| -> Vec<String> {
+                    substrates
+                        .iter()
+                        .map(|substrate| node(&GeneSubstrate::new(gene_index, *substrate)))
+                        .collect()
+                };
+                dot.receptor(
+                    &format!("g{}r{}", gene_index, receptor_index),
+                    &ids(receptor.triggers()),
+                    &ids(receptor.substrates()),
+                    &ids(receptor.enzyme().educts()),
+                    &ids(receptor.enzyme().products()),
+                    &annotate(
+                        format!(
+                            "{}\n{}",
+                            receptor.state().label(),
+                            receptor.enzyme().reaction().label()
+                        ),
+                        Knockout::Receptor {
+                            gene: gene_index,
+                            receptor: receptor_index,
+                        },
+                    ),
+                );
+            }
+        }
+        dot.end_cluster();
+    }
+    dot.finish()
+}
+
+/// Renders the translated [`Organism`] as DOT graph. Genome level associations are
+/// translated into single shared [`Substrate`]s, so the graph shows the network as
+/// it is executed.
+///
+/// # Parameters
+///
+/// * `organism` - the [`Organism`] to render
+///
+/// [`Organism`]: ../population/struct.Organism.html
+/// [`Substrate`]: ../protein/struct.Substrate.html
+pub fn organism_to_dot<
+    ReactionType: Reaction<InformationType>,
+    StateType: State<InformationType>,
+    InformationType: Information,
+    InputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    InputSensorType: Input<InputElementType, InformationType>,
+    OutputElementType: Clone + std::fmt::Debug + PartialEq + Send + Sync + Serialize + DeserializeOwned,
+    OutputSensorType: Output<OutputElementType, InformationType>,
+>(
+    organism: &Organism<
+        ReactionType,
+        StateType,
+        InformationType,
+        InputElementType,
+        InputSensorType,
+        OutputElementType,
+        OutputSensorType,
+    >,
+) -> String {
+    let node = |substrate: usize| format!("s{}", substrate);
+    let mut dot = DotGraph::new("organism");
+    let mut colours: HashMap<String, &str> = HashMap::new();
+    for (index, substrate) in organism
+        .input_sensor()
+        .input_substrates()
+        .iter()
+        .enumerate()
+    {
+        if let Some(substrate) = substrate {
+            dot.sensor(&format!("input{}", index), &format!("input {}", index), "invhouse");
+            dot.edge(&format!("input{}", index), &node(*substrate), "");
+            colours.insert(node(*substrate), COLOUR_INPUT);
+        }
+    }
+    for (index, substrate) in organism
+        .output_sensor()
+        .output_substrates()
+        .iter()
+        .enumerate()
+    {
+        if let Some(substrate) = substrate {
+            dot.sensor(&format!("output{}", index), &format!("output {}", index), "house");
+            dot.edge(&node(*substrate), &format!("output{}", index), "");
+            colours.insert(node(*substrate), COLOUR_OUTPUT);
+        }
+    }
+    // Receptors are shared between all their triggering substrates.
+    let mut triggers: Vec<Vec<String>> = vec![Vec::new(); organism.number_of_receptors()];
+    for (index, substrate) in organism.substrates().iter().enumerate() {
+        let id = node(index);
+        match substrate.substrate_type() {
+            SubstrateType::ConventionalSubstrate => {},
+            SubstrateType::InputFeedbackSubstrate(associations) => {
+                for association in associations {
+                    let sensor = format!("input_feedback{}", association);
+                    dot.sensor(&sensor, &format!("input feedback {}", association), "invhouse");
+                    dot.edge(&id, &sensor, "style=dashed");
+                }
+                colours.insert(id.clone(), COLOUR_FEEDBACK);
+            },
+            SubstrateType::OutputFeedbackSubstrate(associations) => {
+                for association in associations {
+                    let sensor = format!("output_feedback{}", association);
+                    dot.sensor(&sensor, &format!("output feedback {}", association), "house");
+                    dot.edge(&id, &sensor, "style=dashed");
+                }
+                colours.insert(id.clone(), COLOUR_FEEDBACK);
+            },
+            SubstrateType::OutputFinishSubstrate => {
+                dot.sensor("finish", "finish", "doubleoctagon");
+                dot.edge(&id, "finish", "");
+                colours.insert(id.clone(), COLOUR_FINISH);
+            },
+        }
+        let attributes = substrate_attributes(colours.get(&id).copied());
+        dot.node(&id, &format!("{}\n{}", index, substrate.last_value().label()), &attributes);
+        for receptor in substrate.receptors() {
+            triggers[*receptor].push(id.clone());
+        }
+    }
+    for (index, (receptor, triggers)) in organism.receptors().iter().zip(&triggers).enumerate() {
+        let ids = |substrates: &[usize]| {
+            substrates
+                .iter()
+                .map(|substrate| node(*substrate))
+                .collect::<Vec<String>>()
+        };
+        dot.receptor(
+            &format!("r{}", index),
+            triggers,
+            &ids(receptor.substrates()),
+            &ids(receptor.enzyme().educts()),
+            &ids(receptor.enzyme().products()),
+            &format!("{}\n{}", receptor.state().label(), receptor.enzyme().reaction().label()),
+        );
+    }
+    dot.finish()
+}
+
+/// Returns the node attributes of a [`Substrate`] highlighted with the specified colour.
+///
+/// # Parameters
+///
+/// * `colour` - the fill colour if the [`Substrate`] should be highlighted
+///
+/// [`Substrate`]: ../protein/struct.Substrate.html
+fn substrate_attributes(colour: Option<&str>) -> String {
+    colour.map_or_else(String::new, |colour| format!("style=filled, fillcolor={}", colour))
+}
+
+/// Returns the entries of the feedback map ordered by their sensor index.
+///
+/// # Parameters
+///
+/// * `feedback` - the feedback map
+fn sorted(feedback: &HashMap<usize, GeneSubstrate>) -> Vec<(&usize, &GeneSubstrate)> {
+    let mut feedback: Vec<(&usize, &GeneSubstrate)> = feedback.iter().collect();
+    feedback.sort_by_key(|(index, _)| **index);
+    feedback
+}
+
+/// Escapes the specified text for usage in a quoted DOT string.
+///
+/// # Parameters
+///
+/// * `text` - the text to escape
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// A `DotGraph` incrementally builds the textual representation of a directed DOT graph.
+/// Edges are collected separately and placed after all nodes, so nodes are always declared
+/// within their cluster first.
+struct DotGraph {
+    content: String,
+    edges: String,
+    indentation: usize,
+    declared: Vec<String>,
+}
+
+impl DotGraph {
+    /// Creates a new empty `DotGraph` with the specified name.
+    ///
+    /// # Parameters
+    ///
+    /// * `name` - the name of the graph
+    fn new(name: &str) -> Self {
+        let mut graph = DotGraph {
+            content: format!("digraph {} {{\n", name),
+            edges: String::new(),
+            indentation: 1,
+            declared: Vec::new(),
+        };
+        graph.line("rankdir=LR;");
+        graph.line("node [shape=ellipse];");
+        graph
+    }
+
+    /// Appends a single indented line.
+    ///
+    /// # Parameters
+    ///
+    /// * `line` - the line to append
+    fn line(&mut self, line: &str) {
+        self.content.push_str(&"    ".repeat(self.indentation));
+        self.content.push_str(line);
+        self.content.push('\n');
+    }
+
+    /// Declares a node with the specified label and additional attributes.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - the identifier of the node
+    /// * `label` - the label of the node
+    /// * `attributes` - additional comma separated attributes, which may be empty
+    fn node(&mut self, id: &str, label: &str, attributes: &str) {
+        let separator = if attributes.is_empty() { "" } else { ", " };
+        self.line(&format!("{} [label=\"{}\"{}{}];", id, escape(label), separator, attributes));
+    }
+
+    /// Declares a sensor node unless it was already declared.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - the identifier of the node
+    /// * `label` - the label of the node
+    /// * `shape` - the shape of the node
+    fn sensor(&mut self, id: &str, label: &str, shape: &str) {
+        if !self.declared.iter().any(|declared| declared == id) {
+            self.declared.push(id.to_string());
+            self.node(id, label, &format!("shape={}", shape));
+        }
+    }
+
+    /// Adds an edge between the specified nodes.
+    ///
+    /// # Parameters
+    ///
+    /// * `from` - the identifier of the source node
+    /// * `to` - the identifier of the target node
+    /// * `attributes` - comma separated attributes, which may be empty
+    fn edge(&mut self, from: &str, to: &str, attributes: &str) {
+        self.edges.push_str("    ");
+        if attributes.is_empty() {
+            self.edges.push_str(&format!("{} -> {};\n", from, to));
+        } else {
+            self.edges
+                .push_str(&format!("{} -> {} [{}];\n", from, to, attributes));
+        }
+    }
+
+    /// Adds the edges of a receptor. Each trigger is connected to each product with an
+    /// edge labelled with the state and reaction. State and educt substrates, which are no
+    /// triggers, are connected by dashed and dotted edges respectively. If the reaction has
+    /// no products, the edges end in a point node.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - the identifier of the receptor
+    /// * `triggers` - the node identifiers of the triggering substrates
+    /// * `states` - the node identifiers of the substrates the state is checked for
+    /// * `educts` - the node identifiers of the educts
+    /// * `products` - the node identifiers of the products
+    /// * `label` - the label describing the state and reaction
+    fn receptor(
+        &mut self,
+        id: &str,
+        triggers: &[String],
+        states: &[String],
+        educts: &[String],
+        products: &[String],
+        label: &str,
+    ) {
+        let targets = if products.is_empty() {
+            self.line(&format!("{} [shape=point];", id));
+            vec![id.to_string()]
+        } else {
+            products.to_vec()
+        };
+        let label = escape(label);
+        for target in &targets {
+            for trigger in triggers {
+                self.edge(trigger, target, &format!("label=\"{}\"", label));
+            }
+            for state in states.iter().filter(|state| !triggers.contains(state)) {
+                self.edge(state, target, "style=dashed, label=\"state\"");
+            }
+            for educt in educts.iter().filter(|educt| !triggers.contains(educt)) {
+                self.edge(educt, target, "style=dotted, label=\"educt\"");
+            }
+        }
+    }
+
+    /// Opens a cluster subgraph.
+    ///
+    /// # Parameters
+    ///
+    /// * `id` - the identifier of the cluster
+    /// * `label` - the label of the cluster
+    fn begin_cluster(&mut self, id: &str, label: &str) {
+        self.line(&format!("subgraph cluster_{} {{", id));
+        self.indentation += 1;
+        self.line(&format!("label=\"{}\";", escape(label)));
+    }
+
+    /// Closes the current cluster subgraph.
+    fn end_cluster(&mut self) {
+        self.indentation -= 1;
+        self.line("}");
+    }
+
+    /// Closes the graph and returns its textual representation.
+    fn finish(mut self) -> String {
+        self.content.push_str(&self.edges);
+        self.content.push_str("}\n");
+        self.content
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,103 @@
+use std::num::NonZeroU32;
+
+use crate::evolution::gene::{
+    Gene, GeneAssociation, GenomicCatalyticCentre, GenomicInputSensor, GenomicOutputSensor,
+    GenomicReceptor,
+};
+use crate::evolution::helper::testing::{
+    TestGenome, TestInformation, TestInput, TestOutput, TestReaction, TestState,
+};
+use crate::evolution::neuron::random_genome;
+
+use super::*;
+
+/// Checks if every opening brace of the DOT graph is closed.
+fn is_balanced(dot: &str) -> bool {
+    dot.matches('{').count() == dot.matches('}').count()
+}
+
+#[test]
+/// Tests if the function `genome_to_dot` correctly renders genes as clusters and highlights
+/// the sensor substrates.
+fn test_genome_to_dot() {
+    let mut gene = Gene::new(vec![
+        TestInformation { value: 0 },
+        TestInformation { value: 1 },
+        TestInformation { value: 2 },
+    ]);
+    gene.add_receptor(GenomicReceptor::new(
+        vec![0],
+        vec![],
+        TestState {},
+        GenomicCatalyticCentre::new(vec![], vec![], TestReaction {}),
+    ));
+    let mut feedback = HashMap::new();
+    feedback.insert(3, GeneSubstrate::new(1, 0));
+    let mut genome: TestGenome = Genome::new(
+        GenomicInputSensor::new(
+            vec![Some(GeneSubstrate::new(0, 0)), None],
+            HashMap::new(),
+            TestInput::default(),
+        ),
+        GenomicOutputSensor::new(
+            vec![Some(GeneSubstrate::new(0, 1))],
+            feedback,
+            Some(GeneSubstrate::new(0, 2)),
+            TestOutput::default(),
+        ),
+        vec![gene, Gene::new(vec![TestInformation { value: 3 }])],
+    );
+    let mut association = GeneAssociation::new(TestInformation { value: 4 });
+    association.add_association(GeneSubstrate::new(1, 0));
+    genome.add_association(association);
+    let dot = genome_to_dot(&genome);
+    assert!(dot.starts_with("digraph genome {"));
+    assert!(is_balanced(&dot));
+    assert!(dot.contains("subgraph cluster_gene0 {"));
+    assert!(dot.contains("subgraph cluster_gene1 {"));
+    assert!(dot.contains(
+        "g0s0 [label=\"0\\nTestInformation { value: 0 }\", style=filled, fillcolor=lightblue];"
+    ));
+    assert!(dot.contains("fillcolor=lightsalmon"));
+    assert!(dot.contains("fillcolor=palegreen"));
+    assert!(dot.contains("fillcolor=khaki"));
+    assert!(dot.contains("input0 -> g0s0;"));
+    assert!(!dot.contains("input1"));
+    assert!(dot.contains("g0s1 -> output0;"));
+    assert!(dot.contains("g0s2 -> finish;"));
+    assert!(dot.contains("g1s0 -> output_feedback3 [style=dashed];"));
+    assert!(dot.contains("a0 -> g1s0 [style=dashed, arrowhead=none];"));
+    assert!(dot.contains("g0r0 [shape=point];"));
+    assert!(dot.contains("g0s0 -> g0r0 [label=\"TestState\\nTestReaction\"];"));
+}
+
+#[test]
+/// Tests if the functions `genome_to_dot` and `organism_to_dot` correctly render the
+/// neuronal chemistry and every translated substrate exactly once.
+fn test_organism_to_dot() {
+    let genome = random_genome(NonZeroU32::new(6).unwrap(), NonZeroU32::new(10).unwrap(), 2, 2);
+    let dot = genome_to_dot(&genome);
+    assert!(is_balanced(&dot));
+    assert!(dot.contains("threshold "));
+    assert!(dot.contains("weight "));
+    assert!(dot.contains("potential "));
+    let organism = genome.translate();
+    let dot = organism_to_dot(&organism);
+    assert!(dot.starts_with("digraph organism {"));
+    assert!(is_balanced(&dot));
+    let nodes = dot
+        .lines()
+        .filter(|line| line.trim_start().starts_with('s') && !line.contains("->"))
+        .count();
+    assert_eq!(nodes, organism.number_of_substrates());
+    assert!(dot.contains("input0 -> s"));
+    assert!(dot.contains("-> output0;"));
+    assert!(dot.contains("label=\"threshold "));
+    assert!(dot.contains("\\nweight "));
+}
+
+#[test]
+/// Tests if the function `escape` correctly escapes quotes, backslashes and line breaks.
+fn test_escape() {
+    assert_eq!(escape("a \"b\"\\c\nd"), "a \\\"b\\\"\\\\c\\nd");
+}